@@ -32,6 +32,11 @@ pub enum PylotError {
     #[error("Invalid package name: {0}")]
     InvalidPackageName(String),
 
+    /// A Python version request could not be parsed or no available
+    /// interpreter satisfies it.
+    #[error("Invalid Python version: {0}")]
+    InvalidPythonVersion(String),
+
     /// A required environment variable (e.g. `HOME`) is not set.
     #[error("Environment variable not set: {0}")]
     EnvVarNotSet(String),
@@ -45,6 +50,11 @@ pub enum PylotError {
     #[error("Settings error: {0}")]
     Settings(String),
 
+    /// A request to a remote service (e.g. a package index) failed or returned
+    /// an unexpected response.
+    #[error("Network error: {0}")]
+    Network(String),
+
     /// The user explicitly cancelled an interactive prompt.
     #[error("Cancelled by user")]
     Cancelled,
@@ -120,6 +130,12 @@ mod tests {
         assert_eq!(err.to_string(), "Invalid package name: bad;pkg");
     }
 
+    #[test]
+    fn test_invalid_python_version_display() {
+        let err = PylotError::InvalidPythonVersion("3.x".to_string());
+        assert_eq!(err.to_string(), "Invalid Python version: 3.x");
+    }
+
     #[test]
     fn test_env_var_not_set_display() {
         let err = PylotError::EnvVarNotSet("SHELL".to_string());
@@ -138,6 +154,12 @@ mod tests {
         assert_eq!(err.to_string(), "Settings error: missing key");
     }
 
+    #[test]
+    fn test_network_error_display() {
+        let err = PylotError::Network("connection refused".to_string());
+        assert_eq!(err.to_string(), "Network error: connection refused");
+    }
+
     #[test]
     fn test_cancelled_display() {
         let err = PylotError::Cancelled;
@@ -0,0 +1,645 @@
+//! Non-interactive API for embedding Pylot
+//!
+//! The commands at the crate root serve the CLI: they print tables, log what
+//! they did and ask on stdin when a name or a confirmation is missing. The
+//! functions here never prompt and print no tables. Environments are named
+//! explicitly, results come back as values and progress, including every line
+//! uv prints, goes to the handler of the context, see
+//! [`Pylot::with_progress`]. Like the rest of Pylot they still log through the
+//! `log` crate, so the output of uv also reaches the logger if the embedder
+//! installed one.
+//!
+//! # Examples
+//! ```no_run
+//! use pylot::api::{self, CreateOptions};
+//! use pylot_shared::{progress::ProgressEvent, Pylot};
+//!
+//! # async fn example() -> pylot_shared::Result<()> {
+//! let ctx = Pylot::load().with_progress(|event| {
+//!     if let ProgressEvent::Step(step) = event {
+//!         eprintln!("{}", step);
+//!     }
+//! });
+//! let options = CreateOptions {
+//!     python_version: Some("3.12".to_string()),
+//!     packages: vec!["numpy".to_string()],
+//!     ..Default::default()
+//! };
+//! let venv = api::create(Some(&ctx), "api", options).await?;
+//! for package in venv.packages {
+//!     println!("{} {}", package.name, package.version);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{borrow::Cow, io, path::PathBuf};
+
+use pylot_shared::{
+    constants::{DEFAULT_PYTHON_VERSION, ERROR_CREATING_VENV},
+    error::{PylotError, Result},
+    progress::ProgressEvent,
+    pyrequest, utils, uvctrl,
+    uvvenv::UvVenv,
+    venvtraits::{Create, Delete},
+    Pylot,
+};
+
+use crate::context;
+
+/// How [`create`] sets up a new virtual environment
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CreateOptions {
+    /// The Python version request, e.g. `3.12`, `>=3.10`, `pypy@3.10` or a
+    /// path; [`DEFAULT_PYTHON_VERSION`] when `None`
+    pub python_version: Option<String>,
+    /// Packages to install
+    pub packages: Vec<String>,
+    /// A requirements file whose packages are installed as well
+    pub requirements: Option<String>,
+    /// Also install the `default_pkgs` from settings.toml
+    pub default_packages: bool,
+}
+
+/// A virtual environment made by [`create`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedVenv {
+    pub name: String,
+    pub path: PathBuf,
+    /// The Python version from the environment's `pyvenv.cfg`
+    pub python_version: String,
+    pub packages: Vec<PackageInfo>,
+}
+
+/// A virtual environment found under the venvs root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VenvInfo {
+    pub name: String,
+    pub path: PathBuf,
+    /// The Python version from the environment's `pyvenv.cfg`
+    pub python_version: String,
+    pub package_count: usize,
+    /// Size on disk in bytes
+    pub size: u64,
+}
+
+/// A package installed in a virtual environment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageInfo {
+    /// The normalized name, e.g. `typing-extensions`
+    pub name: String,
+    pub version: String,
+}
+
+impl PackageInfo {
+    /// Parse a `"name version"` entry of [`UvVenv::installed_packages`].
+    fn parse(entry: &str) -> Self {
+        let (name, version) = entry.split_once(' ').unwrap_or((entry, ""));
+        PackageInfo {
+            name: name.to_string(),
+            version: version.to_string(),
+        }
+    }
+}
+
+impl From<&UvVenv<'_>> for VenvInfo {
+    fn from(venv: &UvVenv<'_>) -> Self {
+        VenvInfo {
+            name: venv.name.to_string(),
+            path: PathBuf::from(&venv.path),
+            python_version: venv.python_version.clone(),
+            package_count: venv.package_count.unwrap_or(0),
+            size: venv.disk_usage.map(|u| u.total()).unwrap_or(0),
+        }
+    }
+}
+
+fn package_infos(venv: &UvVenv<'_>) -> Vec<PackageInfo> {
+    venv.installed_packages
+        .iter()
+        .map(|entry| PackageInfo::parse(entry))
+        .collect()
+}
+
+/// List the virtual environments under the venvs root
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+///
+/// # Returns
+/// * `Vec<VenvInfo>` - The environments, in directory order
+pub async fn list(ctx: Option<&Pylot>) -> Vec<VenvInfo> {
+    let ctx = context(ctx);
+    ctx.manager()
+        .list()
+        .await
+        .iter()
+        .map(VenvInfo::from)
+        .collect()
+}
+
+/// Describe a virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The name of the virtual environment
+///
+/// # Returns
+/// * `Result<VenvInfo>` - The environment, or `VenvNotFound`
+pub async fn info(ctx: Option<&Pylot>, name: &str) -> Result<VenvInfo> {
+    let ctx = context(ctx);
+    let venv = ctx.manager().get(name).await?;
+    Ok(VenvInfo::from(&venv))
+}
+
+/// List the packages installed in a virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The name of the virtual environment
+///
+/// # Returns
+/// * `Result<Vec<PackageInfo>>` - The packages, sorted by name
+pub async fn packages(ctx: Option<&Pylot>, name: &str) -> Result<Vec<PackageInfo>> {
+    let ctx = context(ctx);
+    let venv = ctx.manager().get(name).await?;
+    Ok(package_infos(&venv))
+}
+
+/// Create a new virtual environment
+///
+/// The Python request is checked against the available interpreters before
+/// anything is created, and a partially created environment is removed again
+/// when uv fails.
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The name of the virtual environment
+/// * `options` - The Python version and packages to set it up with
+///
+/// # Returns
+/// * `Result<CreatedVenv>` - The new environment
+pub async fn create(
+    ctx: Option<&Pylot>,
+    name: &str,
+    options: CreateOptions,
+) -> Result<CreatedVenv> {
+    let ctx = context(ctx);
+    UvVenv::validate_venv_name(name)?;
+    require_uv(&ctx).await?;
+
+    let manager = ctx.manager();
+    if manager.check_if_exists(name).await {
+        return Err(PylotError::VenvExists(format!(
+            "A virtual environment with the name {} already exists",
+            name
+        )));
+    }
+
+    let python_version = options
+        .python_version
+        .unwrap_or_else(|| DEFAULT_PYTHON_VERSION.to_string());
+    if let Some(resolved) = pyrequest::validate(&ctx, &python_version).await? {
+        ctx.report(ProgressEvent::Step(format!("Using Python {}", resolved)));
+    }
+
+    let mut packages = options.packages;
+    if let Some(req) = &options.requirements {
+        update_packages_from_requirements(req, &mut packages).await?;
+    }
+    for pkg in &packages {
+        UvVenv::validate_package_name(pkg)?;
+    }
+
+    ctx.report(ProgressEvent::Step(format!(
+        "Creating virtual environment '{}'",
+        name
+    )));
+    let venv = UvVenv::new(
+        &ctx,
+        Cow::Borrowed(name),
+        "".to_owned(),
+        python_version,
+        packages,
+        options.default_packages,
+    );
+    if let Err(e) = venv.create().await {
+        ctx.report(ProgressEvent::Step(format!(
+            "Removing the partially created '{}'",
+            name
+        )));
+        let _ = venv.delete(io::empty(), false).await;
+        return Err(PylotError::Other(format!("{}: {}", ERROR_CREATING_VENV, e)));
+    }
+
+    let venv = manager.get(name).await?;
+    Ok(CreatedVenv {
+        name: name.to_string(),
+        path: PathBuf::from(&venv.path),
+        python_version: venv.python_version.clone(),
+        packages: package_infos(&venv),
+    })
+}
+
+/// Delete a virtual environment without asking for confirmation
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The name of the virtual environment
+///
+/// # Returns
+/// * `Result<()>` - Ok if deleted
+pub async fn delete(ctx: Option<&Pylot>, name: &str) -> Result<()> {
+    let ctx = context(ctx);
+    let venv = ctx.manager().get(name).await?;
+    ctx.report(ProgressEvent::Step(format!(
+        "Deleting virtual environment '{}'",
+        name
+    )));
+    venv.delete(io::empty(), false).await
+}
+
+/// Install packages into a virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The name of the virtual environment
+/// * `packages` - Packages to install
+/// * `requirements` - A requirements file to install packages from
+///
+/// # Returns
+/// * `Result<Vec<PackageInfo>>` - The packages installed afterwards
+pub async fn add(
+    ctx: Option<&Pylot>,
+    name: &str,
+    packages: Vec<String>,
+    requirements: Option<&str>,
+) -> Result<Vec<PackageInfo>> {
+    let ctx = context(ctx);
+    let manager = ctx.manager();
+    let venv = manager.get(name).await?;
+    require_uv(&ctx).await?;
+    if !packages.is_empty() {
+        ctx.report(ProgressEvent::Step(format!(
+            "Installing {} into '{}'",
+            packages.join(", "),
+            name
+        )));
+        venv.add_packages(packages).await?;
+    }
+    if let Some(req) = requirements {
+        ctx.report(ProgressEvent::Step(format!(
+            "Installing from {} into '{}'",
+            req, name
+        )));
+        venv.install_from_requirements(req).await?;
+    }
+    Ok(package_infos(&manager.get(name).await?))
+}
+
+/// Uninstall packages from a virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The name of the virtual environment
+/// * `packages` - Packages to uninstall
+///
+/// # Returns
+/// * `Result<Vec<PackageInfo>>` - The packages installed afterwards
+pub async fn remove(
+    ctx: Option<&Pylot>,
+    name: &str,
+    packages: Vec<String>,
+) -> Result<Vec<PackageInfo>> {
+    let ctx = context(ctx);
+    let manager = ctx.manager();
+    let venv = manager.get(name).await?;
+    require_uv(&ctx).await?;
+    ctx.report(ProgressEvent::Step(format!(
+        "Uninstalling {} from '{}'",
+        packages.join(", "),
+        name
+    )));
+    venv.remove_packages(packages).await?;
+    Ok(package_infos(&manager.get(name).await?))
+}
+
+/// Upgrade packages in a virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The name of the virtual environment
+/// * `packages` - Packages to upgrade
+///
+/// # Returns
+/// * `Result<Vec<PackageInfo>>` - The packages installed afterwards
+pub async fn upgrade(
+    ctx: Option<&Pylot>,
+    name: &str,
+    packages: Vec<String>,
+) -> Result<Vec<PackageInfo>> {
+    let ctx = context(ctx);
+    let manager = ctx.manager();
+    let venv = manager.get(name).await?;
+    require_uv(&ctx).await?;
+    ctx.report(ProgressEvent::Step(format!(
+        "Upgrading {} in '{}'",
+        packages.join(", "),
+        name
+    )));
+    venv.upgrade_packages(packages).await?;
+    Ok(package_infos(&manager.get(name).await?))
+}
+
+/// Upgrade every package installed in a virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The name of the virtual environment
+///
+/// # Returns
+/// * `Result<Vec<PackageInfo>>` - The packages installed afterwards
+pub async fn upgrade_all(ctx: Option<&Pylot>, name: &str) -> Result<Vec<PackageInfo>> {
+    let ctx = context(ctx);
+    let manager = ctx.manager();
+    let mut venv = manager.get(name).await?;
+    require_uv(&ctx).await?;
+    ctx.report(ProgressEvent::Step(format!(
+        "Upgrading every package in '{}'",
+        name
+    )));
+    venv.upgrade_all_packages().await?;
+    Ok(package_infos(&manager.get(name).await?))
+}
+
+pub(crate) async fn update_packages_from_requirements(
+    requirements: &str,
+    packages: &mut Vec<String>,
+) -> Result<()> {
+    if !requirements.is_empty() {
+        let read_pkgs = utils::read_requirements_file(requirements)
+            .await
+            .map_err(|e| PylotError::Other(e.to_string()))?;
+
+        // Preserve package order while deduplicating
+        // This ensures installation order is maintained, which can matter
+        // for packages with conflicting dependencies or when using --no-deps
+        for req in read_pkgs {
+            if !packages.contains(&req) {
+                packages.push(req);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) async fn require_uv(ctx: &Pylot) -> Result<()> {
+    uvctrl::check(ctx, "uv").await.map(|_| ()).map_err(|e| {
+        if ctx.runner.which(&ctx.settings.uv_program()).is_some() {
+            // Installed, but outside `required_uv_version`.
+            return PylotError::Other(e.to_string());
+        }
+        PylotError::Other(format!(
+            "Astral UV is not installed. Please run '{} uv install' to install it.",
+            env!("CARGO_PKG_NAME")
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::Path,
+        sync::{Arc, Mutex},
+    };
+
+    use pylot_shared::{
+        logger,
+        processes::{fake_venv, CommandOutput, RecordingRunner, Stream},
+        settings::UvVersionPolicy,
+    };
+    use tempfile::tempdir;
+
+    use super::*;
+
+    const PYTHON_LIST: &str = "cpython-3.12.4-linux-x86_64-gnu    /usr/bin/python3.12\n";
+
+    /// A runner that fakes uv for an environment named `api` in `root`, where
+    /// `pip install` always installs numpy.
+    fn fake_uv(root: &Path) -> RecordingRunner {
+        let site_packages = root
+            .join("api")
+            .join("lib")
+            .join("python3.12")
+            .join("site-packages");
+        RecordingRunner::new()
+            .with_program("uv", "/usr/bin/uv")
+            .respond("python list", CommandOutput::ok(PYTHON_LIST))
+            .respond_with("pip install", move |_| {
+                std::fs::create_dir_all(site_packages.join("numpy-2.0.0.dist-info")).unwrap();
+                CommandOutput::ok("Installed 1 package")
+            })
+            .respond_with("venv", fake_venv)
+    }
+
+    fn recording_context(
+        root: &Path,
+        runner: RecordingRunner,
+    ) -> (Pylot, Arc<Mutex<Vec<ProgressEvent>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let ctx = Pylot::default()
+            .with_runner(Arc::new(runner))
+            .with_venvs_path(root.to_str().unwrap())
+            .with_progress(move |event| sink.lock().unwrap().push(event));
+        (ctx, events)
+    }
+
+    // ── create ───────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_create_returns_created_venv_and_reports_progress() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempdir().unwrap();
+        let (ctx, events) = recording_context(root.path(), fake_uv(root.path()));
+        let options = CreateOptions {
+            python_version: Some("3.12".to_string()),
+            packages: vec!["numpy".to_string()],
+            ..Default::default()
+        };
+
+        let venv = create(Some(&ctx), "api", options).await.unwrap();
+        assert_eq!(venv.name, "api");
+        assert_eq!(venv.path, root.path().join("api"));
+        assert_eq!(venv.python_version, "3.12.4");
+        assert_eq!(
+            venv.packages,
+            [PackageInfo {
+                name: "numpy".to_string(),
+                version: "2.0.0".to_string(),
+            }]
+        );
+
+        let events = events.lock().unwrap();
+        assert!(events.contains(&ProgressEvent::Step(
+            "Creating virtual environment 'api'".to_string()
+        )));
+        assert!(events.contains(&ProgressEvent::Output {
+            stream: Stream::Stdout,
+            line: "Installed 1 package".to_string(),
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_create_existing_venv() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("api")).unwrap();
+        let (ctx, _) = recording_context(root.path(), fake_uv(root.path()));
+        let result = create(Some(&ctx), "api", CreateOptions::default()).await;
+        assert!(matches!(result, Err(PylotError::VenvExists(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_reports_uv_outside_required_version() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempdir().unwrap();
+        let runner = fake_uv(root.path()).respond("--version", CommandOutput::ok("uv 0.4.30"));
+        let (mut ctx, _) = recording_context(root.path(), runner);
+        ctx.settings.required_uv_version = Some(">=0.5".to_string());
+        ctx.settings.uv_version_policy = UvVersionPolicy::Fail;
+
+        let result = create(Some(&ctx), "api", CreateOptions::default()).await;
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("0.4.30"), "{}", message);
+        assert!(!message.contains("not installed"), "{}", message);
+        assert!(!root.path().join("api").exists());
+    }
+
+    #[tokio::test]
+    async fn test_create_removes_partial_venv_on_failure() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempdir().unwrap();
+        let runner = RecordingRunner::new()
+            .with_program("uv", "/usr/bin/uv")
+            .respond("python list", CommandOutput::ok(PYTHON_LIST))
+            .respond(
+                "pip install",
+                CommandOutput::failed(2, "error: no such package"),
+            )
+            .respond_with("venv", fake_venv);
+        let (ctx, _) = recording_context(root.path(), runner);
+        let options = CreateOptions {
+            packages: vec!["numpy".to_string()],
+            ..Default::default()
+        };
+
+        let result = create(Some(&ctx), "api", options).await;
+        assert!(result.is_err());
+        assert!(!root.path().join("api").exists());
+    }
+
+    // ── list / info / packages ───────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_list_info_and_packages() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempdir().unwrap();
+        let (ctx, _) = recording_context(root.path(), fake_uv(root.path()));
+        let options = CreateOptions {
+            packages: vec!["numpy".to_string()],
+            ..Default::default()
+        };
+        create(Some(&ctx), "api", options).await.unwrap();
+
+        let venvs = list(Some(&ctx)).await;
+        assert_eq!(venvs.len(), 1);
+        assert_eq!(venvs[0], info(Some(&ctx), "api").await.unwrap());
+        assert_eq!(venvs[0].package_count, 1);
+        assert!(venvs[0].size > 0);
+
+        let pkgs = packages(Some(&ctx), "api").await.unwrap();
+        assert_eq!(pkgs[0].name, "numpy");
+        assert!(matches!(
+            info(Some(&ctx), "missing").await,
+            Err(PylotError::VenvNotFound(_))
+        ));
+    }
+
+    // ── delete ───────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_delete_does_not_prompt() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempdir().unwrap();
+        let (ctx, events) = recording_context(root.path(), fake_uv(root.path()));
+        create(Some(&ctx), "api", CreateOptions::default())
+            .await
+            .unwrap();
+
+        delete(Some(&ctx), "api").await.unwrap();
+        assert!(!root.path().join("api").exists());
+        assert!(events.lock().unwrap().contains(&ProgressEvent::Step(
+            "Deleting virtual environment 'api'".to_string()
+        )));
+        assert!(matches!(
+            delete(Some(&ctx), "api").await,
+            Err(PylotError::VenvNotFound(_))
+        ));
+    }
+
+    // ── add ──────────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_add_returns_installed_packages() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempdir().unwrap();
+        let (ctx, _) = recording_context(root.path(), fake_uv(root.path()));
+        create(Some(&ctx), "api", CreateOptions::default())
+            .await
+            .unwrap();
+        assert!(packages(Some(&ctx), "api").await.unwrap().is_empty());
+
+        let pkgs = add(Some(&ctx), "api", vec!["numpy".to_string()], None)
+            .await
+            .unwrap();
+        assert_eq!(pkgs.len(), 1);
+        assert_eq!(pkgs[0].version, "2.0.0");
+    }
+
+    #[tokio::test]
+    async fn test_package_changes_need_uv() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempdir().unwrap();
+        let (ctx, _) = recording_context(root.path(), fake_uv(root.path()));
+        create(Some(&ctx), "api", CreateOptions::default())
+            .await
+            .unwrap();
+        let runner = Arc::new(RecordingRunner::new());
+        let no_uv = ctx.clone().with_runner(runner.clone());
+
+        let numpy = || vec!["numpy".to_string()];
+        let results = [
+            add(Some(&no_uv), "api", numpy(), None).await,
+            remove(Some(&no_uv), "api", numpy()).await,
+            upgrade(Some(&no_uv), "api", numpy()).await,
+            upgrade_all(Some(&no_uv), "api").await,
+        ];
+        for result in results {
+            let message = result.unwrap_err().to_string();
+            assert!(message.contains("not installed"), "{}", message);
+        }
+        assert!(runner.calls().is_empty());
+    }
+
+    // ── PackageInfo ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_package_info_parse() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let info = PackageInfo::parse("typing-extensions 4.12.2");
+        assert_eq!(info.name, "typing-extensions");
+        assert_eq!(info.version, "4.12.2");
+        assert_eq!(PackageInfo::parse("numpy").version, "");
+    }
+}
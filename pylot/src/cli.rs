@@ -1,2 +1,3 @@
 pub mod cmds;
+pub mod progress;
 mod styles;
@@ -1,6 +1,10 @@
 use super::styles;
 use clap::{Parser, Subcommand};
-use pylot_shared::constants::DEFAULT_PYTHON_VERSION;
+use pylot_shared::{
+    constants::DEFAULT_PYTHON_VERSION, diskusage::SizeSort, pyrequest::PythonRequest,
+    venvquery::VenvSort,
+};
+use std::path::PathBuf;
 use styles::custom_styles;
 
 /// Command Line Interface for Pylot
@@ -24,7 +28,9 @@ pub struct Cli {
 /// # Usage
 /// * `pylot uv` - UV management commands
 /// * `pylot venv` - Virtual environment management commands
+/// * `pylot python` - Python interpreter management commands
 /// * `pylot complete` - Shell completion script generation
+/// * `pylot doctor` - Check the toolchain Pylot depends on
 #[derive(Debug, Subcommand)]
 pub enum Commands {
     /// UV management commands
@@ -55,6 +61,35 @@ pub enum Commands {
         #[command(subcommand)]
         command: VenvCommands,
     },
+    /// Python interpreter management commands
+    ///
+    /// # Usage
+    /// * `pylot python` - Manage Python interpreters
+    /// * `pylot py` - Manage Python interpreters (alias)
+    #[command(
+        visible_alias = "py",
+        about = "Commands for managing Python interpreters",
+        long_about = "This command group contains commands for managing the Python interpreters Astral UV can use"
+    )]
+    Python {
+        #[command(subcommand)]
+        command: PythonCommands,
+    },
+    /// CLI tool management commands
+    ///
+    /// # Usage
+    /// * `pylot tool` - Manage CLI tools installed with uv
+    /// * `pylot t` - Manage CLI tools installed with uv (alias)
+    #[command(
+        visible_alias = "t",
+        about = "Commands for managing CLI tools",
+        long_about = "This command group manages globally installed CLI tools such as ruff or pylint via 'uv tool'. \
+            Each tool gets its own isolated environment, so it does not need to be installed in every virtual environment."
+    )]
+    Tool {
+        #[command(subcommand)]
+        command: ToolCommands,
+    },
     /// Shell completion script generation
     ///
     /// # Usage
@@ -86,6 +121,157 @@ pub enum Commands {
         long_about = "Launches an interactive terminal user interface to list and manage Python virtual environments and Astral UV"
     )]
     Tui,
+    /// Check the toolchain Pylot depends on
+    ///
+    /// # Usage
+    /// * `pylot doctor` - Print a pass/warn/fail report
+    #[command(
+        about = "Check the toolchain Pylot depends on",
+        long_about = "Checks that Astral UV is installed and reports its version, whether ~/.local/bin is on PATH, the detected shell, \
+            which settings file is in use, whether the venvs path is writable, and the uv cache directory and installed Pythons.\n\
+            Every check is reported as pass, warn or fail, with a suggested fix for problems."
+    )]
+    Doctor,
+}
+
+/// Python interpreter management commands
+///
+/// # Usage
+/// * `pylot python` - Python interpreter management commands
+/// * `pylot py` - Python interpreter management commands (alias)
+#[derive(Subcommand, Debug)]
+pub enum PythonCommands {
+    /// List Python interpreters
+    ///
+    /// # Usage
+    /// * `pylot python list` - List installed and downloadable interpreters
+    /// * `pylot py ls --all-versions` - Include every patch release (alias)
+    #[command(
+        visible_aliases = ["l", "ls"],
+        about = "List Python interpreters",
+        long_about = "Lists installed and downloadable Python interpreters and the virtual environments created from each one"
+    )]
+    List {
+        #[arg(long, help = "Include every downloadable patch release")]
+        all_versions: bool,
+    },
+    /// Install a Python version
+    ///
+    /// # Usage
+    /// * `pylot python install 3.12` - Install Python 3.12
+    /// * `pylot py i 3.12` - Install Python 3.12 (alias)
+    #[command(
+        visible_alias = "i",
+        about = "Install a Python version",
+        long_about = "This command installs a Python version with Astral UV"
+    )]
+    Install {
+        #[arg(value_name = "VERSION", help = "Python version to install, e.g. 3.12")]
+        python_version: String,
+    },
+    /// Uninstall a Python version
+    ///
+    /// # Usage
+    /// * `pylot python uninstall 3.12` - Uninstall Python 3.12
+    /// * `pylot py u 3.12` - Uninstall Python 3.12 (alias)
+    #[command(
+        visible_alias = "u",
+        about = "Uninstall a Python version",
+        long_about = "This command uninstalls a Python version managed by Astral UV. \
+            You are asked to confirm when virtual environments use it."
+    )]
+    Uninstall {
+        #[arg(
+            value_name = "VERSION",
+            help = "Python version to uninstall, e.g. 3.12"
+        )]
+        python_version: String,
+    },
+    /// Pin the Python version of the current directory
+    ///
+    /// # Usage
+    /// * `pylot python pin 3.12` - Write `.python-version` with 3.12
+    /// * `pylot py p` - Show the current pin (alias)
+    #[command(
+        visible_alias = "p",
+        about = "Pin the Python version of the current directory",
+        long_about = "Writes a .python-version file in the current directory. Without a version the current pin is shown"
+    )]
+    Pin {
+        #[arg(value_name = "VERSION", help = "Python version to pin, e.g. 3.12")]
+        python_version: Option<String>,
+    },
+}
+
+/// CLI tool management commands
+///
+/// # Usage
+/// * `pylot tool` - CLI tool management commands
+/// * `pylot t` - CLI tool management commands (alias)
+#[derive(Subcommand, Debug)]
+pub enum ToolCommands {
+    /// Install a CLI tool
+    ///
+    /// # Usage
+    /// * `pylot tool install ruff` - Install ruff as a global tool
+    /// * `pylot t i ruff==0.3.0` - Install a specific version (alias)
+    #[command(
+        visible_alias = "i",
+        about = "Install a CLI tool",
+        long_about = "Installs a Python package's executables into an isolated environment and puts them on PATH via 'uv tool install'"
+    )]
+    Install {
+        #[arg(
+            value_name = "PACKAGE",
+            help = "Package to install, e.g. ruff or ruff==0.3.0"
+        )]
+        package: String,
+    },
+    /// List installed CLI tools
+    ///
+    /// # Usage
+    /// * `pylot tool list` - List installed tools, their versions and executables
+    /// * `pylot t ls` - List installed tools (alias)
+    #[command(
+        visible_aliases = ["l", "ls"],
+        about = "List installed CLI tools",
+        long_about = "Lists the tools installed with uv, their versions and the executables they provide"
+    )]
+    List,
+    /// Upgrade CLI tools
+    ///
+    /// # Usage
+    /// * `pylot tool upgrade ruff` - Upgrade ruff
+    /// * `pylot t up --all` - Upgrade every installed tool (alias)
+    #[command(
+        visible_alias = "up",
+        about = "Upgrade CLI tools",
+        long_about = "Upgrades the given tools, or every installed tool with --all, via 'uv tool upgrade'"
+    )]
+    Upgrade {
+        #[arg(
+            value_name = "TOOL",
+            required_unless_present = "all",
+            help = "Tools to upgrade"
+        )]
+        names: Vec<String>,
+        #[arg(long, conflicts_with = "names", help = "Upgrade every installed tool")]
+        all: bool,
+    },
+    /// Uninstall a CLI tool
+    ///
+    /// # Usage
+    /// * `pylot tool uninstall ruff` - Uninstall ruff
+    /// * `pylot t u ruff` - Uninstall ruff (alias)
+    #[command(
+        visible_alias = "u",
+        about = "Uninstall a CLI tool",
+        long_about = "Removes a tool and its executables via 'uv tool uninstall'"
+    )]
+    Uninstall {
+        #[arg(value_name = "TOOL", help = "Tool to uninstall")]
+        name: String,
+    },
 }
 
 /// UV management commands
@@ -99,46 +285,160 @@ pub enum UvCommands {
     ///
     /// # Usage
     /// * `pylot uv install` - Install Astral UV
+    /// * `pylot uv install --version 0.5.11` - Install Astral UV 0.5.11
+    /// * `pylot uv install --from ./uv-x86_64-unknown-linux-gnu.tar.gz` - Install from a downloaded release
     /// * `pylot u i` - Install Astral UV (alias)
     #[command(
         visible_alias = "i",
         about = "Install Astral UV",
-        long_about = "This command installs Astral UV"
+        long_about = "This command installs Astral UV, the latest release or the one given with --version. \
+            With --from, uv is installed from a downloaded release archive or unpacked directory instead; \
+            archives are verified against --sha256 or the .sha256 file next to them, \
+            and a directory's uv binary against --sha256 or the uv.sha256 file next to it.",
+        // `--version` selects the uv release here instead of printing Pylot's.
+        disable_version_flag = true
     )]
-    Install,
+    Install {
+        #[arg(
+            long = "version",
+            value_name = "VERSION",
+            help = "Install this uv release instead of the latest, e.g. 0.5.11"
+        )]
+        uv_version: Option<String>,
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with = "uv_version",
+            help = "Install from a release .tar.gz, .zip or unpacked directory"
+        )]
+        from: Option<PathBuf>,
+        #[arg(
+            long,
+            value_name = "HEX",
+            requires = "from",
+            help = "Expected SHA-256 of the --from archive, or of the uv binary in a directory"
+        )]
+        sha256: Option<String>,
+    },
     /// Update Astral UV
     ///
     /// # Usage
     /// * `pylot uv update` - Update Astral UV
+    /// * `pylot uv update --to 0.4.30` - Switch Astral UV to 0.4.30, also downgrading
     /// * `pylot u up` - Update Astral UV (alias)
     #[command(
         visible_alias = "up",
         about = "Update Astral UV",
-        long_about = "This command updates Astral UV"
+        long_about = "This command updates Astral UV to the latest release, or switches to the release given with --to"
     )]
-    Update,
+    Update {
+        #[arg(
+            long,
+            value_name = "VERSION",
+            help = "Switch to this uv release, which may be older, e.g. 0.5.11"
+        )]
+        to: Option<String>,
+    },
     /// Uninstall Astral UV
     ///
     /// # Usage
     /// * `pylot uv uninstall` - Uninstall Astral UV
+    /// * `pylot uv uninstall --purge` - Also remove the uv cache, tools and Pythons
     /// * `pylot u u` - Uninstall Astral UV (alias)
     #[command(
         visible_alias = "u",
         about = "Uninstall Astral UV",
-        long_about = "This command uninstalls Astral UV"
+        long_about = "This command uninstalls Astral UV the way it was installed: \
+            with cargo, pipx, Homebrew or winget, or by removing the standalone binaries. \
+            A uv installed any other way, e.g. by a distro package, nix or pip, is left alone. \
+            Everything that will be removed, and the virtual environments --purge breaks, is listed before you confirm."
     )]
-    Uninstall,
+    Uninstall {
+        #[arg(
+            long,
+            help = "Also remove the uv cache, tool directory and managed Python installs"
+        )]
+        purge: bool,
+    },
     /// Check if Astral UV is installed
     ///
     /// # Usage
-    /// * `pylot uv check` - Check if Astral UV is installed
+    /// * `pylot uv check` - Show the installed uv version, path, install method and latest release
+    /// * `pylot uv check --json` - The same information as JSON
     /// * `pylot u c` - Check if Astral UV is installed (alias)
     #[command(
         visible_alias = "c",
         about = "Check Astral UV",
-        long_about = "This command checks if Astral UV is installed"
+        long_about = "This command checks if Astral UV is installed and shows its version, location, \
+            how it was installed and the latest available release"
     )]
-    Check,
+    Check {
+        #[arg(long, help = "Print the information as JSON")]
+        json: bool,
+    },
+    /// UV cache management commands
+    ///
+    /// # Usage
+    /// * `pylot uv cache info` - Show the uv cache location and size
+    /// * `pylot u ca prune` - Remove unused cache entries (alias)
+    #[command(
+        visible_alias = "ca",
+        about = "Commands for managing the uv cache",
+        long_about = "This command group shows, cleans and prunes the Astral UV cache, reporting how much space was freed"
+    )]
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+}
+
+/// UV cache management commands
+///
+/// # Usage
+/// * `pylot uv cache` - UV cache management commands
+/// * `pylot u ca` - UV cache management commands (alias)
+#[derive(Subcommand, Debug)]
+pub enum CacheCommands {
+    /// Show the uv cache location and size
+    ///
+    /// # Usage
+    /// * `pylot uv cache info` - Show the uv cache location and size
+    /// * `pylot u ca i` - Show the uv cache location and size (alias)
+    #[command(
+        visible_alias = "i",
+        about = "Show the uv cache location and size",
+        long_about = "Shows the directory of the Astral UV cache and how much disk space it uses"
+    )]
+    Info,
+    /// Clear the uv cache
+    ///
+    /// # Usage
+    /// * `pylot uv cache clean` - Remove everything from the uv cache
+    /// * `pylot uv cache clean numpy` - Remove only the cache entries for `numpy`
+    /// * `pylot u ca c numpy` - Remove the cache entries for `numpy` (alias)
+    #[command(
+        visible_alias = "c",
+        about = "Clear the uv cache",
+        long_about = "Removes everything from the Astral UV cache, or only the entries for the given packages"
+    )]
+    Clean {
+        #[arg(
+            value_name = "PACKAGE",
+            help = "Only remove cache entries for these packages"
+        )]
+        packages: Vec<String>,
+    },
+    /// Remove unused entries from the uv cache
+    ///
+    /// # Usage
+    /// * `pylot uv cache prune` - Remove unused cache entries
+    /// * `pylot u ca p` - Remove unused cache entries (alias)
+    #[command(
+        visible_alias = "p",
+        about = "Remove unused entries from the uv cache",
+        long_about = "Removes cache entries that are no longer needed, such as old package versions, via 'uv cache prune'"
+    )]
+    Prune,
 }
 
 /// Virtual environment management commands
@@ -165,8 +465,9 @@ pub enum VenvCommands {
             short = 'v',
             visible_alias = "pv",
             long,
-            help = "Python version to use",
-            default_value = DEFAULT_PYTHON_VERSION
+            help = "Python version to use, e.g. 3.12, >=3.10, pypy@3.10 or a path to an interpreter",
+            default_value = DEFAULT_PYTHON_VERSION,
+            value_parser = parse_python_request
         )]
         python_version: String,
         #[arg(
@@ -211,12 +512,34 @@ pub enum VenvCommands {
     /// # Usage
     /// * `pylot venv list` - List all python virtual environments
     /// * `pylot v l` - List all python virtual environments (alias)
+    /// * `pylot venv list --python 3.11 --has numpy --sort size` - List the
+    ///   environments on Python 3.11 with numpy installed, largest first
     #[command(
         visible_aliases = ["l", "ls"],
         about = "List all python virtual environments",
-        long_about = "This command lists all python virtual environments"
+        long_about = "This command lists all python virtual environments, sorted by name.\n\
+            Use --python and --has to only list environments on a Python version or with packages installed, and --sort to order them by another column."
     )]
-    List,
+    List {
+        #[arg(
+            long,
+            help = "Only list environments on this Python version, e.g. 3.11"
+        )]
+        python: Option<String>,
+        #[arg(
+            long,
+            help = "Only list environments with this package installed, can be repeated"
+        )]
+        has: Vec<String>,
+        #[arg(
+            short,
+            long,
+            help = "Column to sort by",
+            value_parser = VenvSort::VALUES,
+            default_value = "name"
+        )]
+        sort: String,
+    },
     /// Activate a virtual environment
     ///
     /// # Usage
@@ -234,6 +557,207 @@ pub enum VenvCommands {
         #[arg(index = 1, help = "Name of the virtual environment")]
         name_pos: Option<String>,
     },
+    /// Add packages to a virtual environment
+    ///
+    /// # Usage
+    /// * `pylot venv add myenv numpy pandas` - Install `numpy` and `pandas` into `myenv`
+    /// * `pylot v add myenv -r requirements.txt` - Install packages from `requirements.txt` into `myenv`
+    /// * `pylot v add -n myenv requests` - Install `requests` into `myenv`
+    #[command(
+        visible_alias = "install",
+        about = "Add packages to a python virtual environment",
+        long_about = "This command installs packages into an existing python virtual environment"
+    )]
+    Add {
+        #[arg(short, long, help = "Name of the virtual environment")]
+        name: Option<String>,
+        #[arg(index = 1, help = "Name of the virtual environment")]
+        name_pos: Option<String>,
+        #[arg(index = 2, help = "Packages to install", num_args = 0..)]
+        packages: Vec<String>,
+        #[arg(
+            short = 'r',
+            long,
+            help = "Requirements file to install packages from",
+            default_value = ""
+        )]
+        requirements: String,
+    },
+    /// Remove packages from a virtual environment
+    ///
+    /// # Usage
+    /// * `pylot venv remove myenv numpy` - Uninstall `numpy` from `myenv`
+    /// * `pylot v rm -n myenv numpy pandas` - Uninstall `numpy` and `pandas` from `myenv` (alias)
+    #[command(
+        visible_aliases = ["rm", "uninstall"],
+        about = "Remove packages from a python virtual environment",
+        long_about = "This command uninstalls packages from an existing python virtual environment"
+    )]
+    Remove {
+        #[arg(short, long, help = "Name of the virtual environment")]
+        name: Option<String>,
+        #[arg(index = 1, help = "Name of the virtual environment")]
+        name_pos: Option<String>,
+        #[arg(index = 2, help = "Packages to uninstall", num_args = 0..)]
+        packages: Vec<String>,
+    },
+    /// Upgrade packages in a virtual environment
+    ///
+    /// # Usage
+    /// * `pylot venv upgrade myenv numpy` - Upgrade `numpy` in `myenv`
+    /// * `pylot v up myenv --all` - Upgrade every installed package in `myenv` (alias)
+    #[command(
+        visible_alias = "up",
+        about = "Upgrade packages in a python virtual environment",
+        long_about = "This command upgrades packages in an existing python virtual environment to their latest versions"
+    )]
+    Upgrade {
+        #[arg(short, long, help = "Name of the virtual environment")]
+        name: Option<String>,
+        #[arg(index = 1, help = "Name of the virtual environment")]
+        name_pos: Option<String>,
+        #[arg(index = 2, help = "Packages to upgrade", num_args = 0.., conflicts_with = "all")]
+        packages: Vec<String>,
+        #[arg(short, long, help = "Upgrade all installed packages")]
+        all: bool,
+    },
+    /// Show packages with newer releases
+    ///
+    /// # Usage
+    /// * `pylot venv outdated myenv` - Show outdated packages in `myenv`
+    /// * `pylot v out --all` - Show outdated packages in every environment (alias)
+    #[command(
+        visible_alias = "out",
+        about = "Show outdated packages in python virtual environments",
+        long_about = "This command compares installed packages against the configured package index and lists the ones with newer releases"
+    )]
+    Outdated {
+        #[arg(
+            short,
+            long,
+            help = "Name of the virtual environment",
+            conflicts_with = "all"
+        )]
+        name: Option<String>,
+        #[arg(
+            index = 1,
+            help = "Name of the virtual environment",
+            conflicts_with = "all"
+        )]
+        name_pos: Option<String>,
+        #[arg(short, long, help = "Check every virtual environment")]
+        all: bool,
+    },
+    /// Show the dependency tree of a virtual environment
+    ///
+    /// # Usage
+    /// * `pylot venv tree myenv` - Show the dependency tree of `myenv`
+    /// * `pylot v tree myenv --reverse urllib3` - Show which packages depend on `urllib3`
+    #[command(
+        visible_alias = "deps",
+        about = "Show the dependency tree of a python virtual environment",
+        long_about = "This command builds a dependency tree from the installed package metadata. Use --reverse to see why a package is installed"
+    )]
+    Tree {
+        #[arg(short, long, help = "Name of the virtual environment")]
+        name: Option<String>,
+        #[arg(index = 1, help = "Name of the virtual environment")]
+        name_pos: Option<String>,
+        #[arg(
+            short,
+            long,
+            value_name = "PACKAGE",
+            help = "Show the packages that depend on PACKAGE"
+        )]
+        reverse: Option<String>,
+    },
+    /// Show the disk usage of virtual environments
+    ///
+    /// # Usage
+    /// * `pylot venv du` - Show the size of every virtual environment
+    /// * `pylot venv du myenv` - Show the size of `myenv`
+    /// * `pylot v size --sort site-packages` - Largest site-packages first (alias)
+    #[command(
+        visible_alias = "size",
+        about = "Show the disk usage of virtual environments",
+        long_about = "Shows how much disk space virtual environments use, broken down by site-packages, bin, bytecode cache and other files.\n\
+            Without a name every environment is listed, largest first."
+    )]
+    Du {
+        #[arg(short, long, help = "Name of the virtual environment")]
+        name: Option<String>,
+        #[arg(index = 1, help = "Name of the virtual environment")]
+        name_pos: Option<String>,
+        #[arg(
+            short,
+            long,
+            help = "Column to sort by",
+            value_parser = SizeSort::VALUES,
+            default_value = "size"
+        )]
+        sort: String,
+    },
+    /// Diagnose and repair broken virtual environments
+    ///
+    /// # Usage
+    /// * `pylot venv doctor myenv` - Check `myenv` for problems
+    /// * `pylot v dr --all` - Check every virtual environment (alias)
+    /// * `pylot venv doctor myenv --fix` - Repair the problems found in `myenv`
+    #[command(
+        visible_alias = "dr",
+        about = "Diagnose and repair broken python virtual environments",
+        long_about = "Checks virtual environments for a missing base interpreter, a broken python link, a Python version that differs from pyvenv.cfg, \
+            activate scripts pointing at an old location and packages without a RECORD file.\n\
+            Each problem is listed with a suggested fix. Use --fix to repair them, rebuilding the environment when needed."
+    )]
+    Doctor {
+        #[arg(
+            short,
+            long,
+            help = "Name of the virtual environment",
+            conflicts_with = "all"
+        )]
+        name: Option<String>,
+        #[arg(
+            index = 1,
+            help = "Name of the virtual environment",
+            conflicts_with = "all"
+        )]
+        name_pos: Option<String>,
+        #[arg(short, long, help = "Check every virtual environment")]
+        all: bool,
+        #[arg(long, help = "Repair the problems that were found")]
+        fix: bool,
+    },
+}
+
+impl VenvCommands {
+    /// Resolve the venv name and package list for commands that accept both a
+    /// `--name` flag and a positional name.
+    ///
+    /// When `--name` is given the first positional argument is a package, not
+    /// the venv name, so it is moved to the front of `packages`.
+    pub fn split_name_and_packages(
+        name: Option<String>,
+        name_pos: Option<String>,
+        mut packages: Vec<String>,
+    ) -> (Option<String>, Vec<String>) {
+        match (name, name_pos) {
+            (Some(n), Some(first)) => {
+                packages.insert(0, first);
+                (Some(n), packages)
+            }
+            (name, name_pos) => (name.or(name_pos), packages),
+        }
+    }
+}
+
+/// Reject Python version requests that can never be valid before any work is
+/// done. Whether a matching interpreter is available is checked later.
+fn parse_python_request(value: &str) -> Result<String, String> {
+    PythonRequest::parse(value)
+        .map(|_| value.trim().to_string())
+        .map_err(|e| e.to_string())
 }
 
 #[cfg(test)]
@@ -245,4 +769,42 @@ mod tests {
         use clap::CommandFactory;
         Cli::command().debug_assert();
     }
+
+    #[test]
+    fn test_split_name_and_packages_positional() {
+        let (name, pkgs) = VenvCommands::split_name_and_packages(
+            None,
+            Some("myenv".to_string()),
+            vec!["numpy".to_string()],
+        );
+        assert_eq!(name.as_deref(), Some("myenv"));
+        assert_eq!(pkgs, vec!["numpy"]);
+    }
+
+    #[test]
+    fn test_split_name_and_packages_flag() {
+        let (name, pkgs) = VenvCommands::split_name_and_packages(
+            Some("myenv".to_string()),
+            Some("numpy".to_string()),
+            vec!["pandas".to_string()],
+        );
+        assert_eq!(name.as_deref(), Some("myenv"));
+        assert_eq!(pkgs, vec!["numpy", "pandas"]);
+    }
+
+    #[test]
+    fn test_split_name_and_packages_none() {
+        let (name, pkgs) = VenvCommands::split_name_and_packages(None, None, vec![]);
+        assert!(name.is_none());
+        assert!(pkgs.is_empty());
+    }
+
+    #[test]
+    fn test_parse_python_request() {
+        assert_eq!(parse_python_request(" 3.12 ").unwrap(), "3.12");
+        assert!(parse_python_request("cpython@3.11.4").is_ok());
+        assert!(parse_python_request("3.x")
+            .unwrap_err()
+            .contains("Invalid Python version"));
+    }
 }
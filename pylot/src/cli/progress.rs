@@ -0,0 +1,118 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::Mutex;
+
+use pylot_shared::{progress::ProgressEvent, utils, Pylot};
+
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Show what uv is doing on a status line below the log when stderr is a
+/// terminal.
+pub fn attach(ctx: Pylot) -> Pylot {
+    if !io::stderr().is_terminal() {
+        return ctx;
+    }
+    let line = Mutex::new(ProgressLine::default());
+    ctx.with_progress(move |event| {
+        if let Ok(mut line) = line.lock() {
+            let _ = line.update(&event, &mut io::stderr());
+        }
+    })
+}
+
+/// The status line, redrawn in place on every typed event and cleared before
+/// anything else is written to the terminal.
+#[derive(Default)]
+struct ProgressLine {
+    drawn: bool,
+    frame: usize,
+    installed: usize,
+}
+
+impl ProgressLine {
+    fn update(&mut self, event: &ProgressEvent, out: &mut impl Write) -> io::Result<()> {
+        let status = match event {
+            ProgressEvent::Resolving => "Resolving".to_string(),
+            ProgressEvent::Downloading {
+                package,
+                bytes: Some(bytes),
+            } => format!("Downloading {} ({})", package, utils::format_size(*bytes)),
+            ProgressEvent::Downloading { package, .. } => format!("Downloading {}", package),
+            ProgressEvent::Installing { package } => {
+                self.installed += 1;
+                format!("Installed {}", package)
+            }
+            ProgressEvent::Done => {
+                self.installed = 0;
+                return self.clear(out);
+            }
+            // Anything else is followed by a log line
+            _ => return self.clear(out),
+        };
+        self.clear(out)?;
+        self.frame = (self.frame + 1) % SPINNER.len();
+        write!(out, "{} {}", SPINNER[self.frame], status)?;
+        if self.installed > 0 {
+            write!(out, "  [{} installed]", self.installed)?;
+        }
+        self.drawn = true;
+        out.flush()
+    }
+
+    fn clear(&mut self, out: &mut impl Write) -> io::Result<()> {
+        if self.drawn {
+            write!(out, "\r\x1b[2K")?;
+            self.drawn = false;
+        }
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pylot_shared::processes::Stream;
+
+    use super::*;
+
+    fn render(line: &mut ProgressLine, event: ProgressEvent) -> String {
+        let mut out = Vec::new();
+        line.update(&event, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_progress_line_draws_typed_events() {
+        let mut line = ProgressLine::default();
+        assert_eq!(
+            render(
+                &mut line,
+                ProgressEvent::Downloading {
+                    package: "numpy".to_string(),
+                    bytes: Some(1 << 20),
+                }
+            ),
+            "⠙ Downloading numpy (1.0 MiB)"
+        );
+        assert_eq!(
+            render(
+                &mut line,
+                ProgressEvent::Installing {
+                    package: "numpy".to_string(),
+                }
+            ),
+            "\r\x1b[2K⠹ Installed numpy  [1 installed]"
+        );
+    }
+
+    #[test]
+    fn test_progress_line_clears_before_output() {
+        let mut line = ProgressLine::default();
+        assert_eq!(render(&mut line, ProgressEvent::Done), "");
+        render(&mut line, ProgressEvent::Resolving);
+        let output = ProgressEvent::Output {
+            stream: Stream::Stderr,
+            line: "Resolved 1 package in 3ms".to_string(),
+        };
+        assert_eq!(render(&mut line, output.clone()), "\r\x1b[2K");
+        assert_eq!(render(&mut line, output), "");
+    }
+}
@@ -2,35 +2,51 @@
 //!
 //! [![GitHub Actions Workflow Status](https://img.shields.io/github/actions/workflow/status/FrodeUlr/pylot/rust.yml?branch=main&style=for-the-badge&logo=github)](https://github.com/FrodeUlr/Pylot) [![Codecov](https://img.shields.io/codecov/c/github/FrodeUlr/Pylot?style=for-the-badge&logo=codecov&label=CODECOV)](https://codecov.io/github/FrodeUlr/pylot)
 //!
+//! The functions at the crate root back the `pylot` commands and may print or
+//! prompt on stdin. Use [`api`] to embed Pylot in another program.
+pub mod api;
 pub mod cli;
 
 use std::{borrow::Cow, io};
 
 use pylot_shared::{
-    constants::{DEFAULT_PYTHON_VERSION, ERROR_CREATING_VENV},
+    constants::ERROR_VENV_NOT_EXISTS,
+    deptree, diskusage, doctor,
     error::{PylotError, Result},
-    utils, uvctrl, uvvenv, venvmanager,
-    venvtraits::{Activate, Create, Delete},
+    outdated, toolchain, utils, uvcache, uvctrl, uvpython, uvtool, uvvenv,
+    venvquery::{VenvFilter, VenvSort},
+    venvtraits::{Activate, Delete},
+    Pylot,
 };
 
 /// Activate a virtual environment by named position or name
 ///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+///
 /// # Returns
 /// * `Result<()>` - Ok if activated
 ///
 /// # Examples
 /// ```
 /// use pylot::activate;
-/// activate(Some("test_env"));
+/// activate(None, Some("test_env"));
 /// ```
-pub async fn activate(name: Option<&str>) -> Result<()> {
-    let venv = venvmanager::VENVMANAGER
+pub async fn activate(ctx: Option<&Pylot>, name: Option<&str>) -> Result<()> {
+    let ctx = context(ctx);
+    let venv = ctx
+        .manager()
         .find_venv(io::stdin(), name, "activate")
         .await?;
     venv.activate().await
 }
 
-/// Check if Astral UV is installed and configured
+/// Check if Astral UV is installed and configured, and print its version,
+/// path, install method and latest release
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `json` - Print the information as JSON instead of a table
 ///
 /// # Returns
 /// * `Result<()>` - Ok if installed, Err if not
@@ -38,262 +54,1093 @@ pub async fn activate(name: Option<&str>) -> Result<()> {
 /// # Examples
 /// ```
 /// use pylot::check;
-/// check();
+/// check(None, false);
 /// ```
-pub async fn check() -> Result<()> {
+pub async fn check(ctx: Option<&Pylot>, json: bool) -> Result<()> {
+    let ctx = context(ctx);
     log::info!("Checking if Astral UV is installed and configured...");
-    uvctrl::check("uv")
+    uvctrl::check(&ctx, "uv")
         .await
-        .map(|_| ())
-        .map_err(|e| PylotError::Other(e.to_string()))
+        .map_err(|e| PylotError::Other(e.to_string()))?;
+    let info = uvctrl::info(&ctx).await.map_err(PylotError::Other)?;
+    if json {
+        let json =
+            serde_json::to_string_pretty(&info).map_err(|e| PylotError::Other(e.to_string()))?;
+        println!("{}", json);
+    } else {
+        uvctrl::print_info_table_to(&mut io::stdout(), &info);
+    }
+    Ok(())
+}
+
+/// Check everything Pylot depends on and print a pass/warn/fail report
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+///
+/// # Returns
+/// * `Result<()>` - Ok unless a check failed
+///
+/// # Examples
+/// ```
+/// use pylot::check_toolchain;
+/// check_toolchain(None);
+/// ```
+pub async fn check_toolchain(ctx: Option<&Pylot>) -> Result<()> {
+    let ctx = context(ctx);
+    log::info!("Checking the Pylot toolchain...");
+    let checks = toolchain::run_checks(&ctx).await;
+    toolchain::print_report_to(&mut io::stdout(), &checks);
+    let failed = checks
+        .iter()
+        .filter(|c| c.status == toolchain::Status::Fail)
+        .count();
+    match toolchain::overall(&checks) {
+        toolchain::Status::Fail => Err(PylotError::Other(format!(
+            "{} check(s) failed, see the suggested fixes above",
+            failed
+        ))),
+        toolchain::Status::Warn => {
+            log::warn!("Pylot works, but some checks reported warnings");
+            Ok(())
+        }
+        toolchain::Status::Pass => {
+            log::info!("Everything looks good");
+            Ok(())
+        }
+    }
+}
+
+/// Create a new virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The name of the virtual environment
+/// * `python_version` - The Python version request, e.g. `3.12`, `>=3.10`, `pypy@3.10` or a path.
+///   It is checked against the available interpreters before anything is created
+/// * `packages` - A vector of packages to install
+/// * `requirements` - A requirements file to install packages from
+/// * `default` -  Whether to install default packages from settings.toml
+///
+/// # Returns
+/// * `Result<()>` - Ok if created
+///
+/// # Examples
+/// ```
+/// use pylot::create;
+///
+/// // With named_pos:
+/// let numpy = "numpy".to_string();
+/// let pandas = "pandas".to_string();
+/// create(None, "test_env", Some("3.8"), Some(vec![numpy, pandas]), None, false);
+/// // Install default packages defined in settings.toml:
+/// create(None, "test_env", Some("3.8"), None, None, true);
+/// // With requirements file:
+/// create(None, "test_env", None, None, Some("requirements.txt"), false);
+/// ```
+pub async fn create(
+    ctx: Option<&Pylot>,
+    name: &str,
+    python_version: Option<&str>,
+    packages: Option<Vec<String>>,
+    requirements: Option<&str>,
+    default: bool,
+) -> Result<()> {
+    let options = api::CreateOptions {
+        python_version: python_version.map(str::to_string),
+        packages: packages.unwrap_or_default(),
+        requirements: requirements.map(str::to_string),
+        default_packages: default,
+    };
+    let venv = api::create(ctx, name, options).await?;
+    log::info!(
+        "Virtual environment '{}' created with Python {}.",
+        venv.name,
+        venv.python_version
+    );
+    Ok(())
+}
+
+/// Delete a virtual environment by name or index position
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `confirm_input` - A reader for user input (e.g., stdin)
+/// * `find_input` - A reader for user input to find the venv (e.g., stdin)
+/// * `name` - The name of the virtual environment to delete
+///
+/// # Returns
+/// * `Result<()>` - Ok if deleted
+///
+/// # Examples
+/// ```
+/// use pylot::delete;
+/// use std::io;
+///
+/// // With name provided:
+/// delete(None, io::stdin(), io::stdin(), Some("test_env"));
+/// // Without name provided, will prompt user to select:
+/// delete(None, io::stdin(), io::stdin(), None);
+/// ```
+pub async fn delete<R: std::io::Read, F: std::io::Read>(
+    ctx: Option<&Pylot>,
+    confirm_input: R,
+    find_input: F,
+    name: Option<&str>,
+) -> Result<()> {
+    let ctx = context(ctx);
+    let venv = ctx.manager().find_venv(find_input, name, "delete").await?;
+
+    venv.delete(confirm_input, true).await?;
+    log::info!("Virtual environment '{}' deleted.", venv.name);
+    Ok(())
+}
+
+/// Add packages to an existing virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `find_input` - A reader for user input to find the venv (e.g., stdin)
+/// * `name` - The name of the virtual environment, prompts if `None`
+/// * `packages` - Packages to install
+/// * `requirements` - A requirements file to install packages from
+///
+/// # Returns
+/// * `Result<()>` - Ok if the packages were installed
+///
+/// # Examples
+/// ```
+/// use pylot::add;
+/// use std::io;
+///
+/// add(None, io::stdin(), Some("test_env"), vec!["numpy".to_string()], None);
+/// // With requirements file:
+/// add(None, io::stdin(), Some("test_env"), vec![], Some("requirements.txt"));
+/// ```
+pub async fn add<R: std::io::Read>(
+    ctx: Option<&Pylot>,
+    find_input: R,
+    name: Option<&str>,
+    packages: Vec<String>,
+    requirements: Option<&str>,
+) -> Result<()> {
+    let ctx = context(ctx);
+    let requirements = requirements.filter(|r| !r.is_empty());
+    if packages.is_empty() && requirements.is_none() {
+        return Err(PylotError::Other(
+            "No packages or requirements file provided".to_string(),
+        ));
+    }
+    let venv = ctx
+        .manager()
+        .find_venv(find_input, name, "add packages to")
+        .await?;
+
+    api::add(Some(&ctx), &venv.name, packages, requirements).await?;
+    log::info!("Packages added to '{}'.", venv.name);
+    Ok(())
+}
+
+/// Remove packages from an existing virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `find_input` - A reader for user input to find the venv (e.g., stdin)
+/// * `name` - The name of the virtual environment, prompts if `None`
+/// * `packages` - Packages to uninstall
+///
+/// # Returns
+/// * `Result<()>` - Ok if the packages were removed
+///
+/// # Examples
+/// ```
+/// use pylot::remove;
+/// use std::io;
+///
+/// remove(None, io::stdin(), Some("test_env"), vec!["numpy".to_string()]);
+/// ```
+pub async fn remove<R: std::io::Read>(
+    ctx: Option<&Pylot>,
+    find_input: R,
+    name: Option<&str>,
+    packages: Vec<String>,
+) -> Result<()> {
+    let ctx = context(ctx);
+    if packages.is_empty() {
+        return Err(PylotError::Other("No packages provided".to_string()));
+    }
+    let venv = ctx
+        .manager()
+        .find_venv(find_input, name, "remove packages from")
+        .await?;
+
+    api::remove(Some(&ctx), &venv.name, packages).await?;
+    log::info!("Packages removed from '{}'.", venv.name);
+    Ok(())
+}
+
+/// Upgrade packages in an existing virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `find_input` - A reader for user input to find the venv (e.g., stdin)
+/// * `name` - The name of the virtual environment, prompts if `None`
+/// * `packages` - Packages to upgrade
+/// * `all` - Upgrade every installed package instead of `packages`
+///
+/// # Returns
+/// * `Result<()>` - Ok if the packages were upgraded
+///
+/// # Examples
+/// ```
+/// use pylot::upgrade;
+/// use std::io;
+///
+/// upgrade(None, io::stdin(), Some("test_env"), vec!["numpy".to_string()], false);
+/// // Upgrade everything:
+/// upgrade(None, io::stdin(), Some("test_env"), vec![], true);
+/// ```
+pub async fn upgrade<R: std::io::Read>(
+    ctx: Option<&Pylot>,
+    find_input: R,
+    name: Option<&str>,
+    packages: Vec<String>,
+    all: bool,
+) -> Result<()> {
+    let ctx = context(ctx);
+    if packages.is_empty() && !all {
+        return Err(PylotError::Other(
+            "No packages provided, use --all to upgrade every package".to_string(),
+        ));
+    }
+    let venv = ctx
+        .manager()
+        .find_venv(find_input, name, "upgrade packages in")
+        .await?;
+
+    if all {
+        api::upgrade_all(Some(&ctx), &venv.name).await?;
+    } else {
+        api::upgrade(Some(&ctx), &venv.name, packages).await?;
+    }
+    log::info!("Packages upgraded in '{}'.", venv.name);
+    Ok(())
+}
+
+/// Show packages with newer releases in one or all virtual environments
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `find_input` - A reader for user input to find the venv (e.g., stdin)
+/// * `name` - The name of the virtual environment, prompts if `None` and `all` is `false`
+/// * `all` - Check every virtual environment instead of a single one
+///
+/// # Returns
+/// * `Result<()>` - Ok if the check completed
+///
+/// # Examples
+/// ```
+/// use pylot::outdated;
+/// use std::io;
+///
+/// outdated(None, io::stdin(), Some("test_env"), false);
+/// // Check every environment:
+/// outdated(None, io::stdin(), None, true);
+/// ```
+pub async fn outdated<R: std::io::Read>(
+    ctx: Option<&Pylot>,
+    find_input: R,
+    name: Option<&str>,
+    all: bool,
+) -> Result<()> {
+    let ctx = context(ctx);
+    let mut venvs = if all {
+        ctx.manager().list().await
+    } else {
+        vec![find_existing_venv(&ctx, find_input, name, "check for outdated packages in").await?]
+    };
+    if venvs.is_empty() {
+        log::info!("No virtual environments found");
+        return Ok(());
+    }
+
+    let mut entries = Vec::new();
+    for venv in &mut venvs {
+        match venv.outdated_packages().await {
+            Ok(report) => {
+                for (pkg, e) in &report.failed {
+                    log::warn!("Could not check '{}' in '{}': {}", pkg, venv.name, e);
+                }
+                if !report.outdated.is_empty() {
+                    entries.push((venv.name.to_string(), report.outdated));
+                } else if report.failed.is_empty() {
+                    log::info!("All packages in '{}' are up to date.", venv.name)
+                }
+            }
+            Err(e) if all => log::warn!("Could not check '{}': {}", venv.name, e),
+            Err(e) => return Err(e),
+        }
+    }
+    if !entries.is_empty() {
+        outdated::print_outdated_table_to(&mut io::stdout(), &entries, all);
+    }
+    Ok(())
+}
+
+/// Print the dependency tree of a virtual environment
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `find_input` - A reader for user input to find the venv (e.g., stdin)
+/// * `name` - The name of the virtual environment, prompts if `None`
+/// * `reverse` - When set, show the packages that depend on this package instead
+///
+/// # Returns
+/// * `Result<()>` - Ok if the tree was printed
+///
+/// # Examples
+/// ```
+/// use pylot::tree;
+/// use std::io;
+///
+/// tree(None, io::stdin(), Some("test_env"), None);
+/// // Why is urllib3 installed?
+/// tree(None, io::stdin(), Some("test_env"), Some("urllib3"));
+/// ```
+pub async fn tree<R: std::io::Read>(
+    ctx: Option<&Pylot>,
+    find_input: R,
+    name: Option<&str>,
+    reverse: Option<&str>,
+) -> Result<()> {
+    let ctx = context(ctx);
+    let venv = find_existing_venv(&ctx, find_input, name, "show the dependency tree of").await?;
+    let graph = venv.dependency_graph().await?;
+
+    let nodes = match reverse {
+        Some(pkg) => vec![graph.reverse_tree(pkg).ok_or_else(|| {
+            PylotError::Other(format!(
+                "Package '{}' is not installed in '{}'",
+                pkg, venv.name
+            ))
+        })?],
+        None => graph.tree(),
+    };
+    if nodes.is_empty() {
+        log::info!("No packages installed in '{}'", venv.name);
+        return Ok(());
+    }
+    for line in deptree::render(&nodes) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Show how much disk space one or all virtual environments use
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The name of the virtual environment, every environment if `None`
+/// * `sort` - Column to order the environments by
+///
+/// # Returns
+/// * `Result<()>` - Ok if the report was printed
+///
+/// # Examples
+/// ```
+/// use pylot::du;
+/// use pylot_shared::diskusage::SizeSort;
+///
+/// du(None, Some("test_env"), SizeSort::Size);
+/// // Every environment, largest site-packages first:
+/// du(None, None, SizeSort::SitePackages);
+/// ```
+pub async fn du(ctx: Option<&Pylot>, name: Option<&str>, sort: diskusage::SizeSort) -> Result<()> {
+    let ctx = context(ctx);
+    let mut venvs = match name {
+        Some(_) => vec![find_existing_venv(&ctx, io::empty(), name, "measure").await?],
+        None => ctx.manager().list().await,
+    };
+    if venvs.is_empty() {
+        log::info!("No virtual environments found");
+        return Ok(());
+    }
+
+    let mut entries = Vec::new();
+    for venv in &mut venvs {
+        let usage = match venv.disk_usage {
+            Some(usage) => usage,
+            None => venv.measure_disk_usage().await,
+        };
+        entries.push((venv.name.to_string(), usage));
+    }
+    sort.sort(&mut entries);
+    diskusage::print_disk_usage_table_to(&mut io::stdout(), &entries);
+    Ok(())
+}
+
+/// Diagnose virtual environments for common breakage and optionally repair them
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `confirm_input` - A reader to confirm rebuilding environments (e.g., stdin)
+/// * `find_input` - Input used to pick a venv interactively when no name is given
+/// * `name` - The name of the virtual environment
+/// * `all` - Check every environment under the configured venvs path
+/// * `fix` - Repair the problems that were found. Environments that need a
+///   rebuild are only rebuilt after the user confirms
+///
+/// # Returns
+/// * `Result<()>` - Ok if every checked environment was diagnosed and, with
+///   `fix`, repaired. A failed repair does not stop the others; the failures
+///   are reported together at the end
+///
+/// # Examples
+/// ```
+/// use pylot::doctor;
+/// doctor(None, std::io::stdin(), std::io::stdin(), Some("test_env"), false, true);
+/// doctor(None, std::io::stdin(), std::io::stdin(), None, true, false);
+/// ```
+pub async fn doctor<R: std::io::Read, F: std::io::Read>(
+    ctx: Option<&Pylot>,
+    confirm_input: R,
+    find_input: F,
+    name: Option<&str>,
+    all: bool,
+    fix: bool,
+) -> Result<()> {
+    let ctx = context(ctx);
+    let venvs = if all {
+        let names = ctx.manager().list_names_including_broken().await;
+        if names.is_empty() {
+            log::info!("No virtual environments found");
+            return Ok(());
+        }
+        names.into_iter().map(|n| ctx.venv(n)).collect()
+    } else {
+        vec![find_existing_venv(&ctx, find_input, name, "diagnose").await?]
+    };
+
+    let mut broken = Vec::new();
+    for venv in &venvs {
+        let issues = venv.diagnose().await;
+        if issues.is_empty() {
+            log::info!("'{}' is healthy", venv.name);
+        } else {
+            broken.push((venv, issues));
+        }
+    }
+    if broken.is_empty() {
+        return Ok(());
+    }
+    let report: Vec<_> = broken
+        .iter()
+        .map(|(venv, issues)| (venv.name.to_string(), issues.clone()))
+        .collect();
+    doctor::print_report_to(&mut io::stdout(), &report);
+    if !fix {
+        log::info!("Run again with --fix to repair the problems above");
+        return Ok(());
+    }
+
+    let rebuilds: Vec<&str> = broken
+        .iter()
+        .filter(|(_, issues)| issues.iter().any(doctor::Issue::needs_rebuild))
+        .map(|(venv, _)| venv.name.as_ref())
+        .collect();
+    let rebuild = rebuilds.is_empty() || {
+        log::info!(
+            "Rebuilding deletes and recreates {} with the same packages",
+            rebuilds.join(", ")
+        );
+        utils::confirm(confirm_input)
+    };
+
+    let mut failed = Vec::new();
+    for (venv, issues) in &broken {
+        if !rebuild && rebuilds.contains(&venv.name.as_ref()) {
+            log::warn!("Skipped rebuilding '{}'", venv.name);
+            continue;
+        }
+        log::info!("Repairing '{}'...", venv.name);
+        if let Err(e) = venv.repair(issues).await {
+            log::error!("Could not repair '{}': {}", venv.name, e);
+            failed.push(format!("'{}': {}", venv.name, e));
+        }
+    }
+    if !failed.is_empty() {
+        return Err(PylotError::Other(format!(
+            "Could not repair {} environment(s): {}",
+            failed.len(),
+            failed.join("; ")
+        )));
+    }
+    Ok(())
+}
+
+async fn find_existing_venv<'a, R: std::io::Read>(
+    ctx: &Pylot,
+    find_input: R,
+    name: Option<&'a str>,
+    method: &str,
+) -> Result<uvvenv::UvVenv<'a>> {
+    let manager = ctx.manager();
+    let venv = manager.find_venv(find_input, name, method).await?;
+    uvvenv::UvVenv::validate_venv_name(&venv.name)?;
+    if !manager.check_if_exists(&venv.name).await {
+        return Err(PylotError::VenvNotFound(ERROR_VENV_NOT_EXISTS.to_string()));
+    }
+    Ok(venv)
+}
+
+/// Install Astral UV
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `input` - A reader for user input (e.g., stdin)
+/// * `version` - A specific uv release to install, e.g. `0.5.11`. When uv is
+///   already installed at another version it is switched to this one, after
+///   the user confirms
+///
+/// # Returns
+/// * `Result<()>` - Ok if installed
+///
+/// # Examples
+/// ```
+/// use pylot::install;
+/// use std::io;
+///
+/// install(None, io::stdin(), None);
+/// install(None, io::stdin(), Some("0.5.11"));
+/// ```
+pub async fn install<R: std::io::Read>(
+    ctx: Option<&Pylot>,
+    input: R,
+    version: Option<&str>,
+) -> Result<()> {
+    let ctx = context(ctx);
+    // Only presence matters here: a uv outside required_uv_version is what
+    // a pinned install fixes.
+    if ctx.runner.which(&ctx.settings.uv_program()).is_some() {
+        let Some(version) = version else {
+            log::info!("Astral UV is already installed.");
+            return Ok(());
+        };
+        let wanted = uvctrl::parse_release(version).map_err(PylotError::Other)?;
+        let installed = uvctrl::installed_version(&ctx).await.ok();
+        if installed.as_ref() == Some(&wanted) {
+            log::info!("Astral UV {} is already installed.", wanted);
+            return Ok(());
+        }
+        let installed = installed.map_or("another version".to_string(), |v| v.to_string());
+        log::info!(
+            "Astral UV {} is installed. This will switch it to {}\n",
+            installed,
+            wanted
+        );
+        if !utils::confirm(input) {
+            log::warn!("Exiting...");
+            return Ok(());
+        }
+        return uvctrl::update(&ctx, Some(version))
+            .await
+            .map_err(PylotError::Other);
+    }
+    uvctrl::install(&ctx, input, version)
+        .await
+        .map_err(PylotError::Other)
+}
+
+/// Install Astral UV from a local release
+/// Installs uv from a downloaded `.tar.gz` or `.zip` release archive, or a directory
+/// holding the unpacked release, without contacting the network
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `input` - A reader for user input (e.g., stdin)
+/// * `source` - Path to the archive or directory
+/// * `sha256` - Expected SHA-256 of the archive; read from `<archive>.sha256`
+///   when `None`
+///
+/// # Returns
+/// * `Result<()>` - Ok if installed
+///
+/// # Examples
+/// ```
+/// use pylot::install_from;
+/// use std::{io, path::Path};
+///
+/// install_from(None, io::stdin(), Path::new("uv-x86_64-unknown-linux-gnu.tar.gz"), None);
+/// ```
+pub async fn install_from<R: std::io::Read>(
+    ctx: Option<&Pylot>,
+    input: R,
+    source: &std::path::Path,
+    sha256: Option<&str>,
+) -> Result<()> {
+    let ctx = context(ctx);
+    uvctrl::install_from_archive(&ctx, input, source, sha256)
+        .await
+        .map_err(PylotError::Other)
+}
+
+/// Update Astral UV
+/// Checks for updates and applies them if available, or switches to a
+/// specific release, which may be older than the installed one
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `to` - The uv release to move to, e.g. `0.5.11`; the latest when `None`
+///
+/// # Returns
+/// * `Result<()>` - Ok if updated, or if uv is not installed
+///
+/// # Examples
+/// ```
+/// use pylot::update;
+///
+/// update(None, None);
+/// update(None, Some("0.5.11"));
+/// ```
+pub async fn update(ctx: Option<&Pylot>, to: Option<&str>) -> Result<()> {
+    let ctx = context(ctx);
+    if ctx.runner.which(&ctx.settings.uv_program()).is_none() {
+        log::info!("Astral UV is not installed.");
+        return Ok(());
+    }
+    uvctrl::update(&ctx, to).await.map_err(PylotError::Other)
+}
+
+/// Uninstall Astral UV
+/// Uninstalls Astral UV from the system the same way it was installed
+/// (standalone, cargo, pipx, Homebrew or winget)
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `input` - A reader for user input (e.g., stdin)
+/// * `purge` - Also remove the uv cache, tool directory and managed Pythons
+///
+/// # Returns
+/// * `Result<()>` - Ok if uninstalled, or if uv is not installed
+///
+/// # Examples
+/// ```
+/// use pylot::uninstall;
+/// use std::io;
+///
+/// uninstall(None, io::stdin(), false);
+/// ```
+pub async fn uninstall<R: std::io::Read>(ctx: Option<&Pylot>, input: R, purge: bool) -> Result<()> {
+    let ctx = context(ctx);
+    uvctrl::uninstall(&ctx, input, purge)
+        .await
+        .map_err(PylotError::Other)
+}
+
+/// Show the uv cache location and size
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+///
+/// # Returns
+/// * `Result<()>` - Ok if the cache could be inspected
+///
+/// # Examples
+/// ```
+/// use pylot::cache_info;
+///
+/// cache_info(None);
+/// ```
+pub async fn cache_info(ctx: Option<&Pylot>) -> Result<()> {
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    let info = uvcache::info(&ctx).await?;
+    log::info!("UV cache: {}", info.path.display());
+    log::info!("Size:     {}", utils::format_size(info.size));
+    Ok(())
+}
+
+/// Clear the uv cache, or only the entries for some packages
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `packages` - Packages to remove from the cache; everything when empty
+///
+/// # Returns
+/// * `Result<()>` - Ok if the cache was cleaned
+///
+/// # Examples
+/// ```
+/// use pylot::cache_clean;
+///
+/// cache_clean(None, &["numpy".to_string()]);
+/// ```
+pub async fn cache_clean(ctx: Option<&Pylot>, packages: &[String]) -> Result<()> {
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    let freed = uvcache::clean(&ctx, packages).await?;
+    log::info!("Cleaned the uv cache, freed {}.", utils::format_size(freed));
+    Ok(())
+}
+
+/// Remove unused entries from the uv cache
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+///
+/// # Returns
+/// * `Result<()>` - Ok if the cache was pruned
+///
+/// # Examples
+/// ```
+/// use pylot::cache_prune;
+///
+/// cache_prune(None);
+/// ```
+pub async fn cache_prune(ctx: Option<&Pylot>) -> Result<()> {
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    let freed = uvcache::prune(&ctx).await?;
+    log::info!("Pruned the uv cache, freed {}.", utils::format_size(freed));
+    Ok(())
+}
+
+/// List the available virtual environments
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `filter` - Which environments to list, e.g. by Python version or package
+/// * `sort` - The column to order the environments by
+///
+/// # Returns
+/// * `()` - Nothing
+///
+/// # Examples
+/// ```
+/// use pylot::list;
+/// use pylot_shared::{venvquery::{VenvFilter, VenvSort}, Pylot};
+///
+/// list(None, &VenvFilter::default(), VenvSort::Name);
+/// // Environments on Python 3.11 with numpy, largest first:
+/// let filter = VenvFilter {
+///     python: Some("3.11".to_string()),
+///     has: vec!["numpy".to_string()],
+///     ..Default::default()
+/// };
+/// list(None, &filter, VenvSort::Size);
+/// // Another venvs root, without touching settings.toml:
+/// let ctx = Pylot::load().with_venvs_path("/srv/venvs");
+/// list(Some(&ctx), &VenvFilter::default(), VenvSort::Name);
+/// ```
+pub async fn list(ctx: Option<&Pylot>, filter: &VenvFilter, sort: VenvSort) {
+    let ctx = context(ctx);
+    ctx.manager().print_venv_table(filter, sort).await;
+}
+
+/// List installed and downloadable Python interpreters and the virtual
+/// environments that use them
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `all_versions` - Include every downloadable patch release
+///
+/// # Returns
+/// * `Result<()>` - Ok if the interpreters were listed
+///
+/// # Examples
+/// ```
+/// use pylot::python_list;
+///
+/// python_list(None, false);
+/// ```
+pub async fn python_list(ctx: Option<&Pylot>, all_versions: bool) -> Result<()> {
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    let installs = uvpython::list(&ctx, all_versions).await?;
+    if installs.is_empty() {
+        log::info!("No Python interpreters found");
+        return Ok(());
+    }
+    let usage = python_usage(&ctx, &installs).await;
+    uvpython::print_python_table_to(&mut io::stdout(), &installs, &usage);
+    Ok(())
+}
+
+/// Install a Python version with Astral UV
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `version` - The Python version to install, e.g. `3.12`
+///
+/// # Returns
+/// * `Result<()>` - Ok if installed
+///
+/// # Examples
+/// ```
+/// use pylot::python_install;
+///
+/// python_install(None, "3.12");
+/// ```
+pub async fn python_install(ctx: Option<&Pylot>, version: &str) -> Result<()> {
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    uvpython::install(&ctx, version).await?;
+    log::info!("Python {} installed.", version);
+    Ok(())
 }
 
-/// Create a new virtual environment
+/// Uninstall a uv-managed Python version
+///
+/// Asks for confirmation when virtual environments were created from it,
+/// since they stop working once the interpreter is gone.
 ///
 /// # Arguments
-/// * `name` - The name of the virtual environment
-/// * `python_version` - The Python version to use
-/// * `packages` - A vector of packages to install
-/// * `requirements` - A requirements file to install packages from
-/// * `default` -  Whether to install default packages from settings.toml
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `input` - A reader for user input (e.g., stdin)
+/// * `version` - The Python version to uninstall, e.g. `3.12`
 ///
 /// # Returns
-/// * `Result<()>` - Ok if created
+/// * `Result<()>` - Ok if uninstalled or cancelled
 ///
 /// # Examples
 /// ```
-/// use pylot::create;
+/// use pylot::python_uninstall;
+/// use std::io;
 ///
-/// // With named_pos:
-/// let numpy = "numpy".to_string();
-/// let pandas = "pandas".to_string();
-/// create("test_env", Some("3.8"), Some(vec![numpy, pandas]), None, false);
-/// // Install default packages defined in settings.toml:
-/// create("test_env", Some("3.8"), None, None, true);
-/// // With requirements file:
-/// create("test_env", None, None, Some("requirements.txt"), false);
+/// python_uninstall(None, io::stdin(), "3.12");
 /// ```
-pub async fn create(
-    name: &str,
-    python_version: Option<&str>,
-    packages: Option<Vec<String>>,
-    requirements: Option<&str>,
-    default: bool,
-) -> Result<()> {
-    // Validate venv name
-    uvvenv::UvVenv::validate_venv_name(name)?;
-
-    uvctrl::check("uv").await.map_err(|_| {
-        PylotError::Other(format!(
-            "Astral UV is not installed. Please run '{} uv install' to install it.",
-            env!("CARGO_PKG_NAME")
-        ))
-    })?;
-
-    let mut pkgs = packages.unwrap_or_default();
-
-    if venvmanager::VENVMANAGER.check_if_exists(name).await {
-        return Err(PylotError::VenvExists(format!(
-            "A virtual environment with the name {} already exists",
-            name
-        )));
-    }
-
-    if let Some(req) = requirements {
-        update_packages_from_requirements(req, &mut pkgs).await?;
-    }
-
-    // Validate all package names
-    for pkg in &pkgs {
-        uvvenv::UvVenv::validate_package_name(pkg)?;
-    }
-
-    let venv = uvvenv::UvVenv::new(
-        Cow::Borrowed(name),
-        "".to_owned(),
-        python_version.unwrap_or(DEFAULT_PYTHON_VERSION).to_owned(),
-        pkgs,
-        default,
-    );
-
-    match venv.create().await {
-        Ok(_) => Ok(()),
-        Err(e) => {
-            // Try to clean up failed venv creation
-            let _ = venv.delete(io::stdin(), false).await;
-            Err(PylotError::Other(format!("{}: {}", ERROR_CREATING_VENV, e)))
-        }
-    }
-}
-
-async fn update_packages_from_requirements(
-    requirements: &str,
-    packages: &mut Vec<String>,
+pub async fn python_uninstall<R: std::io::Read>(
+    ctx: Option<&Pylot>,
+    input: R,
+    version: &str,
 ) -> Result<()> {
-    if !requirements.is_empty() {
-        let read_pkgs = utils::read_requirements_file(requirements)
-            .await
-            .map_err(|e| PylotError::Other(e.to_string()))?;
-
-        // Preserve package order while deduplicating
-        // This ensures installation order is maintained, which can matter
-        // for packages with conflicting dependencies or when using --no-deps
-        for req in read_pkgs {
-            if !packages.contains(&req) {
-                packages.push(req);
-            }
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    let installs = uvpython::list(&ctx, false).await?;
+    let usage = python_usage(&ctx, &installs).await;
+    let users: Vec<String> = installs
+        .iter()
+        .filter(|install| install.is_installed() && install.matches_version(version))
+        .filter_map(|install| usage.get(&install.key))
+        .flatten()
+        .cloned()
+        .collect();
+    if !users.is_empty() {
+        log::warn!(
+            "Python {} is used by: {}. These environments stop working once it is removed.",
+            version,
+            users.join(", ")
+        );
+        if !utils::confirm(input) {
+            log::warn!("Exiting...");
+            return Ok(());
         }
     }
+    uvpython::uninstall(&ctx, version).await?;
+    log::info!("Python {} uninstalled.", version);
     Ok(())
 }
 
-/// Delete a virtual environment by name or index position
+/// Pin the Python version of the current directory by writing
+/// `.python-version`, or show the current pin
 ///
 /// # Arguments
-/// * `confirm_input` - A reader for user input (e.g., stdin)
-/// * `find_input` - A reader for user input to find the venv (e.g., stdin)
-/// * `name` - The name of the virtual environment to delete
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `version` - The Python version to pin, `None` shows the current pin
 ///
 /// # Returns
-/// * `Result<()>` - Ok if deleted
+/// * `Result<()>` - Ok if pinned
 ///
 /// # Examples
 /// ```
-/// use pylot::delete;
-/// use std::io;
+/// use pylot::python_pin;
 ///
-/// // With name provided:
-/// delete(io::stdin(), io::stdin(), Some("test_env"));
-/// // Without name provided, will prompt user to select:
-/// delete(io::stdin(), io::stdin(), None);
+/// python_pin(None, Some("3.12"));
 /// ```
-pub async fn delete<R: std::io::Read, F: std::io::Read>(
-    confirm_input: R,
-    find_input: F,
-    name: Option<&str>,
-) -> Result<()> {
-    let venv = venvmanager::VENVMANAGER
-        .find_venv(find_input, name, "delete")
-        .await?;
-
-    venv.delete(confirm_input, true).await?;
-    log::info!("Virtual environment '{}' deleted.", venv.name);
-    Ok(())
+pub async fn python_pin(ctx: Option<&Pylot>, version: Option<&str>) -> Result<()> {
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    uvpython::pin(&ctx, version).await
 }
 
-/// Install Astral UV
+/// List installed CLI tools
 ///
 /// # Arguments
-/// * `input` - A reader for user input (e.g., stdin)
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
 ///
 /// # Returns
-/// * `Result<()>` - Ok if installed
+/// * `Result<()>` - Ok if the tools were listed
 ///
 /// # Examples
 /// ```
-/// use pylot::install;
-/// use std::io;
+/// use pylot::tool_list;
 ///
-/// install(io::stdin());
+/// tool_list(None);
 /// ```
-pub async fn install<R: std::io::Read>(input: R) -> Result<()> {
-    if (uvctrl::check("uv").await).is_ok() {
-        log::info!("Astral UV is already installed.");
+pub async fn tool_list(ctx: Option<&Pylot>) -> Result<()> {
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    let tools = uvtool::list(&ctx).await?;
+    if tools.is_empty() {
+        log::info!("No tools installed");
         return Ok(());
     }
-    uvctrl::install(input).await.map_err(PylotError::Other)
+    uvtool::print_tool_table_to(&mut io::stdout(), &tools);
+    Ok(())
 }
 
-/// Update Astral UV
-/// Checks for updates and applies them if available
+/// Install a CLI tool with Astral UV
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `package` - The package to install, e.g. `ruff` or `ruff==0.3.0`
 ///
 /// # Returns
-/// * `()` - Nothing
+/// * `Result<()>` - Ok if installed
 ///
 /// # Examples
 /// ```
-/// use pylot::update;
+/// use pylot::tool_install;
 ///
-/// update();
+/// tool_install(None, "ruff");
 /// ```
-pub async fn update() {
-    if (uvctrl::check("uv").await).is_err() {
-        log::info!("Astral UV is not installed.");
-        return;
-    }
-    uvctrl::update().await.unwrap_or_else(|e| {
-        log::error!("{}", e);
-    });
+pub async fn tool_install(ctx: Option<&Pylot>, package: &str) -> Result<()> {
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    uvtool::install(&ctx, package).await?;
+    log::info!("Tool {} installed.", package);
+    Ok(())
 }
 
-/// Uninstall Astral UV
-/// Uninstalls Astral UV from the system
+/// Upgrade CLI tools
 ///
 /// # Arguments
-/// * `input` - A reader for user input (e.g., stdin)
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `names` - The tools to upgrade
+/// * `all` - Upgrade every installed tool instead of `names`
 ///
 /// # Returns
-/// * `Result<()>` - Ok if uninstalled
+/// * `Result<()>` - Ok if upgraded
 ///
 /// # Examples
 /// ```
-/// use pylot::uninstall;
-/// use std::io;
+/// use pylot::tool_upgrade;
 ///
-/// uninstall(io::stdin());
+/// tool_upgrade(None, &["ruff".to_string()], false);
+/// // Upgrade every tool:
+/// tool_upgrade(None, &[], true);
 /// ```
-pub async fn uninstall<R: std::io::Read>(input: R) -> Result<()> {
-    if (uvctrl::check("uv").await).is_err() {
-        log::info!("Astral UV is not installed.");
-        return Ok(());
-    }
-    uvctrl::uninstall(input).await.map_err(PylotError::Other)
+pub async fn tool_upgrade(ctx: Option<&Pylot>, names: &[String], all: bool) -> Result<()> {
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    uvtool::upgrade(&ctx, names, all).await
 }
 
-/// List all available virtual environments
+/// Uninstall a CLI tool
+///
+/// # Arguments
+/// * `ctx` - The context to run in, loaded from `settings.toml` when `None`
+/// * `name` - The tool to uninstall
 ///
 /// # Returns
-/// * `()` - Nothing
+/// * `Result<()>` - Ok if uninstalled
 ///
 /// # Examples
 /// ```
-/// use pylot::list;
+/// use pylot::tool_uninstall;
 ///
-/// list();
+/// tool_uninstall(None, "ruff");
 /// ```
-pub async fn list() {
-    venvmanager::VENVMANAGER.print_venv_table().await;
+pub async fn tool_uninstall(ctx: Option<&Pylot>, name: &str) -> Result<()> {
+    let ctx = context(ctx);
+    api::require_uv(&ctx).await?;
+    uvtool::uninstall(&ctx, name).await?;
+    log::info!("Tool {} uninstalled.", name);
+    Ok(())
+}
+
+async fn python_usage(
+    ctx: &Pylot,
+    installs: &[uvpython::PythonInstall],
+) -> std::collections::HashMap<String, Vec<String>> {
+    let venvs = ctx.manager().list().await;
+    uvpython::venv_usage(installs, &uvpython::venv_configs(&venvs).await)
+}
+
+/// The context to run in: the caller's, or the one described by
+/// `settings.toml` when the caller has none.
+fn context(ctx: Option<&Pylot>) -> Cow<'_, Pylot> {
+    ctx.map_or_else(|| Cow::Owned(Pylot::load()), Cow::Borrowed)
 }
 
 #[cfg(test)]
 mod tests {
-    use pylot_shared::logger;
+    use std::sync::Arc;
+
+    use pylot_shared::{
+        logger,
+        processes::{CommandOutput, RecordingRunner},
+    };
 
     use super::*;
+    use crate::api::update_packages_from_requirements;
 
     #[tokio::test]
     async fn test_check() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        _ = check().await;
+        _ = check(Some(&Pylot::default()), false).await;
     }
 
     #[tokio::test]
     async fn test_list() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        list().await;
+        list(
+            Some(&Pylot::default()),
+            &VenvFilter::default(),
+            VenvSort::Name,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_python_list() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        // Fails with a helpful message when uv is not installed.
+        if let Err(e) = python_list(Some(&Pylot::default()), false).await {
+            assert!(matches!(
+                e,
+                PylotError::Other(_) | PylotError::CommandExecution(_)
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_python_uninstall_without_uv_or_users() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        if uvctrl::check(&Pylot::default(), "uv").await.is_err() {
+            let result =
+                python_uninstall(Some(&Pylot::default()), io::Cursor::new("n\n"), "3.99").await;
+            assert!(matches!(result, Err(PylotError::Other(_))));
+        }
     }
 
     #[tokio::test]
     async fn test_delete() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let result = delete(io::stdin(), io::stdin(), Some("test_env")).await;
+        let result = delete(
+            Some(&Pylot::default()),
+            io::stdin(),
+            io::stdin(),
+            Some("test_env"),
+        )
+        .await;
         assert!(result.is_err());
     }
 
     #[tokio::test]
     async fn test_activate() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let result = activate(Some("test_env_not_here")).await;
+        let result = activate(Some(&Pylot::default()), Some("test_env_not_here")).await;
         assert!(result.is_err());
     }
 
@@ -302,9 +1149,17 @@ mod tests {
     async fn test_create_missing_uv() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("y\n");
-        let result_un = uninstall(cursor).await;
+        let result_un = uninstall(Some(&Pylot::default()), cursor, false).await;
         assert!(result_un.is_ok());
-        let result = create("test_env", Some("3.8"), None, None, false).await;
+        let result = create(
+            Some(&Pylot::default()),
+            "test_env",
+            Some("3.8"),
+            None,
+            None,
+            false,
+        )
+        .await;
         assert!(result.is_err());
     }
 
@@ -333,7 +1188,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_missing_name() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let result = create("", None, None, None, false).await;
+        let result = create(Some(&Pylot::default()), "", None, None, None, false).await;
         assert!(result.is_err());
     }
 
@@ -341,16 +1196,42 @@ mod tests {
     async fn test_install_uv_no() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("n\n");
-        let result_in = install(cursor.clone()).await;
+        let result_in = install(Some(&Pylot::default()), cursor.clone(), None).await;
         assert!(result_in.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_install_pinned_uv_asks_before_switching() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let runner = Arc::new(
+            RecordingRunner::new()
+                .with_program("uv", "/opt/uv/bin/uv")
+                .respond("--version", CommandOutput::ok("uv 0.5.10")),
+        );
+        let ctx = Pylot::default().with_runner(runner.clone());
+        let result = install(Some(&ctx), std::io::Cursor::new("n\n"), Some("0.5.11")).await;
+        assert!(result.is_ok());
+        assert_eq!(runner.command_lines(), ["uv --version"]);
+    }
+
+    /// A context whose uv is a standalone install in `dir`.
+    fn standalone_uv(dir: &std::path::Path) -> Pylot {
+        let uv = dir.join("uv");
+        std::fs::write(&uv, "").unwrap();
+        let mut ctx =
+            Pylot::default().with_runner(Arc::new(RecordingRunner::new().with_program("uv", uv)));
+        ctx.settings.uv_install_dir = Some(dir.display().to_string());
+        ctx
+    }
+
     #[tokio::test]
     async fn test_uninstall_uv_no() {
         logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempfile::tempdir().unwrap();
         let cursor = std::io::Cursor::new("n\n");
-        let result_un = uninstall(cursor).await;
+        let result_un = uninstall(Some(&standalone_uv(dir.path())), cursor, false).await;
         assert!(result_un.is_ok());
+        assert!(dir.path().join("uv").exists());
     }
 
     #[tokio::test]
@@ -358,7 +1239,7 @@ mod tests {
     async fn test_install_uv_yes() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("y\n");
-        let result_in = install(cursor.clone()).await;
+        let result_in = install(Some(&Pylot::default()), cursor.clone(), None).await;
         assert!(result_in.is_ok());
     }
 
@@ -367,8 +1248,8 @@ mod tests {
     async fn test_install_update_uv_yes() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("y\n");
-        let result_in = install(cursor.clone()).await;
-        update().await;
+        let result_in = install(Some(&Pylot::default()), cursor.clone(), None).await;
+        assert!(update(Some(&Pylot::default()), None).await.is_ok());
         assert!(result_in.is_ok());
     }
 
@@ -379,15 +1260,15 @@ mod tests {
         #[cfg(unix)]
         {
             let cursor = std::io::Cursor::new("y\n");
-            let result_in = install(cursor.clone()).await;
+            let result_in = install(Some(&Pylot::default()), cursor.clone(), None).await;
             assert!(result_in.is_ok());
-            let result_un = uninstall(cursor).await;
+            let result_un = uninstall(Some(&Pylot::default()), cursor, false).await;
             assert!(result_un.is_ok());
         }
         #[cfg(not(unix))]
         {
             let cursor = std::io::Cursor::new("y\n");
-            let result_un = uninstall(cursor).await;
+            let result_un = uninstall(Some(&Pylot::default()), cursor, false).await;
             assert!(result_un.is_ok());
         }
     }
@@ -395,10 +1276,14 @@ mod tests {
     #[tokio::test]
     async fn test_uninstall_update_uv_yes() {
         logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempfile::tempdir().unwrap();
         let cursor = std::io::Cursor::new("y\n");
-        let result_un = uninstall(cursor).await;
+        let result_un = uninstall(Some(&standalone_uv(dir.path())), cursor, false).await;
         assert!(result_un.is_ok());
-        update().await;
+        assert!(!dir.path().join("uv").exists());
+        // Updating without uv is a no-op.
+        let missing = Pylot::default().with_runner(Arc::new(RecordingRunner::new()));
+        assert!(update(Some(&missing), None).await.is_ok());
     }
 
     // ── update_packages_from_requirements – additional coverage ──────────────
@@ -450,6 +1335,7 @@ mod tests {
         // A package containing a shell metacharacter should be rejected before
         // any network or FS operation occurs.
         let result = create(
+            Some(&Pylot::default()),
             "valid_env",
             None,
             Some(vec!["bad;pkg".to_string()]),
@@ -460,12 +1346,308 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // ── add / remove / upgrade ───────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_outdated_nonexistent_venv() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = outdated(
+            Some(&Pylot::default()),
+            io::stdin(),
+            Some("nonexistent_venv_xyz"),
+            false,
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::VenvNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_outdated_invalid_venv_name() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = outdated(
+            Some(&Pylot::default()),
+            io::stdin(),
+            Some("bad;name"),
+            false,
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::InvalidVenvName(_))));
+    }
+
+    #[tokio::test]
+    async fn test_tree_nonexistent_venv() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = tree(
+            Some(&Pylot::default()),
+            io::stdin(),
+            Some("nonexistent_venv_xyz"),
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::VenvNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_tree_reverse_invalid_venv_name() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = tree(
+            Some(&Pylot::default()),
+            io::stdin(),
+            Some("bad;name"),
+            Some("urllib3"),
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::InvalidVenvName(_))));
+    }
+
+    #[tokio::test]
+    async fn test_du_nonexistent_venv() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = du(
+            Some(&Pylot::default()),
+            Some("nonexistent_venv_xyz"),
+            diskusage::SizeSort::Size,
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::VenvNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_du_invalid_venv_name() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = du(
+            Some(&Pylot::default()),
+            Some("bad;name"),
+            diskusage::SizeSort::Name,
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::InvalidVenvName(_))));
+    }
+
+    #[tokio::test]
+    async fn test_du_all() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        assert!(du(Some(&Pylot::default()), None, diskusage::SizeSort::Size)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_du_in_context_root() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempfile::tempdir().unwrap();
+        let ctx = Pylot::default().with_venvs_path(root.path().to_str().unwrap());
+        assert!(du(Some(&ctx), None, diskusage::SizeSort::Size)
+            .await
+            .is_ok());
+        // Only the context's own root is searched.
+        let result = du(Some(&ctx), Some("env2"), diskusage::SizeSort::Size).await;
+        assert!(matches!(result, Err(PylotError::VenvNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_check_toolchain() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = check_toolchain(Some(&Pylot::default())).await;
+        if let Err(e) = result {
+            assert!(e.to_string().contains("failed"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_doctor_nonexistent_venv() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = doctor(
+            Some(&Pylot::default()),
+            io::empty(),
+            io::empty(),
+            Some("nonexistent_venv_xyz"),
+            false,
+            false,
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::VenvNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_doctor_invalid_venv_name() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = doctor(
+            Some(&Pylot::default()),
+            io::empty(),
+            io::empty(),
+            Some("bad;name"),
+            false,
+            true,
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::InvalidVenvName(_))));
+    }
+
+    /// Two environments whose interpreter is gone, so both need a rebuild.
+    fn broken_venvs(root: &std::path::Path) {
+        for name in ["api", "web"] {
+            let venv = root.join(name);
+            std::fs::create_dir_all(&venv).unwrap();
+            std::fs::write(venv.join("pyvenv.cfg"), "version = 3.12.4\n").unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_doctor_fix_asks_before_rebuilding() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempfile::tempdir().unwrap();
+        broken_venvs(root.path());
+        let runner = Arc::new(RecordingRunner::new());
+        let ctx = Pylot::default()
+            .with_runner(runner.clone())
+            .with_venvs_path(root.path().to_str().unwrap());
+        let result = doctor(
+            Some(&ctx),
+            std::io::Cursor::new("n\n"),
+            io::empty(),
+            None,
+            true,
+            true,
+        )
+        .await;
+        assert!(result.is_ok());
+        assert!(runner.calls().is_empty());
+        assert!(root.path().join("api").join("pyvenv.cfg").exists());
+    }
+
+    #[tokio::test]
+    async fn test_doctor_fix_all_keeps_going_after_a_failure() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let root = tempfile::tempdir().unwrap();
+        broken_venvs(root.path());
+        let runner = Arc::new(
+            RecordingRunner::new()
+                .respond(
+                    "venv api",
+                    CommandOutput::failed(2, "error: download failed"),
+                )
+                .respond_with("venv web", |spec| {
+                    let cwd = spec.cwd.clone().unwrap();
+                    std::fs::create_dir_all(cwd.join("web").join("bin")).unwrap();
+                    CommandOutput::ok("")
+                }),
+        );
+        let ctx = Pylot::default()
+            .with_runner(runner.clone())
+            .with_venvs_path(root.path().to_str().unwrap());
+        let result = doctor(
+            Some(&ctx),
+            std::io::Cursor::new("y\n"),
+            io::empty(),
+            None,
+            true,
+            true,
+        )
+        .await;
+        let err = result.unwrap_err().to_string();
+        assert!(
+            err.starts_with("Could not repair 1 environment(s): 'api'"),
+            "{}",
+            err
+        );
+        assert_eq!(
+            runner.command_lines(),
+            ["uv venv api --python 3.12", "uv venv web --python 3.12"]
+        );
+        assert!(root.path().join("api").join("pyvenv.cfg").exists());
+        assert!(root.path().join("web").join("bin").exists());
+    }
+
+    #[tokio::test]
+    async fn test_add_nonexistent_venv() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = add(
+            Some(&Pylot::default()),
+            std::io::stdin(),
+            Some("definitely_does_not_exist_venv"),
+            vec!["numpy".to_string()],
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::VenvNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_add_without_packages() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = add(
+            Some(&Pylot::default()),
+            std::io::stdin(),
+            Some("test_env"),
+            vec![],
+            Some(""),
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_remove_without_packages() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = remove(
+            Some(&Pylot::default()),
+            std::io::stdin(),
+            Some("test_env"),
+            vec![],
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_remove_nonexistent_venv() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = remove(
+            Some(&Pylot::default()),
+            std::io::stdin(),
+            Some("definitely_does_not_exist_venv"),
+            vec!["numpy".to_string()],
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::VenvNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_without_packages_or_all() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = upgrade(
+            Some(&Pylot::default()),
+            std::io::stdin(),
+            Some("test_env"),
+            vec![],
+            false,
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_invalid_venv_name() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let result = upgrade(
+            Some(&Pylot::default()),
+            std::io::stdin(),
+            Some("bad/name"),
+            vec![],
+            true,
+        )
+        .await;
+        assert!(matches!(result, Err(PylotError::InvalidVenvName(_))));
+    }
+
     // ── delete – venv not found by name ──────────────────────────────────────
 
     #[tokio::test]
     async fn test_delete_nonexistent_venv() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let result = delete(
+            Some(&Pylot::default()),
             std::io::Cursor::new("y\n"),
             std::io::stdin(),
             Some("definitely_does_not_exist_venv"),
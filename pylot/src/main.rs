@@ -1,17 +1,29 @@
 pub mod cli;
 
 use clap_complete::{generate, Shell};
-use pylot::{activate, check, create, delete, install, list, uninstall, update};
-use std::{io, str::FromStr};
+use pylot::{
+    activate, add, cache_clean, cache_info, cache_prune, check, check_toolchain, create, delete,
+    doctor, du, install, install_from, list, outdated, python_install, python_list, python_pin,
+    python_uninstall, remove, tool_install, tool_list, tool_uninstall, tool_upgrade, tree,
+    uninstall, update, upgrade,
+};
+use std::{io, process::ExitCode, str::FromStr};
 
 use clap::{CommandFactory, Parser};
 use cli::cmds::{Cli, Commands};
-use pylot_shared::{logger, settings};
+use pylot_shared::{
+    diskusage::SizeSort,
+    logger, processes,
+    venvquery::{VenvFilter, VenvSort},
+    Pylot,
+};
 
-use crate::cli::cmds::{UvCommands, VenvCommands};
+use crate::cli::cmds::{CacheCommands, PythonCommands, ToolCommands, UvCommands, VenvCommands};
 
+/// Runs the command and exits with a non-zero code if it failed, so scripts
+/// and CI can tell, e.g. when uv is outside `required_uv_version`.
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
     let args = Cli::parse();
 
     // Handle completion generation before settings/logger init so that no
@@ -23,39 +35,96 @@ async fn main() {
             .unwrap_or(Shell::Bash);
         let mut cmd = Cli::command();
         generate(shell, &mut cmd, "pylot", &mut io::stdout());
-        return;
+        return ExitCode::SUCCESS;
     }
 
-    settings::Settings::init().await;
+    let ctx = Pylot::load();
     logger::initialize_logger(log::LevelFilter::Info);
+    // The TUI draws progress itself.
+    let ctx = match args.commands {
+        Some(Commands::Tui) => ctx,
+        _ => cli::progress::attach(ctx),
+    };
+    // uv and the shells running it are not in the terminal's process group,
+    // so Ctrl-C has to stop them before pylot exits.
+    tokio::spawn(async {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            processes::kill_running();
+            std::process::exit(130);
+        }
+    });
 
-    match args.commands {
+    let result = match args.commands {
         Some(Commands::Complete { .. }) => unreachable!(),
         Some(Commands::Uv { command }) => match command {
-            UvCommands::Install => match install(io::stdin()).await {
-                Ok(_) => {}
-                Err(e) => log::error!("{}", e),
-            },
-            UvCommands::Update => update().await,
-            UvCommands::Uninstall => match uninstall(io::stdin()).await {
-                Ok(_) => {}
-                Err(e) => log::error!("{}", e),
-            },
-            UvCommands::Check => match check().await {
-                Ok(_) => log::info!("Astral UV is installed"),
-                Err(e) => log::error!("{}", e),
-            },
+            UvCommands::Install {
+                uv_version,
+                from,
+                sha256,
+            } => match from {
+                Some(source) => {
+                    install_from(Some(&ctx), io::stdin(), &source, sha256.as_deref()).await
+                }
+                None => install(Some(&ctx), io::stdin(), uv_version.as_deref()).await,
+            }
+            .map_err(|e| e.to_string()),
+            UvCommands::Update { to } => update(Some(&ctx), to.as_deref())
+                .await
+                .map_err(|e| e.to_string()),
+            UvCommands::Uninstall { purge } => uninstall(Some(&ctx), io::stdin(), purge)
+                .await
+                .map_err(|e| e.to_string()),
+            UvCommands::Check { json } => check(Some(&ctx), json).await.map_err(|e| e.to_string()),
+            UvCommands::Cache { command } => match command {
+                CacheCommands::Info => cache_info(Some(&ctx)).await,
+                CacheCommands::Clean { packages } => cache_clean(Some(&ctx), &packages).await,
+                CacheCommands::Prune => cache_prune(Some(&ctx)).await,
+            }
+            .map_err(|e| e.to_string()),
+        },
+
+        Some(Commands::Python { command }) => match command {
+            PythonCommands::List { all_versions } => python_list(Some(&ctx), all_versions)
+                .await
+                .map_err(|e| format!("Error listing Python versions: {}", e)),
+            PythonCommands::Install { python_version } => {
+                python_install(Some(&ctx), &python_version)
+                    .await
+                    .map_err(|e| format!("Error installing Python {}: {}", python_version, e))
+            }
+            PythonCommands::Uninstall { python_version } => {
+                python_uninstall(Some(&ctx), io::stdin(), &python_version)
+                    .await
+                    .map_err(|e| format!("Error uninstalling Python {}: {}", python_version, e))
+            }
+            PythonCommands::Pin { python_version } => {
+                python_pin(Some(&ctx), python_version.as_deref())
+                    .await
+                    .map_err(|e| format!("Error pinning Python version: {}", e))
+            }
+        },
+
+        Some(Commands::Tool { command }) => match command {
+            ToolCommands::Install { package } => tool_install(Some(&ctx), &package)
+                .await
+                .map_err(|e| format!("Error installing tool {}: {}", package, e)),
+            ToolCommands::List => tool_list(Some(&ctx))
+                .await
+                .map_err(|e| format!("Error listing tools: {}", e)),
+            ToolCommands::Upgrade { names, all } => tool_upgrade(Some(&ctx), &names, all)
+                .await
+                .map_err(|e| format!("Error upgrading tools: {}", e)),
+            ToolCommands::Uninstall { name } => tool_uninstall(Some(&ctx), &name)
+                .await
+                .map_err(|e| format!("Error uninstalling tool {}: {}", name, e)),
         },
 
         Some(Commands::Venv { command }) => match command {
             VenvCommands::Activate { name_pos, name } => {
                 let venv_name = name.or(name_pos);
-                match activate(venv_name.as_deref()).await {
-                    Ok(_) => {}
-                    Err(e) => {
-                        log::error!("Error activating environment: {}", e);
-                    }
-                }
+                activate(Some(&ctx), venv_name.as_deref())
+                    .await
+                    .map_err(|e| format!("Error activating environment: {}", e))
             }
             VenvCommands::Create {
                 name_pos,
@@ -64,15 +133,9 @@ async fn main() {
                 packages,
                 requirements,
                 default,
-            } => {
-                let name = match name.or(name_pos) {
-                    Some(n) => n,
-                    None => {
-                        log::error!("Virtual environment name is required for creation");
-                        return;
-                    }
-                };
-                match create(
+            } => match name.or(name_pos) {
+                Some(name) => create(
+                    Some(&ctx),
                     &name,
                     Some(&python_version),
                     Some(packages),
@@ -80,33 +143,137 @@ async fn main() {
                     default,
                 )
                 .await
-                {
-                    Ok(_) => {}
-                    Err(e) => {
-                        log::error!("{}", e);
-                    }
-                }
-            }
+                .map_err(|e| e.to_string()),
+                None => Err("Virtual environment name is required for creation".to_string()),
+            },
             VenvCommands::Delete { name_pos, name } => {
                 let venv_name = name.or(name_pos);
-                match delete(io::stdin(), io::stdin(), venv_name.as_deref()).await {
-                    Ok(_) => {}
-                    Err(e) => {
-                        log::error!("Error deleting environment: {}", e);
-                    }
-                }
+                delete(Some(&ctx), io::stdin(), io::stdin(), venv_name.as_deref())
+                    .await
+                    .map_err(|e| format!("Error deleting environment: {}", e))
+            }
+            VenvCommands::List { python, has, sort } => {
+                let filter = VenvFilter {
+                    python,
+                    has,
+                    ..Default::default()
+                };
+                // clap restricts `sort` to VenvSort::VALUES.
+                let sort = sort.parse::<VenvSort>().unwrap_or_default();
+                list(Some(&ctx), &filter, sort).await;
+                Ok(())
+            }
+            VenvCommands::Add {
+                name,
+                name_pos,
+                packages,
+                requirements,
+            } => {
+                let (venv_name, packages) =
+                    VenvCommands::split_name_and_packages(name, name_pos, packages);
+                add(
+                    Some(&ctx),
+                    io::stdin(),
+                    venv_name.as_deref(),
+                    packages,
+                    Some(&requirements),
+                )
+                .await
+                .map_err(|e| format!("Error adding packages: {}", e))
+            }
+            VenvCommands::Remove {
+                name,
+                name_pos,
+                packages,
+            } => {
+                let (venv_name, packages) =
+                    VenvCommands::split_name_and_packages(name, name_pos, packages);
+                remove(Some(&ctx), io::stdin(), venv_name.as_deref(), packages)
+                    .await
+                    .map_err(|e| format!("Error removing packages: {}", e))
+            }
+            VenvCommands::Outdated {
+                name,
+                name_pos,
+                all,
+            } => {
+                let venv_name = name.or(name_pos);
+                outdated(Some(&ctx), io::stdin(), venv_name.as_deref(), all)
+                    .await
+                    .map_err(|e| format!("Error checking for outdated packages: {}", e))
+            }
+            VenvCommands::Du {
+                name,
+                name_pos,
+                sort,
+            } => {
+                let venv_name = name.or(name_pos);
+                // clap restricts `sort` to SizeSort::VALUES.
+                let sort = sort.parse::<SizeSort>().unwrap_or_default();
+                du(Some(&ctx), venv_name.as_deref(), sort)
+                    .await
+                    .map_err(|e| format!("Error measuring disk usage: {}", e))
+            }
+            VenvCommands::Doctor {
+                name,
+                name_pos,
+                all,
+                fix,
+            } => {
+                let venv_name = name.or(name_pos);
+                doctor(
+                    Some(&ctx),
+                    io::stdin(),
+                    io::stdin(),
+                    venv_name.as_deref(),
+                    all,
+                    fix,
+                )
+                .await
+                .map_err(|e| format!("Error running doctor: {}", e))
+            }
+            VenvCommands::Tree {
+                name,
+                name_pos,
+                reverse,
+            } => {
+                let venv_name = name.or(name_pos);
+                tree(
+                    Some(&ctx),
+                    io::stdin(),
+                    venv_name.as_deref(),
+                    reverse.as_deref(),
+                )
+                .await
+                .map_err(|e| format!("Error showing dependency tree: {}", e))
+            }
+            VenvCommands::Upgrade {
+                name,
+                name_pos,
+                packages,
+                all,
+            } => {
+                let (venv_name, packages) =
+                    VenvCommands::split_name_and_packages(name, name_pos, packages);
+                upgrade(Some(&ctx), io::stdin(), venv_name.as_deref(), packages, all)
+                    .await
+                    .map_err(|e| format!("Error upgrading packages: {}", e))
             }
-            VenvCommands::List => list().await,
         },
 
-        None => {
-            log::error!("No command provided");
-        }
+        None => Err("No command provided".to_string()),
 
-        Some(Commands::Tui) => {
-            if let Err(e) = pylot_tui::run().await {
-                log::error!("TUI error: {}", e);
-            }
+        Some(Commands::Tui) => pylot_tui::run(ctx)
+            .await
+            .map_err(|e| format!("TUI error: {}", e)),
+        Some(Commands::Doctor) => check_toolchain(Some(&ctx)).await.map_err(|e| e.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            log::error!("{}", e);
+            ExitCode::FAILURE
         }
     }
 }
@@ -118,8 +285,12 @@ mod tests {
     use clap::Parser;
     use predicates::prelude::*;
 
-    use pylot::cli::cmds::{Cli, Commands, VenvCommands};
-    use pylot_shared::constants::ERROR_VENV_NOT_EXISTS;
+    use pylot::cli::cmds::{
+        CacheCommands, Cli, Commands, PythonCommands, ToolCommands, UvCommands, VenvCommands,
+    };
+    use pylot_shared::{
+        constants::ERROR_VENV_NOT_EXISTS, diskusage::SizeSort, venvquery::VenvSort,
+    };
 
     #[test]
     fn test_cli_output_help() {
@@ -147,13 +318,23 @@ mod tests {
     #[test]
     fn test_cli_output_check() {
         let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
-        cmd.args(["uv", "check"])
+        let assert = cmd
+            .args(["uv", "check"])
             .current_dir(env!("CARGO_MANIFEST_DIR"))
             .assert()
-            .success()
             .stderr(predicate::str::contains(
                 "Checking if Astral UV is installed and configured...",
             ));
+        // Without uv the check fails, and so does the process.
+        if std::process::Command::new("uv")
+            .arg("--version")
+            .output()
+            .is_ok()
+        {
+            assert.success();
+        } else {
+            assert.failure();
+        }
     }
 
     #[test]
@@ -162,7 +343,7 @@ mod tests {
         cmd.args(["venv", "activate"])
             .current_dir(env!("CARGO_MANIFEST_DIR"))
             .assert()
-            .success()
+            .failure()
             .stderr(predicate::str::contains("virtual environment"));
     }
 
@@ -172,7 +353,7 @@ mod tests {
         cmd.args(["venv", "delete"])
             .current_dir(env!("CARGO_MANIFEST_DIR"))
             .assert()
-            .success()
+            .failure()
             .stderr(predicate::str::contains("virtual environment"));
     }
 
@@ -182,7 +363,7 @@ mod tests {
         cmd.args(["venv", "delete", "myvenv"])
             .current_dir(env!("CARGO_MANIFEST_DIR"))
             .assert()
-            .success()
+            .failure()
             .stderr(predicate::str::contains(ERROR_VENV_NOT_EXISTS));
     }
 
@@ -192,7 +373,7 @@ mod tests {
         cmd.args(["venv", "activate", "myvenv"])
             .current_dir(env!("CARGO_MANIFEST_DIR"))
             .assert()
-            .success()
+            .failure()
             .stderr(predicate::str::contains(ERROR_VENV_NOT_EXISTS));
     }
 
@@ -263,18 +444,264 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_accepts_version_specifiers() {
+        for request in [">=3.10", "pypy3.10", "cpython@3.11.4", "/usr/bin/python3"] {
+            let args = Cli::try_parse_from(["program", "v", "c", "env", "-v", request]);
+            assert!(args.is_ok(), "{}", request);
+        }
+    }
+
+    #[test]
+    fn test_create_invalid_python_version_is_rejected() {
+        let result = Cli::try_parse_from(["program", "venv", "create", "env", "-v", "3.x"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_list_command() {
         let args = Cli::try_parse_from(["program", "venv", "list"]).unwrap();
 
+        if let Some(Commands::Venv {
+            command: VenvCommands::List { python, has, sort },
+        }) = args.commands
+        {
+            assert!(python.is_none());
+            assert!(has.is_empty());
+            assert_eq!(sort, "name");
+        } else {
+            panic!("Failed to parse list command");
+        }
+    }
+
+    #[test]
+    fn test_list_with_filters_and_sort() {
+        let args = Cli::try_parse_from([
+            "program", "v", "ls", "--python", "3.11", "--has", "numpy", "--has", "pandas",
+            "--sort", "size",
+        ])
+        .unwrap();
+
+        if let Some(Commands::Venv {
+            command: VenvCommands::List { python, has, sort },
+        }) = args.commands
+        {
+            assert_eq!(python.as_deref(), Some("3.11"));
+            assert_eq!(has, ["numpy", "pandas"]);
+            assert_eq!(sort.parse::<VenvSort>().unwrap(), VenvSort::Size);
+        } else {
+            panic!("Failed to parse list command");
+        }
+        assert!(Cli::try_parse_from(["program", "venv", "list", "--sort", "colour"]).is_err());
+    }
+
+    #[test]
+    fn test_add_command() {
+        let args = Cli::try_parse_from([
+            "program", "venv", "add", "my-venv", "numpy", "pandas", "-r", "req.txt",
+        ])
+        .unwrap();
+
+        if let Some(Commands::Venv {
+            command:
+                VenvCommands::Add {
+                    name_pos,
+                    packages,
+                    requirements,
+                    ..
+                },
+        }) = args.commands
+        {
+            assert_eq!(name_pos, Some("my-venv".to_string()));
+            assert_eq!(packages, vec!["numpy", "pandas"]);
+            assert_eq!(requirements, "req.txt");
+        } else {
+            panic!("Failed to parse add command");
+        }
+    }
+
+    #[test]
+    fn test_remove_command() {
+        let args = Cli::try_parse_from(["program", "venv", "rm", "my-venv", "numpy"]).unwrap();
+
+        if let Some(Commands::Venv {
+            command: VenvCommands::Remove {
+                name_pos, packages, ..
+            },
+        }) = args.commands
+        {
+            assert_eq!(name_pos, Some("my-venv".to_string()));
+            assert_eq!(packages, vec!["numpy"]);
+        } else {
+            panic!("Failed to parse remove command");
+        }
+    }
+
+    #[test]
+    fn test_upgrade_all_command() {
+        let args = Cli::try_parse_from(["program", "venv", "upgrade", "my-venv", "--all"]).unwrap();
+
+        if let Some(Commands::Venv {
+            command:
+                VenvCommands::Upgrade {
+                    name_pos,
+                    packages,
+                    all,
+                    ..
+                },
+        }) = args.commands
+        {
+            assert_eq!(name_pos, Some("my-venv".to_string()));
+            assert!(packages.is_empty());
+            assert!(all);
+        } else {
+            panic!("Failed to parse upgrade command");
+        }
+    }
+
+    #[test]
+    fn test_upgrade_all_conflicts_with_packages() {
+        let result =
+            Cli::try_parse_from(["program", "venv", "upgrade", "my-venv", "numpy", "--all"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_outdated_command() {
+        let args = Cli::try_parse_from(["program", "venv", "outdated", "my-venv"]).unwrap();
+
+        if let Some(Commands::Venv {
+            command: VenvCommands::Outdated { name_pos, all, .. },
+        }) = args.commands
+        {
+            assert_eq!(name_pos, Some("my-venv".to_string()));
+            assert!(!all);
+        } else {
+            panic!("Failed to parse outdated command");
+        }
+    }
+
+    #[test]
+    fn test_outdated_all_conflicts_with_name() {
+        let result = Cli::try_parse_from(["program", "venv", "out", "my-venv", "--all"]);
+        assert!(result.is_err());
+        let args = Cli::try_parse_from(["program", "venv", "out", "--all"]).unwrap();
         assert!(matches!(
             args.commands,
             Some(Commands::Venv {
-                command: VenvCommands::List
+                command: VenvCommands::Outdated { all: true, .. }
             })
         ));
     }
 
+    #[test]
+    fn test_du_command_defaults_to_size_sort() {
+        let args = Cli::try_parse_from(["program", "venv", "du"]).unwrap();
+        if let Some(Commands::Venv {
+            command: VenvCommands::Du { name_pos, sort, .. },
+        }) = args.commands
+        {
+            assert!(name_pos.is_none());
+            assert_eq!(sort, "size");
+        } else {
+            panic!("Failed to parse du command");
+        }
+    }
+
+    #[test]
+    fn test_du_alias_with_sort() {
+        let args = Cli::try_parse_from([
+            "program",
+            "venv",
+            "size",
+            "my-venv",
+            "--sort",
+            "site-packages",
+        ])
+        .unwrap();
+        if let Some(Commands::Venv {
+            command: VenvCommands::Du { name_pos, sort, .. },
+        }) = args.commands
+        {
+            assert_eq!(name_pos, Some("my-venv".to_string()));
+            assert_eq!(sort.parse::<SizeSort>().unwrap(), SizeSort::SitePackages);
+        } else {
+            panic!("Failed to parse du command");
+        }
+    }
+
+    #[test]
+    fn test_du_invalid_sort_is_rejected() {
+        let result = Cli::try_parse_from(["program", "venv", "du", "--sort", "color"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_doctor_command_with_fix() {
+        let args = Cli::try_parse_from(["program", "venv", "doctor", "my-venv", "--fix"]).unwrap();
+        if let Some(Commands::Venv {
+            command: VenvCommands::Doctor {
+                name_pos, all, fix, ..
+            },
+        }) = args.commands
+        {
+            assert_eq!(name_pos, Some("my-venv".to_string()));
+            assert!(!all);
+            assert!(fix);
+        } else {
+            panic!("Failed to parse doctor command");
+        }
+    }
+
+    #[test]
+    fn test_doctor_alias_all() {
+        let args = Cli::try_parse_from(["program", "v", "dr", "--all"]).unwrap();
+        if let Some(Commands::Venv {
+            command: VenvCommands::Doctor { all, fix, .. },
+        }) = args.commands
+        {
+            assert!(all);
+            assert!(!fix);
+        } else {
+            panic!("Failed to parse doctor command");
+        }
+    }
+
+    #[test]
+    fn test_doctor_all_conflicts_with_name() {
+        let result = Cli::try_parse_from(["program", "venv", "doctor", "my-venv", "--all"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tree_reverse_command() {
+        let args =
+            Cli::try_parse_from(["program", "venv", "tree", "my-venv", "--reverse", "urllib3"])
+                .unwrap();
+
+        if let Some(Commands::Venv {
+            command: VenvCommands::Tree {
+                name_pos, reverse, ..
+            },
+        }) = args.commands
+        {
+            assert_eq!(name_pos, Some("my-venv".to_string()));
+            assert_eq!(reverse, Some("urllib3".to_string()));
+        } else {
+            panic!("Failed to parse tree command");
+        }
+    }
+
+    #[test]
+    fn test_cli_output_add_name() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        cmd.args(["venv", "add", "myvenv", "numpy"])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(ERROR_VENV_NOT_EXISTS));
+    }
+
     #[test]
     fn test_no_command() {
         let result = Cli::try_parse_from(["program"]);
@@ -282,6 +709,263 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_uv_install_with_version() {
+        let args =
+            Cli::try_parse_from(["program", "uv", "install", "--version", "0.5.11"]).unwrap();
+        match args.commands {
+            Some(Commands::Uv {
+                command: UvCommands::Install { uv_version, .. },
+            }) => assert_eq!(uv_version.as_deref(), Some("0.5.11")),
+            _ => panic!("Failed to parse uv install --version"),
+        }
+    }
+
+    #[test]
+    fn test_uv_install_from_archive() {
+        let args = Cli::try_parse_from([
+            "program",
+            "uv",
+            "i",
+            "--from",
+            "uv.tar.gz",
+            "--sha256",
+            "abc123",
+        ])
+        .unwrap();
+        match args.commands {
+            Some(Commands::Uv {
+                command:
+                    UvCommands::Install {
+                        uv_version,
+                        from,
+                        sha256,
+                    },
+            }) => {
+                assert!(uv_version.is_none());
+                assert_eq!(from.as_deref(), Some(std::path::Path::new("uv.tar.gz")));
+                assert_eq!(sha256.as_deref(), Some("abc123"));
+            }
+            _ => panic!("Failed to parse uv install --from"),
+        }
+        assert!(Cli::try_parse_from([
+            "program",
+            "uv",
+            "install",
+            "--from",
+            "uv.tar.gz",
+            "--version",
+            "0.5.11"
+        ])
+        .is_err());
+        assert!(Cli::try_parse_from(["program", "uv", "install", "--sha256", "abc123"]).is_err());
+    }
+
+    #[test]
+    fn test_uv_uninstall_purge() {
+        let args = Cli::try_parse_from(["program", "uv", "u", "--purge"]).unwrap();
+        match args.commands {
+            Some(Commands::Uv {
+                command: UvCommands::Uninstall { purge },
+            }) => assert!(purge),
+            _ => panic!("Failed to parse uv uninstall --purge"),
+        }
+        let args = Cli::try_parse_from(["program", "uv", "uninstall"]).unwrap();
+        match args.commands {
+            Some(Commands::Uv {
+                command: UvCommands::Uninstall { purge },
+            }) => assert!(!purge),
+            _ => panic!("Failed to parse uv uninstall"),
+        }
+    }
+
+    #[test]
+    fn test_uv_check_json() {
+        let args = Cli::try_parse_from(["program", "uv", "check", "--json"]).unwrap();
+        assert!(matches!(
+            args.commands,
+            Some(Commands::Uv {
+                command: UvCommands::Check { json: true }
+            })
+        ));
+        let args = Cli::try_parse_from(["program", "u", "c"]).unwrap();
+        assert!(matches!(
+            args.commands,
+            Some(Commands::Uv {
+                command: UvCommands::Check { json: false }
+            })
+        ));
+    }
+
+    #[test]
+    fn test_uv_cache_commands() {
+        let args = Cli::try_parse_from(["program", "uv", "cache", "info"]).unwrap();
+        assert!(matches!(
+            args.commands,
+            Some(Commands::Uv {
+                command: UvCommands::Cache {
+                    command: CacheCommands::Info
+                }
+            })
+        ));
+        let args = Cli::try_parse_from(["program", "u", "ca", "c", "numpy", "pandas"]).unwrap();
+        match args.commands {
+            Some(Commands::Uv {
+                command:
+                    UvCommands::Cache {
+                        command: CacheCommands::Clean { packages },
+                    },
+            }) => assert_eq!(packages, vec!["numpy", "pandas"]),
+            _ => panic!("Failed to parse uv cache clean"),
+        }
+        let args = Cli::try_parse_from(["program", "uv", "cache", "prune"]).unwrap();
+        assert!(matches!(
+            args.commands,
+            Some(Commands::Uv {
+                command: UvCommands::Cache {
+                    command: CacheCommands::Prune
+                }
+            })
+        ));
+    }
+
+    #[test]
+    fn test_tool_install_and_uninstall() {
+        let args = Cli::try_parse_from(["program", "tool", "install", "ruff==0.3.0"]).unwrap();
+        match args.commands {
+            Some(Commands::Tool {
+                command: ToolCommands::Install { package },
+            }) => assert_eq!(package, "ruff==0.3.0"),
+            _ => panic!("Failed to parse tool install"),
+        }
+        let args = Cli::try_parse_from(["program", "t", "u", "ruff"]).unwrap();
+        match args.commands {
+            Some(Commands::Tool {
+                command: ToolCommands::Uninstall { name },
+            }) => assert_eq!(name, "ruff"),
+            _ => panic!("Failed to parse tool uninstall"),
+        }
+        let args = Cli::try_parse_from(["program", "t", "ls"]).unwrap();
+        assert!(matches!(
+            args.commands,
+            Some(Commands::Tool {
+                command: ToolCommands::List
+            })
+        ));
+    }
+
+    #[test]
+    fn test_tool_upgrade_names_or_all() {
+        let args = Cli::try_parse_from(["program", "tool", "upgrade", "ruff", "pylint"]).unwrap();
+        match args.commands {
+            Some(Commands::Tool {
+                command: ToolCommands::Upgrade { names, all },
+            }) => {
+                assert_eq!(names, vec!["ruff", "pylint"]);
+                assert!(!all);
+            }
+            _ => panic!("Failed to parse tool upgrade"),
+        }
+        let args = Cli::try_parse_from(["program", "t", "up", "--all"]).unwrap();
+        match args.commands {
+            Some(Commands::Tool {
+                command: ToolCommands::Upgrade { names, all },
+            }) => {
+                assert!(names.is_empty());
+                assert!(all);
+            }
+            _ => panic!("Failed to parse tool upgrade --all"),
+        }
+        assert!(Cli::try_parse_from(["program", "tool", "upgrade"]).is_err());
+        assert!(Cli::try_parse_from(["program", "tool", "upgrade", "ruff", "--all"]).is_err());
+    }
+
+    #[test]
+    fn test_uv_update_to_version_alias() {
+        let args = Cli::try_parse_from(["program", "uv", "up", "--to", "0.4.30"]).unwrap();
+        match args.commands {
+            Some(Commands::Uv {
+                command: UvCommands::Update { to },
+            }) => assert_eq!(to.as_deref(), Some("0.4.30")),
+            _ => panic!("Failed to parse uv update --to"),
+        }
+        let args = Cli::try_parse_from(["program", "uv", "update"]).unwrap();
+        assert!(matches!(
+            args.commands,
+            Some(Commands::Uv {
+                command: UvCommands::Update { to: None }
+            })
+        ));
+    }
+
+    #[test]
+    fn test_python_list_command() {
+        let args = Cli::try_parse_from(["program", "py", "ls", "--all-versions"]).unwrap();
+        if let Some(Commands::Python {
+            command: PythonCommands::List { all_versions },
+        }) = args.commands
+        {
+            assert!(all_versions);
+        } else {
+            panic!("Failed to parse python list command");
+        }
+    }
+
+    #[test]
+    fn test_python_install_command() {
+        let args = Cli::try_parse_from(["program", "python", "install", "3.12"]).unwrap();
+        if let Some(Commands::Python {
+            command: PythonCommands::Install { python_version },
+        }) = args.commands
+        {
+            assert_eq!(python_version, "3.12");
+        } else {
+            panic!("Failed to parse python install command");
+        }
+    }
+
+    #[test]
+    fn test_python_install_requires_version() {
+        let result = Cli::try_parse_from(["program", "python", "install"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_python_uninstall_alias() {
+        let args = Cli::try_parse_from(["program", "py", "u", "3.11"]).unwrap();
+        assert!(matches!(
+            args.commands,
+            Some(Commands::Python {
+                command: PythonCommands::Uninstall { .. }
+            })
+        ));
+    }
+
+    #[test]
+    fn test_python_pin_without_version() {
+        let args = Cli::try_parse_from(["program", "python", "pin"]).unwrap();
+        if let Some(Commands::Python {
+            command: PythonCommands::Pin { python_version },
+        }) = args.commands
+        {
+            assert!(python_version.is_none());
+        } else {
+            panic!("Failed to parse python pin command");
+        }
+    }
+
+    #[test]
+    fn test_doctor_command() {
+        let args = Cli::try_parse_from(["program", "doctor"]).unwrap();
+        assert!(matches!(args.commands, Some(Commands::Doctor)));
+    }
+
+    #[test]
+    fn test_doctor_command_takes_no_arguments() {
+        let result = Cli::try_parse_from(["program", "doctor", "myenv"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_complete_bash() {
         let args = Cli::try_parse_from(["program", "complete", "bash"]).unwrap();
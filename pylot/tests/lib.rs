@@ -1,55 +1,115 @@
 #[cfg(test)]
 mod tests {
-    use pylot::{create, delete, install, list};
-    use pylot_shared::logger;
-    use shellexpand::tilde;
-    use std::io;
+    use pylot::{create, delete, list};
+    use pylot_shared::{
+        logger,
+        processes::{fake_venv, CommandOutput, RecordingRunner},
+        venvquery::{VenvFilter, VenvSort},
+        Pylot,
+    };
+    use std::{io, path::Path, sync::Arc};
+    use tempfile::{tempdir, TempDir};
     use tokio::fs::write;
 
+    const PYTHON_LIST: &str = "cpython-3.12.4-linux-x86_64-gnu    /usr/bin/python3.12\n";
+
     struct TestContext {
+        ctx: Pylot,
+        runner: Arc<RecordingRunner>,
+        root: TempDir,
         cursor_yes: std::io::Cursor<&'static str>,
         cursor_one: std::io::Cursor<&'static str>,
         cursor_no: std::io::Cursor<&'static str>,
     }
 
     impl TestContext {
-        async fn setup() -> Self {
+        /// A context whose venvs live in a temporary directory and whose uv
+        /// is faked, so nothing is downloaded or installed.
+        fn setup() -> Self {
             logger::initialize_logger(log::LevelFilter::Trace);
-            install(std::io::Cursor::new("y\n")).await.ok();
-
-            let uv_path = tilde("~/.local/bin/uv");
-            std::env::set_var(
-                "PATH",
-                format!("{}:{}", uv_path, std::env::var("PATH").unwrap()),
+            let root = tempdir().unwrap();
+            let runner = Arc::new(
+                RecordingRunner::new()
+                    .with_program("uv", "/usr/bin/uv")
+                    .respond("python list", CommandOutput::ok(PYTHON_LIST))
+                    .respond("pip install", CommandOutput::ok("Installed 1 package"))
+                    .respond_with("venv", fake_venv),
             );
+            let ctx = Pylot::default()
+                .with_runner(runner.clone())
+                .with_venvs_path(root.path().to_str().unwrap());
             TestContext {
+                ctx,
+                runner,
+                root,
                 cursor_yes: std::io::Cursor::new("y\n"),
                 cursor_one: std::io::Cursor::new("1\n"),
                 cursor_no: std::io::Cursor::new("n\n"),
             }
         }
+
+        fn venv_path(&self, name: &str) -> String {
+            self.root.path().join(name).display().to_string()
+        }
     }
 
     #[tokio::test]
     async fn test_create_venv_already_exists() {
         #[cfg(unix)]
         {
-            let tc = TestContext::setup().await;
+            let tc = TestContext::setup();
 
-            list().await;
+            list(Some(&tc.ctx), &VenvFilter::default(), VenvSort::Name).await;
             let venv_name = "test_env_exists";
-            let result = create(venv_name, None, Some(vec!["numpy".to_string()]), None, true).await;
+            let result = create(
+                Some(&tc.ctx),
+                venv_name,
+                None,
+                Some(vec!["numpy".to_string()]),
+                None,
+                true,
+            )
+            .await;
             log::error!("Result: {:?}", result);
             assert!(result.is_ok());
-            let result_exists =
-                create(venv_name, None, Some(vec!["numpy".to_string()]), None, true).await;
+            let result_exists = create(
+                Some(&tc.ctx),
+                venv_name,
+                None,
+                Some(vec!["numpy".to_string()]),
+                None,
+                true,
+            )
+            .await;
             log::error!("Result exists: {:?}", result_exists);
             assert!(result_exists.is_err());
-            let result_index = delete(tc.cursor_no.clone(), tc.cursor_one.clone(), None).await;
+            // The second create stops before running uv again.
+            let venvs = tc
+                .runner
+                .calls()
+                .iter()
+                .filter(|spec| spec.args.first().is_some_and(|arg| arg == "venv"))
+                .count();
+            assert_eq!(venvs, 1);
+
+            let result_index = delete(
+                Some(&tc.ctx),
+                tc.cursor_no.clone(),
+                tc.cursor_one.clone(),
+                None,
+            )
+            .await;
             assert!(result_index.is_ok());
-            let result_name =
-                delete(tc.cursor_yes.clone(), std::io::stdin(), Some(venv_name)).await;
+            assert!(Path::new(&tc.venv_path(venv_name)).exists());
+            let result_name = delete(
+                Some(&tc.ctx),
+                tc.cursor_yes.clone(),
+                std::io::stdin(),
+                Some(venv_name),
+            )
+            .await;
             assert!(result_name.is_ok());
+            assert!(!Path::new(&tc.venv_path(venv_name)).exists());
         }
     }
 
@@ -57,10 +117,11 @@ mod tests {
     async fn test_create_venv_invalid_python() {
         #[cfg(unix)]
         {
-            TestContext::setup().await;
+            let tc = TestContext::setup();
 
-            list().await;
+            list(Some(&tc.ctx), &VenvFilter::default(), VenvSort::Name).await;
             let result_pyerr = create(
+                Some(&tc.ctx),
                 "test_env_invalid_python",
                 Some("0.1"),
                 Some(vec!["numpy".to_string()]),
@@ -70,6 +131,12 @@ mod tests {
             .await;
             log::error!("Result pyerr: {:?}", result_pyerr);
             assert!(result_pyerr.is_err());
+            // Rejected before an environment is created.
+            assert!(!tc
+                .runner
+                .command_lines()
+                .iter()
+                .any(|line| line.starts_with("uv venv")));
         }
     }
 
@@ -77,10 +144,11 @@ mod tests {
     async fn test_create_venv_invalid_requirements() {
         #[cfg(unix)]
         {
-            TestContext::setup().await;
+            let tc = TestContext::setup();
 
-            list().await;
+            list(Some(&tc.ctx), &VenvFilter::default(), VenvSort::Name).await;
             let result_reqerr = create(
+                Some(&tc.ctx),
                 "test_env_invalid_req",
                 None,
                 Some(vec!["numpy".to_string()]),
@@ -90,6 +158,11 @@ mod tests {
             .await;
             log::error!("Result reqerr: {:?}", result_reqerr);
             assert!(result_reqerr.is_err());
+            assert!(!tc
+                .runner
+                .command_lines()
+                .iter()
+                .any(|line| line.starts_with("uv venv")));
         }
     }
 
@@ -97,22 +170,44 @@ mod tests {
     async fn test_create_venv_with_requirements() {
         #[cfg(unix)]
         {
-            use tokio::fs;
-
             let venv_name = "test_env_req";
-            let tc = TestContext::setup().await;
-            let pwd = std::env::current_dir().unwrap();
-            let requirements = format!("{}/create_requirements.txt", pwd.display());
-            let file_result = write(&requirements, "pandas\nscipy\n").await;
+            let tc = TestContext::setup();
+            let req_dir = tempdir().unwrap();
+            let requirements = req_dir.path().join("create_requirements.txt");
+            let requirements = requirements.to_str().unwrap();
+            let file_result = write(requirements, "pandas\nscipy\n").await;
             assert!(file_result.is_ok());
-            list().await;
-            let result = create(venv_name, None, None, Some(&requirements), true).await;
+            list(Some(&tc.ctx), &VenvFilter::default(), VenvSort::Name).await;
+            let result = create(
+                Some(&tc.ctx),
+                venv_name,
+                None,
+                None,
+                Some(requirements),
+                true,
+            )
+            .await;
             log::error!("Result: {:?}", result);
             assert!(result.is_ok());
-            list().await;
-            let result = delete(tc.cursor_yes.clone(), io::stdin(), Some(venv_name)).await;
+            assert_eq!(
+                tc.runner.command_lines()[1..],
+                [
+                    format!("uv venv {} --python 3.12", venv_name),
+                    format!(
+                        "sh -c . {}/bin/activate && uv pip install pandas scipy",
+                        tc.venv_path(venv_name)
+                    ),
+                ]
+            );
+            list(Some(&tc.ctx), &VenvFilter::default(), VenvSort::Name).await;
+            let result = delete(
+                Some(&tc.ctx),
+                tc.cursor_yes.clone(),
+                io::stdin(),
+                Some(venv_name),
+            )
+            .await;
             assert!(result.is_ok());
-            fs::remove_file(requirements).await.unwrap();
         }
     }
 
@@ -120,11 +215,13 @@ mod tests {
     async fn test_create_venv_defaults() {
         #[cfg(unix)]
         {
-            let tc = TestContext::setup().await;
+            let mut tc = TestContext::setup();
+            tc.ctx.settings.default_pkgs = vec!["requests".to_string()];
             let venv_name = "test_env_def";
 
-            list().await;
+            list(Some(&tc.ctx), &VenvFilter::default(), VenvSort::Name).await;
             let result = create(
+                Some(&tc.ctx),
                 venv_name,
                 None,
                 Some(vec!["pandas".to_string()]),
@@ -134,8 +231,22 @@ mod tests {
             .await;
             log::error!("Result: {:?}", result);
             assert!(result.is_ok());
-            list().await;
-            let result = delete(tc.cursor_yes.clone(), io::stdin(), Some(venv_name)).await;
+            let calls = tc.runner.calls();
+            let pip = calls.last().unwrap();
+            assert_eq!(pip.program, "sh");
+            assert!(
+                pip.args[1].ends_with("uv pip install pandas requests"),
+                "{}",
+                pip
+            );
+            list(Some(&tc.ctx), &VenvFilter::default(), VenvSort::Name).await;
+            let result = delete(
+                Some(&tc.ctx),
+                tc.cursor_yes.clone(),
+                io::stdin(),
+                Some(venv_name),
+            )
+            .await;
             assert!(result.is_ok());
         }
     }
@@ -0,0 +1,208 @@
+use crate::{
+    processes::{self, CommandRunner},
+    progress::{ProgressEvent, ProgressHandler, ReportingRunner},
+    settings::Settings,
+    uvvenv::UvVenv,
+    venvmanager::VenvManager,
+};
+use std::{
+    borrow::Cow,
+    path::PathBuf,
+    sync::{mpsc, Arc},
+};
+
+/// Everything a Pylot operation needs from its surroundings: the settings,
+/// the runner that spawns `uv` and friends, and through
+/// [`Settings::venvs_path`] the root the environments live under.
+///
+/// The CLI builds one with [`Pylot::load`] at start-up and passes it down.
+/// Embedders can hold several side by side, e.g. one per venvs root, and
+/// tests can point one at a temporary directory without touching any other
+/// state.
+///
+/// # Example
+///
+/// ```
+/// use pylot_shared::{context::Pylot, settings::Settings};
+///
+/// let ctx = Pylot::new(Settings::default()).with_venvs_path("/tmp/pylot-venvs");
+/// assert_eq!(ctx.venvs_path(), std::path::PathBuf::from("/tmp/pylot-venvs"));
+/// ```
+#[derive(Clone)]
+pub struct Pylot {
+    pub settings: Settings,
+    pub runner: Arc<dyn CommandRunner>,
+    progress: Option<ProgressHandler>,
+}
+
+impl Pylot {
+    /// A context for `settings` that runs real processes.
+    pub fn new(settings: Settings) -> Self {
+        Pylot {
+            settings,
+            runner: processes::system_runner(),
+            progress: None,
+        }
+    }
+
+    /// A context for the `settings.toml` next to the running executable, see
+    /// [`Settings::load`].
+    pub fn load() -> Self {
+        Self::new(Settings::load())
+    }
+
+    /// Run commands through `runner`, e.g. a
+    /// [`RecordingRunner`](crate::processes::RecordingRunner) in tests.
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    /// Report the steps of every operation, and each line the commands it
+    /// runs print, to `on_event`. Call this after [`Pylot::with_runner`], as
+    /// the output is picked up by wrapping the runner.
+    ///
+    /// Handlers add up: a clone given another handler reports to both.
+    pub fn with_progress(
+        mut self,
+        on_event: impl Fn(ProgressEvent) + Send + Sync + 'static,
+    ) -> Self {
+        let on_event: ProgressHandler = Arc::new(on_event);
+        self.runner = Arc::new(ReportingRunner::new(self.runner, on_event.clone()));
+        self.progress = Some(match self.progress.take() {
+            Some(previous) => Arc::new(move |event: ProgressEvent| {
+                previous(event.clone());
+                on_event(event);
+            }),
+            None => on_event,
+        });
+        self
+    }
+
+    /// Like [`Pylot::with_progress`], with the events sent over a channel.
+    ///
+    /// ```
+    /// use pylot_shared::{context::Pylot, progress::ProgressEvent};
+    ///
+    /// let (ctx, rx) = Pylot::default().with_progress_channel();
+    /// ctx.report(ProgressEvent::Step("Working".to_string()));
+    /// assert_eq!(rx.try_recv(), Ok(ProgressEvent::Step("Working".to_string())));
+    /// ```
+    pub fn with_progress_channel(self) -> (Self, mpsc::Receiver<ProgressEvent>) {
+        let (tx, rx) = mpsc::channel();
+        let ctx = self.with_progress(move |event| {
+            let _ = tx.send(event);
+        });
+        (ctx, rx)
+    }
+
+    /// Pass `event` to the handler set with [`Pylot::with_progress`], if any.
+    pub fn report(&self, event: ProgressEvent) {
+        if let Some(on_event) = &self.progress {
+            on_event(event);
+        }
+    }
+
+    /// Manage the environments under `path` instead of the configured root.
+    pub fn with_venvs_path(mut self, path: impl Into<String>) -> Self {
+        self.settings.venvs_path = path.into();
+        self
+    }
+
+    /// The venvs root with `~` expanded.
+    pub fn venvs_path(&self) -> PathBuf {
+        PathBuf::from(shellexpand::tilde(&self.settings.venvs_path).to_string())
+    }
+
+    /// Discovery and selection of the environments under this context's root.
+    pub fn manager(&self) -> VenvManager<'_> {
+        VenvManager::new(self)
+    }
+
+    /// A lightweight handle to the environment `name`, enough to create,
+    /// delete, activate or change its packages.
+    pub fn venv<'a>(&self, name: impl Into<Cow<'a, str>>) -> UvVenv<'a> {
+        UvVenv::new(
+            self,
+            name.into(),
+            "".to_string(),
+            "".to_string(),
+            vec![],
+            false,
+        )
+    }
+}
+
+impl Default for Pylot {
+    fn default() -> Self {
+        Self::new(Settings::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        logger,
+        processes::{CommandOutput, CommandSpec, RecordingRunner, Stream},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_venvs_path_expands_tilde() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let ctx = Pylot::default();
+        assert_eq!(
+            ctx.venvs_path(),
+            PathBuf::from(shellexpand::tilde("~/pylot/venvs").to_string())
+        );
+    }
+
+    #[test]
+    fn test_contexts_are_independent() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let first = Pylot::default().with_venvs_path("/tmp/first");
+        let second = first.clone().with_venvs_path("/tmp/second");
+        assert_eq!(first.venvs_path(), PathBuf::from("/tmp/first"));
+        assert_eq!(second.venvs_path(), PathBuf::from("/tmp/second"));
+
+        let venv = second.venv("env");
+        assert_eq!(venv.name, "env");
+        assert_eq!(venv.ctx.settings.venvs_path, "/tmp/second");
+    }
+
+    #[tokio::test]
+    async fn test_progress_handlers_add_up() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let runner = Arc::new(
+            RecordingRunner::new().respond("pip install", CommandOutput::ok(" + numpy==2.1.0")),
+        );
+        let (first, first_rx) = Pylot::default().with_runner(runner).with_progress_channel();
+        let (second, second_rx) = first.clone().with_progress_channel();
+
+        second.report(ProgressEvent::Step("Installing".to_string()));
+        processes::run_logged(
+            second.runner.as_ref(),
+            &CommandSpec::new("uv", &["pip", "install", "numpy"]),
+        )
+        .await
+        .unwrap();
+
+        let expected = [
+            ProgressEvent::Step("Installing".to_string()),
+            ProgressEvent::Output {
+                stream: Stream::Stdout,
+                line: " + numpy==2.1.0".to_string(),
+            },
+            ProgressEvent::Installing {
+                package: "numpy".to_string(),
+            },
+            ProgressEvent::Done,
+        ];
+        assert_eq!(first_rx.try_iter().collect::<Vec<_>>(), expected);
+        assert_eq!(second_rx.try_iter().collect::<Vec<_>>(), expected);
+
+        first.report(ProgressEvent::Done);
+        assert!(second_rx.try_recv().is_err());
+    }
+}
@@ -1,8 +1,8 @@
+use crate::constants::{DEFAULT_INDEX_URL, INDEX_URL_ENV, UV_COMMAND};
 use config::{Config, File, FileFormat};
 use std::{
     env,
     path::{Path, PathBuf},
-    sync::{LazyLock, Mutex},
 };
 
 /// Application settings deserialized from `settings.toml`.
@@ -16,6 +16,15 @@ use std::{
 /// ```toml
 /// venvs_path = "~/pylot/venvs"
 /// default_pkgs = ["numpy", "requests"]
+/// index_url = "https://pypi.org/simple"
+/// outdated_source = "index"
+/// outdated_cache_ttl = 3600
+/// required_uv_version = ">=0.5, <0.7"
+/// uv_version_policy = "fail"
+/// uv_mirror_url = "https://mirror.example.com/astral-sh/uv/releases"
+/// uv_install_dir = "~/bin"
+/// uv_path = "~/tools/uv-0.5.11/uv"
+/// max_parallel_tasks = 3
 /// ```
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct Settings {
@@ -28,36 +37,121 @@ pub struct Settings {
     /// created with the `--default` flag.
     #[serde(default)]
     pub default_pkgs: Vec<String>,
+    /// Base URL of the package index queried for newer package versions.
+    ///
+    /// Must serve the PEP 691 simple-API JSON.  The `PYLOT_INDEX_URL`
+    /// environment variable takes precedence, see [`Settings::index_url`].
+    #[serde(default = "default_index_url")]
+    pub index_url: String,
+    /// Where outdated package information comes from.
+    #[serde(default)]
+    pub outdated_source: OutdatedSource,
+    /// How long (in seconds) a looked-up latest version is reused before the
+    /// index is queried again.
+    #[serde(default = "default_outdated_cache_ttl")]
+    pub outdated_cache_ttl: u64,
+    /// Semver requirement the installed uv must satisfy, e.g. `">=0.5, <0.7"`
+    /// or `"=0.5.11"`.  A bare version such as `"0.5.11"` allows compatible
+    /// updates (`^0.5.11`).  Unset means any version.
+    #[serde(default)]
+    pub required_uv_version: Option<String>,
+    /// What happens when the installed uv does not satisfy
+    /// [`required_uv_version`](Settings::required_uv_version).
+    #[serde(default)]
+    pub uv_version_policy: UvVersionPolicy,
+    /// Base URL of a uv release mirror laid out like
+    /// `https://github.com/astral-sh/uv/releases`, which is used when unset.
+    /// `pylot uv install` downloads the release archive and its `.sha256` file
+    /// from here.  Must be HTTPS.
+    #[serde(default)]
+    pub uv_mirror_url: Option<String>,
+    /// Directory `pylot uv install` places the uv binaries in.  The
+    /// `UV_INSTALL_DIR` environment variable takes precedence; unset falls
+    /// back to `XDG_BIN_HOME`, then `~/.local/bin`.
+    #[serde(default)]
+    pub uv_install_dir: Option<String>,
+    /// The `uv` executable Pylot runs, e.g. `~/tools/uv-0.5.11/uv`.  Unset
+    /// means `uv` from `PATH`.
+    #[serde(default)]
+    pub uv_path: Option<String>,
+    /// How many jobs the TUI runs at once; the rest wait in its task queue.
+    #[serde(default = "default_max_parallel_tasks")]
+    pub max_parallel_tasks: usize,
+}
+
+/// Backend used to detect outdated packages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutdatedSource {
+    /// Query the configured [`index_url`](Settings::index_url) directly.
+    #[default]
+    Index,
+    /// Run `uv pip list --outdated` inside the environment.
+    Uv,
+}
+
+/// How a uv version outside [`Settings::required_uv_version`] is treated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UvVersionPolicy {
+    /// Log a warning and carry on.
+    #[default]
+    Warn,
+    /// Treat uv as unusable until a matching version is installed.
+    Fail,
 }
 
 fn default_venv_path() -> String {
     String::from("~/pylot/venvs")
 }
 
-static SETTINGS: LazyLock<Mutex<Settings>> = LazyLock::new(|| Mutex::new(Settings::default()));
+fn default_index_url() -> String {
+    String::from(DEFAULT_INDEX_URL)
+}
+
+fn default_outdated_cache_ttl() -> u64 {
+    3600
+}
+
+fn default_max_parallel_tasks() -> usize {
+    3
+}
 
 impl Default for Settings {
     fn default() -> Self {
         Settings {
             venvs_path: default_venv_path(),
             default_pkgs: Vec::new(),
+            index_url: default_index_url(),
+            outdated_source: OutdatedSource::default(),
+            outdated_cache_ttl: default_outdated_cache_ttl(),
+            required_uv_version: None,
+            uv_version_policy: UvVersionPolicy::default(),
+            uv_mirror_url: None,
+            uv_install_dir: None,
+            uv_path: None,
+            max_parallel_tasks: default_max_parallel_tasks(),
         }
     }
 }
 
 impl Settings {
-    /// Load settings from `settings.toml` next to the running executable and
-    /// store them in the process-wide singleton.
+    /// Load settings from `settings.toml` next to the running executable.
     ///
-    /// This must be called once at application start-up (before any call to
-    /// [`Settings::get_settings`]).  If the file does not exist it is created
-    /// with default values.
-    pub async fn init() {
+    /// If the file does not exist it is created with default values.  The
+    /// result is usually handed to a [`Pylot`](crate::context::Pylot) context.
+    pub fn load() -> Settings {
         let exe_dir = Self::get_exe_dir(env::current_exe);
-        Self::init_with_dir(&exe_dir).await;
+        Self::load_from_dir(&exe_dir)
+    }
+
+    /// Return the path of the `settings.toml` file that [`Settings::load`]
+    /// reads, whether or not it exists.
+    pub fn file_path() -> PathBuf {
+        Self::get_exe_dir(env::current_exe).join("settings.toml")
     }
 
-    async fn init_with_dir(dir: &Path) {
+    fn load_from_dir(dir: &Path) -> Settings {
         let settings_path = dir.join("settings.toml");
 
         if !settings_path.exists() {
@@ -85,20 +179,7 @@ impl Settings {
             .unwrap_or_else(|_| Settings::default());
 
         new_settings.validate_venv_path();
-
-        if let Ok(mut settings_lock) = SETTINGS.lock() {
-            *settings_lock = new_settings;
-        }
-    }
-
-    /// Return a clone of the current process-wide settings.
-    ///
-    /// Falls back to [`Settings::default`] if the internal mutex is poisoned.
-    pub fn get_settings() -> Settings {
-        SETTINGS
-            .lock()
-            .map(|s| s.clone())
-            .unwrap_or_else(|_| Settings::default())
+        new_settings
     }
 
     /// Ensure that [`Settings::venvs_path`] exists on disk, creating the
@@ -116,6 +197,28 @@ impl Settings {
         }
     }
 
+    /// Return the package index URL, preferring the `PYLOT_INDEX_URL`
+    /// environment variable over the configured value.
+    ///
+    /// Trailing slashes are removed so callers can append path segments.
+    pub fn index_url(&self) -> String {
+        env::var(INDEX_URL_ENV)
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+            .unwrap_or_else(|| self.index_url.clone())
+            .trim_end_matches('/')
+            .to_string()
+    }
+
+    /// The `uv` executable to run: [`uv_path`](Settings::uv_path) when set,
+    /// otherwise `uv` from `PATH`.
+    pub fn uv_program(&self) -> String {
+        match &self.uv_path {
+            Some(path) if !path.trim().is_empty() => shellexpand::tilde(path.trim()).to_string(),
+            _ => UV_COMMAND.to_string(),
+        }
+    }
+
     /// Return the directory that contains the running executable.
     ///
     /// Accepts a callable `current_exe_fn` so the logic can be tested without
@@ -161,6 +264,7 @@ mod tests {
         let settings = Settings {
             venvs_path: "~/pylot/venvs".to_string(),
             default_pkgs: vec![],
+            ..Default::default()
         };
         settings.validate_venv_path();
         let expected_path = shellexpand::tilde("~/pylot/venvs").to_string();
@@ -173,6 +277,7 @@ mod tests {
         let settings = Settings {
             venvs_path: "~/pylot/venvs".to_string(),
             default_pkgs: vec![],
+            ..Default::default()
         };
         let settings_lock = Mutex::new(settings);
         let settings = settings_lock.lock().unwrap();
@@ -187,25 +292,45 @@ mod tests {
         assert_eq!(settings.default_pkgs, empty_vec);
     }
 
-    #[tokio::test]
-    async fn test_init() {
+    #[test]
+    fn test_load() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        Settings::init().await;
-        let settings = Settings::get_settings();
+        let settings = Settings::load();
         assert_eq!(settings.venvs_path, "~/pylot/venvs");
     }
 
+    #[test]
+    fn test_uv_program() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        assert_eq!(Settings::default().uv_program(), "uv");
+        let settings = Settings {
+            uv_path: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(settings.uv_program(), "uv");
+        let settings = Settings {
+            uv_path: Some("~/tools/uv".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.uv_program(),
+            shellexpand::tilde("~/tools/uv").to_string()
+        );
+    }
+
     #[test]
     fn test_settings_deserialize() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let toml_str = r#"
             venvs_path = "~/custom/venvs"
             default_pkgs = ["numpy", "pandas"]
+            max_parallel_tasks = 1
         "#;
 
         let settings: Settings = toml::from_str(toml_str).unwrap();
         assert_eq!(settings.venvs_path, "~/custom/venvs");
         assert_eq!(settings.default_pkgs, vec!["numpy", "pandas"]);
+        assert_eq!(settings.max_parallel_tasks, 1);
     }
 
     #[test]
@@ -218,6 +343,72 @@ mod tests {
         let settings: Settings = toml::from_str(toml_str).unwrap();
         assert_eq!(settings.venvs_path, "~/pylot/venvs");
         assert_eq!(settings.default_pkgs, vec!["requests"]);
+        assert_eq!(settings.max_parallel_tasks, 3);
+    }
+
+    #[test]
+    fn test_settings_deserialize_outdated_fields() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let toml_str = r#"
+            index_url = "http://127.0.0.1:8080/simple/"
+            outdated_source = "uv"
+            outdated_cache_ttl = 60
+        "#;
+
+        let settings: Settings = toml::from_str(toml_str).unwrap();
+        assert_eq!(settings.index_url, "http://127.0.0.1:8080/simple/");
+        assert_eq!(settings.outdated_source, OutdatedSource::Uv);
+        assert_eq!(settings.outdated_cache_ttl, 60);
+    }
+
+    #[test]
+    fn test_settings_outdated_defaults() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let settings = Settings::default();
+        assert_eq!(settings.index_url, DEFAULT_INDEX_URL);
+        assert_eq!(settings.outdated_source, OutdatedSource::Index);
+        assert_eq!(settings.outdated_cache_ttl, 3600);
+    }
+
+    #[test]
+    fn test_settings_deserialize_uv_version_fields() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let toml_str = r#"
+            required_uv_version = ">=0.5, <0.7"
+            uv_version_policy = "fail"
+            uv_mirror_url = "http://127.0.0.1:8080/uv/releases"
+            uv_install_dir = "~/bin"
+            uv_path = "~/tools/uv"
+        "#;
+
+        let settings: Settings = toml::from_str(toml_str).unwrap();
+        assert_eq!(settings.required_uv_version.as_deref(), Some(">=0.5, <0.7"));
+        assert_eq!(settings.uv_version_policy, UvVersionPolicy::Fail);
+        assert_eq!(
+            settings.uv_mirror_url.as_deref(),
+            Some("http://127.0.0.1:8080/uv/releases")
+        );
+
+        let defaults = Settings::default();
+        assert!(defaults.required_uv_version.is_none());
+        assert_eq!(settings.uv_install_dir.as_deref(), Some("~/bin"));
+        assert!(defaults.uv_mirror_url.is_none());
+        assert!(defaults.uv_install_dir.is_none());
+        assert_eq!(settings.uv_path.as_deref(), Some("~/tools/uv"));
+        assert!(defaults.uv_path.is_none());
+        assert_eq!(defaults.uv_version_policy, UvVersionPolicy::Warn);
+    }
+
+    #[test]
+    fn test_index_url_trims_trailing_slash() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let settings = Settings {
+            index_url: "http://localhost/simple/".to_string(),
+            ..Default::default()
+        };
+        if std::env::var(INDEX_URL_ENV).is_err() {
+            assert_eq!(settings.index_url(), "http://localhost/simple");
+        }
     }
 
     #[test]
@@ -240,6 +431,13 @@ mod tests {
         assert_eq!(exe_dir, PathBuf::from("/usr/local/bin"));
     }
 
+    #[test]
+    fn test_file_path_is_next_to_executable() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let exe_dir = Settings::get_exe_dir(env::current_exe);
+        assert_eq!(Settings::file_path(), exe_dir.join("settings.toml"));
+    }
+
     #[test]
     fn test_get_exe_dir_error() {
         logger::initialize_logger(log::LevelFilter::Trace);
@@ -248,14 +446,14 @@ mod tests {
         assert_eq!(exe_dir, PathBuf::from("."));
     }
 
-    #[tokio::test]
-    async fn test_init_creates_default_settings_toml_when_missing() {
+    #[test]
+    fn test_load_creates_default_settings_toml_when_missing() {
         let tmp = tempfile::tempdir().unwrap();
         let settings_path = tmp.path().join("settings.toml");
 
         assert!(!settings_path.exists(), "settings.toml should not exist yet");
 
-        Settings::init_with_dir(tmp.path()).await;
+        let settings = Settings::load_from_dir(tmp.path());
 
         assert!(settings_path.exists(), "settings.toml should have been created");
 
@@ -264,5 +462,6 @@ mod tests {
             content.contains("venvs_path"),
             "generated settings.toml should contain venvs_path key"
         );
+        assert_eq!(settings.venvs_path, Settings::default().venvs_path);
     }
 }
@@ -1 +1,3 @@
 pub mod processes;
+pub mod progress;
+pub mod toolchain;
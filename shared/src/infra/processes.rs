@@ -1,5 +1,14 @@
-use crate::error::{PylotError, Result};
-use std::process::{Command as StdCommand, Stdio};
+use crate::{
+    error::{PylotError, Result},
+    settings::Settings,
+};
+use futures::future::BoxFuture;
+use std::{
+    fmt,
+    path::PathBuf,
+    process::{Command as StdCommand, Stdio},
+    sync::{Arc, Mutex},
+};
 use tokio::{
     io::{AsyncBufRead, AsyncBufReadExt, BufReader},
     process::{Child, Command},
@@ -53,6 +62,435 @@ impl Shell {
     }
 }
 
+/// A program to run, with its arguments and working directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandSpec {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Directory to run in; the current directory when `None`.
+    pub cwd: Option<PathBuf>,
+}
+
+impl CommandSpec {
+    pub fn new<S: AsRef<str>>(program: &str, args: &[S]) -> Self {
+        CommandSpec {
+            program: program.to_string(),
+            args: args.iter().map(|a| a.as_ref().to_string()).collect(),
+            cwd: None,
+        }
+    }
+
+    /// `uv <args>`, running the uv executable `settings` point at, see
+    /// [`Settings::uv_program`].
+    pub fn uv<S: AsRef<str>>(settings: &Settings, args: &[S]) -> Self {
+        Self::new(&settings.uv_program(), args)
+    }
+
+    /// Run the command in `dir`.
+    pub fn current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cwd = Some(dir.into());
+        self
+    }
+}
+
+impl fmt::Display for CommandSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.program)?;
+        for arg in &self.args {
+            write!(f, " {}", arg)?;
+        }
+        Ok(())
+    }
+}
+
+/// The stream a line of output was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// The exit status and captured output of a finished command.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandOutput {
+    /// Exit code, `None` when the process was killed by a signal.
+    pub code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl CommandOutput {
+    /// A successful run that printed `stdout`.
+    pub fn ok(stdout: &str) -> Self {
+        CommandOutput {
+            code: Some(0),
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+        }
+    }
+
+    /// A run that exited with `code` after printing `stderr`.
+    pub fn failed(code: i32, stderr: &str) -> Self {
+        CommandOutput {
+            code: Some(code),
+            stdout: String::new(),
+            stderr: stderr.to_string(),
+        }
+    }
+
+    pub fn success(&self) -> bool {
+        self.code == Some(0)
+    }
+}
+
+/// Called with every line a running command prints.
+pub type LineHandler<'a> = &'a (dyn Fn(Stream, &str) + Send + Sync);
+
+/// Spawns external programs.
+///
+/// Everything that runs `uv`, `winget` or a shell goes through a runner, so
+/// the real [`SystemRunner`] can be swapped for a fake in tests.
+pub trait CommandRunner: Send + Sync {
+    /// Run `cmd` to completion, passing each line of stdout and stderr to
+    /// `on_line` as it is printed.  The returned output carries the exit
+    /// status; the streamed lines are not captured again.
+    fn stream<'a>(
+        &'a self,
+        cmd: &'a CommandSpec,
+        on_line: LineHandler<'a>,
+    ) -> BoxFuture<'a, Result<CommandOutput>>;
+
+    /// Run `cmd` to completion and capture its output.
+    fn output<'a>(&'a self, cmd: &'a CommandSpec) -> BoxFuture<'a, Result<CommandOutput>>;
+
+    /// Locate `program` on `PATH`; a path to an executable is returned as is.
+    fn which(&self, program: &str) -> Option<PathBuf>;
+}
+
+/// The shared [`SystemRunner`].
+pub fn system_runner() -> Arc<dyn CommandRunner> {
+    Arc::new(SystemRunner)
+}
+
+/// Runs commands as real child processes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemRunner;
+
+impl SystemRunner {
+    fn spawn(cmd: &CommandSpec) -> Result<Child> {
+        let mut command = Command::new(&cmd.program);
+        command
+            .args(&cmd.args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if let Some(dir) = &cmd.cwd {
+            command.current_dir(dir);
+        }
+        // Lead a process group of its own, so everything it starts can be
+        // killed with one signal, see [`kill_tree`].
+        #[cfg(unix)]
+        command.process_group(0);
+        command.spawn().map_err(|e| {
+            PylotError::CommandExecution(format!("Failed to execute '{}': {}", cmd.program, e))
+        })
+    }
+}
+
+/// Children of [`SystemRunner`] that are still running.
+static RUNNING: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+/// Kills the process tree of a running child when dropped, e.g. because the
+/// task waiting for it was aborted.  [`ChildGuard::finished`] disarms it.
+struct ChildGuard {
+    pid: Option<u32>,
+}
+
+impl ChildGuard {
+    fn new(child: &Child) -> Self {
+        let pid = child.id();
+        if let (Some(pid), Ok(mut running)) = (pid, RUNNING.lock()) {
+            running.push(pid);
+        }
+        ChildGuard { pid }
+    }
+
+    fn finished(mut self) {
+        self.forget();
+    }
+
+    fn forget(&mut self) -> Option<u32> {
+        let pid = self.pid.take()?;
+        if let Ok(mut running) = RUNNING.lock() {
+            running.retain(|&p| p != pid);
+        }
+        Some(pid)
+    }
+}
+
+impl Drop for ChildGuard {
+    fn drop(&mut self) {
+        if let Some(pid) = self.forget() {
+            log::debug!("Killing process tree of {}", pid);
+            kill_tree(pid);
+        }
+    }
+}
+
+/// Kill the process trees of all commands [`SystemRunner`] is still running.
+///
+/// They lead process groups of their own, so Ctrl-C in the terminal does not
+/// reach them; call this before exiting on it.
+pub fn kill_running() {
+    let pids = RUNNING
+        .lock()
+        .map(|mut running| std::mem::take(&mut *running))
+        .unwrap_or_default();
+    for pid in pids {
+        kill_tree(pid);
+    }
+}
+
+/// Kill the process `pid` and everything it started, e.g. `uv` below the
+/// shell that runs it.
+///
+/// On Unix `pid` must lead its process group, as the children of
+/// [`SystemRunner`] do; the whole group is killed at once, so a process
+/// forked meanwhile cannot escape.
+pub fn kill_tree(pid: u32) {
+    #[cfg(windows)]
+    let _ = StdCommand::new("taskkill")
+        .args(["/PID", &pid.to_string(), "/T", "/F"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+    #[cfg(unix)]
+    // SAFETY: killpg only sends a signal; a group that already exited is
+    // reported as ESRCH and ignored.
+    unsafe {
+        libc::killpg(pid as libc::pid_t, libc::SIGKILL);
+    }
+}
+
+impl CommandRunner for SystemRunner {
+    fn stream<'a>(
+        &'a self,
+        cmd: &'a CommandSpec,
+        on_line: LineHandler<'a>,
+    ) -> BoxFuture<'a, Result<CommandOutput>> {
+        Box::pin(async move {
+            let mut child = Self::spawn(cmd)?;
+            let guard = ChildGuard::new(&child);
+            let stdout = child
+                .stdout
+                .take()
+                .ok_or_else(|| PylotError::CommandExecution("Failed to open stdout".to_string()))?;
+            let stderr = child
+                .stderr
+                .take()
+                .ok_or_else(|| PylotError::CommandExecution("Failed to open stderr".to_string()))?;
+            let (out, err) = tokio::join!(
+                forward_lines(stdout, Stream::Stdout, on_line),
+                forward_lines(stderr, Stream::Stderr, on_line)
+            );
+            out.and(err).map_err(|e| {
+                PylotError::CommandExecution(format!("Error reading output: {}", e))
+            })?;
+            let status = child.wait().await.map_err(|e| {
+                PylotError::CommandExecution(format!("Failed to wait for '{}': {}", cmd.program, e))
+            })?;
+            guard.finished();
+            Ok(CommandOutput {
+                code: status.code(),
+                ..CommandOutput::default()
+            })
+        })
+    }
+
+    fn output<'a>(&'a self, cmd: &'a CommandSpec) -> BoxFuture<'a, Result<CommandOutput>> {
+        Box::pin(async move {
+            let child = Self::spawn(cmd)?;
+            let guard = ChildGuard::new(&child);
+            let output = child.wait_with_output().await.map_err(|e| {
+                PylotError::CommandExecution(format!("Failed to run '{}': {}", cmd.program, e))
+            })?;
+            guard.finished();
+            Ok(CommandOutput {
+                code: output.status.code(),
+                stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            })
+        })
+    }
+
+    fn which(&self, program: &str) -> Option<PathBuf> {
+        which::which(program).ok()
+    }
+}
+
+async fn forward_lines<R: tokio::io::AsyncRead + Unpin>(
+    reader: R,
+    stream: Stream,
+    on_line: LineHandler<'_>,
+) -> std::io::Result<()> {
+    let mut lines = BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await? {
+        on_line(stream, &line);
+    }
+    Ok(())
+}
+
+/// Run `cmd`, forwarding stdout lines to the `info` log level and stderr
+/// lines to `warn`, like [`run_command`].
+///
+/// # Errors
+///
+/// Returns [`PylotError::CommandExecution`] if the command cannot be run,
+/// printed a line containing `"error:"` or exited with a non-zero status.
+pub async fn run_logged(runner: &dyn CommandRunner, cmd: &CommandSpec) -> Result<()> {
+    let first_error: Mutex<Option<String>> = Mutex::new(None);
+    let on_line = |stream: Stream, line: &str| {
+        if line.contains("error:") {
+            if let Ok(mut first) = first_error.lock() {
+                first.get_or_insert_with(|| line.to_string());
+            }
+            return;
+        }
+        match stream {
+            Stream::Stdout => log::info!("{}", line),
+            Stream::Stderr => log::warn!("{}", line),
+        }
+    };
+    let output = runner.stream(cmd, &on_line).await?;
+    match first_error.into_inner().ok().flatten() {
+        Some(line) => Err(PylotError::CommandExecution(line)),
+        None if !output.success() => Err(PylotError::CommandExecution(match output.code {
+            Some(code) => format!("'{}' exited with code {}", cmd, code),
+            None => format!("'{}' was terminated by a signal", cmd),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// A [`CommandRunner`] that records every command instead of running it, for
+/// testing flows that would otherwise need uv and the network.
+///
+/// Commands get the reply registered with [`RecordingRunner::respond`] for
+/// the first pattern found in their command line, or succeed silently.
+#[cfg(any(test, feature = "testing"))]
+#[derive(Default)]
+pub struct RecordingRunner {
+    calls: Mutex<Vec<CommandSpec>>,
+    responses: Vec<(String, Responder)>,
+    programs: Vec<(String, PathBuf)>,
+}
+
+#[cfg(any(test, feature = "testing"))]
+type Responder = Box<dyn Fn(&CommandSpec) -> CommandOutput + Send + Sync>;
+
+#[cfg(any(test, feature = "testing"))]
+impl RecordingRunner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reply with `output` to commands whose command line contains `pattern`.
+    pub fn respond(self, pattern: &str, output: CommandOutput) -> Self {
+        self.respond_with(pattern, move |_| output.clone())
+    }
+
+    /// Like [`respond`](Self::respond), but `reply` is called with the command,
+    /// e.g. to create the files the real program would.
+    pub fn respond_with(
+        mut self,
+        pattern: &str,
+        reply: impl Fn(&CommandSpec) -> CommandOutput + Send + Sync + 'static,
+    ) -> Self {
+        self.responses.push((pattern.to_string(), Box::new(reply)));
+        self
+    }
+
+    /// Report `program` as installed at `path` from [`CommandRunner::which`].
+    pub fn with_program(mut self, program: &str, path: impl Into<PathBuf>) -> Self {
+        self.programs.push((program.to_string(), path.into()));
+        self
+    }
+
+    /// The commands run so far, in order.
+    pub fn calls(&self) -> Vec<CommandSpec> {
+        self.calls.lock().map(|c| c.clone()).unwrap_or_default()
+    }
+
+    /// The command lines run so far, e.g. `"uv venv myenv --python 3.12"`.
+    pub fn command_lines(&self) -> Vec<String> {
+        self.calls().iter().map(CommandSpec::to_string).collect()
+    }
+
+    fn reply(&self, cmd: &CommandSpec) -> CommandOutput {
+        if let Ok(mut calls) = self.calls.lock() {
+            calls.push(cmd.clone());
+        }
+        let line = cmd.to_string();
+        self.responses
+            .iter()
+            .find(|(pattern, _)| line.contains(pattern.as_str()))
+            .map(|(_, reply)| reply(cmd))
+            .unwrap_or_else(|| CommandOutput::ok(""))
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl CommandRunner for RecordingRunner {
+    fn stream<'a>(
+        &'a self,
+        cmd: &'a CommandSpec,
+        on_line: LineHandler<'a>,
+    ) -> BoxFuture<'a, Result<CommandOutput>> {
+        Box::pin(async move {
+            let output = self.reply(cmd);
+            output
+                .stdout
+                .lines()
+                .for_each(|l| on_line(Stream::Stdout, l));
+            output
+                .stderr
+                .lines()
+                .for_each(|l| on_line(Stream::Stderr, l));
+            Ok(CommandOutput {
+                code: output.code,
+                ..CommandOutput::default()
+            })
+        })
+    }
+
+    fn output<'a>(&'a self, cmd: &'a CommandSpec) -> BoxFuture<'a, Result<CommandOutput>> {
+        Box::pin(async move { Ok(self.reply(cmd)) })
+    }
+
+    fn which(&self, program: &str) -> Option<PathBuf> {
+        self.programs
+            .iter()
+            .find(|(name, _)| name == program)
+            .map(|(_, path)| path.clone())
+    }
+}
+
+/// A [`RecordingRunner`] reply faking `uv venv <name>`: lay out an empty
+/// Unix environment with Python 3.12.4 in the working directory.
+#[cfg(any(test, feature = "testing"))]
+pub fn fake_venv(spec: &CommandSpec) -> CommandOutput {
+    let venv = spec.cwd.clone().unwrap_or_default().join(&spec.args[1]);
+    let created =
+        std::fs::create_dir_all(venv.join("lib").join("python3.12").join("site-packages"))
+            .and_then(|_| std::fs::create_dir_all(venv.join("bin")))
+            .and_then(|_| std::fs::write(venv.join("bin").join("python"), ""))
+            .and_then(|_| std::fs::write(venv.join("pyvenv.cfg"), "version = 3.12.4\n"));
+    match created {
+        Ok(()) => CommandOutput::ok("Using CPython 3.12.4"),
+        Err(e) => CommandOutput::failed(1, &e.to_string()),
+    }
+}
+
 /// Spawn `cmd` as a Tokio async child process with stdout and stderr piped.
 ///
 /// If `run` is non-empty it is prepended to `args` as the first argument (used
@@ -269,7 +707,7 @@ pub fn get_parent_shell() -> Result<Shell> {
             .file_name()
             .and_then(|n| n.to_str())
             .unwrap_or(&shell);
-        return Ok(Shell::from_process_name(name));
+        Ok(Shell::from_process_name(name))
     }
 
     #[cfg(target_os = "windows")]
@@ -448,6 +886,101 @@ mod tests {
         }
     }
 
+    // ── CommandRunner ────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_system_runner_stream_and_output() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        if cfg!(windows) {
+            return;
+        }
+        let runner = SystemRunner;
+        let cmd = CommandSpec::new(SH_CMD, &["-c", "echo out; echo err >&2; exit 3"]);
+        let lines = Mutex::new(Vec::new());
+        let on_line = |stream: Stream, line: &str| {
+            lines.lock().unwrap().push((stream, line.to_string()));
+        };
+        let status = runner.stream(&cmd, &on_line).await.unwrap();
+        assert_eq!(status.code, Some(3));
+        let mut lines = lines.into_inner().unwrap();
+        lines.sort_by_key(|(stream, _)| *stream == Stream::Stderr);
+        assert_eq!(
+            lines,
+            vec![
+                (Stream::Stdout, "out".to_string()),
+                (Stream::Stderr, "err".to_string())
+            ]
+        );
+
+        let tmp = tempfile::tempdir().unwrap();
+        let output = runner
+            .output(&CommandSpec::new(SH_CMD, &["-c", "pwd"]).current_dir(tmp.path()))
+            .await
+            .unwrap();
+        assert!(output.success());
+        assert_eq!(
+            std::fs::canonicalize(output.stdout.trim()).unwrap(),
+            std::fs::canonicalize(tmp.path()).unwrap()
+        );
+        assert!(runner.which(SH_CMD).is_some());
+        assert!(runner
+            .output(&CommandSpec::new::<&str>(
+                "this_command_does_not_exist_12345",
+                &[]
+            ))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_recording_runner() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let runner = RecordingRunner::new()
+            .respond("--version", CommandOutput::ok("uv 0.5.11\n"))
+            .respond("fail", CommandOutput::failed(2, "error: boom\n"))
+            .with_program("uv", "/opt/uv/bin/uv");
+        let output = runner
+            .output(&CommandSpec::new("uv", &["--version"]))
+            .await
+            .unwrap();
+        assert_eq!(output.stdout, "uv 0.5.11\n");
+        assert!(
+            run_logged(&runner, &CommandSpec::new("uv", &["pip", "list"]))
+                .await
+                .is_ok()
+        );
+        let err = run_logged(&runner, &CommandSpec::new("uv", &["fail"]))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("error: boom"));
+        assert_eq!(
+            runner.command_lines(),
+            vec!["uv --version", "uv pip list", "uv fail"]
+        );
+        assert_eq!(runner.which("uv"), Some(PathBuf::from("/opt/uv/bin/uv")));
+        assert_eq!(runner.which("winget"), None);
+    }
+
+    #[tokio::test]
+    async fn test_run_logged_fails_on_nonzero_exit() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let runner = RecordingRunner::new()
+            .respond("venv", CommandOutput::failed(2, "No interpreter found\n"));
+        let err = run_logged(&runner, &CommandSpec::new("uv", &["venv", "api"]))
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("'uv venv api' exited with code 2"));
+    }
+
+    #[test]
+    fn test_command_spec_display() {
+        let cmd = CommandSpec::new("uv", &["venv", "myenv"]).current_dir("/tmp");
+        assert_eq!(cmd.to_string(), "uv venv myenv");
+        assert_eq!(cmd.cwd, Some(PathBuf::from("/tmp")));
+    }
+
     #[tokio::test]
     async fn test_run_command_with_handlers() {
         logger::initialize_logger(log::LevelFilter::Trace);
@@ -516,4 +1049,44 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    // ── ChildGuard ───────────────────────────────────────────────────────────
+
+    #[cfg(unix)]
+    fn is_running(pid: &str) -> bool {
+        let stat = StdCommand::new("ps")
+            .args(["-o", "stat=", "-p", pid])
+            .output()
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+            .unwrap_or_default();
+        !stat.is_empty() && !stat.starts_with('Z')
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_aborted_stream_kills_process_tree() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let (tx, rx) = std::sync::mpsc::channel::<String>();
+        let task = tokio::spawn(async move {
+            let cmd = CommandSpec::new(SH_CMD, &["-c", "sleep 30 & echo $!; wait"]);
+            let on_line = move |_: Stream, line: &str| {
+                let _ = tx.send(line.to_string());
+            };
+            SystemRunner.stream(&cmd, &on_line).await
+        });
+        let pid = tokio::task::spawn_blocking(move || rx.recv().unwrap())
+            .await
+            .unwrap();
+        assert!(is_running(&pid));
+
+        task.abort();
+        assert!(task.await.unwrap_err().is_cancelled());
+        for _ in 0..50 {
+            if !is_running(&pid) {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        panic!("sleep {} survived the aborted task", pid);
+    }
 }
@@ -0,0 +1,250 @@
+use crate::{
+    error::Result,
+    processes::{CommandOutput, CommandRunner, CommandSpec, LineHandler, Stream},
+};
+use futures::future::BoxFuture;
+use std::{path::PathBuf, sync::Arc};
+
+/// Something a long-running operation reports while it works.
+///
+/// Every line a command prints arrives as [`ProgressEvent::Output`]. Lines uv
+/// prints while resolving, downloading and installing are also parsed into
+/// the typed events that follow, see [`ProgressEvent::from_uv_line`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProgressEvent {
+    /// A new step started, e.g. `"Creating virtual environment 'api'"`.
+    Step(String),
+    /// A line printed by uv or the shell running it.
+    Output { stream: Stream, line: String },
+    /// uv is picking an interpreter or resolving dependencies.
+    Resolving,
+    /// uv is downloading `package`, `bytes` large when it said so.
+    Downloading { package: String, bytes: Option<u64> },
+    /// `package` was installed.
+    Installing { package: String },
+    /// A command finished.
+    Done,
+    /// uv printed a warning.
+    Warning(String),
+}
+
+impl ProgressEvent {
+    /// Parse a line of uv output, e.g. `Downloading numpy (15.4MiB)` or
+    /// ` + numpy==2.1.0`, into a typed event. Other lines give `None`.
+    pub fn from_uv_line(line: &str) -> Option<ProgressEvent> {
+        let line = line.trim();
+        if let Some(message) = line.strip_prefix("warning:") {
+            return Some(ProgressEvent::Warning(message.trim().to_string()));
+        }
+        if line.starts_with("Resolved ") || line.starts_with("Using ") {
+            return Some(ProgressEvent::Resolving);
+        }
+        if let Some(rest) = line.strip_prefix("Downloading ") {
+            let (package, bytes) = match rest.split_once(" (") {
+                Some((package, size)) => (package, parse_size(size.trim_end_matches(')'))),
+                None => (rest, None),
+            };
+            return Some(ProgressEvent::Downloading {
+                package: package.trim().to_string(),
+                bytes,
+            });
+        }
+        let spec = line.strip_prefix("+ ")?;
+        let package = spec.split(['=', ' ', '@']).next().unwrap_or(spec);
+        Some(ProgressEvent::Installing {
+            package: package.to_string(),
+        })
+    }
+}
+
+/// Parse a size as uv prints it, e.g. `15.4MiB` or `912 KiB`.
+fn parse_size(size: &str) -> Option<u64> {
+    let size = size.trim();
+    let split = size
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(size.len());
+    let (number, unit) = size.split_at(split);
+    let factor: u64 = match unit.trim() {
+        "B" => 1,
+        "KiB" | "kB" => 1 << 10,
+        "MiB" | "MB" => 1 << 20,
+        "GiB" | "GB" => 1 << 30,
+        _ => return None,
+    };
+    let number: f64 = number.parse().ok()?;
+    Some((number * factor as f64).round() as u64)
+}
+
+/// Receives the [`ProgressEvent`]s of a [`Pylot`](crate::context::Pylot)
+/// context, see [`Pylot::with_progress`](crate::context::Pylot::with_progress).
+pub type ProgressHandler = Arc<dyn Fn(ProgressEvent) + Send + Sync>;
+
+/// A [`CommandRunner`] that reports every line a streamed command prints as
+/// [`ProgressEvent::Output`] before handing it to the caller as usual. The
+/// typed event parsed from a line follows once the caller has seen it, and
+/// [`ProgressEvent::Done`] is reported when the command finishes.
+pub struct ReportingRunner {
+    inner: Arc<dyn CommandRunner>,
+    on_event: ProgressHandler,
+}
+
+impl ReportingRunner {
+    pub fn new(inner: Arc<dyn CommandRunner>, on_event: ProgressHandler) -> Self {
+        ReportingRunner { inner, on_event }
+    }
+}
+
+impl CommandRunner for ReportingRunner {
+    fn stream<'a>(
+        &'a self,
+        cmd: &'a CommandSpec,
+        on_line: LineHandler<'a>,
+    ) -> BoxFuture<'a, Result<CommandOutput>> {
+        Box::pin(async move {
+            let report = |stream: Stream, line: &str| {
+                (self.on_event)(ProgressEvent::Output {
+                    stream,
+                    line: line.to_string(),
+                });
+                on_line(stream, line);
+                if let Some(event) = ProgressEvent::from_uv_line(line) {
+                    (self.on_event)(event);
+                }
+            };
+            let output = self.inner.stream(cmd, &report).await;
+            (self.on_event)(ProgressEvent::Done);
+            output
+        })
+    }
+
+    fn output<'a>(&'a self, cmd: &'a CommandSpec) -> BoxFuture<'a, Result<CommandOutput>> {
+        self.inner.output(cmd)
+    }
+
+    fn which(&self, program: &str) -> Option<PathBuf> {
+        self.inner.which(program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{logger, processes::RecordingRunner};
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reporting_runner_reports_and_forwards_lines() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let inner = Arc::new(RecordingRunner::new().respond(
+            "venv",
+            CommandOutput {
+                code: Some(0),
+                stdout: "Using CPython 3.12.4\n".to_string(),
+                stderr: "Creating virtual environment at: api\n".to_string(),
+            },
+        ));
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let runner = ReportingRunner::new(
+            inner.clone(),
+            Arc::new(move |event| sink.lock().unwrap().push(event)),
+        );
+
+        let forwarded = Mutex::new(Vec::new());
+        let on_line = |_: Stream, line: &str| forwarded.lock().unwrap().push(line.to_string());
+        let output = runner
+            .stream(&CommandSpec::new("uv", &["venv", "api"]), &on_line)
+            .await
+            .unwrap();
+
+        assert!(output.success());
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                ProgressEvent::Output {
+                    stream: Stream::Stdout,
+                    line: "Using CPython 3.12.4".to_string(),
+                },
+                ProgressEvent::Resolving,
+                ProgressEvent::Output {
+                    stream: Stream::Stderr,
+                    line: "Creating virtual environment at: api".to_string(),
+                },
+                ProgressEvent::Done,
+            ]
+        );
+        assert_eq!(forwarded.lock().unwrap().len(), 2);
+        assert_eq!(inner.command_lines(), ["uv venv api"]);
+    }
+
+    #[tokio::test]
+    async fn test_reporting_runner_does_not_report_captured_output() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let inner = Arc::new(RecordingRunner::new().respond("--version", CommandOutput::ok("uv")));
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let runner = ReportingRunner::new(
+            inner,
+            Arc::new(move |event| sink.lock().unwrap().push(event)),
+        );
+        let output = runner
+            .output(&CommandSpec::new("uv", &["--version"]))
+            .await
+            .unwrap();
+        assert_eq!(output.stdout, "uv");
+        assert!(events.lock().unwrap().is_empty());
+    }
+
+    // ── from_uv_line ─────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_from_uv_line_parses_install_output() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        assert_eq!(
+            ProgressEvent::from_uv_line("Resolved 5 packages in 245ms"),
+            Some(ProgressEvent::Resolving)
+        );
+        assert_eq!(
+            ProgressEvent::from_uv_line("Downloading numpy (15.4MiB)"),
+            Some(ProgressEvent::Downloading {
+                package: "numpy".to_string(),
+                bytes: Some(16148070),
+            })
+        );
+        assert_eq!(
+            ProgressEvent::from_uv_line("Downloading cpython-3.12.4-linux-x86_64-gnu"),
+            Some(ProgressEvent::Downloading {
+                package: "cpython-3.12.4-linux-x86_64-gnu".to_string(),
+                bytes: None,
+            })
+        );
+        assert_eq!(
+            ProgressEvent::from_uv_line(" + numpy==2.1.0"),
+            Some(ProgressEvent::Installing {
+                package: "numpy".to_string(),
+            })
+        );
+        assert_eq!(
+            ProgressEvent::from_uv_line("warning: `VIRTUAL_ENV` does not match"),
+            Some(ProgressEvent::Warning(
+                "`VIRTUAL_ENV` does not match".to_string()
+            ))
+        );
+        assert_eq!(
+            ProgressEvent::from_uv_line("Prepared 5 packages in 1.2s"),
+            None
+        );
+        assert_eq!(ProgressEvent::from_uv_line(" - numpy==2.0.0"), None);
+    }
+
+    #[test]
+    fn test_parse_size() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        assert_eq!(parse_size("912 KiB"), Some(933888));
+        assert_eq!(parse_size("1GiB"), Some(1 << 30));
+        assert_eq!(parse_size("12B"), Some(12));
+        assert_eq!(parse_size("big"), None);
+    }
+}
@@ -0,0 +1,495 @@
+use crate::{
+    context::Pylot,
+    error::Result,
+    infra::processes::{self, CommandSpec, Shell},
+    settings::{Settings, UvVersionPolicy},
+    uvarchive, uvctrl,
+};
+use comfy_table::{
+    modifiers::UTF8_SOLID_INNER_BORDERS, presets::UTF8_FULL, ContentArrangement, Table,
+};
+use std::{env, ffi::OsString, fmt, io::Write, path::Path};
+use tokio::fs as async_fs;
+
+/// Outcome of a single toolchain check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Pass,
+    /// Pylot works, but something should be looked at.
+    Warn,
+    /// Pylot cannot work until this is fixed.
+    Fail,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Warn => write!(f, "warn"),
+            Status::Fail => write!(f, "fail"),
+        }
+    }
+}
+
+/// Result of checking one thing Pylot depends on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    /// Short name of what was checked, e.g. `"uv"`.
+    pub name: &'static str,
+    pub status: Status,
+    /// What was found.
+    pub detail: String,
+    /// How to fix a warning or failure.
+    pub hint: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Check {
+            name,
+            status: Status::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Check {
+            name,
+            status: Status::Warn,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Check {
+            name,
+            status: Status::Fail,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// Run every toolchain check, in report order.
+///
+/// The uv cache and Python checks are skipped when uv itself is missing.
+pub async fn run_checks(ctx: &Pylot) -> Vec<Check> {
+    let uv_found = ctx.runner.which(&ctx.settings.uv_program()).is_some();
+    let uv = check_uv(ctx).await;
+    let shell = processes::get_parent_shell().unwrap_or_else(|_| Shell::Unknown(String::new()));
+    let install_dir = uvarchive::install_dir(&ctx.settings);
+    let mut checks = vec![
+        uv,
+        check_install_dir_on_path(env::var_os("PATH"), &install_dir, &shell),
+        check_shell(processes::get_parent_shell()),
+        check_settings_file(&Settings::file_path()).await,
+        check_venvs_path(&ctx.venvs_path()).await,
+    ];
+    if uv_found {
+        checks.push(check_uv_cache(ctx).await);
+        checks.push(check_pythons(ctx).await);
+    }
+    checks
+}
+
+/// The most serious status among `checks`, [`Status::Pass`] when empty.
+pub fn overall(checks: &[Check]) -> Status {
+    checks
+        .iter()
+        .map(|c| c.status)
+        .max()
+        .unwrap_or(Status::Pass)
+}
+
+/// Print one row per check with its status and, for problems, how to fix it.
+pub fn print_report_to<W: Write>(writer: &mut W, checks: &[Check]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_SOLID_INNER_BORDERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Check", "Status", "Details", "Suggested fix"]);
+    for check in checks {
+        table.add_row(vec![
+            check.name.to_string(),
+            check.status.to_string(),
+            check.detail.clone(),
+            check.hint.clone().unwrap_or_default(),
+        ]);
+    }
+    let _ = writeln!(writer, "{}", table);
+}
+
+async fn check_uv(ctx: &Pylot) -> Check {
+    let uv = ctx.settings.uv_program();
+    if ctx.runner.which(&uv).is_none() {
+        return Check::fail(
+            "uv",
+            format!("'{}' not found", uv),
+            "Run 'pylot uv install'",
+        );
+    }
+    match uv_output(ctx, &["--version"]).await {
+        Some(version) => check_uv_version(
+            &version,
+            ctx.settings.required_uv_version.as_deref(),
+            ctx.settings.uv_version_policy,
+        ),
+        None => Check::warn(
+            "uv",
+            "uv is on PATH but 'uv --version' failed",
+            "Reinstall with 'pylot uv install'",
+        ),
+    }
+}
+
+/// Judge the `uv --version` output against `required_uv_version`, if set.
+fn check_uv_version(output: &str, required: Option<&str>, policy: UvVersionPolicy) -> Check {
+    let Some(required) = required else {
+        return Check::pass("uv", output);
+    };
+    let result = uvctrl::parse_uv_version(output)
+        .ok_or_else(|| format!("Could not read the uv version from '{}'", output))
+        .and_then(|installed| uvctrl::meets_requirement(&installed, required));
+    let hint = format!(
+        "Run 'pylot uv update --to <version>' with a version matching '{}'",
+        required
+    );
+    match (result, policy) {
+        (Ok(()), _) => Check::pass("uv", format!("{} (required: {})", output, required)),
+        (Err(message), UvVersionPolicy::Warn) => Check::warn("uv", message, hint),
+        (Err(message), UvVersionPolicy::Fail) => Check::fail("uv", message, hint),
+    }
+}
+
+/// uv is installed into [`uvarchive::install_dir`], `~/.local/bin` by
+/// default, which is not on `PATH` in every shell.
+fn check_install_dir_on_path(path_var: Option<OsString>, dir: &Path, shell: &Shell) -> Check {
+    if uvarchive::is_on_path(dir, path_var.as_deref()) {
+        Check::pass("uv install dir on PATH", dir.display().to_string())
+    } else {
+        Check::warn(
+            "uv install dir on PATH",
+            format!("{} is not on PATH", dir.display()),
+            format!(
+                "Add it to PATH in your shell profile so uv can be found: {}",
+                uvarchive::path_hint(dir, shell)
+            ),
+        )
+    }
+}
+
+fn check_shell(shell: Result<Shell>) -> Check {
+    match shell {
+        Ok(Shell::Unknown(name)) => Check::warn(
+            "Shell",
+            format!("Unrecognized shell '{}'", name),
+            "Activation may not work, supported shells are bash, zsh, fish, nu and PowerShell",
+        ),
+        Ok(shell) => Check::pass("Shell", shell.executable()),
+        Err(e) => Check::warn(
+            "Shell",
+            e.to_string(),
+            "Set SHELL to your login shell so 'venv activate' can start it",
+        ),
+    }
+}
+
+async fn check_settings_file(path: &Path) -> Check {
+    let Ok(content) = async_fs::read_to_string(path).await else {
+        return Check::warn(
+            "Settings file",
+            format!("{} not found, using defaults", path.display()),
+            "It is created on the next start, check that the directory is writable",
+        );
+    };
+    match toml::from_str::<Settings>(&content) {
+        Ok(_) => Check::pass("Settings file", path.display().to_string()),
+        Err(e) => Check::fail(
+            "Settings file",
+            format!(
+                "{} is invalid, using defaults: {}",
+                path.display(),
+                e.message()
+            ),
+            "Fix or delete the file to regenerate it",
+        ),
+    }
+}
+
+async fn check_venvs_path(path: &Path) -> Check {
+    if !async_fs::try_exists(path).await.unwrap_or(false) {
+        return Check::fail(
+            "Venvs path",
+            format!("{} does not exist", path.display()),
+            "Create it or change venvs_path in the settings file",
+        );
+    }
+    let probe = path.join(format!(".pylot-doctor-{}", std::process::id()));
+    match async_fs::write(&probe, b"").await {
+        Ok(()) => {
+            let _ = async_fs::remove_file(&probe).await;
+            Check::pass("Venvs path", format!("{} is writable", path.display()))
+        }
+        Err(e) => Check::fail(
+            "Venvs path",
+            format!("{} is not writable: {}", path.display(), e),
+            "Fix the directory permissions or change venvs_path in the settings file",
+        ),
+    }
+}
+
+async fn check_uv_cache(ctx: &Pylot) -> Check {
+    match uv_output(ctx, &["cache", "dir"]).await {
+        Some(dir) => Check::pass("uv cache", dir),
+        None => Check::warn(
+            "uv cache",
+            "'uv cache dir' failed",
+            "Check that UV_CACHE_DIR points to a usable directory",
+        ),
+    }
+}
+
+async fn check_pythons(ctx: &Pylot) -> Check {
+    let Some(output) = uv_output(ctx, &["python", "list", "--only-installed"]).await else {
+        return Check::warn(
+            "Python installs",
+            "'uv python list' failed",
+            "Run 'uv python list' to see the error",
+        );
+    };
+    let versions = parse_python_list(&output);
+    if versions.is_empty() {
+        Check::warn(
+            "Python installs",
+            "No Python interpreters found",
+            "uv downloads one on first use, or run 'uv python install'",
+        )
+    } else {
+        Check::pass("Python installs", versions.join(", "))
+    }
+}
+
+/// Extract the interpreter keys (e.g. `cpython-3.12.3-linux-x86_64-gnu`) from
+/// `uv python list` output, without duplicates.
+fn parse_python_list(output: &str) -> Vec<String> {
+    let mut versions: Vec<String> = Vec::new();
+    for key in output.lines().filter_map(|l| l.split_whitespace().next()) {
+        if !versions.iter().any(|v| v == key) {
+            versions.push(key.to_string());
+        }
+    }
+    versions
+}
+
+/// Run `uv` with `args` and return its trimmed stdout, or `None` on failure.
+async fn uv_output(ctx: &Pylot, args: &[&str]) -> Option<String> {
+    let output = ctx
+        .runner
+        .output(&CommandSpec::uv(&ctx.settings, args))
+        .await
+        .ok()?;
+    if !output.success() {
+        return None;
+    }
+    Some(output.stdout.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::PylotError, logger};
+
+    // ── check_uv_version ─────────────────────────────────────────────────────
+
+    #[test]
+    fn test_check_uv_version_against_requirement() {
+        let output = "uv 0.5.11 (c4d0caaee 2024-12-19)";
+        assert_eq!(
+            check_uv_version(output, None, UvVersionPolicy::Fail).status,
+            Status::Pass
+        );
+        let ok = check_uv_version(output, Some(">=0.5"), UvVersionPolicy::Fail);
+        assert_eq!(ok.status, Status::Pass);
+        assert!(ok.detail.contains("required: >=0.5"));
+
+        let warn = check_uv_version(output, Some(">=0.6"), UvVersionPolicy::Warn);
+        assert_eq!(warn.status, Status::Warn);
+        assert!(warn.hint.unwrap().contains("pylot uv update --to"));
+        let fail = check_uv_version(output, Some(">=0.6"), UvVersionPolicy::Fail);
+        assert_eq!(fail.status, Status::Fail);
+        let garbled = check_uv_version("uv ???", Some(">=0.6"), UvVersionPolicy::Warn);
+        assert!(garbled.detail.contains("Could not read the uv version"));
+    }
+
+    // ── check_install_dir_on_path ────────────────────────────────────────────
+
+    #[test]
+    fn test_install_dir_on_path() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = Path::new("/home/dev/.local/bin");
+        let path = env::join_paths([Path::new("/usr/bin"), dir]).unwrap();
+        let check = check_install_dir_on_path(Some(path), dir, &Shell::Bash);
+        assert_eq!(check.status, Status::Pass);
+    }
+
+    #[test]
+    fn test_install_dir_missing_from_path() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = Path::new("/home/dev/.local/bin");
+        let path = OsString::from("/usr/bin");
+        let check = check_install_dir_on_path(Some(path), dir, &Shell::Zsh);
+        assert_eq!(check.status, Status::Warn);
+        assert!(check.hint.unwrap().contains("~/.zshrc"));
+        let check = check_install_dir_on_path(None, dir, &Shell::Bash);
+        assert_eq!(check.status, Status::Warn);
+    }
+
+    // ── check_shell ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_check_shell() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        assert_eq!(check_shell(Ok(Shell::Zsh)).status, Status::Pass);
+        assert_eq!(check_shell(Ok(Shell::Zsh)).detail, "zsh");
+        let unknown = check_shell(Ok(Shell::Unknown("tcsh".to_string())));
+        assert_eq!(unknown.status, Status::Warn);
+        assert!(unknown.detail.contains("tcsh"));
+        let unset = check_shell(Err(PylotError::EnvVarNotSet("SHELL".to_string())));
+        assert_eq!(unset.status, Status::Warn);
+    }
+
+    // ── check_settings_file ──────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_check_settings_file() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("settings.toml");
+        assert_eq!(check_settings_file(&path).await.status, Status::Warn);
+
+        async_fs::write(&path, "venvs_path = \"~/venvs\"\n")
+            .await
+            .unwrap();
+        let check = check_settings_file(&path).await;
+        assert_eq!(check.status, Status::Pass);
+        assert!(check.detail.contains("settings.toml"));
+
+        async_fs::write(&path, "venvs_path = 123\n").await.unwrap();
+        assert_eq!(check_settings_file(&path).await.status, Status::Fail);
+    }
+
+    // ── check_venvs_path ─────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_check_venvs_path() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let check = check_venvs_path(tmp.path()).await;
+        assert_eq!(check.status, Status::Pass);
+        // The probe file is cleaned up.
+        let mut entries = async_fs::read_dir(tmp.path()).await.unwrap();
+        assert!(entries.next_entry().await.unwrap().is_none());
+
+        let missing = check_venvs_path(&tmp.path().join("missing")).await;
+        assert_eq!(missing.status, Status::Fail);
+    }
+
+    // ── parse_python_list ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_python_list() {
+        let output = "\
+cpython-3.12.3-linux-x86_64-gnu    /usr/bin/python3.12
+cpython-3.12.3-linux-x86_64-gnu    /usr/bin/python3
+cpython-3.11.9-linux-x86_64-gnu    /home/dev/.local/share/uv/python/cpython-3.11.9/bin/python3
+
+";
+        assert_eq!(
+            parse_python_list(output),
+            vec![
+                "cpython-3.12.3-linux-x86_64-gnu",
+                "cpython-3.11.9-linux-x86_64-gnu"
+            ]
+        );
+        assert!(parse_python_list("").is_empty());
+    }
+
+    // ── run_checks / report ──────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_run_checks_covers_core_checks() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let checks = run_checks(&Pylot::default()).await;
+        let names: Vec<_> = checks.iter().map(|c| c.name).collect();
+        for name in [
+            "uv",
+            "uv install dir on PATH",
+            "Shell",
+            "Settings file",
+            "Venvs path",
+        ] {
+            assert!(names.contains(&name), "missing check {}", name);
+        }
+        if checks[0].status == Status::Fail {
+            assert!(!names.contains(&"uv cache"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_checks_with_recording_runner() {
+        use crate::processes::{CommandOutput, RecordingRunner};
+        use std::sync::Arc;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let runner = RecordingRunner::new()
+            .with_program("uv", "/opt/uv/bin/uv")
+            .respond("--version", CommandOutput::ok("uv 0.5.11\n"))
+            .respond("cache dir", CommandOutput::ok("/home/dev/.cache/uv\n"))
+            .respond(
+                "python list",
+                CommandOutput::ok("cpython-3.12.3-linux-x86_64-gnu  /usr/bin/python3.12\n"),
+            );
+        let mut ctx = Pylot::default()
+            .with_runner(Arc::new(runner))
+            .with_venvs_path(tmp.path().to_str().unwrap());
+        ctx.settings.required_uv_version = Some(">=0.5".to_string());
+
+        let checks = run_checks(&ctx).await;
+        let find = |name: &str| checks.iter().find(|c| c.name == name).unwrap();
+        assert_eq!(find("uv").status, Status::Pass);
+        assert_eq!(find("uv").detail, "uv 0.5.11 (required: >=0.5)");
+        assert_eq!(find("Venvs path").status, Status::Pass);
+        assert_eq!(find("uv cache").detail, "/home/dev/.cache/uv");
+        assert_eq!(
+            find("Python installs").detail,
+            "cpython-3.12.3-linux-x86_64-gnu"
+        );
+
+        let missing = Pylot::default().with_runner(Arc::new(RecordingRunner::new()));
+        let checks = run_checks(&missing).await;
+        assert_eq!(checks[0].status, Status::Fail);
+        assert!(!checks.iter().any(|c| c.name == "uv cache"));
+    }
+
+    #[test]
+    fn test_overall_and_report() {
+        let checks = vec![
+            Check::pass("uv", "uv 0.5.0"),
+            Check::warn("Shell", "Unrecognized shell 'tcsh'", "Use bash"),
+        ];
+        assert_eq!(overall(&checks), Status::Warn);
+        assert_eq!(overall(&[]), Status::Pass);
+
+        let mut output = Vec::new();
+        print_report_to(&mut output, &checks);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Suggested fix"));
+        assert!(output.contains("pass"));
+        assert!(output.contains("warn"));
+        assert!(output.contains("Use bash"));
+    }
+}
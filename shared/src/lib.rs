@@ -5,12 +5,27 @@
 //!
 //! | Module | Purpose |
 //! |--------|---------|
-//! | [`cfg::settings`] | Process-wide [`Settings`](cfg::settings::Settings) singleton loaded from `settings.toml` |
+//! | [`cfg::context`] | [`Pylot`](cfg::context::Pylot) context holding the settings, command runner and venvs root |
+//! | [`cfg::settings`] | [`Settings`](cfg::settings::Settings) loaded from `settings.toml` |
 //! | [`cfg::logger`] | Colored `env_logger` initializer |
 //! | [`infra::processes`] | Spawn subprocesses and activate virtual environment shells |
+//! | [`infra::progress`] | [`ProgressEvent`](infra::progress::ProgressEvent)s reported by long-running operations |
+//! | [`infra::toolchain`] | Pass/warn/fail checks of uv, `PATH`, the shell and settings |
 //! | [`uv::uvctrl`] | Install, update, uninstall, and check Astral UV |
+//! | [`uv::pyrequest`] | Parse and resolve Python version requests (`3.12`, `>=3.10`, `pypy@3.10`, paths) |
+//! | [`uv::uvarchive`] | Install uv from a release archive, unpacked directory or mirror, with SHA-256 verification |
+//! | [`uv::uvcache`] | Location, size, clean and prune of the uv cache via `uv cache` |
+//! | [`uv::uvpython`] | List, install, uninstall, and pin Python interpreters via `uv python` |
+//! | [`uv::uvtool`] | List, install, upgrade, and uninstall CLI tools via `uv tool` |
 //! | [`virtualenv::uvvenv`] | [`UvVenv`](virtualenv::uvvenv::UvVenv) — concrete virtual environment type |
+//! | [`virtualenv::distinfo`] | `.dist-info` metadata parsing (`METADATA`, `Requires-Dist`) |
+//! | [`virtualenv::deptree`] | Dependency graph and tree rendering for installed packages |
+//! | [`virtualenv::diskusage`] | Disk usage of environments, broken down by site-packages, bin and cache |
+//! | [`virtualenv::doctor`] | Health checks and repairs for broken environments |
+//! | [`virtualenv::outdated`] | Newer-version lookups against a package index or `uv pip list --outdated` |
+//! | [`virtualenv::scancache`] | Metadata cache that lets environment scans skip unchanged environments |
 //! | [`virtualenv::venvmanager`] | Discovery, selection, and table rendering for environments |
+//! | [`virtualenv::venvquery`] | Sorting, filtering and fuzzy search of environment listings |
 //! | [`virtualenv::venvtraits`] | Re-export of the [`Create`](virtualenv::venvtraits::Create) / [`Delete`](virtualenv::venvtraits::Delete) / [`Activate`](virtualenv::venvtraits::Activate) traits |
 //! | [`utility::utils`] | Confirmation prompts, requirements-file parsing, path helpers |
 //! | [`utility::constants`] | Platform constants (commands, paths, error messages) |
@@ -22,10 +37,16 @@ pub mod error;
 pub mod utility;
 pub mod uv;
 pub mod virtualenv;
+#[cfg(test)]
+pub(crate) mod testsupport;
 
-pub use cfg::{logger, settings};
-pub use infra::processes;
+pub use cfg::context::Pylot;
+pub use cfg::{context, logger, settings};
+pub use infra::{processes, progress, toolchain};
 pub use error::{PylotError, Result};
 pub use utility::{constants, utils};
-pub use uv::uvctrl;
-pub use virtualenv::{uvvenv, venvmanager, venvtraits};
+pub use uv::{pyrequest, uvarchive, uvcache, uvctrl, uvpython, uvtool};
+pub use virtualenv::{
+    deptree, diskusage, distinfo, doctor, outdated, scancache, uvvenv, venvmanager, venvquery,
+    venvtraits,
+};
@@ -0,0 +1,65 @@
+//! Helpers shared by the unit tests of several modules.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+};
+
+/// Serve `routes` (path → body) over plain HTTP on a random local port and
+/// return the base URL, e.g. `http://127.0.0.1:41234`.  Bodies are sent with
+/// `content_type` when given, except a [`redirect`], which is sent as is.
+/// Unknown paths answer with 404.
+pub(crate) fn spawn_stub_server(
+    content_type: Option<&'static str>,
+    routes: Vec<(String, Vec<u8>)>,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            let _ = reader.read_line(&mut request_line);
+            // Drain the remaining request headers.
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok() && line.trim() != "" {
+                line.clear();
+            }
+            let path = request_line.split_whitespace().nth(1).unwrap_or("");
+            match routes.iter().find(|(route, _)| route == path) {
+                Some((_, response)) if response.starts_with(b"HTTP/") => {
+                    let _ = stream.write_all(response);
+                }
+                Some((_, body)) => {
+                    let content_type = content_type
+                        .map(|t| format!("Content-Type: {}\r\n", t))
+                        .unwrap_or_default();
+                    let head = format!(
+                        "HTTP/1.1 200 OK\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+                        content_type,
+                        body.len()
+                    );
+                    let _ = stream.write_all(head.as_bytes());
+                    let _ = stream.write_all(body);
+                }
+                None => {
+                    let _ = stream.write_all(
+                        b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    );
+                }
+            }
+        }
+    });
+    format!("http://{}", addr)
+}
+
+/// A complete `302 Found` response pointing at `location`, for use as a
+/// [`spawn_stub_server`] route.
+pub(crate) fn redirect(location: &str) -> Vec<u8> {
+    format!(
+        "HTTP/1.1 302 Found\r\nLocation: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        location
+    )
+    .into_bytes()
+}
@@ -23,11 +23,9 @@ pub const SH_CMD: &str = "sh";
 pub const UNIX_PYTHON3_EXEC: &str = "bin/python3";
 /// Relative path to `python` inside a Unix virtual environment.
 pub const UNIX_PYTHON_EXEC: &str = "bin/python";
-/// `sh -c` arguments that download and run the official UV install script.
-pub const UV_UNIX_INSTALL_ARGS: &[&str] =
-    &["-c", "curl -LsSf https://astral.sh/uv/install.sh | sh"];
-/// `sh -c` arguments that remove the UV and UVX binaries from `~/.local/bin/`.
-pub const UV_UNIX_UNINSTALL_ARGS: &[&str] = &["-c", "rm ~/.local/bin/uv ~/.local/bin/uvx"];
+/// Official uv releases, the default base URL release archives are downloaded
+/// from.
+pub const UV_RELEASES_URL: &str = "https://github.com/astral-sh/uv/releases";
 
 // ── Shared constants ──────────────────────────────────────────────────────────
 
@@ -37,8 +35,17 @@ pub const UV_COMMAND: &str = "uv";
 pub const UPDATE_ARGS: &[&str] = &["self", "update"];
 /// Default directory where Pylot stores managed virtual environments.
 pub const DEFAULT_VENV_HOME: &str = "~/pylot/venvs/";
+/// File in a virtual environment whose modification time records when Pylot
+/// last activated it.
+pub const LAST_USED_FILE: &str = ".pylot-last-used";
 /// Default Python version used when none is specified.
 pub const DEFAULT_PYTHON_VERSION: &str = "3.12";
+/// Default package index queried for the latest package versions.
+pub const DEFAULT_INDEX_URL: &str = "https://pypi.org/simple";
+/// Environment variable that overrides the configured package index URL.
+pub const INDEX_URL_ENV: &str = "PYLOT_INDEX_URL";
+/// `Accept` header value requesting the PEP 691 JSON form of the simple API.
+pub const SIMPLE_API_JSON_ACCEPT: &str = "application/vnd.pypi.simple.v1+json";
 
 // ── Error messages ────────────────────────────────────────────────────────────
 
@@ -1,3 +1,4 @@
+use crate::processes::CommandRunner;
 use std::io::{stdout, BufRead, Write};
 use tokio::fs;
 
@@ -23,6 +24,21 @@ pub async fn read_requirements_file(
     Ok(lines)
 }
 
+/// Write `content` to `path` through a temporary file next to it, so a
+/// concurrent reader never sees half a file.
+pub async fn write_file_atomic(path: &std::path::Path, content: &str) -> std::io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(".{}", std::process::id()));
+    let result = match fs::write(&tmp, content).await {
+        Ok(()) => fs::rename(&tmp, path).await,
+        Err(e) => Err(e),
+    };
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp).await;
+    }
+    result
+}
+
 /// Prompt the user to confirm an action by reading a line from `input`.
 ///
 /// Returns `true` only if the user types `y`, `yes`, `Y`, or `YES`.
@@ -53,16 +69,47 @@ pub fn shorten_home_path(path: &str) -> String {
     }
 }
 
-/// Check that every command in `cmd` is available on `PATH`.
+/// Format a byte count for display, e.g. `1536` → `"1.5 KiB"`.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Format how long before `now` something happened, e.g. `"3d ago"`.
+pub fn format_age(then: std::time::SystemTime, now: std::time::SystemTime) -> String {
+    let secs = now.duration_since(then).map(|d| d.as_secs()).unwrap_or(0);
+    match secs {
+        0..60 => "just now".to_string(),
+        60..3600 => format!("{}m ago", secs / 60),
+        3600..86400 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86400),
+    }
+}
+
+/// Check that every command in `cmd` is available on `PATH`, as `runner`
+/// looks it up.
 ///
 /// # Errors
 ///
 /// Returns an error that lists all missing commands when at least one entry in
 /// `cmd` is not found.
-pub fn which_check(cmd: &[&str]) -> Result<(), Box<dyn std::error::Error>> {
+pub fn which_check(
+    runner: &dyn CommandRunner,
+    cmd: &[&str],
+) -> Result<(), Box<dyn std::error::Error>> {
     let missing_cmds: Vec<&str> = cmd
         .iter()
-        .filter(|&&c| which::which(c).is_err())
+        .filter(|&&c| runner.which(c).is_none())
         .cloned()
         .collect();
     if missing_cmds.is_empty() {
@@ -75,6 +122,7 @@ pub fn which_check(cmd: &[&str]) -> Result<(), Box<dyn std::error::Error>> {
 #[cfg(test)]
 mod tests {
     use crate::logger;
+    use crate::processes::{RecordingRunner, SystemRunner};
 
     use super::*;
     use std::{
@@ -205,17 +253,17 @@ mod tests {
     #[test]
     fn test_which_check_found() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        // `sh` is available on all Unix systems; `cmd` on Windows.
-        #[cfg(unix)]
-        assert!(which_check(&["sh"]).is_ok());
-        #[cfg(windows)]
-        assert!(which_check(&["cmd"]).is_ok());
+        let runner = RecordingRunner::new().with_program("uv", "/usr/bin/uv");
+        assert!(which_check(&runner, &["uv"]).is_ok());
     }
 
     #[test]
     fn test_which_check_not_found() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let result = which_check(&["this_command_definitely_does_not_exist_12345"]);
+        let result = which_check(
+            &RecordingRunner::new(),
+            &["this_command_definitely_does_not_exist_12345"],
+        );
         assert!(result.is_err());
     }
 
@@ -223,18 +271,32 @@ mod tests {
     fn test_which_check_empty_list() {
         logger::initialize_logger(log::LevelFilter::Trace);
         // An empty list means nothing is missing → Ok.
-        assert!(which_check(&[]).is_ok());
+        assert!(which_check(&RecordingRunner::new(), &[]).is_ok());
     }
 
     #[test]
     fn test_which_check_mixed() {
         logger::initialize_logger(log::LevelFilter::Trace);
         // One valid command and one that does not exist → Err.
+        let runner = RecordingRunner::new().with_program("uv", "/usr/bin/uv");
+        let result = which_check(
+            &runner,
+            &["uv", "this_command_definitely_does_not_exist_12345"],
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("this_command_definitely"));
+    }
+
+    #[test]
+    fn test_which_check_system_runner() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        // `sh` is available on all Unix systems; `cmd` on Windows.
         #[cfg(unix)]
-        {
-            let result = which_check(&["sh", "this_command_definitely_does_not_exist_12345"]);
-            assert!(result.is_err());
-        }
+        assert!(which_check(&SystemRunner, &["sh"]).is_ok());
+        #[cfg(windows)]
+        assert!(which_check(&SystemRunner, &["cmd"]).is_ok());
     }
 
     // ── shorten_home_path ────────────────────────────────────────────────────
@@ -285,4 +347,26 @@ mod tests {
         let pkgs = read_requirements_file(path.to_str().unwrap()).await.unwrap();
         assert!(pkgs.is_empty());
     }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MiB");
+        assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0 GiB");
+    }
+
+    #[test]
+    fn test_format_age() {
+        use std::time::{Duration, SystemTime};
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let ago = |secs| format_age(now - Duration::from_secs(secs), now);
+        assert_eq!(ago(5), "just now");
+        assert_eq!(ago(150), "2m ago");
+        assert_eq!(ago(7200), "2h ago");
+        assert_eq!(ago(3 * 86400 + 5), "3d ago");
+        // A clock that went backwards is not in the future.
+        assert_eq!(format_age(now + Duration::from_secs(60), now), "just now");
+    }
 }
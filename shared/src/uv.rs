@@ -1 +1,6 @@
+pub mod pyrequest;
+pub mod uvarchive;
+pub mod uvcache;
 pub mod uvctrl;
+pub mod uvpython;
+pub mod uvtool;
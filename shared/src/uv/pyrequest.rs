@@ -0,0 +1,716 @@
+use crate::{
+    context::Pylot,
+    error::{PylotError, Result},
+    uvpython::{self, PythonInstall},
+};
+use std::{
+    cmp::Ordering,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// Implementation names accepted in front of a version, longest first so that
+/// `cpython3.12` is not read as `python` with a stray `c`.
+const IMPLEMENTATIONS: &[&str] = &["cpython", "graalpy", "python", "pypy"];
+
+/// Comparison operator of a single version specifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `3.12` or `==3.12`: every release segment given must match.
+    Equal,
+    /// `!=3.12`
+    NotEqual,
+    /// `>3.12`
+    Greater,
+    /// `>=3.12`
+    GreaterEqual,
+    /// `<3.12`
+    Less,
+    /// `<=3.12`
+    LessEqual,
+    /// `~=3.10`: at least 3.10, below 4.
+    Compatible,
+}
+
+impl Operator {
+    fn symbol(self) -> &'static str {
+        match self {
+            Operator::Equal => "==",
+            Operator::NotEqual => "!=",
+            Operator::Greater => ">",
+            Operator::GreaterEqual => ">=",
+            Operator::Less => "<",
+            Operator::LessEqual => "<=",
+            Operator::Compatible => "~=",
+        }
+    }
+}
+
+/// One comma-separated part of a version request, e.g. `>=3.10`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Specifier {
+    pub op: Operator,
+    /// Release segments, e.g. `[3, 10]`.
+    pub release: Vec<u64>,
+}
+
+impl Specifier {
+    fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let (op, rest) = [
+            (">=", Operator::GreaterEqual),
+            ("<=", Operator::LessEqual),
+            ("==", Operator::Equal),
+            ("!=", Operator::NotEqual),
+            ("~=", Operator::Compatible),
+            (">", Operator::Greater),
+            ("<", Operator::Less),
+        ]
+        .iter()
+        .find_map(|(symbol, op)| input.strip_prefix(symbol).map(|rest| (*op, rest)))
+        .unwrap_or((Operator::Equal, input));
+        let rest = rest.trim();
+        // `==3.12.*` means the same as `3.12` here.
+        let rest = match op {
+            Operator::Equal | Operator::NotEqual => rest.strip_suffix(".*").unwrap_or(rest),
+            _ => rest,
+        };
+        let release = rest
+            .split('.')
+            .map(|segment| segment.parse::<u64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        if release.len() > 3 || (op == Operator::Compatible && release.len() < 2) {
+            return None;
+        }
+        Some(Specifier { op, release })
+    }
+
+    /// Whether `version` (release segments of an interpreter) satisfies this
+    /// specifier.
+    pub fn matches(&self, version: &[u64]) -> bool {
+        let is_prefix =
+            || self.release.len() <= version.len() && version[..self.release.len()] == self.release;
+        let ordering = compare_release(version, &self.release);
+        match self.op {
+            Operator::Equal => is_prefix(),
+            Operator::NotEqual => !is_prefix(),
+            Operator::Greater => ordering == Ordering::Greater,
+            Operator::GreaterEqual => ordering != Ordering::Less,
+            Operator::Less => ordering == Ordering::Less,
+            Operator::LessEqual => ordering != Ordering::Greater,
+            Operator::Compatible => {
+                let upper = &self.release[..self.release.len() - 1];
+                ordering != Ordering::Less
+                    && upper.len() <= version.len()
+                    && version[..upper.len()] == *upper
+            }
+        }
+    }
+}
+
+impl fmt::Display for Specifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let release: Vec<String> = self.release.iter().map(u64::to_string).collect();
+        write!(f, "{}{}", self.op.symbol(), release.join("."))
+    }
+}
+
+/// A parsed `--python` request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PythonRequest {
+    /// A version request such as `3.12`, `>=3.10,<3.13`, `pypy3.10`,
+    /// `cpython@3.11.4` or `3.13t`.
+    Version {
+        /// Lower-cased implementation name, or `None` for any implementation.
+        implementation: Option<String>,
+        /// All specifiers must match; empty matches every version.
+        specifiers: Vec<Specifier>,
+        /// Whether the free-threaded build was requested (`t` suffix).
+        freethreaded: bool,
+    },
+    /// A path to an interpreter executable or an installation directory.
+    Path(PathBuf),
+}
+
+impl PythonRequest {
+    /// Parse a request in any of the forms uv accepts for `--python`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PylotError::InvalidPythonVersion`] when the request is empty
+    /// or not a recognisable version, specifier list or path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pylot_shared::pyrequest::PythonRequest;
+    ///
+    /// assert!(PythonRequest::parse("3.12").is_ok());
+    /// assert!(PythonRequest::parse("pypy@3.10").is_ok());
+    /// assert!(PythonRequest::parse("3.x").is_err());
+    /// ```
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.trim();
+        let invalid = || PylotError::InvalidPythonVersion(format!("'{}'", input));
+        if input.is_empty() {
+            return Err(PylotError::InvalidPythonVersion(
+                "no version given".to_string(),
+            ));
+        }
+        if input.contains(['/', '\\']) || input.starts_with('.') {
+            return Ok(PythonRequest::Path(PathBuf::from(
+                shellexpand::tilde(input).to_string(),
+            )));
+        }
+
+        let lower = input.to_ascii_lowercase();
+        let (implementation, rest) =
+            match IMPLEMENTATIONS.iter().find(|name| lower.starts_with(*name)) {
+                Some(name) => {
+                    let rest = &lower[name.len()..];
+                    let rest = rest.strip_prefix(['@', '-']).unwrap_or(rest);
+                    // Plain `python` places no restriction on the implementation.
+                    let implementation = (*name != "python").then(|| name.to_string());
+                    (implementation, rest)
+                }
+                None => (None, lower.as_str()),
+            };
+
+        let (rest, freethreaded) = match rest.strip_suffix('t') {
+            Some(rest) if rest.ends_with(|c: char| c.is_ascii_digit()) => (rest, true),
+            _ => (rest, false),
+        };
+        let specifiers = if rest.is_empty() {
+            Vec::new()
+        } else {
+            rest.split(',')
+                .map(Specifier::parse)
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(invalid)?
+        };
+        if specifiers.is_empty() && implementation.is_none() && lower != "python" {
+            return Err(invalid());
+        }
+        Ok(PythonRequest::Version {
+            implementation,
+            specifiers,
+            freethreaded,
+        })
+    }
+
+    /// Whether `install` satisfies this request.  Path requests never match a
+    /// listed interpreter.
+    pub fn matches(&self, install: &PythonInstall) -> bool {
+        match self {
+            PythonRequest::Version {
+                implementation,
+                specifiers,
+                freethreaded,
+            } => {
+                let implementation_matches = implementation
+                    .as_deref()
+                    .is_none_or(|name| install.implementation.eq_ignore_ascii_case(name));
+                implementation_matches
+                    && is_freethreaded(install) == *freethreaded
+                    && release(&install.version)
+                        .is_some_and(|version| specifiers.iter().all(|s| s.matches(&version)))
+            }
+            PythonRequest::Path(_) => false,
+        }
+    }
+}
+
+/// What a request resolved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolved {
+    /// The best listed interpreter; uv downloads it first if it is not
+    /// installed.
+    Interpreter(PythonInstall),
+    /// An interpreter given by path.
+    Path(PathBuf),
+}
+
+impl fmt::Display for Resolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Resolved::Interpreter(install) if install.is_installed() => {
+                write!(f, "{} {}", install.implementation, install.version)
+            }
+            Resolved::Interpreter(install) => write!(
+                f,
+                "{} {} (will be downloaded)",
+                install.implementation, install.version
+            ),
+            Resolved::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Resolve `request` against the interpreters in `installs`.
+///
+/// Installed interpreters are preferred over downloadable ones, then the
+/// highest version wins.  Path requests are checked on disk instead.
+///
+/// # Errors
+///
+/// Returns [`PylotError::InvalidPythonVersion`] when nothing matches, naming
+/// the nearest available version when there is one.
+pub fn resolve(request: &PythonRequest, installs: &[PythonInstall]) -> Result<Resolved> {
+    if let PythonRequest::Path(path) = request {
+        return resolve_path(path).map(Resolved::Path);
+    }
+    installs
+        .iter()
+        .filter(|install| request.matches(install))
+        .max_by(|a, b| {
+            a.is_installed().cmp(&b.is_installed()).then_with(|| {
+                compare_release(
+                    &release(&a.version).unwrap_or_default(),
+                    &release(&b.version).unwrap_or_default(),
+                )
+            })
+        })
+        .map(|install| Resolved::Interpreter(install.clone()))
+        .ok_or_else(|| {
+            let message = format!("no available interpreter matches '{}'", display(request));
+            PylotError::InvalidPythonVersion(match suggest(request, installs) {
+                Some(suggestion) => format!("{}; did you mean '{}'?", message, suggestion),
+                None => format!(
+                    "{}; run 'pylot python list' to see the available versions",
+                    message
+                ),
+            })
+        })
+}
+
+/// Parse `input` and resolve it against every version `uv python list
+/// --all-versions` knows about.
+///
+/// When uv cannot list interpreters the request is only checked for syntax,
+/// leaving the final word to `uv venv`.
+///
+/// # Errors
+///
+/// Returns [`PylotError::InvalidPythonVersion`] when the request is invalid or
+/// cannot be satisfied.
+pub async fn validate(ctx: &Pylot, input: &str) -> Result<Option<Resolved>> {
+    let request = PythonRequest::parse(input)?;
+    if let PythonRequest::Path(path) = &request {
+        return resolve_path(path).map(|path| Some(Resolved::Path(path)));
+    }
+    match uvpython::list(ctx, true).await {
+        Ok(installs) => resolve(&request, &installs).map(Some),
+        Err(e) => {
+            log::warn!(
+                "Could not list Python interpreters to check '{}': {}",
+                input,
+                e
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// Version strings to offer for selection: one `major.minor` entry per
+/// CPython minor version and `implementation@major.minor` for the others, in
+/// the order uv lists them.
+pub fn version_choices(installs: &[PythonInstall]) -> Vec<String> {
+    let mut choices: Vec<String> = Vec::new();
+    for install in installs {
+        let Some(version) = release(&install.version) else {
+            continue;
+        };
+        let minor = truncate(&version, 2);
+        let suffix = if is_freethreaded(install) { "t" } else { "" };
+        let choice = if install.implementation.eq_ignore_ascii_case("cpython") {
+            format!("{}{}", minor, suffix)
+        } else {
+            format!("{}@{}{}", install.implementation, minor, suffix)
+        };
+        if !choices.contains(&choice) {
+            choices.push(choice);
+        }
+    }
+    choices
+}
+
+/// Check that `path` is an interpreter, or a directory containing one.
+fn resolve_path(path: &Path) -> Result<PathBuf> {
+    if path.is_file() {
+        return Ok(path.to_path_buf());
+    }
+    if path.is_dir() {
+        let candidates = [
+            "bin/python3",
+            "bin/python",
+            "Scripts/python.exe",
+            "python.exe",
+        ];
+        if let Some(found) = candidates
+            .iter()
+            .map(|candidate| path.join(candidate))
+            .find(|candidate| candidate.is_file())
+        {
+            return Ok(found);
+        }
+        return Err(PylotError::InvalidPythonVersion(format!(
+            "no Python interpreter found in '{}'",
+            path.display()
+        )));
+    }
+    Err(PylotError::InvalidPythonVersion(format!(
+        "'{}' does not exist",
+        path.display()
+    )))
+}
+
+/// Numeric release segments of an interpreter version such as `3.13.0rc1` or
+/// `3.13.0+freethreaded`.
+fn release(version: &str) -> Option<Vec<u64>> {
+    let base = version.split('+').next()?;
+    let segments: Vec<u64> = base
+        .split('.')
+        .map_while(|segment| {
+            let digits: String = segment.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse().ok()
+        })
+        .collect();
+    (!segments.is_empty()).then_some(segments)
+}
+
+fn is_freethreaded(install: &PythonInstall) -> bool {
+    install.version.contains("+freethreaded")
+}
+
+/// Compare release segments, treating missing trailing segments as zero.
+fn compare_release(a: &[u64], b: &[u64]) -> Ordering {
+    (0..a.len().max(b.len()))
+        .map(|i| {
+            let x = a.get(i).copied().unwrap_or(0);
+            let y = b.get(i).copied().unwrap_or(0);
+            x.cmp(&y)
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+fn truncate(version: &[u64], segments: usize) -> String {
+    version
+        .iter()
+        .take(segments)
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn display(request: &PythonRequest) -> String {
+    match request {
+        PythonRequest::Version {
+            implementation,
+            specifiers,
+            freethreaded,
+        } => {
+            let specifiers: Vec<String> = specifiers
+                .iter()
+                .map(|s| match s.op {
+                    Operator::Equal => s.to_string()[2..].to_string(),
+                    _ => s.to_string(),
+                })
+                .collect();
+            let mut text = specifiers.join(",");
+            if *freethreaded {
+                text.push('t');
+            }
+            match implementation {
+                Some(name) if text.is_empty() => name.clone(),
+                Some(name) => format!("{}@{}", name, text),
+                None => text,
+            }
+        }
+        PythonRequest::Path(path) => path.display().to_string(),
+    }
+}
+
+/// Pick the available version closest to what was asked for.
+///
+/// Candidates are compared as text first, so transposed digits (`3.21`) find
+/// `3.12`, then numerically.
+fn suggest(request: &PythonRequest, installs: &[PythonInstall]) -> Option<String> {
+    let PythonRequest::Version {
+        implementation,
+        specifiers,
+        ..
+    } = request
+    else {
+        return None;
+    };
+    let target = specifiers.first()?;
+    let precision = target.release.len().max(2);
+    let same_implementation: Vec<&PythonInstall> = installs
+        .iter()
+        .filter(|install| {
+            implementation
+                .as_deref()
+                .is_none_or(|name| install.implementation.eq_ignore_ascii_case(name))
+        })
+        .collect();
+    let pool = if same_implementation.is_empty() {
+        installs.iter().collect()
+    } else {
+        same_implementation
+    };
+    let wanted = truncate(&target.release, precision);
+    pool.into_iter()
+        .filter_map(|install| {
+            let version = release(&install.version)?;
+            let text = truncate(&version, precision);
+            let numeric: Vec<u64> = (0..precision)
+                .map(|i| {
+                    let have = version.get(i).copied().unwrap_or(0);
+                    let want = target.release.get(i).copied().unwrap_or(0);
+                    have.abs_diff(want)
+                })
+                .collect();
+            let choice = if install.implementation.eq_ignore_ascii_case("cpython") {
+                text.clone()
+            } else {
+                format!("{}@{}", install.implementation, text)
+            };
+            Some((edit_distance(&wanted, &text), numeric, choice))
+        })
+        .min_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)))
+        .map(|(_, _, choice)| choice)
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent
+/// transpositions.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uvpython::parse_list;
+
+    const LIST_OUTPUT: &str = "\
+cpython-3.13.1-linux-x86_64-gnu                  <download available>
+cpython-3.13.1+freethreaded-linux-x86_64-gnu     <download available>
+cpython-3.12.8-linux-x86_64-gnu                  <download available>
+cpython-3.12.3-linux-x86_64-gnu                  /home/dev/.local/share/uv/python/cpython-3.12.3-linux-x86_64-gnu/bin/python3.12
+cpython-3.11.4-linux-x86_64-gnu                  /usr/bin/python3.11
+cpython-3.10.16-linux-x86_64-gnu                 <download available>
+pypy-3.10.14-linux-x86_64-gnu                    <download available>
+";
+
+    fn resolved_version(input: &str) -> Result<String> {
+        let installs = parse_list(LIST_OUTPUT);
+        match resolve(&PythonRequest::parse(input)?, &installs)? {
+            Resolved::Interpreter(install) => Ok(install.key),
+            Resolved::Path(path) => Ok(path.display().to_string()),
+        }
+    }
+
+    // ── parsing ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_forms() {
+        assert_eq!(
+            PythonRequest::parse("3.12").unwrap(),
+            PythonRequest::Version {
+                implementation: None,
+                specifiers: vec![Specifier {
+                    op: Operator::Equal,
+                    release: vec![3, 12]
+                }],
+                freethreaded: false,
+            }
+        );
+        let PythonRequest::Version { specifiers, .. } =
+            PythonRequest::parse(">=3.10, <3.13").unwrap()
+        else {
+            panic!("expected a version request");
+        };
+        assert_eq!(specifiers.len(), 2);
+        assert_eq!(specifiers[0].op, Operator::GreaterEqual);
+        assert_eq!(specifiers[1].op, Operator::Less);
+
+        for (input, implementation) in [
+            ("pypy3.10", "pypy"),
+            ("pypy@3.10", "pypy"),
+            ("cpython@3.11.4", "cpython"),
+            ("CPython-3.11", "cpython"),
+        ] {
+            let PythonRequest::Version {
+                implementation: parsed,
+                ..
+            } = PythonRequest::parse(input).unwrap()
+            else {
+                panic!("expected a version request for {}", input);
+            };
+            assert_eq!(parsed.as_deref(), Some(implementation), "{}", input);
+        }
+
+        assert!(matches!(
+            PythonRequest::parse("3.13t").unwrap(),
+            PythonRequest::Version {
+                freethreaded: true,
+                ..
+            }
+        ));
+        assert!(matches!(
+            PythonRequest::parse("python3.12").unwrap(),
+            PythonRequest::Version {
+                implementation: None,
+                ..
+            }
+        ));
+        assert!(matches!(
+            PythonRequest::parse("/usr/bin/python3").unwrap(),
+            PythonRequest::Path(_)
+        ));
+        assert!(matches!(
+            PythonRequest::parse("./.venv/bin/python").unwrap(),
+            PythonRequest::Path(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_rejects_garbage() {
+        for input in [
+            "", "  ", "3.x", "three", "3..12", ">=", "~=3", "3.12.1.1", "pypy3.a",
+        ] {
+            let err = PythonRequest::parse(input).unwrap_err();
+            assert!(
+                matches!(err, PylotError::InvalidPythonVersion(_)),
+                "{}",
+                input
+            );
+        }
+    }
+
+    // ── matching ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_specifier_matches() {
+        let spec = |s: &str| Specifier::parse(s).unwrap();
+        assert!(spec("3.12").matches(&[3, 12, 3]));
+        assert!(!spec("3.1").matches(&[3, 12, 3]));
+        assert!(spec("==3.12.*").matches(&[3, 12, 0]));
+        assert!(!spec("!=3.12").matches(&[3, 12, 1]));
+        assert!(spec(">=3.10").matches(&[3, 10, 0]));
+        assert!(!spec(">3.10").matches(&[3, 10, 0]));
+        assert!(spec("<3.13").matches(&[3, 12, 8]));
+        assert!(spec("<=3.12").matches(&[3, 12, 0]));
+        assert!(!spec("<=3.12").matches(&[3, 12, 1]));
+        assert!(spec("~=3.10").matches(&[3, 13, 1]));
+        assert!(!spec("~=3.10").matches(&[4, 0, 0]));
+        assert!(!spec("~=3.11.2").matches(&[3, 12, 0]));
+    }
+
+    // ── resolve ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_resolve_prefers_installed_then_highest() {
+        assert_eq!(
+            resolved_version("3.12").unwrap(),
+            "cpython-3.12.3-linux-x86_64-gnu"
+        );
+        assert_eq!(
+            resolved_version(">=3.10").unwrap(),
+            "cpython-3.12.3-linux-x86_64-gnu"
+        );
+        assert_eq!(
+            resolved_version(">=3.13").unwrap(),
+            "cpython-3.13.1-linux-x86_64-gnu"
+        );
+        assert_eq!(
+            resolved_version("cpython@3.11.4").unwrap(),
+            "cpython-3.11.4-linux-x86_64-gnu"
+        );
+        assert_eq!(
+            resolved_version("pypy3.10").unwrap(),
+            "pypy-3.10.14-linux-x86_64-gnu"
+        );
+        assert_eq!(
+            resolved_version("3.13t").unwrap(),
+            "cpython-3.13.1+freethreaded-linux-x86_64-gnu"
+        );
+    }
+
+    #[test]
+    fn test_resolve_suggests_nearest_match() {
+        let message = |input: &str| resolved_version(input).unwrap_err().to_string();
+        assert!(message("3.21").contains("did you mean '3.12'"));
+        assert!(message("3.9").contains("did you mean '3.10'"));
+        assert!(message("3.11.5").contains("did you mean '3.11.4'"));
+        assert!(message("pypy3.9").contains("did you mean 'pypy@3.10'"));
+        assert!(message("<3.0").contains("no available interpreter matches '<3.0'"));
+
+        let err = resolve(&PythonRequest::parse("3.12").unwrap(), &[]).unwrap_err();
+        assert!(err.to_string().contains("pylot python list"));
+    }
+
+    #[test]
+    fn test_resolve_path() {
+        let tmp = tempfile::tempdir().unwrap();
+        let bin = tmp.path().join("bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        std::fs::write(bin.join("python3"), "").unwrap();
+
+        let dir = tmp.path().to_string_lossy().to_string();
+        assert_eq!(
+            resolved_version(&dir).unwrap(),
+            bin.join("python3").display().to_string()
+        );
+        let exe = bin.join("python3").to_string_lossy().to_string();
+        assert_eq!(resolved_version(&exe).unwrap(), exe);
+
+        let missing = tmp.path().join("nope").to_string_lossy().to_string();
+        assert!(resolved_version(&missing)
+            .unwrap_err()
+            .to_string()
+            .contains("does not exist"));
+        let empty = tempfile::tempdir().unwrap();
+        assert!(resolved_version(&empty.path().to_string_lossy())
+            .unwrap_err()
+            .to_string()
+            .contains("no Python interpreter found"));
+    }
+
+    #[test]
+    fn test_version_choices() {
+        let installs = parse_list(LIST_OUTPUT);
+        assert_eq!(
+            version_choices(&installs),
+            vec!["3.13", "3.13t", "3.12", "3.11", "3.10", "pypy@3.10"]
+        );
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("3.21", "3.12"), 1);
+        assert_eq!(edit_distance("3.9", "3.10"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}
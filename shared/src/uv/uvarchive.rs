@@ -0,0 +1,756 @@
+use crate::{
+    constants::UV_RELEASES_URL,
+    error::{PylotError, Result},
+    processes::Shell,
+    settings::Settings,
+};
+use semver::Version;
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tempfile::TempDir;
+
+/// Environment variable the official installer reads for the install
+/// directory; honoured here too so both methods agree.
+pub const UV_INSTALL_DIR_ENV: &str = "UV_INSTALL_DIR";
+
+/// Extension of the `sha256sum`-style file published next to each release
+/// archive.
+const CHECKSUM_SUFFIX: &str = ".sha256";
+
+/// Timeout for a single mirror download.
+const DOWNLOAD_TIMEOUT_SECS: u64 = 300;
+
+/// Names of the binaries in a uv release.
+pub(crate) fn binary_names() -> [&'static str; 2] {
+    if cfg!(windows) {
+        ["uv.exe", "uvx.exe"]
+    } else {
+        ["uv", "uvx"]
+    }
+}
+
+/// Rust target triple of the running platform, as used in uv release asset
+/// names, or `None` when uv publishes no build for it.
+pub fn target_triple() -> Option<&'static str> {
+    let musl = cfg!(target_env = "musl");
+    match (std::env::consts::ARCH, std::env::consts::OS) {
+        ("x86_64", "linux") if musl => Some("x86_64-unknown-linux-musl"),
+        ("x86_64", "linux") => Some("x86_64-unknown-linux-gnu"),
+        ("aarch64", "linux") if musl => Some("aarch64-unknown-linux-musl"),
+        ("aarch64", "linux") => Some("aarch64-unknown-linux-gnu"),
+        ("x86_64", "macos") => Some("x86_64-apple-darwin"),
+        ("aarch64", "macos") => Some("aarch64-apple-darwin"),
+        ("x86_64", "windows") => Some("x86_64-pc-windows-msvc"),
+        ("aarch64", "windows") => Some("aarch64-pc-windows-msvc"),
+        _ => None,
+    }
+}
+
+/// File name of the release archive for `triple`, e.g.
+/// `uv-x86_64-unknown-linux-gnu.tar.gz`.
+pub fn archive_name(triple: &str) -> String {
+    if triple.contains("windows") {
+        format!("uv-{}.zip", triple)
+    } else {
+        format!("uv-{}.tar.gz", triple)
+    }
+}
+
+/// URL of the release archive on a mirror laid out like
+/// `https://github.com/astral-sh/uv/releases`.
+///
+/// A pinned `version` is fetched from `<mirror>/download/<version>/`, the
+/// latest release from `<mirror>/latest/download/`.
+pub fn mirror_archive_url(mirror: &str, version: Option<&Version>, asset: &str) -> String {
+    let mirror = mirror.trim_end_matches('/');
+    match version {
+        Some(version) => format!("{}/download/{}/{}", mirror, version, asset),
+        None => format!("{}/latest/download/{}", mirror, asset),
+    }
+}
+
+/// Base URL releases are downloaded from: the `uv_mirror_url` setting, or
+/// the official GitHub releases.
+pub fn releases_url(settings: &Settings) -> String {
+    settings
+        .uv_mirror_url
+        .clone()
+        .filter(|url| !url.trim().is_empty())
+        .unwrap_or_else(|| UV_RELEASES_URL.to_string())
+}
+
+/// Directory the uv binaries are installed into: `UV_INSTALL_DIR`, then the
+/// `uv_install_dir` setting, then `XDG_BIN_HOME`, then `~/.local/bin`,
+/// matching the official installer.
+pub fn install_dir(settings: &Settings) -> PathBuf {
+    resolve_install_dir(
+        std::env::var(UV_INSTALL_DIR_ENV).ok(),
+        settings.uv_install_dir.clone(),
+        std::env::var("XDG_BIN_HOME").ok(),
+    )
+}
+
+fn resolve_install_dir(
+    env_dir: Option<String>,
+    configured: Option<String>,
+    xdg_bin_home: Option<String>,
+) -> PathBuf {
+    [env_dir, configured, xdg_bin_home]
+        .into_iter()
+        .flatten()
+        .find(|dir| !dir.trim().is_empty())
+        .map(|dir| PathBuf::from(shellexpand::tilde(&dir).to_string()))
+        .unwrap_or_else(|| PathBuf::from(shellexpand::tilde("~/.local/bin").to_string()))
+}
+
+/// Reject download URLs that are not HTTPS.  Plain HTTP is only allowed for
+/// loopback hosts, so a local stand-in server can be used.
+pub fn require_https(url: &str) -> Result<()> {
+    if url.starts_with("https://") {
+        return Ok(());
+    }
+    let loopback = ["http://127.0.0.1", "http://localhost", "http://[::1]"];
+    let is_loopback = loopback.iter().any(|prefix| {
+        url.strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with([':', '/']))
+    });
+    if is_loopback {
+        Ok(())
+    } else {
+        Err(PylotError::Network(format!(
+            "Refusing to download uv from {}: only https:// URLs are allowed",
+            url
+        )))
+    }
+}
+
+/// Whether `dir` is one of the entries in `path_var`.
+pub fn is_on_path(dir: &Path, path_var: Option<&std::ffi::OsStr>) -> bool {
+    path_var
+        .map(|p| std::env::split_paths(p).any(|entry| entry == dir))
+        .unwrap_or(false)
+}
+
+/// The line to add to the profile of `shell` so `dir` is on `PATH`.
+pub fn path_hint(dir: &Path, shell: &Shell) -> String {
+    let dir = dir.display();
+    match shell {
+        Shell::Fish => format!("fish_add_path \"{}\"  # in ~/.config/fish/config.fish", dir),
+        Shell::Nu => format!(
+            "$env.PATH = ($env.PATH | prepend '{}')  # in config.nu",
+            dir
+        ),
+        Shell::Pwsh | Shell::PowerShell => {
+            format!("$env:Path = \"{};$env:Path\"  # in $PROFILE", dir)
+        }
+        Shell::Cmd => format!("setx PATH \"{};%PATH%\"", dir),
+        Shell::Zsh => format!("export PATH=\"{}:$PATH\"  # in ~/.zshrc", dir),
+        Shell::Bash => format!("export PATH=\"{}:$PATH\"  # in ~/.bashrc", dir),
+        Shell::Unknown(_) => format!("export PATH=\"{}:$PATH\"  # in your shell profile", dir),
+    }
+}
+
+/// Lower-case hex SHA-256 of the file at `path`.
+pub fn sha256_file(path: &Path) -> Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Read the checksum from `sha256sum`-style text (`<hex>  <file>`), or a bare
+/// hex digest.
+pub fn parse_checksum(text: &str) -> Option<String> {
+    let digest = text.split_whitespace().next()?.to_ascii_lowercase();
+    (digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit())).then_some(digest)
+}
+
+/// Check that the file at `path` hashes to `expected`.
+///
+/// # Errors
+///
+/// Returns [`PylotError::Other`] on a mismatch and [`PylotError::Io`] when the
+/// file cannot be read.
+pub fn verify_checksum(path: &Path, expected: &str) -> Result<()> {
+    let expected = parse_checksum(expected).ok_or_else(|| {
+        PylotError::Other(format!("'{}' is not a SHA-256 checksum", expected.trim()))
+    })?;
+    let actual = sha256_file(path)?;
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(PylotError::Other(format!(
+            "Checksum mismatch for {}: expected {}, got {}",
+            path.display(),
+            expected,
+            actual
+        )))
+    }
+}
+
+/// The checksum to verify `archive` against: `given`, or the contents of the
+/// `<archive>.sha256` file next to it.
+///
+/// # Errors
+///
+/// Returns [`PylotError::Other`] when neither is available, since an archive
+/// is never installed unverified.
+pub fn expected_checksum(archive: &Path, given: Option<&str>) -> Result<String> {
+    if let Some(given) = given {
+        return Ok(given.to_string());
+    }
+    let sidecar = PathBuf::from(format!("{}{}", archive.display(), CHECKSUM_SUFFIX));
+    fs::read_to_string(&sidecar).map_err(|_| {
+        PylotError::Other(format!(
+            "No checksum for {}: pass --sha256 or place {} next to it",
+            archive.display(),
+            sidecar.display()
+        ))
+    })
+}
+
+/// Unpack a `.tar.gz` or, as published for Windows, `.zip` uv release into
+/// `dest`.
+///
+/// # Errors
+///
+/// Returns [`PylotError::Other`] for other archive formats, or when the
+/// archive is damaged.
+pub fn unpack(archive: &Path, dest: &Path) -> Result<()> {
+    let name = archive
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let failed = |e: &dyn std::fmt::Display| {
+        PylotError::Other(format!("Failed to unpack {}: {}", archive.display(), e))
+    };
+    if name.ends_with(".zip") {
+        let file = fs::File::open(archive)?;
+        return zip::ZipArchive::new(file)
+            .and_then(|mut zip| zip.extract(dest))
+            .map_err(|e| failed(&e));
+    }
+    if !(name.ends_with(".tar.gz") || name.ends_with(".tgz")) {
+        return Err(PylotError::Other(format!(
+            "Unsupported archive {}: expected a .tar.gz or .zip, or a directory with the unpacked release",
+            archive.display()
+        )));
+    }
+    let file = fs::File::open(archive)?;
+    tar::Archive::new(flate2::read::GzDecoder::new(file))
+        .unpack(dest)
+        .map_err(|e| failed(&e))
+}
+
+/// Find the uv binaries in an unpacked release: directly in `dir` or one
+/// level down (release archives contain a `uv-<triple>/` folder).
+///
+/// # Errors
+///
+/// Returns [`PylotError::PathError`] when there is no `uv` binary.
+pub fn find_binaries(dir: &Path) -> Result<Vec<PathBuf>> {
+    let [uv, _] = binary_names();
+    let mut candidates = vec![dir.to_path_buf()];
+    if let Ok(entries) = fs::read_dir(dir) {
+        let mut subdirs: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .collect();
+        subdirs.sort();
+        candidates.extend(subdirs);
+    }
+    let release_dir = candidates
+        .into_iter()
+        .find(|candidate| candidate.join(uv).is_file())
+        .ok_or_else(|| {
+            PylotError::PathError(format!("No {} binary found in {}", uv, dir.display()))
+        })?;
+    Ok(binary_names()
+        .iter()
+        .map(|name| release_dir.join(name))
+        .filter(|path| path.is_file())
+        .collect())
+}
+
+/// Copy `binaries` into `install_dir`, creating it if needed, and make them
+/// executable.  Returns the installed paths.
+pub fn copy_binaries(binaries: &[PathBuf], install_dir: &Path) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(install_dir)?;
+    let mut installed = Vec::new();
+    for binary in binaries {
+        let Some(name) = binary.file_name() else {
+            continue;
+        };
+        let target = install_dir.join(name);
+        // Copy next to the target and rename, so a running uv is replaced
+        // atomically instead of being truncated.
+        let staging = install_dir.join(format!(".{}.pylot-tmp", name.to_string_lossy()));
+        fs::copy(binary, &staging)?;
+        set_executable(&staging)?;
+        fs::rename(&staging, &target)?;
+        installed.push(target);
+    }
+    Ok(installed)
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+/// Install uv from a pre-downloaded release: a `.tar.gz` or `.zip` archive, verified
+/// against `sha256` or its `.sha256` file, or a directory holding the
+/// unpacked release.
+///
+/// For a directory, the `uv` binary is verified against `sha256` or the
+/// `uv.sha256` file next to it.  Returns the installed binary paths.
+///
+/// # Errors
+///
+/// Returns an error when the checksum is missing or does not match, the
+/// archive cannot be unpacked, or no `uv` binary is found.
+pub fn install_from(
+    source: &Path,
+    sha256: Option<&str>,
+    install_dir: &Path,
+) -> Result<Vec<PathBuf>> {
+    if source.is_dir() {
+        let binaries = find_binaries(source)?;
+        let expected = expected_checksum(&binaries[0], sha256)?;
+        verify_checksum(&binaries[0], &expected)?;
+        log::info!("Checksum verified for {}", binaries[0].display());
+        return copy_binaries(&binaries, install_dir);
+    }
+    if !source.is_file() {
+        return Err(PylotError::PathError(format!(
+            "{} does not exist",
+            source.display()
+        )));
+    }
+
+    let expected = expected_checksum(source, sha256)?;
+    verify_checksum(source, &expected)?;
+    log::info!("Checksum verified for {}", source.display());
+
+    let staging = staging_dir()?;
+    unpack(source, staging.path())?;
+    let binaries = find_binaries(staging.path())?;
+    copy_binaries(&binaries, install_dir)
+}
+
+/// Download the release for this platform from `base_url`, verify it against
+/// its published `.sha256` file and install it into `install_dir`.  Replaces
+/// the official `curl … | sh` installer.  Returns the installed binary paths.
+///
+/// # Errors
+///
+/// Returns [`PylotError::Network`] when `base_url` is not HTTPS or a download
+/// fails, and the errors of [`install_from`] otherwise.
+pub fn install_release(
+    base_url: &str,
+    version: Option<&Version>,
+    install_dir: &Path,
+) -> Result<Vec<PathBuf>> {
+    let (_staging, archive) = download_release(base_url, version)?;
+    install_from(&archive, None, install_dir)
+}
+
+/// Download the release archive for this platform and its `.sha256` file from
+/// `base_url` into a fresh temporary directory.  Returns the directory, which
+/// is removed when dropped, and the archive path in it.
+///
+/// # Errors
+///
+/// Returns [`PylotError::Network`] when `base_url` is not HTTPS or a download
+/// fails, and [`PylotError::Other`] when uv has no build for this platform.
+pub fn download_release(base_url: &str, version: Option<&Version>) -> Result<(TempDir, PathBuf)> {
+    require_https(base_url)?;
+    let triple = target_triple().ok_or_else(|| {
+        PylotError::Other(format!(
+            "uv publishes no build for {}-{}",
+            std::env::consts::ARCH,
+            std::env::consts::OS
+        ))
+    })?;
+    let asset = archive_name(triple);
+    let url = mirror_archive_url(base_url, version, &asset);
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(Duration::from_secs(DOWNLOAD_TIMEOUT_SECS)))
+        .build()
+        .into();
+
+    let dir = staging_dir()?;
+    let archive = dir.path().join(&asset);
+    log::info!("Downloading {}", url);
+    fs::write(&archive, download(&agent, &url)?)?;
+    let checksum_url = format!("{}{}", url, CHECKSUM_SUFFIX);
+    fs::write(
+        dir.path().join(format!("{}{}", asset, CHECKSUM_SUFFIX)),
+        download(&agent, &checksum_url)?,
+    )?;
+    Ok((dir, archive))
+}
+
+/// The newest release on `base_url`, read from where `<base_url>/latest`
+/// redirects to, e.g. `…/releases/tag/0.5.11` on GitHub.
+///
+/// # Errors
+///
+/// Returns [`PylotError::Network`] when `base_url` is not HTTPS, cannot be
+/// reached or does not redirect to a release.
+pub fn latest_release(base_url: &str) -> Result<Version> {
+    require_https(base_url)?;
+    let url = format!("{}/latest", base_url.trim_end_matches('/'));
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(Duration::from_secs(30)))
+        .max_redirects(0)
+        .build()
+        .into();
+    let response = agent
+        .get(&url)
+        .call()
+        .map_err(|e| PylotError::Network(format!("Failed to look up {}: {}", url, e)))?;
+    response
+        .headers()
+        .get("location")
+        .and_then(|location| location.to_str().ok())
+        .and_then(|location| location.trim_end_matches('/').rsplit('/').next())
+        .and_then(|tag| Version::parse(tag.strip_prefix('v').unwrap_or(tag)).ok())
+        .ok_or_else(|| PylotError::Network(format!("{} does not redirect to a uv release", url)))
+}
+
+fn download(agent: &ureq::Agent, url: &str) -> Result<Vec<u8>> {
+    let mut response = agent
+        .get(url)
+        .call()
+        .map_err(|e| PylotError::Network(format!("Failed to download {}: {}", url, e)))?;
+    let mut body = Vec::new();
+    response
+        .body_mut()
+        .as_reader()
+        .read_to_end(&mut body)
+        .map_err(|e| PylotError::Network(format!("Failed to read {}: {}", url, e)))?;
+    Ok(body)
+}
+
+/// A new, empty directory under the system temp directory, with a random
+/// name and only accessible to the current user, so nothing can be planted in
+/// it before the binaries are installed.  Removed when dropped.
+fn staging_dir() -> Result<TempDir> {
+    Ok(tempfile::Builder::new().prefix("pylot-uv-").tempdir()?)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{logger, testsupport};
+    use std::io::Write;
+
+    pub(crate) const FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/uv-fixture.tar.gz"
+    );
+    pub(crate) const FIXTURE_SHA256: &str =
+        "ba87171087b2c68a46e7247a9808984dd5f4c85be7d241eea1a1518f1646e9ff";
+
+    /// Serve `routes` (path → bytes) as a release mirror and return its base
+    /// URL.  Unknown paths answer with 404.
+    pub(crate) fn spawn_stub_mirror(routes: Vec<(String, Vec<u8>)>) -> String {
+        format!("{}/releases", testsupport::spawn_stub_server(None, routes))
+    }
+
+    // ── checksums ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_checksum() {
+        let line = format!("{}  uv-fixture.tar.gz\n", FIXTURE_SHA256.to_uppercase());
+        assert_eq!(parse_checksum(&line).as_deref(), Some(FIXTURE_SHA256));
+        assert!(parse_checksum("abc123  file").is_none());
+        assert!(parse_checksum("").is_none());
+    }
+
+    #[test]
+    fn test_verify_checksum() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let fixture = Path::new(FIXTURE);
+        assert_eq!(sha256_file(fixture).unwrap(), FIXTURE_SHA256);
+        assert!(verify_checksum(fixture, FIXTURE_SHA256).is_ok());
+        let wrong = "0".repeat(64);
+        let err = verify_checksum(fixture, &wrong).unwrap_err().to_string();
+        assert!(err.contains("Checksum mismatch"));
+        assert!(verify_checksum(fixture, "not-a-hash").is_err());
+    }
+
+    #[test]
+    fn test_expected_checksum_reads_sidecar_or_requires_one() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let sidecar = expected_checksum(Path::new(FIXTURE), None).unwrap();
+        assert_eq!(parse_checksum(&sidecar).as_deref(), Some(FIXTURE_SHA256));
+        assert_eq!(
+            expected_checksum(Path::new(FIXTURE), Some("abc")).unwrap(),
+            "abc"
+        );
+
+        let tmp = tempfile::tempdir().unwrap();
+        let bare = tmp.path().join("uv.tar.gz");
+        fs::copy(FIXTURE, &bare).unwrap();
+        let err = expected_checksum(&bare, None).unwrap_err().to_string();
+        assert!(err.contains("pass --sha256"));
+    }
+
+    // ── install_from ─────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_install_from_archive() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dest = tempfile::tempdir().unwrap();
+        let installed = install_from(Path::new(FIXTURE), None, dest.path()).unwrap();
+        let names: Vec<_> = installed
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["uv", "uvx"]);
+        let script = fs::read_to_string(dest.path().join("uv")).unwrap();
+        assert!(script.contains("uv 0.5.11 (fixture)"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(dest.path().join("uv"))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o111, 0o111);
+        }
+    }
+
+    #[test]
+    fn test_install_from_archive_rejects_bad_checksum() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dest = tempfile::tempdir().unwrap();
+        let wrong = "f".repeat(64);
+        let err = install_from(Path::new(FIXTURE), Some(&wrong), dest.path()).unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"));
+        assert!(!dest.path().join("uv").exists());
+    }
+
+    #[test]
+    fn test_install_from_directory() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let unpacked = tempfile::tempdir().unwrap();
+        unpack(Path::new(FIXTURE), unpacked.path()).unwrap();
+        let dest = tempfile::tempdir().unwrap();
+        let err = install_from(unpacked.path(), None, dest.path()).unwrap_err();
+        assert!(err.to_string().contains("pass --sha256"));
+        assert!(!dest.path().join("uv").exists());
+
+        let uv = find_binaries(unpacked.path()).unwrap().remove(0);
+        let checksum = sha256_file(&uv).unwrap();
+        let installed = install_from(unpacked.path(), Some(&checksum), dest.path()).unwrap();
+        assert_eq!(installed.len(), 2);
+        let sidecar = format!("{}{}", uv.display(), CHECKSUM_SUFFIX);
+        fs::write(sidecar, format!("{}  uv\n", checksum)).unwrap();
+        assert!(install_from(unpacked.path(), None, dest.path()).is_ok());
+
+        let empty = tempfile::tempdir().unwrap();
+        let err = install_from(empty.path(), None, dest.path()).unwrap_err();
+        assert!(matches!(err, PylotError::PathError(_)));
+    }
+
+    #[test]
+    fn test_install_from_rejects_missing_and_unsupported_sources() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dest = tempfile::tempdir().unwrap();
+        let missing = dest.path().join("nope.tar.gz");
+        assert!(matches!(
+            install_from(&missing, None, dest.path()).unwrap_err(),
+            PylotError::PathError(_)
+        ));
+
+        let tmp = tempfile::tempdir().unwrap();
+        let seven_zip = tmp.path().join("uv.7z");
+        fs::write(&seven_zip, b"7z").unwrap();
+        let checksum = sha256_file(&seven_zip).unwrap();
+        let err = install_from(&seven_zip, Some(&checksum), dest.path()).unwrap_err();
+        assert!(err.to_string().contains("Unsupported archive"));
+
+        let zip = tmp.path().join("uv.zip");
+        fs::write(&zip, b"PK").unwrap();
+        let checksum = sha256_file(&zip).unwrap();
+        let err = install_from(&zip, Some(&checksum), dest.path()).unwrap_err();
+        assert!(err.to_string().contains("Failed to unpack"));
+    }
+
+    #[test]
+    fn test_install_from_zip_archive() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let archive = tmp.path().join("uv-x86_64-pc-windows-msvc.zip");
+        let mut zip = zip::ZipWriter::new(fs::File::create(&archive).unwrap());
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        for name in binary_names() {
+            zip.start_file(format!("uv-x86_64-pc-windows-msvc/{}", name), options)
+                .unwrap();
+            zip.write_all(b"binary").unwrap();
+        }
+        zip.finish().unwrap();
+        let checksum = sha256_file(&archive).unwrap();
+
+        let dest = tempfile::tempdir().unwrap();
+        let installed = install_from(&archive, Some(&checksum), dest.path()).unwrap();
+        assert_eq!(installed.len(), 2);
+        assert_eq!(fs::read(&installed[0]).unwrap(), b"binary");
+    }
+
+    // ── mirror ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_mirror_archive_url() {
+        let version = Version::new(0, 5, 11);
+        assert_eq!(
+            mirror_archive_url("https://mirror.local/uv/", Some(&version), "uv-x.tar.gz"),
+            "https://mirror.local/uv/download/0.5.11/uv-x.tar.gz"
+        );
+        assert_eq!(
+            mirror_archive_url("https://mirror.local/uv", None, "uv-x.tar.gz"),
+            "https://mirror.local/uv/latest/download/uv-x.tar.gz"
+        );
+        assert!(archive_name("x86_64-pc-windows-msvc").ends_with(".zip"));
+    }
+
+    #[test]
+    fn test_download_release_then_install() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let Some(triple) = target_triple() else {
+            return;
+        };
+        let asset = archive_name(triple);
+        let archive = fs::read(FIXTURE).unwrap();
+        let checksum = format!("{}  {}\n", FIXTURE_SHA256, asset);
+        let mirror = spawn_stub_mirror(vec![
+            (
+                format!("/releases/download/0.5.11/{}", asset),
+                archive.clone(),
+            ),
+            (
+                format!("/releases/download/0.5.11/{}.sha256", asset),
+                checksum.clone().into_bytes(),
+            ),
+            (format!("/releases/latest/download/{}", asset), archive),
+            (
+                format!("/releases/latest/download/{}.sha256", asset),
+                checksum
+                    .replace(FIXTURE_SHA256, &"0".repeat(64))
+                    .into_bytes(),
+            ),
+        ]);
+
+        let (staging, downloaded) =
+            download_release(&mirror, Some(&Version::new(0, 5, 11))).unwrap();
+        assert!(downloaded.ends_with(&asset));
+        assert!(downloaded.starts_with(staging.path()));
+        let staging_path = staging.path().to_path_buf();
+        drop(staging);
+        assert!(!staging_path.exists());
+        // The fixture is a .tar.gz, which cannot stand in for the .zip.
+        if asset.ends_with(".zip") {
+            return;
+        }
+
+        let dest = tempfile::tempdir().unwrap();
+        let installed = install_release(&mirror, Some(&Version::new(0, 5, 11)), dest.path());
+        assert_eq!(installed.unwrap().len(), 2);
+
+        // The latest release is served with a checksum that does not match.
+        let dest = tempfile::tempdir().unwrap();
+        let err = install_release(&mirror, None, dest.path()).unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"));
+        assert!(!dest.path().join("uv").exists());
+    }
+
+    #[test]
+    fn test_latest_release() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let mirror = spawn_stub_mirror(vec![(
+            "/releases/latest".to_string(),
+            testsupport::redirect("/releases/tag/v0.5.12"),
+        )]);
+        assert_eq!(latest_release(&mirror).unwrap(), Version::new(0, 5, 12));
+
+        let empty = spawn_stub_mirror(Vec::new());
+        assert!(matches!(
+            latest_release(&empty).unwrap_err(),
+            PylotError::Network(_)
+        ));
+    }
+
+    #[test]
+    fn test_download_release_errors() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let mirror = spawn_stub_mirror(Vec::new());
+        let err = download_release(&mirror, None).unwrap_err();
+        assert!(matches!(err, PylotError::Network(_)));
+
+        let err = download_release("http://mirror.example.com/uv", None).unwrap_err();
+        assert!(err.to_string().contains("only https://"));
+    }
+
+    #[test]
+    fn test_require_https() {
+        assert!(require_https("https://github.com/astral-sh/uv/releases").is_ok());
+        assert!(require_https("http://127.0.0.1:8080/releases").is_ok());
+        assert!(require_https("http://localhost/releases").is_ok());
+        assert!(require_https("http://localhost.evil.com/releases").is_err());
+        assert!(require_https("http://mirror.example.com").is_err());
+        assert!(require_https("ftp://mirror.example.com").is_err());
+    }
+
+    // ── install dir / PATH ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_resolve_install_dir_precedence() {
+        let some = |s: &str| Some(s.to_string());
+        assert_eq!(
+            resolve_install_dir(some("/opt/uv"), some("/srv/bin"), some("/xdg")),
+            PathBuf::from("/opt/uv")
+        );
+        assert_eq!(
+            resolve_install_dir(some(" "), some("/srv/bin"), some("/xdg")),
+            PathBuf::from("/srv/bin")
+        );
+        assert_eq!(
+            resolve_install_dir(None, None, some("/xdg")),
+            PathBuf::from("/xdg")
+        );
+        assert!(resolve_install_dir(None, None, None).ends_with(".local/bin"));
+    }
+
+    #[test]
+    fn test_path_hints() {
+        let dir = Path::new("/home/dev/.local/bin");
+        let path = std::env::join_paths([Path::new("/usr/bin"), dir]).unwrap();
+        assert!(is_on_path(dir, Some(&path)));
+        assert!(!is_on_path(dir, Some(std::ffi::OsStr::new("/usr/bin"))));
+        assert!(!is_on_path(dir, None));
+
+        assert_eq!(
+            path_hint(dir, &Shell::Bash),
+            "export PATH=\"/home/dev/.local/bin:$PATH\"  # in ~/.bashrc"
+        );
+        assert!(path_hint(dir, &Shell::Fish).starts_with("fish_add_path"));
+        assert!(path_hint(dir, &Shell::Pwsh).contains("$env:Path"));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    context::Pylot,
+    diskusage,
+    error::{PylotError, Result},
+    infra::processes::{self, CommandSpec},
+    utils::format_size,
+};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// Location and size of the uv cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheInfo {
+    /// The cache directory reported by `uv cache dir`.
+    pub path: PathBuf,
+    /// Bytes used by the cache; `0` when the directory does not exist yet.
+    pub size: u64,
+}
+
+impl fmt::Display for CacheInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.path.display(), format_size(self.size))
+    }
+}
+
+/// The uv cache directory, as reported by `uv cache dir` (which honours
+/// `UV_CACHE_DIR` and `--cache-dir` configuration).
+///
+/// # Errors
+///
+/// Returns [`PylotError::CommandExecution`] if uv cannot be run or exits with
+/// an error.
+pub async fn cache_dir(ctx: &Pylot) -> Result<PathBuf> {
+    let output = ctx
+        .runner
+        .output(&CommandSpec::uv(&ctx.settings, &["cache", "dir"]))
+        .await
+        .map_err(|e| PylotError::CommandExecution(format!("Failed to run uv: {}", e)))?;
+    let stdout = output.stdout.trim();
+    if !output.success() || stdout.is_empty() {
+        return Err(PylotError::CommandExecution(format!(
+            "'uv cache dir' failed: {}",
+            output.stderr.trim()
+        )));
+    }
+    Ok(PathBuf::from(stdout))
+}
+
+/// Location and current size of the uv cache.
+pub async fn info(ctx: &Pylot) -> Result<CacheInfo> {
+    let path = cache_dir(ctx).await?;
+    let size = measure(&path).await;
+    Ok(CacheInfo { path, size })
+}
+
+/// Remove the whole uv cache, or only the entries for `packages`, via
+/// `uv cache clean`.  Returns the number of bytes freed.
+pub async fn clean(ctx: &Pylot, packages: &[String]) -> Result<u64> {
+    let mut args = vec!["cache", "clean"];
+    args.extend(packages.iter().map(String::as_str));
+    shrink(ctx, &args).await
+}
+
+/// Remove unused entries from the uv cache via `uv cache prune`.  Returns the
+/// number of bytes freed.
+pub async fn prune(ctx: &Pylot) -> Result<u64> {
+    shrink(ctx, &["cache", "prune"]).await
+}
+
+/// Run a uv cache command and report how much smaller the cache got.
+async fn shrink(ctx: &Pylot, args: &[&str]) -> Result<u64> {
+    let path = cache_dir(ctx).await?;
+    let before = measure(&path).await;
+    processes::run_logged(ctx.runner.as_ref(), &CommandSpec::uv(&ctx.settings, args)).await?;
+    Ok(freed(before, measure(&path).await))
+}
+
+async fn measure(path: &Path) -> u64 {
+    diskusage::measure(path).await.total()
+}
+
+/// Bytes freed going from `before` to `after`; the cache can also grow when
+/// another uv process writes to it meanwhile.
+fn freed(before: u64, after: u64) -> u64 {
+    before.saturating_sub(after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger;
+
+    #[test]
+    fn test_cache_info_display() {
+        let info = CacheInfo {
+            path: PathBuf::from("/home/dev/.cache/uv"),
+            size: 3 * 1024 * 1024,
+        };
+        assert_eq!(info.to_string(), "/home/dev/.cache/uv (3.0 MiB)");
+    }
+
+    #[test]
+    fn test_freed() {
+        assert_eq!(freed(1000, 400), 600);
+        assert_eq!(freed(400, 1000), 0);
+    }
+
+    #[tokio::test]
+    async fn test_info_and_prune_through_context_runner() {
+        use crate::processes::{CommandOutput, RecordingRunner};
+        use std::sync::Arc;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let entry = tmp.path().join("stale.whl");
+        std::fs::write(&entry, vec![0u8; 100]).unwrap();
+        let dir = tmp.path().display().to_string();
+        let runner = Arc::new(
+            RecordingRunner::new()
+                .respond("cache dir", CommandOutput::ok(&format!("{}\n", dir)))
+                .respond_with("cache prune", move |_| {
+                    std::fs::remove_file(&entry).unwrap();
+                    CommandOutput::ok("Removed 1 file")
+                }),
+        );
+        let ctx = Pylot::default().with_runner(runner.clone());
+
+        let info = info(&ctx).await.unwrap();
+        assert_eq!(info.path, tmp.path());
+        assert_eq!(info.size, 100);
+        assert_eq!(prune(&ctx).await.unwrap(), 100);
+        assert_eq!(
+            runner.command_lines(),
+            ["uv cache dir", "uv cache dir", "uv cache prune"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_measure_cache_dir() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join("wheels-v5/pypi")).unwrap();
+        std::fs::write(tmp.path().join("wheels-v5/pypi/a.whl"), vec![0u8; 100]).unwrap();
+        std::fs::write(tmp.path().join("CACHEDIR.TAG"), vec![0u8; 43]).unwrap();
+        assert_eq!(measure(tmp.path()).await, 143);
+        assert_eq!(measure(&tmp.path().join("missing")).await, 0);
+    }
+}
@@ -1,119 +1,624 @@
 use crate::{
     constants::{UPDATE_ARGS, UV_COMMAND, UV_WINGET_UPGRADE_ARGS},
-    infra::processes,
+    context::Pylot,
+    infra::processes::{self, CommandRunner, CommandSpec},
+    settings::{Settings, UvVersionPolicy},
+    uv::uvarchive,
     utility::constants::{
-        SH_CMD, UV_UNIX_INSTALL_ARGS, UV_UNIX_UNINSTALL_ARGS, UV_WINGET_INSTALL_ARGS,
-        UV_WINGET_UNINSTALL_ARGS, WINGET_CMD,
+        UV_WINGET_INSTALL_ARGS, UV_WINGET_UNINSTALL_ARGS, WINGET_CMD,
     },
-    utils::{self, confirm},
+    utils::confirm,
+    virtualenv::doctor::PyvenvCfg,
+};
+use comfy_table::{
+    modifiers::UTF8_SOLID_INNER_BORDERS, presets::UTF8_FULL, ContentArrangement, Table,
+};
+use semver::{Version, VersionReq};
+use std::{
+    fmt,
+    io::Write,
+    path::{Path, PathBuf},
 };
 
-/// Install Astral UV.
+/// Install Astral UV, the latest release or `version` (e.g. `"0.5.11"`).
 ///
-/// On **Windows** this delegates to `winget install astral-sh.uv`.
-/// On **Unix** it downloads and runs the official installation shell script via
-/// `curl … | sh`.
+/// The release archive for this platform is downloaded over HTTPS from the
+/// `uv_mirror_url` setting, or the official GitHub releases, verified against
+/// its published `.sha256` file and unpacked into the install directory (see
+/// [`uvarchive::install_dir`]).  No shell script is piped into `sh`.
+/// On **Windows**, without a mirror configured, this delegates to
+/// `winget install astral-sh.uv`, adding `--version` when one is given.
 ///
-/// The user is shown the exact command that will run and prompted to confirm
-/// before anything is executed.  Passing `"n\n"` (or any non-`y` response) as
+/// The user is shown what will happen and prompted to confirm before
+/// anything is executed.  Passing `"n\n"` (or any non-`y` response) as
 /// `input` cancels the operation without error.
 ///
 /// # Errors
 ///
-/// Returns `Err(String)` if a required prerequisite (`winget`, `curl`, …) is
-/// missing or if the underlying command fails.
-pub async fn install<R: std::io::Read>(input: R) -> Result<(), String> {
-    log::info!("Installing Astral UV...");
+/// Returns `Err(String)` if `version` is not a valid release number, if the
+/// download or checksum verification fails, or if `winget` is missing or
+/// fails on Windows.
+pub async fn install<R: std::io::Read>(
+    ctx: &Pylot,
+    input: R,
+    version: Option<&str>,
+) -> Result<(), String> {
+    let version = version.map(parse_release).transpose()?;
+    match &version {
+        Some(version) => log::info!("Installing Astral UV {}...", version),
+        None => log::info!("Installing Astral UV..."),
+    }
+    if cfg!(target_os = "windows") && ctx.settings.uv_mirror_url.is_none() {
+        return install_with_winget(ctx.runner.as_ref(), input, version.as_ref()).await;
+    }
+    let dir = uvarchive::install_dir(&ctx.settings);
+    install_release(input, uvarchive::releases_url(&ctx.settings), dir, version).await
+}
 
-    let (cmd, args): (&str, &[&str]) = if cfg!(target_os = "windows") {
-        utils::which_check(&[WINGET_CMD])
-            .map_err(|e| format!("Winget is required for installation(https://learn.microsoft.com/en-us/windows/package-manager/winget/): {}", e))?;
-        (WINGET_CMD, UV_WINGET_INSTALL_ARGS)
-    } else {
-        utils::which_check(&[SH_CMD, "curl", "sh"]).map_err(|e| format!("{}", e))?;
-        (SH_CMD, UV_UNIX_INSTALL_ARGS)
-    };
+async fn install_with_winget<R: std::io::Read>(
+    runner: &dyn CommandRunner,
+    input: R,
+    version: Option<&Version>,
+) -> Result<(), String> {
+    require_program(runner, WINGET_CMD)
+        .map_err(|e| format!("Winget is required for installation(https://learn.microsoft.com/en-us/windows/package-manager/winget/): {}", e))?;
+    let args = winget_args(UV_WINGET_INSTALL_ARGS, version);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
 
-    if let Some(value) = confirm_cmd(input, cmd, args) {
+    if let Some(value) = confirm_cmd(input, WINGET_CMD, &args) {
         return value;
     }
 
-    let mut child = processes::create_child_cmd(cmd, args, "")
-        .map_err(|e| format!("Failed to create command: {}", e))?;
-    processes::run_command(&mut child)
+    processes::run_logged(runner, &CommandSpec::new(WINGET_CMD, &args))
         .await
         .map_err(|e| format!("Installation failed: {}", e))?;
     log::info!("Astral UV has been installed.");
     Ok(())
 }
 
-/// Update Astral UV to the latest version.
+/// Install Astral UV from a pre-downloaded release: a `.tar.gz` or `.zip`
+/// archive or a directory holding the unpacked release, for machines without
+/// access to the official installer.
+///
+/// Archives are verified against `sha256`, or the `<archive>.sha256` file next
+/// to them, before anything is installed; for a directory the same applies to
+/// its `uv` binary.  The binaries are copied into
+/// `UV_INSTALL_DIR`, `XDG_BIN_HOME` or `~/.local/bin`, after the user confirms.
+///
+/// # Errors
+///
+/// Returns `Err(String)` if the checksum is missing or does not match, the
+/// archive cannot be unpacked or contains no `uv` binary.
+pub async fn install_from_archive<R: std::io::Read>(
+    ctx: &Pylot,
+    input: R,
+    source: &Path,
+    sha256: Option<&str>,
+) -> Result<(), String> {
+    let source = PathBuf::from(shellexpand::tilde(&source.to_string_lossy()).to_string());
+    let dir = uvarchive::install_dir(&ctx.settings);
+    log::info!("This will install uv from {} into {}\n", source.display(), dir.display());
+    if !confirm(input) {
+        log::warn!("Exiting...");
+        return Ok(());
+    }
+    let sha256 = sha256.map(str::to_string);
+    let installed = tokio::task::spawn_blocking(move || {
+        uvarchive::install_from(&source, sha256.as_deref(), &dir)
+    })
+    .await
+    .map_err(|e| format!("Installation failed: {}", e))?
+    .map_err(|e| format!("Installation failed: {}", e))?;
+    log_installed(&installed);
+    Ok(())
+}
+
+/// Download the release for this platform from `base_url` and install it
+/// into `dir`.
+async fn install_release<R: std::io::Read>(
+    input: R,
+    base_url: String,
+    dir: PathBuf,
+    version: Option<Version>,
+) -> Result<(), String> {
+    uvarchive::require_https(&base_url).map_err(|e| e.to_string())?;
+    log::info!(
+        "This will download uv from {} and install it into {}\n",
+        base_url,
+        dir.display()
+    );
+    if !confirm(input) {
+        log::warn!("Exiting...");
+        return Ok(());
+    }
+    let installed = tokio::task::spawn_blocking(move || {
+        uvarchive::install_release(&base_url, version.as_ref(), &dir)
+    })
+    .await
+    .map_err(|e| format!("Installation failed: {}", e))?
+    .map_err(|e| format!("Installation failed: {}", e))?;
+    log_installed(&installed);
+    Ok(())
+}
+
+fn log_installed(installed: &[PathBuf]) {
+    for path in installed {
+        log::info!("Installed {}", path.display());
+    }
+    log::info!("Astral UV has been installed.");
+    let Some(dir) = installed.first().and_then(|p| p.parent()) else {
+        return;
+    };
+    if !uvarchive::is_on_path(dir, std::env::var_os("PATH").as_deref()) {
+        let shell = processes::get_parent_shell()
+            .unwrap_or_else(|_| processes::Shell::Unknown(String::new()));
+        log::warn!(
+            "{} is not on PATH. Add it so uv can be found:\n\n\t{}\n",
+            dir.display(),
+            uvarchive::path_hint(dir, &shell)
+        );
+    }
+}
+
+/// Update Astral UV to the latest version, or move to exactly `to` (e.g.
+/// `"0.5.11"`), which may also be a downgrade.
 ///
-/// On **Windows** this runs `winget upgrade astral-sh.uv`.
-/// On **Unix** it runs `uv self update`.
+/// A standalone install, such as one made by [`install`], is replaced with
+/// the release downloaded from the `uv_mirror_url` setting or the official
+/// GitHub releases, in the directory it was found in.  Otherwise, on
+/// **Windows** this runs `winget upgrade astral-sh.uv`, or
+/// `winget install astral-sh.uv --version <to> --force` for a specific release,
+/// and on **Unix** `uv self update [<to>]`.
 ///
 /// # Errors
 ///
-/// Returns `Err(String)` if `winget` / `uv` is not found on `PATH` or if the
-/// underlying update command fails.
-pub async fn update() -> Result<(), String> {
-    log::info!("Updating Astral UV...");
-    if cfg!(target_os = "windows") {
-        utils::which_check(&[WINGET_CMD])
+/// Returns `Err(String)` if `to` is not a valid release number, if `winget` /
+/// `uv` is not found on `PATH` or if the download or update command fails.
+pub async fn update(ctx: &Pylot, to: Option<&str>) -> Result<(), String> {
+    let runner = ctx.runner.as_ref();
+    let to = to.map(parse_release).transpose()?;
+    match &to {
+        Some(version) => log::info!("Switching Astral UV to {}...", version),
+        None => log::info!("Updating Astral UV..."),
+    }
+    if let Some(uv_path) = runner.which(&ctx.settings.uv_program()) {
+        if install_method_of(&ctx.settings, &uv_path) == InstallMethod::Standalone {
+            return update_standalone(ctx, &uv_path, to).await;
+        }
+    }
+    let (cmd, args) = if cfg!(target_os = "windows") {
+        require_program(runner, WINGET_CMD)
             .map_err(|e| format!("Winget is required for update: {}", e))?;
-        let mut child = processes::create_child_cmd(WINGET_CMD, UV_WINGET_UPGRADE_ARGS, "")
-            .map_err(|e| format!("Failed to create command: {}", e))?;
-        processes::run_command(&mut child)
-            .await
-            .map_err(|e| format!("Update failed: {}", e))?;
+        let args = match &to {
+            Some(_) => {
+                let mut args = winget_args(UV_WINGET_INSTALL_ARGS, to.as_ref());
+                args.push("--force".to_string());
+                args
+            }
+            None => winget_args(UV_WINGET_UPGRADE_ARGS, None),
+        };
+        (WINGET_CMD.to_string(), args)
     } else {
-        utils::which_check(&[UV_COMMAND])
+        let uv = ctx.settings.uv_program();
+        require_program(runner, &uv)
             .map_err(|e| format!("UV command is required for update: {}", e))?;
-        let mut child = processes::create_child_cmd(UV_COMMAND, UPDATE_ARGS, "")
-            .map_err(|e| format!("Failed to create command: {}", e))?;
-        processes::run_command(&mut child)
-            .await
-            .map_err(|e| format!("Update failed: {}", e))?;
+        (uv, self_update_args(to.as_ref()))
+    };
+    processes::run_logged(runner, &CommandSpec::new(&cmd, &args))
+        .await
+        .map_err(|e| format!("Update failed: {}", e))?;
+    Ok(())
+}
+
+/// Replace the standalone uv at `uv_path` with release `to`, or the latest
+/// one, unless that release is already installed.
+async fn update_standalone(ctx: &Pylot, uv_path: &Path, to: Option<Version>) -> Result<(), String> {
+    let base_url = uvarchive::releases_url(&ctx.settings);
+    let target = match to {
+        Some(version) => Some(version),
+        // Without a `latest` redirect on the mirror, download
+        // `latest/download/` without knowing the version.
+        None => latest_release(base_url.clone()).await,
+    };
+    if let (Some(target), Ok(installed)) = (&target, installed_version(ctx).await) {
+        if *target == installed {
+            log::info!("Astral UV {} is already installed.", installed);
+            return Ok(());
+        }
+    }
+    let dir = uv_path.parent().unwrap_or(Path::new("")).to_path_buf();
+    let installed = tokio::task::spawn_blocking(move || {
+        uvarchive::install_release(&base_url, target.as_ref(), &dir)
+    })
+    .await
+    .map_err(|e| format!("Update failed: {}", e))?
+    .map_err(|e| format!("Update failed: {}", e))?;
+    for path in &installed {
+        log::info!("Installed {}", path.display());
     }
     Ok(())
 }
 
-/// Uninstall Astral UV.
+/// The newest release on `base_url`, see [`uvarchive::latest_release`].
+async fn latest_release(base_url: String) -> Option<Version> {
+    tokio::task::spawn_blocking(move || uvarchive::latest_release(&base_url))
+        .await
+        .ok()?
+        .map_err(|e| log::debug!("{}", e))
+        .ok()
+}
+
+/// Parse a uv release number given on the command line; a leading `v` is
+/// accepted.
+///
+/// Only plain versions are accepted, so the value is safe to place in the
+/// install command.
+pub fn parse_release(version: &str) -> Result<Version, String> {
+    let trimmed = version.trim();
+    Version::parse(trimmed.strip_prefix('v').unwrap_or(trimmed))
+        .map_err(|e| format!("Invalid uv version '{}': {}", version, e))
+}
+
+/// `winget` arguments, pinned to `version` when one is given.
+fn winget_args(base: &[&str], version: Option<&Version>) -> Vec<String> {
+    let mut args: Vec<String> = base.iter().map(|a| a.to_string()).collect();
+    if let Some(version) = version {
+        args.push("--version".to_string());
+        args.push(version.to_string());
+    }
+    args
+}
+
+/// `uv self update` arguments, targeting `version` when one is given.
+fn self_update_args(version: Option<&Version>) -> Vec<String> {
+    let mut args: Vec<String> = UPDATE_ARGS.iter().map(|a| a.to_string()).collect();
+    args.extend(version.map(Version::to_string));
+    args
+}
+
+/// How the `uv` binary on `PATH` was installed, which decides how it is
+/// removed again.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InstallMethod {
+    /// The standalone installer (or `pylot uv install`), which places the
+    /// binaries in a single directory such as `~/.local/bin` or
+    /// `UV_INSTALL_DIR`.
+    Standalone,
+    /// `cargo install uv`
+    Cargo,
+    /// `pipx install uv`
+    Pipx,
+    /// `brew install uv`
+    Homebrew,
+    /// `winget install astral-sh.uv`
+    Winget,
+    /// Anything else, e.g. a distro package, nix or `pip install uv`.  Pylot
+    /// does not remove these itself.
+    Unknown,
+}
+
+impl fmt::Display for InstallMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            InstallMethod::Standalone => "standalone installer",
+            InstallMethod::Cargo => "cargo",
+            InstallMethod::Pipx => "pipx",
+            InstallMethod::Homebrew => "Homebrew",
+            InstallMethod::Winget => "winget",
+            InstallMethod::Unknown => "an unknown method",
+        };
+        f.write_str(name)
+    }
+}
+
+impl InstallMethod {
+    /// The package manager command that removes uv, or `None` when the
+    /// binaries are deleted directly.
+    pub fn uninstall_command(&self) -> Option<(&'static str, &'static [&'static str])> {
+        match self {
+            InstallMethod::Standalone | InstallMethod::Unknown => None,
+            InstallMethod::Cargo => Some(("cargo", &["uninstall", "uv"])),
+            InstallMethod::Pipx => Some(("pipx", &["uninstall", "uv"])),
+            InstallMethod::Homebrew => Some(("brew", &["uninstall", "uv"])),
+            InstallMethod::Winget => Some((WINGET_CMD, UV_WINGET_UNINSTALL_ARGS)),
+        }
+    }
+}
+
+/// Work out how uv was installed from `uv_path`, the binary found on `PATH`,
+/// and `resolved`, the same path with symlinks resolved.  `cargo_bin` is
+/// `$CARGO_HOME/bin` and `standalone_dirs` the directories the standalone
+/// installer puts uv in, see [`standalone_dirs`].
+pub fn detect_install_method(
+    uv_path: &Path,
+    resolved: &Path,
+    cargo_bin: &Path,
+    standalone_dirs: &[PathBuf],
+) -> InstallMethod {
+    let components: Vec<String> = resolved
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_lowercase())
+        .collect();
+    let has_run = |run: &[&str]| {
+        components
+            .windows(run.len())
+            .any(|w| w.iter().zip(run).all(|(a, b)| a == b))
+    };
+    if has_run(&["cellar", "uv"]) {
+        InstallMethod::Homebrew
+    } else if has_run(&["pipx", "venvs", "uv"]) {
+        InstallMethod::Pipx
+    } else if has_run(&["winget"]) {
+        InstallMethod::Winget
+    } else if uv_path.parent() == Some(cargo_bin) {
+        InstallMethod::Cargo
+    } else if uv_path
+        .parent()
+        .is_some_and(|dir| standalone_dirs.iter().any(|known| known == dir))
+    {
+        InstallMethod::Standalone
+    } else {
+        InstallMethod::Unknown
+    }
+}
+
+/// Detect how the `uv` at `uv_path` was installed, resolving symlinks and
+/// `CARGO_HOME` for [`detect_install_method`].
+pub fn install_method_of(settings: &Settings, uv_path: &Path) -> InstallMethod {
+    let resolved = std::fs::canonicalize(uv_path).unwrap_or_else(|_| uv_path.to_path_buf());
+    let cargo_home = std::env::var("CARGO_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(shellexpand::tilde("~/.cargo").to_string()));
+    detect_install_method(
+        uv_path,
+        &resolved,
+        &cargo_home.join("bin"),
+        &standalone_dirs(settings),
+    )
+}
+
+/// Where the standalone installer and `pylot uv install` put uv: the
+/// configured [`uvarchive::install_dir`], `~/.local/bin`, and the directory
+/// recorded in the installer's receipt.
+pub fn standalone_dirs(settings: &Settings) -> Vec<PathBuf> {
+    let mut dirs = vec![
+        uvarchive::install_dir(settings),
+        PathBuf::from(shellexpand::tilde("~/.local/bin").to_string()),
+    ];
+    if let Some(prefix) = std::fs::read_to_string(receipt_path())
+        .ok()
+        .as_deref()
+        .and_then(receipt_install_prefix)
+    {
+        dirs.push(prefix.join("bin"));
+        dirs.push(prefix);
+    }
+    dirs
+}
+
+/// The install receipt the standalone installer writes.
+fn receipt_path() -> PathBuf {
+    PathBuf::from(shellexpand::tilde("~/.config/uv/uv-receipt.json").to_string())
+}
+
+/// The `install_prefix` recorded in an install receipt.
+pub fn receipt_install_prefix(receipt: &str) -> Option<PathBuf> {
+    let receipt: serde_json::Value = serde_json::from_str(receipt).ok()?;
+    let prefix = receipt.get("install_prefix")?.as_str()?;
+    (!prefix.is_empty()).then(|| PathBuf::from(prefix))
+}
+
+/// Everything [`uninstall`] will do, worked out before asking for
+/// confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UninstallPlan {
+    /// The `uv` binary found on `PATH`.
+    pub uv_path: PathBuf,
+    pub method: InstallMethod,
+    /// Files deleted directly: the binaries and install receipt of a
+    /// standalone install.
+    pub files: Vec<PathBuf>,
+    /// Directories removed with `--purge`, labelled e.g. `"cache"`.
+    pub purge: Vec<(&'static str, PathBuf)>,
+    /// Pylot venvs created from a Python in the purged Python directory,
+    /// which stop working once it is gone.
+    pub broken_venvs: Vec<String>,
+}
+
+impl UninstallPlan {
+    /// One line per action, for listing before the user confirms.
+    pub fn describe(&self) -> Vec<String> {
+        let mut lines = vec![format!(
+            "uv at {} (installed with {})",
+            self.uv_path.display(),
+            self.method
+        )];
+        if let Some((cmd, args)) = self.method.uninstall_command() {
+            lines.push(format!("run: {} {}", cmd, args.join(" ")));
+        }
+        lines.extend(
+            self.files
+                .iter()
+                .map(|file| format!("remove: {}", file.display())),
+        );
+        lines.extend(
+            self.purge
+                .iter()
+                .map(|(label, dir)| format!("remove {}: {}", label, dir.display())),
+        );
+        if !self.broken_venvs.is_empty() {
+            lines.push(format!(
+                "break venvs using these Pythons: {}",
+                self.broken_venvs.join(", ")
+            ));
+        }
+        lines
+    }
+}
+
+/// Work out how to uninstall the `uv` on `PATH`, and with `purge` which
+/// cache, tool and Python directories to remove as well.
 ///
-/// On **Windows** this delegates to `winget uninstall astral-sh.uv`.
-/// On **Unix** it removes the `uv` and `uvx` binaries from
-/// `~/.local/bin/`.
+/// Returns `Ok(None)` when uv is not installed.
+pub async fn uninstall_plan(ctx: &Pylot, purge: bool) -> Result<Option<UninstallPlan>, String> {
+    let Some(uv_path) = ctx.runner.which(&ctx.settings.uv_program()) else {
+        return Ok(None);
+    };
+    let method = install_method_of(&ctx.settings, &uv_path);
+
+    let mut files = Vec::new();
+    if method == InstallMethod::Standalone {
+        let dir = uv_path.parent().unwrap_or(Path::new(""));
+        files.extend(
+            uvarchive::binary_names()
+                .iter()
+                .map(|name| dir.join(name))
+                .filter(|path| path.is_file()),
+        );
+        let receipt = receipt_path();
+        if receipt.is_file() {
+            files.push(receipt);
+        }
+    }
+
+    let mut dirs = Vec::new();
+    if purge {
+        for (label, args) in [
+            ("cache", ["cache", "dir"]),
+            ("tools", ["tool", "dir"]),
+            ("pythons", ["python", "dir"]),
+        ] {
+            if let Some(dir) = uv_dir(ctx, &args).await.filter(|dir| dir.exists()) {
+                dirs.push((label, dir));
+            }
+        }
+    }
+    let broken_venvs = match dirs.iter().find(|(label, _)| *label == "pythons") {
+        Some((_, pythons)) => venvs_using(ctx, pythons).await,
+        None => Vec::new(),
+    };
+    Ok(Some(UninstallPlan {
+        uv_path,
+        method,
+        files,
+        purge: dirs,
+        broken_venvs,
+    }))
+}
+
+/// Names of the venvs whose base interpreter lives under `pythons`.
+async fn venvs_using(ctx: &Pylot, pythons: &Path) -> Vec<String> {
+    let mut names = Vec::new();
+    for name in ctx.manager().list_names_including_broken().await {
+        let cfg = PyvenvCfg::read(&ctx.venvs_path().join(&name)).await;
+        if cfg
+            .and_then(|cfg| cfg.home)
+            .is_some_and(|home| Path::new(&home).starts_with(pythons))
+        {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Run `uv <args>` and return the directory it prints.
+async fn uv_dir(ctx: &Pylot, args: &[&str]) -> Option<PathBuf> {
+    let output = ctx
+        .runner
+        .output(&CommandSpec::uv(&ctx.settings, args))
+        .await
+        .ok()?;
+    let stdout = output.stdout.trim();
+    (output.success() && !stdout.is_empty()).then(|| PathBuf::from(stdout))
+}
+
+/// Uninstall Astral UV the way it was installed.
 ///
-/// The user is shown the command that will run and prompted to confirm before
-/// anything is executed.
+/// The `uv` on `PATH` is located with `which` and its install method
+/// detected: cargo, pipx, Homebrew and winget installs are removed through
+/// their package manager, standalone installs by deleting the binaries from
+/// their directory (e.g. `~/.local/bin` or `UV_INSTALL_DIR`).  With `purge`
+/// the uv cache, tool directory and managed Python installs are removed too.
+///
+/// Everything that will be removed is listed and the user is prompted to
+/// confirm before anything is executed.
 ///
 /// # Errors
 ///
-/// Returns `Err(String)` if `winget` is not available (Windows) or if the
-/// underlying command fails.
-pub async fn uninstall<R: std::io::Read>(input: R) -> Result<(), String> {
-    log::info!("Uninstalling Astral UV...");
-    let (cmd, args): (&str, &[&str]) = if cfg!(target_os = "windows") {
-        utils::which_check(&[WINGET_CMD])
-            .map_err(|e| format!("Winget is required for installation(https://learn.microsoft.com/en-us/windows/package-manager/winget/): {}", e))?;
-        (WINGET_CMD, UV_WINGET_UNINSTALL_ARGS)
-    } else {
-        (SH_CMD, UV_UNIX_UNINSTALL_ARGS)
+/// Returns `Err(String)` if the package manager is not available or if
+/// removing anything fails.
+pub async fn uninstall<R: std::io::Read>(ctx: &Pylot, input: R, purge: bool) -> Result<(), String> {
+    let runner = ctx.runner.as_ref();
+    let Some(plan) = uninstall_plan(ctx, purge).await? else {
+        log::info!("Astral UV is not installed.");
+        return Ok(());
     };
+    if plan.method == InstallMethod::Unknown {
+        return Err(format!(
+            "Pylot cannot tell how uv at {} was installed, so it will not remove it. \
+            Uninstall it with the tool that installed it, e.g. your system package manager, nix or pip",
+            plan.uv_path.display()
+        ));
+    }
+    if let Some((cmd, _)) = plan.method.uninstall_command() {
+        require_program(runner, cmd)
+            .map_err(|e| format!("{} is required to uninstall uv: {}", cmd, e))?;
+    }
 
-    if let Some(value) = confirm_cmd(input, cmd, args) {
-        return value;
+    log::info!("Uninstalling Astral UV. This will:\n");
+    for line in plan.describe() {
+        log::info!("\t{}", line);
+    }
+    if !plan.broken_venvs.is_empty() {
+        log::warn!(
+            "\n{} use a Python that --purge removes and will stop working.",
+            plan.broken_venvs.join(", ")
+        );
+    }
+    if !confirm(input) {
+        log::warn!("Exiting...");
+        return Ok(());
     }
 
-    let mut child = processes::create_child_cmd(cmd, args, "")
-        .map_err(|e| format!("Failed to create command: {}", e))?;
-    processes::run_command(&mut child)
-        .await
-        .map_err(|e| format!("Uninstallation failed: {}", e))?;
+    // Purge first, while uv is still around to clean its own cache.
+    for (label, dir) in &plan.purge {
+        if *label == "cache" && clean_cache(ctx).await.is_ok() {
+            let _ = std::fs::remove_dir_all(dir);
+            continue;
+        }
+        std::fs::remove_dir_all(dir)
+            .map_err(|e| format!("Failed to remove {}: {}", dir.display(), e))?;
+    }
+    if let Some((cmd, args)) = plan.method.uninstall_command() {
+        processes::run_logged(runner, &CommandSpec::new(cmd, args))
+            .await
+            .map_err(|e| format!("Uninstallation failed: {}", e))?;
+    }
+    for file in &plan.files {
+        std::fs::remove_file(file)
+            .map_err(|e| format!("Failed to remove {}: {}", file.display(), e))?;
+    }
     log::info!("Astral UV has been uninstalled.");
     Ok(())
 }
 
+/// `uv cache clean`, which also copes with the read-only files uv keeps in
+/// its cache.
+async fn clean_cache(ctx: &Pylot) -> Result<(), String> {
+    let cmd = CommandSpec::uv(&ctx.settings, &["cache", "clean"]);
+    processes::run_logged(ctx.runner.as_ref(), &cmd)
+        .await
+        .map_err(|e| format!("Failed to clean the uv cache: {}", e))
+}
+
+/// Like [`which_check`](crate::utils::which_check), for a single program looked up by `runner`.
+fn require_program(runner: &dyn CommandRunner, program: &str) -> Result<PathBuf, String> {
+    runner
+        .which(program)
+        .ok_or_else(|| format!("Missing required commands: [{:?}]", program))
+}
+
 fn confirm_cmd<R: std::io::Read>(input: R, cmd: &str, args: &[&str]) -> Option<Result<(), String>> {
     log::info!("This will run the following command:\n");
     log::info!("\t{} {}\n", cmd, args.join(" "));
@@ -126,29 +631,215 @@ fn confirm_cmd<R: std::io::Read>(input: R, cmd: &str, args: &[&str]) -> Option<R
 
 /// Check whether the binary named `name` is present on `PATH`.
 ///
+/// For `uv` the [`uv_path`](crate::settings::Settings::uv_path) setting is honoured and the
+/// installed version is also compared against the `required_uv_version`
+/// setting.  A mismatch is logged as a warning, or returned as an error when
+/// `uv_version_policy = "fail"`.
+///
 /// Returns `Ok(message)` when the binary is found, or `Err(message)` when it
 /// is not.
-pub async fn check(name: &str) -> Result<String, Box<dyn std::error::Error>> {
-    match which::which(name)
-        .map(|_| ())
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
-    {
-        Ok(_) => Ok(format!("{} is installed.", name)),
-        Err(e) => Err(format!("{} not found: {}", name, e).into()),
+pub async fn check(ctx: &Pylot, name: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let program = if name == UV_COMMAND {
+        ctx.settings.uv_program()
+    } else {
+        name.to_string()
+    };
+    if ctx.runner.which(&program).is_none() {
+        return Err(format!("{} not found: {} is not on PATH", name, program).into());
+    }
+    if name == UV_COMMAND {
+        let settings = &ctx.settings;
+        if let Some(required) = settings.required_uv_version.as_deref() {
+            let result = match installed_version(ctx).await {
+                Ok(installed) => meets_requirement(&installed, required),
+                Err(e) => Err(e),
+            };
+            if let Err(message) = result {
+                match settings.uv_version_policy {
+                    UvVersionPolicy::Fail => return Err(message.into()),
+                    UvVersionPolicy::Warn => log::warn!("{}", message),
+                }
+            }
+        }
+    }
+    Ok(format!("{} is installed.", name))
+}
+
+/// Return the version of the `uv` on `PATH`, from `uv --version`.
+///
+/// # Errors
+///
+/// Returns `Err(String)` if uv cannot be run or its output is not understood.
+pub async fn installed_version(ctx: &Pylot) -> Result<Version, String> {
+    let output = ctx
+        .runner
+        .output(&CommandSpec::uv(&ctx.settings, &["--version"]))
+        .await
+        .map_err(|e| format!("Failed to run uv: {}", e))?;
+    // Some older builds printed the version to stderr.
+    parse_uv_version(&output.stdout)
+        .or_else(|| parse_uv_version(&output.stderr))
+        .ok_or_else(|| {
+            format!(
+                "Could not read the uv version from '{}'",
+                output.stdout.trim()
+            )
+        })
+}
+
+/// Parse the output of `uv --version`, e.g. `uv 0.5.11 (c4d0caaee 2024-12-19)`.
+pub fn parse_uv_version(output: &str) -> Option<Version> {
+    let mut words = output.split_whitespace();
+    words.find(|word| *word == UV_COMMAND)?;
+    Version::parse(words.next()?).ok()
+}
+
+/// The newest uv release: for a standalone install the latest release on the
+/// `uv_mirror_url` setting or GitHub, otherwise from `uv self update --dry-run`.
+///
+/// Returns `None` when it cannot be told, e.g. when offline or when uv was
+/// installed with a package manager and cannot update itself.
+pub async fn latest_version(ctx: &Pylot) -> Option<Version> {
+    let uv_path = ctx.runner.which(&ctx.settings.uv_program())?;
+    if install_method_of(&ctx.settings, &uv_path) == InstallMethod::Standalone {
+        return latest_release(uvarchive::releases_url(&ctx.settings)).await;
+    }
+    let output = ctx
+        .runner
+        .output(&CommandSpec::uv(
+            &ctx.settings,
+            &["self", "update", "--dry-run"],
+        ))
+        .await
+        .ok()?;
+    if !output.success() {
+        return None;
+    }
+    // uv writes its dry-run report to stderr.
+    parse_latest_version(&output.stderr)
+}
+
+/// Parse the report of `uv self update --dry-run`, e.g.
+/// `Would update uv from v0.11.2 to v0.11.3` or
+/// `You're already on version v0.11.3 of uv`.
+///
+/// Rather than matching uv's wording, which changes between releases, this
+/// picks the highest version mentioned: the release uv would update to, or
+/// the current one when it is already the latest.
+pub fn parse_latest_version(output: &str) -> Option<Version> {
+    output
+        .split_whitespace()
+        .filter_map(|word| {
+            let word = word.trim_matches(|c: char| !c.is_ascii_alphanumeric());
+            Version::parse(word.strip_prefix('v')?).ok()
+        })
+        .max()
+}
+
+/// Version, location and install method of the `uv` Pylot runs, and the
+/// newest release when it could be determined.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct UvInfo {
+    pub version: Version,
+    /// The `uv` binary, from `PATH` or the `uv_path` setting.
+    pub path: PathBuf,
+    pub install_method: InstallMethod,
+    /// The newest release, `None` when it could not be looked up.
+    pub latest: Option<Version>,
+}
+
+impl UvInfo {
+    /// Returns `true` when a newer release than the installed one is known.
+    pub fn update_available(&self) -> bool {
+        self.latest
+            .as_ref()
+            .is_some_and(|latest| *latest > self.version)
+    }
+}
+
+/// Look up the version, path, install method and newest release of the `uv`
+/// Pylot runs.
+///
+/// # Errors
+///
+/// Returns `Err(String)` if uv is not found or its version cannot be read.
+pub async fn info(ctx: &Pylot) -> Result<UvInfo, String> {
+    let program = ctx.settings.uv_program();
+    let path = ctx
+        .runner
+        .which(&program)
+        .ok_or_else(|| format!("uv not found: {} is not on PATH", program))?;
+    let (version, latest) = tokio::join!(installed_version(ctx), latest_version(ctx));
+    Ok(UvInfo {
+        version: version?,
+        install_method: install_method_of(&ctx.settings, &path),
+        path,
+        latest,
+    })
+}
+
+/// Print `info` as a two-column table.
+pub fn print_info_table_to<W: Write>(writer: &mut W, info: &UvInfo) {
+    let latest = match &info.latest {
+        Some(latest) if info.update_available() => format!("{} (update available)", latest),
+        Some(latest) => latest.to_string(),
+        None => "unknown".to_string(),
+    };
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_SOLID_INNER_BORDERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .add_row(vec!["Version".to_string(), info.version.to_string()])
+        .add_row(vec!["Path".to_string(), info.path.display().to_string()])
+        .add_row(vec![
+            "Installed with".to_string(),
+            info.install_method.to_string(),
+        ])
+        .add_row(vec!["Latest".to_string(), latest]);
+    let _ = writeln!(writer, "{}", table);
+}
+
+/// Compare `installed` with `required`, a semver requirement such as
+/// `">=0.5, <0.7"`.
+///
+/// # Errors
+///
+/// Returns `Err(message)` when `required` is not a valid requirement or
+/// `installed` does not satisfy it.
+pub fn meets_requirement(installed: &Version, required: &str) -> Result<(), String> {
+    let requirement = VersionReq::parse(required)
+        .map_err(|e| format!("Invalid required_uv_version '{}': {}", required, e))?;
+    if requirement.matches(installed) {
+        Ok(())
+    } else {
+        Err(format!(
+            "uv {} does not satisfy required_uv_version '{}'; run 'pylot uv update --to <version>' with a matching version",
+            installed, required
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::logger;
+    use crate::{
+        logger,
+        processes::{CommandOutput, RecordingRunner},
+    };
+    use std::sync::Arc;
 
     use super::*;
 
+    /// A default context whose commands go to `runner`.
+    fn ctx(runner: &Arc<RecordingRunner>) -> Pylot {
+        Pylot::default().with_runner(runner.clone())
+    }
+
     #[tokio::test]
     async fn test_check_command_exists() {
         logger::initialize_logger(log::LevelFilter::Trace);
         // Test with a command that should exist on all systems
-        let result = check("sh").await;
+        let result = check(&Pylot::default(), "sh").await;
         // On Unix systems, sh should exist
         #[cfg(unix)]
         assert!(result.is_ok());
@@ -165,7 +856,9 @@ mod tests {
         #[cfg(unix)]
         {
             let cursor = std::io::Cursor::new("y\n");
-            install(cursor).await.expect("Failed to install Astral UV");
+            install(&Pylot::default(), cursor, None)
+                .await
+                .expect("Failed to install Astral UV");
         }
     }
 
@@ -173,26 +866,472 @@ mod tests {
     async fn test_install_uv_no() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("n\n");
-        install(cursor).await.expect("Failed to install Astral UV");
+        let runner = Arc::new(RecordingRunner::new());
+        install(&ctx(&runner), cursor, None)
+            .await
+            .expect("Failed to install Astral UV");
+        assert!(runner.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_install_uv_invalid_version() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let cursor = std::io::Cursor::new("y\n");
+        let err = install(&Pylot::default(), cursor, Some("0.5; rm -rf ~"))
+            .await
+            .unwrap_err();
+        assert!(err.contains("Invalid uv version"));
+    }
+
+    #[tokio::test]
+    async fn test_install_from_archive_no() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let cursor = std::io::Cursor::new("n\n");
+        let missing = Path::new("/nonexistent/uv.tar.gz");
+        install_from_archive(&Pylot::default(), cursor, missing, None)
+            .await
+            .expect("Declining should not fail");
+    }
+
+    #[tokio::test]
+    async fn test_install_from_archive_missing_source() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let cursor = std::io::Cursor::new("y\n");
+        let missing = Path::new("/nonexistent/uv.tar.gz");
+        let err = install_from_archive(&Pylot::default(), cursor, missing, None)
+            .await
+            .unwrap_err();
+        assert!(err.contains("does not exist"));
     }
 
     #[tokio::test]
     async fn test_uninstall_uv_no() {
         logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let uv = tmp.path().join(uvarchive::binary_names()[0]);
+        std::fs::write(&uv, "").unwrap();
+        let runner = Arc::new(RecordingRunner::new().with_program(UV_COMMAND, &uv));
+        let mut installed = ctx(&runner);
+        installed.settings.uv_install_dir = Some(tmp.path().display().to_string());
         let cursor = std::io::Cursor::new("n\n");
-        uninstall(cursor)
+        uninstall(&installed, cursor, false)
             .await
             .expect("Failed to uninstall Astral UV");
+        assert!(uv.exists());
+    }
+
+    #[tokio::test]
+    async fn test_uninstall_standalone_removes_binaries() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let uv = tmp.path().join(uvarchive::binary_names()[0]);
+        for name in uvarchive::binary_names() {
+            std::fs::write(tmp.path().join(name), "").unwrap();
+        }
+        let runner = Arc::new(RecordingRunner::new().with_program(UV_COMMAND, &uv));
+        let mut installed = ctx(&runner);
+        installed.settings.uv_install_dir = Some(tmp.path().display().to_string());
+        uninstall(&installed, std::io::Cursor::new("y\n"), false)
+            .await
+            .unwrap();
+        assert!(!uv.exists());
+        // Nothing to run for a standalone install without --purge.
+        assert!(runner.calls().is_empty());
+
+        let runner = Arc::new(RecordingRunner::new());
+        uninstall(&ctx(&runner), std::io::Cursor::new("y\n"), false)
+            .await
+            .expect("uv not being installed is not an error");
+    }
+
+    #[tokio::test]
+    async fn test_uninstall_refuses_unknown_install() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let uv = tmp.path().join(uvarchive::binary_names()[0]);
+        std::fs::write(&uv, "").unwrap();
+        let runner = Arc::new(RecordingRunner::new().with_program(UV_COMMAND, &uv));
+        let err = uninstall(&ctx(&runner), std::io::Cursor::new("y\n"), true)
+            .await
+            .unwrap_err();
+        assert!(err.contains("cannot tell how uv"), "{}", err);
+        assert!(uv.exists());
+    }
+
+    #[tokio::test]
+    async fn test_uninstall_plan_lists_venvs_using_purged_pythons() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let pythons = tmp.path().join("pythons");
+        std::fs::create_dir_all(&pythons).unwrap();
+        let venvs = tmp.path().join("venvs");
+        for (name, home) in [
+            (
+                "api",
+                pythons.join("cpython-3.12.3-linux-x86_64-gnu").join("bin"),
+            ),
+            ("web", PathBuf::from("/usr/bin")),
+        ] {
+            std::fs::create_dir_all(venvs.join(name)).unwrap();
+            let cfg = format!("home = {}\nversion_info = 3.12.3\n", home.display());
+            std::fs::write(venvs.join(name).join("pyvenv.cfg"), cfg).unwrap();
+        }
+        let pythons_dir = pythons.display().to_string();
+        let runner = Arc::new(
+            RecordingRunner::new()
+                .with_program(UV_COMMAND, tmp.path().join("uv"))
+                .respond("python dir", CommandOutput::ok(&pythons_dir))
+                .respond("dir", CommandOutput::failed(2, "")),
+        );
+        let ctx = ctx(&runner).with_venvs_path(venvs.to_str().unwrap());
+        let plan = uninstall_plan(&ctx, true).await.unwrap().unwrap();
+        assert_eq!(plan.method, InstallMethod::Unknown);
+        assert_eq!(plan.purge, vec![("pythons", pythons)]);
+        assert_eq!(plan.broken_venvs, vec!["api"]);
+        assert_eq!(
+            plan.describe().last().unwrap(),
+            "break venvs using these Pythons: api"
+        );
     }
 
     #[tokio::test]
     async fn test_update_uv() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let result = update().await;
-        match result {
-            Ok(_) => println!("Astral UV updated successfully."),
-            Err(e) => println!("Failed to update Astral UV: {}", e),
+        let runner = Arc::new(
+            RecordingRunner::new()
+                .with_program(UV_COMMAND, "/opt/uv/bin/uv")
+                .with_program(WINGET_CMD, "C:/winget.exe"),
+        );
+        update(&ctx(&runner), Some("0.5.11")).await.unwrap();
+        let expected = if cfg!(target_os = "windows") {
+            "winget install astral-sh.uv --version 0.5.11 --force"
+        } else {
+            "uv self update 0.5.11"
+        };
+        assert!(runner.command_lines()[0].starts_with(expected));
+
+        let missing = Arc::new(RecordingRunner::new());
+        let err = update(&ctx(&missing), None).await.unwrap_err();
+        assert!(err.contains("is required for update"));
+    }
+
+    #[tokio::test]
+    async fn test_install_then_update_standalone_from_mirror() {
+        use crate::{
+            testsupport,
+            uvarchive::tests::{spawn_stub_mirror, FIXTURE, FIXTURE_SHA256},
+        };
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let Some(triple) = uvarchive::target_triple() else {
+            return;
+        };
+        let asset = uvarchive::archive_name(triple);
+        // The fixture is a .tar.gz, which cannot stand in for the .zip.
+        if asset.ends_with(".zip") {
+            return;
         }
+        let archive = std::fs::read(FIXTURE).unwrap();
+        let checksum = format!("{}  {}\n", FIXTURE_SHA256, asset).into_bytes();
+        let mut routes = vec![(
+            "/releases/latest".to_string(),
+            testsupport::redirect("/releases/tag/0.5.12"),
+        )];
+        for version in ["0.5.11", "0.5.12"] {
+            let path = format!("/releases/download/{}/{}", version, asset);
+            routes.push((format!("{}.sha256", path), checksum.clone()));
+            routes.push((path, archive.clone()));
+        }
+        let mirror = spawn_stub_mirror(routes);
+
+        let dir = tempfile::tempdir().unwrap();
+        let uv = dir.path().join(uvarchive::binary_names()[0]);
+        let runner = Arc::new(
+            RecordingRunner::new()
+                .with_program(UV_COMMAND, &uv)
+                .respond("--version", CommandOutput::ok("uv 0.5.11")),
+        );
+        let mut ctx = ctx(&runner);
+        ctx.settings.uv_mirror_url = Some(mirror);
+        ctx.settings.uv_install_dir = Some(dir.path().display().to_string());
+
+        install(&ctx, std::io::Cursor::new("y\n"), Some("0.5.11"))
+            .await
+            .unwrap();
+        assert!(uv.is_file());
+        assert_eq!(latest_version(&ctx).await, Some(Version::new(0, 5, 12)));
+
+        // The latest release is only served under its version, so this
+        // succeeding means the redirect was followed.
+        std::fs::remove_file(&uv).unwrap();
+        update(&ctx, None).await.unwrap();
+        assert!(uv.is_file());
+        // Already installed: nothing to download.
+        update(&ctx, Some("0.5.11")).await.unwrap();
+        let err = update(&ctx, Some("0.5.10")).await.unwrap_err();
+        assert!(err.starts_with("Update failed"), "{}", err);
+        // uv never updates itself, so no receipt is needed.
+        assert!(runner
+            .command_lines()
+            .iter()
+            .all(|line| line == "uv --version"));
+    }
+
+    #[tokio::test]
+    async fn test_update_uv_invalid_version() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let err = update(&Pylot::default(), Some("latest")).await.unwrap_err();
+        assert!(err.contains("Invalid uv version 'latest'"));
+    }
+
+    // ── version pinning ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_release() {
+        assert_eq!(parse_release("0.5.11").unwrap(), Version::new(0, 5, 11));
+        assert_eq!(parse_release(" v0.4.0 ").unwrap(), Version::new(0, 4, 0));
+        assert!(parse_release("0.5").is_err());
+        assert!(parse_release("0.5.11 && echo").is_err());
+    }
+
+    #[test]
+    fn test_pinned_install_and_update_args() {
+        let version = Version::new(0, 5, 11);
+        assert_eq!(
+            winget_args(UV_WINGET_INSTALL_ARGS, Some(&version)),
+            vec!["install", "astral-sh.uv", "--version", "0.5.11"]
+        );
+        assert_eq!(self_update_args(Some(&version)), vec!["self", "update", "0.5.11"]);
+        assert_eq!(self_update_args(None), UPDATE_ARGS);
+    }
+
+    #[test]
+    fn test_detect_install_method() {
+        let cargo_bin = Path::new("/home/dev/.cargo/bin");
+        let standalone = [
+            PathBuf::from("/home/dev/.local/bin"),
+            PathBuf::from("/opt/custom"),
+        ];
+        let detect = |path: &str, resolved: &str| {
+            detect_install_method(Path::new(path), Path::new(resolved), cargo_bin, &standalone)
+        };
+        assert_eq!(
+            detect("/home/dev/.local/bin/uv", "/home/dev/.local/bin/uv"),
+            InstallMethod::Standalone
+        );
+        assert_eq!(
+            detect("/opt/custom/uv", "/opt/custom/uv"),
+            InstallMethod::Standalone
+        );
+        assert_eq!(detect("/usr/bin/uv", "/usr/bin/uv"), InstallMethod::Unknown);
+        assert_eq!(
+            detect(
+                "/home/dev/.nix-profile/bin/uv",
+                "/nix/store/abc-uv-0.5.11/bin/uv"
+            ),
+            InstallMethod::Unknown
+        );
+        assert_eq!(
+            detect("/home/dev/.cargo/bin/uv", "/home/dev/.cargo/bin/uv"),
+            InstallMethod::Cargo
+        );
+        assert_eq!(
+            detect(
+                "/home/dev/.local/bin/uv",
+                "/home/dev/.local/share/pipx/venvs/uv/bin/uv"
+            ),
+            InstallMethod::Pipx
+        );
+        assert_eq!(
+            detect("/opt/homebrew/bin/uv", "/opt/homebrew/Cellar/uv/0.5.11/bin/uv"),
+            InstallMethod::Homebrew
+        );
+        assert_eq!(
+            detect(
+                "C:/Users/dev/AppData/Local/Microsoft/WinGet/Links/uv.exe",
+                "C:/Users/dev/AppData/Local/Microsoft/WinGet/Packages/astral-sh.uv/uv.exe"
+            ),
+            InstallMethod::Winget
+        );
+    }
+
+    #[test]
+    fn test_uninstall_plan_describe() {
+        let plan = UninstallPlan {
+            uv_path: PathBuf::from("/opt/custom/uv"),
+            method: InstallMethod::Standalone,
+            files: vec![PathBuf::from("/opt/custom/uv"), PathBuf::from("/opt/custom/uvx")],
+            purge: vec![("cache", PathBuf::from("/home/dev/.cache/uv"))],
+            broken_venvs: Vec::new(),
+        };
+        assert_eq!(
+            plan.describe(),
+            vec![
+                "uv at /opt/custom/uv (installed with standalone installer)",
+                "remove: /opt/custom/uv",
+                "remove: /opt/custom/uvx",
+                "remove cache: /home/dev/.cache/uv",
+            ]
+        );
+
+        let plan = UninstallPlan {
+            uv_path: PathBuf::from("/home/dev/.cargo/bin/uv"),
+            method: InstallMethod::Cargo,
+            files: Vec::new(),
+            purge: Vec::new(),
+            broken_venvs: Vec::new(),
+        };
+        assert_eq!(plan.describe()[1], "run: cargo uninstall uv");
+        assert!(InstallMethod::Standalone.uninstall_command().is_none());
+        assert!(InstallMethod::Unknown.uninstall_command().is_none());
+        assert_eq!(
+            receipt_install_prefix(
+                r#"{"install_prefix":"/home/dev/.local/bin","binaries":["uv"]}"#
+            ),
+            Some(PathBuf::from("/home/dev/.local/bin"))
+        );
+        assert_eq!(receipt_install_prefix("{}"), None);
+    }
+
+    #[test]
+    fn test_parse_uv_version() {
+        assert_eq!(
+            parse_uv_version("uv 0.5.11 (c4d0caaee 2024-12-19)\n"),
+            Some(Version::new(0, 5, 11))
+        );
+        assert_eq!(parse_uv_version("uv 0.4.0"), Some(Version::new(0, 4, 0)));
+        assert_eq!(parse_uv_version("command not found"), None);
+    }
+
+    // ── version detection ────────────────────────────────────────────────────
+
+    macro_rules! fixture {
+        ($name:literal) => {
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/uv-output/",
+                $name
+            ))
+        };
+    }
+
+    #[test]
+    fn test_parse_uv_version_fixtures() {
+        assert_eq!(
+            parse_uv_version(fixture!("version.txt")),
+            Some(Version::new(0, 5, 11))
+        );
+        assert_eq!(
+            parse_uv_version(fixture!("version-homebrew.txt")),
+            Some(Version::new(0, 6, 3))
+        );
+        assert_eq!(
+            parse_uv_version(fixture!("version-bare.txt")),
+            Some(Version::new(0, 4, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_latest_version_fixtures() {
+        assert_eq!(
+            parse_latest_version(fixture!("self-update-available.txt")),
+            Some(Version::new(0, 11, 3))
+        );
+        assert_eq!(
+            parse_latest_version(fixture!("self-update-current.txt")),
+            Some(Version::new(0, 11, 3))
+        );
+        assert_eq!(
+            parse_latest_version(fixture!("self-update-current-0.4.txt")),
+            Some(Version::new(0, 4, 30))
+        );
+        assert_eq!(
+            parse_latest_version(fixture!("self-update-unavailable.txt")),
+            None
+        );
+        assert_eq!(parse_latest_version(""), None);
+    }
+
+    #[tokio::test]
+    async fn test_info_from_runner() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let runner = Arc::new(
+            RecordingRunner::new()
+                .with_program(UV_COMMAND, "/opt/uv/bin/uv")
+                .respond("--version", CommandOutput::ok(fixture!("version.txt")))
+                .respond(
+                    "--dry-run",
+                    CommandOutput {
+                        code: Some(0),
+                        stdout: String::new(),
+                        stderr: fixture!("self-update-available.txt").to_string(),
+                    },
+                ),
+        );
+        let uv = info(&ctx(&runner)).await.unwrap();
+        assert_eq!(uv.version, Version::new(0, 5, 11));
+        assert_eq!(uv.path, PathBuf::from("/opt/uv/bin/uv"));
+        assert_eq!(uv.latest, Some(Version::new(0, 11, 3)));
+        assert!(uv.update_available());
+
+        let runner = Arc::new(
+            RecordingRunner::new()
+                .with_program(UV_COMMAND, "/opt/uv/bin/uv")
+                .respond("--version", CommandOutput::ok(fixture!("version.txt")))
+                .respond(
+                    "--dry-run",
+                    CommandOutput::failed(2, fixture!("self-update-unavailable.txt")),
+                ),
+        );
+        assert_eq!(info(&ctx(&runner)).await.unwrap().latest, None);
+        let missing = Arc::new(RecordingRunner::new());
+        assert!(info(&ctx(&missing)).await.is_err());
+    }
+
+    #[test]
+    fn test_uv_info_update_available_and_json() {
+        let mut info = UvInfo {
+            version: Version::new(0, 5, 11),
+            path: PathBuf::from("/home/dev/.local/bin/uv"),
+            install_method: InstallMethod::Standalone,
+            latest: Some(Version::new(0, 6, 0)),
+        };
+        assert!(info.update_available());
+        assert_eq!(
+            serde_json::to_value(&info).unwrap(),
+            serde_json::json!({
+                "version": "0.5.11",
+                "path": "/home/dev/.local/bin/uv",
+                "install_method": "standalone",
+                "latest": "0.6.0",
+            })
+        );
+        let mut buf = Vec::new();
+        print_info_table_to(&mut buf, &info);
+        assert!(String::from_utf8(buf)
+            .unwrap()
+            .contains("0.6.0 (update available)"));
+
+        info.latest = Some(Version::new(0, 5, 11));
+        assert!(!info.update_available());
+        info.latest = None;
+        assert!(!info.update_available());
+        assert_eq!(
+            serde_json::to_value(&info).unwrap()["latest"],
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn test_meets_requirement() {
+        let installed = Version::new(0, 5, 11);
+        assert!(meets_requirement(&installed, ">=0.5, <0.7").is_ok());
+        assert!(meets_requirement(&installed, "0.5.2").is_ok());
+        assert!(meets_requirement(&installed, "=0.5.11").is_ok());
+        let err = meets_requirement(&installed, ">=0.6").unwrap_err();
+        assert!(err.contains("uv 0.5.11 does not satisfy required_uv_version '>=0.6'"));
+        let err = meets_requirement(&installed, "latest").unwrap_err();
+        assert!(err.contains("Invalid required_uv_version"));
     }
 
     #[tokio::test]
@@ -202,8 +1341,10 @@ mod tests {
         #[cfg(unix)]
         {
             let cursor = std::io::Cursor::new("y\n");
-            install(cursor).await.expect("Failed to install Astral UV");
-            let result = update().await;
+            install(&Pylot::default(), cursor, None)
+                .await
+                .expect("Failed to install Astral UV");
+            let result = update(&Pylot::default(), None).await;
             match result {
                 Ok(_) => println!("Astral UV updated successfully."),
                 Err(e) => println!("Failed to update Astral UV: {}", e),
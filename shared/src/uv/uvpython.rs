@@ -0,0 +1,384 @@
+use crate::{
+    context::Pylot,
+    doctor::PyvenvCfg,
+    error::{PylotError, Result},
+    infra::processes::{self, CommandSpec},
+    uvvenv::UvVenv,
+};
+use comfy_table::{
+    modifiers::UTF8_SOLID_INNER_BORDERS, presets::UTF8_FULL, ContentArrangement, Table,
+};
+use std::{collections::HashMap, io::Write, path::Path};
+
+/// Marker `uv python list` prints instead of a path for versions that are not
+/// installed yet.
+const DOWNLOAD_AVAILABLE: &str = "<download available>";
+
+/// A Python interpreter reported by `uv python list`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PythonInstall {
+    /// Full uv key, e.g. `cpython-3.12.3-linux-x86_64-gnu`.
+    pub key: String,
+    /// Implementation name, e.g. `cpython` or `pypy`.
+    pub implementation: String,
+    /// Version, e.g. `3.12.3` or `3.13.0+freethreaded`.
+    pub version: String,
+    /// Interpreter path, or `None` when it can be downloaded but is not
+    /// installed.
+    pub path: Option<String>,
+}
+
+impl PythonInstall {
+    /// Parse one line of `uv python list` output.
+    ///
+    /// Symlinked interpreters are shown by uv as `path -> target`; only the
+    /// path is kept.
+    pub fn parse_line(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let (key, rest) = match line.split_once(char::is_whitespace) {
+            Some((key, rest)) => (key, rest.trim()),
+            None => (line, ""),
+        };
+        let mut parts = key.splitn(3, '-');
+        let implementation = parts.next().filter(|p| !p.is_empty())?;
+        let version = parts
+            .next()
+            .filter(|v| v.starts_with(|c: char| c.is_ascii_digit()))?;
+        let path = match rest {
+            "" | DOWNLOAD_AVAILABLE => None,
+            rest => Some(
+                rest.split_once(" -> ")
+                    .map_or(rest, |(path, _)| path)
+                    .to_string(),
+            ),
+        };
+        Some(PythonInstall {
+            key: key.to_string(),
+            implementation: implementation.to_string(),
+            version: version.to_string(),
+            path,
+        })
+    }
+
+    /// Whether the interpreter is installed, as opposed to only downloadable.
+    pub fn is_installed(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// Whether this interpreter satisfies `request`, a version prefix such as
+    /// `3.12` or `3.12.3`.
+    pub fn matches_version(&self, request: &str) -> bool {
+        self.version == request
+            || self
+                .version
+                .strip_prefix(request)
+                .is_some_and(|rest| rest.starts_with(['.', '+']))
+    }
+}
+
+/// Parse the output of `uv python list`, keeping the first entry per key.
+///
+/// uv lists the same interpreter once per path it was found at (e.g.
+/// `python3` and `python3.12`), which is noise in Pylot's overview.
+pub fn parse_list(output: &str) -> Vec<PythonInstall> {
+    let mut installs: Vec<PythonInstall> = Vec::new();
+    for install in output.lines().filter_map(PythonInstall::parse_line) {
+        if !installs.iter().any(|i| i.key == install.key) {
+            installs.push(install);
+        }
+    }
+    installs
+}
+
+/// List installed and downloadable interpreters via `uv python list`.
+///
+/// With `all_versions` every downloadable patch release is included instead
+/// of only the latest per minor version.
+///
+/// # Errors
+///
+/// Returns [`PylotError::CommandExecution`] if uv cannot be run or exits with
+/// an error.
+pub async fn list(ctx: &Pylot, all_versions: bool) -> Result<Vec<PythonInstall>> {
+    let mut args = vec!["python", "list"];
+    if all_versions {
+        args.push("--all-versions");
+    }
+    let output = ctx
+        .runner
+        .output(&CommandSpec::uv(&ctx.settings, &args))
+        .await
+        .map_err(|e| PylotError::CommandExecution(format!("Failed to run uv: {}", e)))?;
+    if !output.success() {
+        return Err(PylotError::CommandExecution(format!(
+            "'uv python list' failed: {}",
+            output.stderr.trim()
+        )));
+    }
+    Ok(parse_list(&output.stdout))
+}
+
+/// Install a Python version via `uv python install`.
+pub async fn install(ctx: &Pylot, version: &str) -> Result<()> {
+    run_uv_python(ctx, &["install", version]).await
+}
+
+/// Uninstall a uv-managed Python version via `uv python uninstall`.
+pub async fn uninstall(ctx: &Pylot, version: &str) -> Result<()> {
+    run_uv_python(ctx, &["uninstall", version]).await
+}
+
+/// Pin the Python version of the current directory via `uv python pin`,
+/// which writes `.python-version`.  Without a version the current pin is
+/// shown.
+pub async fn pin(ctx: &Pylot, version: Option<&str>) -> Result<()> {
+    let mut args = vec!["pin"];
+    args.extend(version);
+    run_uv_python(ctx, &args).await
+}
+
+async fn run_uv_python(ctx: &Pylot, args: &[&str]) -> Result<()> {
+    let mut uv_args = vec!["python"];
+    uv_args.extend_from_slice(args);
+    processes::run_logged(
+        ctx.runner.as_ref(),
+        &CommandSpec::uv(&ctx.settings, &uv_args),
+    )
+    .await
+}
+
+/// Read the base interpreter of every venv from its `pyvenv.cfg`.
+///
+/// Venvs without a readable config are left out.
+pub async fn venv_configs(venvs: &[UvVenv<'_>]) -> Vec<(String, PyvenvCfg)> {
+    let mut configs = Vec::new();
+    for venv in venvs {
+        if let Some(cfg) = PyvenvCfg::read(Path::new(&venv.path)).await {
+            configs.push((venv.name.to_string(), cfg));
+        }
+    }
+    configs
+}
+
+/// Map each interpreter key to the names of the venvs that were created from
+/// it.
+///
+/// A venv is matched by the `home` directory in its `pyvenv.cfg`, falling
+/// back to the first installed interpreter with the same version.
+pub fn venv_usage(
+    installs: &[PythonInstall],
+    configs: &[(String, PyvenvCfg)],
+) -> HashMap<String, Vec<String>> {
+    let mut usage: HashMap<String, Vec<String>> = HashMap::new();
+    for (name, cfg) in configs {
+        let by_home = cfg.home.as_deref().and_then(|home| {
+            installs.iter().find(|install| {
+                install
+                    .path
+                    .as_deref()
+                    .and_then(|path| Path::new(path).parent())
+                    .is_some_and(|dir| dir == Path::new(home))
+            })
+        });
+        let by_version = || {
+            let version = cfg.version.as_deref()?;
+            installs
+                .iter()
+                .find(|install| install.is_installed() && install.matches_version(version))
+        };
+        if let Some(install) = by_home.or_else(by_version) {
+            usage
+                .entry(install.key.clone())
+                .or_default()
+                .push(name.clone());
+        }
+    }
+    usage
+}
+
+/// Print one row per interpreter with its status and the venvs using it.
+pub fn print_python_table_to<W: Write>(
+    writer: &mut W,
+    installs: &[PythonInstall],
+    usage: &HashMap<String, Vec<String>>,
+) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_SOLID_INNER_BORDERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Version", "Implementation", "Path", "Used by"]);
+    for install in installs {
+        let used_by = usage
+            .get(&install.key)
+            .map(|names| names.join(", "))
+            .unwrap_or_default();
+        table.add_row(vec![
+            install.version.clone(),
+            install.implementation.clone(),
+            install
+                .path
+                .clone()
+                .unwrap_or_else(|| "(not installed)".to_string()),
+            used_by,
+        ]);
+    }
+    let _ = writeln!(writer, "{}", table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger;
+
+    const LIST_OUTPUT: &str = "\
+cpython-3.13.0-linux-x86_64-gnu                  <download available>
+cpython-3.12.3-linux-x86_64-gnu                  /home/dev/.local/share/uv/python/cpython-3.12.3-linux-x86_64-gnu/bin/python3.12
+cpython-3.12.3-linux-x86_64-gnu                  /home/dev/.local/share/uv/python/cpython-3.12.3-linux-x86_64-gnu/bin/python3 -> python3.12
+cpython-3.11.9-linux-x86_64-gnu                  /usr/bin/python3.11
+cpython-3.13.0+freethreaded-linux-x86_64-gnu     <download available>
+pypy-3.10.14-linux-x86_64-gnu                    <download available>
+";
+
+    fn cfg(home: Option<&str>, version: Option<&str>) -> PyvenvCfg {
+        PyvenvCfg {
+            home: home.map(str::to_string),
+            version: version.map(str::to_string),
+        }
+    }
+
+    // ── parsing ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_line() {
+        let install = PythonInstall::parse_line(
+            "cpython-3.12.3-linux-x86_64-gnu    /usr/bin/python3 -> python3.12",
+        )
+        .unwrap();
+        assert_eq!(install.implementation, "cpython");
+        assert_eq!(install.version, "3.12.3");
+        assert_eq!(install.path.as_deref(), Some("/usr/bin/python3"));
+        assert!(install.is_installed());
+
+        let available =
+            PythonInstall::parse_line("pypy-3.10.14-linux-x86_64-gnu  <download available>")
+                .unwrap();
+        assert_eq!(available.implementation, "pypy");
+        assert!(!available.is_installed());
+
+        assert!(PythonInstall::parse_line("").is_none());
+        assert!(PythonInstall::parse_line("warning: something happened").is_none());
+    }
+
+    #[test]
+    fn test_parse_list_dedupes_keys() {
+        let installs = parse_list(LIST_OUTPUT);
+        let versions: Vec<_> = installs.iter().map(|i| i.version.as_str()).collect();
+        assert_eq!(
+            versions,
+            vec![
+                "3.13.0",
+                "3.12.3",
+                "3.11.9",
+                "3.13.0+freethreaded",
+                "3.10.14"
+            ]
+        );
+        assert!(installs[1].path.as_deref().unwrap().ends_with("python3.12"));
+    }
+
+    #[test]
+    fn test_matches_version() {
+        let installs = parse_list(LIST_OUTPUT);
+        assert!(installs[1].matches_version("3.12"));
+        assert!(installs[1].matches_version("3.12.3"));
+        assert!(!installs[1].matches_version("3.1"));
+        assert!(installs[3].matches_version("3.13"));
+        assert!(installs[3].matches_version("3.13.0"));
+    }
+
+    // ── venv_usage ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_venv_usage_matches_home_then_version() {
+        let installs = parse_list(LIST_OUTPUT);
+        let configs = vec![
+            (
+                "web".to_string(),
+                cfg(
+                    Some("/home/dev/.local/share/uv/python/cpython-3.12.3-linux-x86_64-gnu/bin"),
+                    Some("3.12.3"),
+                ),
+            ),
+            (
+                "tools".to_string(),
+                cfg(Some("/opt/elsewhere"), Some("3.11.9")),
+            ),
+            ("old".to_string(), cfg(None, Some("3.8.10"))),
+        ];
+        let usage = venv_usage(&installs, &configs);
+        assert_eq!(usage["cpython-3.12.3-linux-x86_64-gnu"], vec!["web"]);
+        assert_eq!(usage["cpython-3.11.9-linux-x86_64-gnu"], vec!["tools"]);
+        assert_eq!(usage.values().flatten().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_venv_configs_skips_missing_cfg() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let with_cfg = tmp.path().join("a");
+        tokio::fs::create_dir_all(&with_cfg).await.unwrap();
+        tokio::fs::write(with_cfg.join("pyvenv.cfg"), "version_info = 3.12.1\n")
+            .await
+            .unwrap();
+        let venvs = vec![
+            UvVenv::new(
+                &Pylot::default(),
+                "a".into(),
+                with_cfg.to_string_lossy().to_string(),
+                String::new(),
+                vec![],
+                false,
+            ),
+            UvVenv::new(
+                &Pylot::default(),
+                "b".into(),
+                tmp.path().join("b").to_string_lossy().to_string(),
+                String::new(),
+                vec![],
+                false,
+            ),
+        ];
+        let configs = venv_configs(&venvs).await;
+        assert_eq!(configs.len(), 1);
+        assert_eq!(configs[0].1.version.as_deref(), Some("3.12.1"));
+    }
+
+    // ── print_python_table_to ────────────────────────────────────────────────
+
+    #[test]
+    fn test_print_python_table() {
+        let installs = parse_list(LIST_OUTPUT);
+        let mut usage = HashMap::new();
+        usage.insert(
+            "cpython-3.11.9-linux-x86_64-gnu".to_string(),
+            vec!["tools".to_string(), "web".to_string()],
+        );
+        let mut output = Vec::new();
+        print_python_table_to(&mut output, &installs, &usage);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Used by"));
+        assert!(output.contains("tools, web"));
+        assert!(output.contains("(not installed)"));
+        assert!(output.contains("pypy"));
+    }
+
+    #[tokio::test]
+    async fn test_list_returns_ok_or_command_error() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        // uv may not be installed in the test environment.
+        if let Err(e) = list(&Pylot::default(), false).await {
+            assert!(matches!(e, PylotError::CommandExecution(_)));
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{
+    context::Pylot,
+    error::{PylotError, Result},
+    infra::processes::{self, CommandSpec},
+    uvvenv::UvVenv,
+};
+use comfy_table::{
+    modifiers::UTF8_SOLID_INNER_BORDERS, presets::UTF8_FULL, ContentArrangement, Table,
+};
+use std::io::Write;
+
+/// A CLI tool installed with `uv tool install`, as reported by `uv tool list`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolInstall {
+    /// Package name, e.g. `ruff`.
+    pub name: String,
+    /// Installed version, e.g. `0.3.0`.
+    pub version: String,
+    /// Executables the tool puts on `PATH`, e.g. `black` and `blackd`.
+    pub executables: Vec<String>,
+}
+
+/// Parse the output of `uv tool list`:
+///
+/// ```text
+/// black v24.2.0
+/// - black
+/// - blackd
+/// ruff v0.3.0
+/// - ruff
+/// ```
+///
+/// Paths printed by `--show-paths` and lines that are not part of the listing,
+/// such as `No tools installed` or warnings, are ignored.
+pub fn parse_list(output: &str) -> Vec<ToolInstall> {
+    let mut tools: Vec<ToolInstall> = Vec::new();
+    for line in output.lines() {
+        let line = line.trim_end();
+        if let Some(executable) = line.strip_prefix("- ") {
+            if let (Some(tool), Some(name)) =
+                (tools.last_mut(), executable.split_whitespace().next())
+            {
+                tool.executables.push(name.to_string());
+            }
+            continue;
+        }
+        let mut words = line.split_whitespace();
+        let (Some(name), Some(version)) = (words.next(), words.next()) else {
+            continue;
+        };
+        let Some(version) = version
+            .strip_prefix('v')
+            .filter(|v| v.starts_with(|c: char| c.is_ascii_digit()))
+        else {
+            continue;
+        };
+        tools.push(ToolInstall {
+            name: name.to_string(),
+            version: version.to_string(),
+            executables: Vec::new(),
+        });
+    }
+    tools
+}
+
+/// List the installed tools via `uv tool list`.
+///
+/// # Errors
+///
+/// Returns [`PylotError::CommandExecution`] if uv cannot be run or exits with
+/// an error.
+pub async fn list(ctx: &Pylot) -> Result<Vec<ToolInstall>> {
+    let output = ctx
+        .runner
+        .output(&CommandSpec::uv(&ctx.settings, &["tool", "list"]))
+        .await
+        .map_err(|e| PylotError::CommandExecution(format!("Failed to run uv: {}", e)))?;
+    if !output.success() {
+        return Err(PylotError::CommandExecution(format!(
+            "'uv tool list' failed: {}",
+            output.stderr.trim()
+        )));
+    }
+    Ok(parse_list(&output.stdout))
+}
+
+/// Install a tool via `uv tool install`.  `package` may carry a version
+/// specifier, e.g. `ruff==0.3.0`.
+pub async fn install(ctx: &Pylot, package: &str) -> Result<()> {
+    UvVenv::validate_package_name(package)?;
+    run_uv_tool(ctx, &["install", package]).await
+}
+
+/// Upgrade the named tools, or every installed tool when `all` is set, via
+/// `uv tool upgrade`.
+///
+/// # Errors
+///
+/// Returns [`PylotError::Other`] when `names` is empty and `all` is not set,
+/// or when both are given.
+pub async fn upgrade(ctx: &Pylot, names: &[String], all: bool) -> Result<()> {
+    match (names.is_empty(), all) {
+        (true, false) => {
+            return Err(PylotError::Other(
+                "No tools provided, use --all to upgrade every tool".to_string(),
+            ))
+        }
+        (false, true) => {
+            return Err(PylotError::Other(
+                "Give tool names or --all, not both".to_string(),
+            ))
+        }
+        _ => {}
+    }
+    for name in names {
+        UvVenv::validate_package_name(name)?;
+    }
+    let mut args = vec!["upgrade"];
+    if all {
+        args.push("--all");
+    } else {
+        args.extend(names.iter().map(String::as_str));
+    }
+    run_uv_tool(ctx, &args).await
+}
+
+/// Uninstall a tool and its executables via `uv tool uninstall`.
+pub async fn uninstall(ctx: &Pylot, name: &str) -> Result<()> {
+    UvVenv::validate_package_name(name)?;
+    run_uv_tool(ctx, &["uninstall", name]).await
+}
+
+async fn run_uv_tool(ctx: &Pylot, args: &[&str]) -> Result<()> {
+    let mut uv_args = vec!["tool"];
+    uv_args.extend_from_slice(args);
+    processes::run_logged(
+        ctx.runner.as_ref(),
+        &CommandSpec::uv(&ctx.settings, &uv_args),
+    )
+    .await
+}
+
+/// Print one row per tool with its version and executables.
+pub fn print_tool_table_to<W: Write>(writer: &mut W, tools: &[ToolInstall]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_SOLID_INNER_BORDERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Tool", "Version", "Executables"]);
+    for tool in tools {
+        table.add_row(vec![
+            tool.name.clone(),
+            tool.version.clone(),
+            tool.executables.join(", "),
+        ]);
+    }
+    let _ = writeln!(writer, "{}", table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger;
+
+    const LIST_OUTPUT: &str = "\
+black v24.2.0
+- black
+- blackd
+ruff v0.3.0 (/home/dev/.local/share/uv/tools/ruff)
+- ruff (/home/dev/.local/bin/ruff)
+";
+
+    // ── parsing ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_list() {
+        let tools = parse_list(LIST_OUTPUT);
+        assert_eq!(tools.len(), 2);
+        assert_eq!(tools[0].name, "black");
+        assert_eq!(tools[0].version, "24.2.0");
+        assert_eq!(tools[0].executables, vec!["black", "blackd"]);
+        assert_eq!(tools[1].name, "ruff");
+        assert_eq!(tools[1].version, "0.3.0");
+        assert_eq!(tools[1].executables, vec!["ruff"]);
+    }
+
+    #[test]
+    fn test_parse_list_ignores_noise() {
+        assert!(parse_list("No tools installed\n").is_empty());
+        assert!(parse_list("").is_empty());
+        let tools = parse_list("- orphan\nwarning: something happened\nruff v0.3.0\n- ruff\n");
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].executables, vec!["ruff"]);
+    }
+
+    // ── validation ───────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_rejects_invalid_names() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let ctx = Pylot::default();
+        assert!(matches!(
+            install(&ctx, "ruff; rm -rf ~").await,
+            Err(PylotError::InvalidPackageName(_))
+        ));
+        assert!(matches!(
+            uninstall(&ctx, "").await,
+            Err(PylotError::InvalidPackageName(_))
+        ));
+        assert!(matches!(
+            upgrade(&ctx, &["$(whoami)".to_string()], false).await,
+            Err(PylotError::InvalidPackageName(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_needs_names_or_all() {
+        use crate::processes::RecordingRunner;
+        use std::sync::Arc;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let runner = Arc::new(RecordingRunner::new());
+        let ctx = Pylot::default().with_runner(runner.clone());
+        let err = upgrade(&ctx, &[], false).await.unwrap_err();
+        assert!(err.to_string().contains("use --all"));
+        assert!(matches!(
+            upgrade(&ctx, &["ruff".to_string()], true).await,
+            Err(PylotError::Other(_))
+        ));
+        upgrade(&ctx, &["ruff".to_string()], false).await.unwrap();
+        assert_eq!(runner.command_lines(), ["uv tool upgrade ruff"]);
+    }
+
+    #[tokio::test]
+    async fn test_commands_run_through_context_runner() {
+        use crate::processes::{CommandOutput, RecordingRunner};
+        use std::sync::Arc;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let runner =
+            Arc::new(RecordingRunner::new().respond("tool list", CommandOutput::ok(LIST_OUTPUT)));
+        let ctx = Pylot::default().with_runner(runner.clone());
+        assert_eq!(list(&ctx).await.unwrap().len(), 2);
+        upgrade(&ctx, &[], true).await.unwrap();
+        install(&ctx, "ruff==0.3.0").await.unwrap();
+        assert_eq!(
+            runner.command_lines(),
+            [
+                "uv tool list",
+                "uv tool upgrade --all",
+                "uv tool install ruff==0.3.0"
+            ]
+        );
+
+        let failing =
+            RecordingRunner::new().respond("tool list", CommandOutput::failed(2, "error: boom"));
+        let ctx = Pylot::default().with_runner(Arc::new(failing));
+        assert!(matches!(
+            list(&ctx).await,
+            Err(PylotError::CommandExecution(_))
+        ));
+    }
+
+    // ── table ────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_print_tool_table() {
+        let mut buf = Vec::new();
+        print_tool_table_to(&mut buf, &parse_list(LIST_OUTPUT));
+        let out = String::from_utf8(buf).unwrap();
+        assert!(out.contains("Tool"));
+        assert!(out.contains("24.2.0"));
+        assert!(out.contains("black, blackd"));
+    }
+}
@@ -1,3 +1,10 @@
+pub mod deptree;
+pub mod diskusage;
+pub mod distinfo;
+pub mod doctor;
+pub mod outdated;
+pub mod scancache;
 pub mod uvvenv;
 pub mod venvmanager;
+pub mod venvquery;
 pub mod venvtraits;
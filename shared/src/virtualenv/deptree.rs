@@ -0,0 +1,444 @@
+use super::distinfo::{self, normalize_name, DistInfo, Requirement};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::Path,
+};
+
+/// Dependency graph of the packages installed in one environment, built from
+/// the `Requires-Dist` entries of every `.dist-info/METADATA` file.
+///
+/// Requirements that only apply to an optional extra are ignored, as are
+/// requirements whose target is not installed but that carry an environment
+/// marker (they most likely do not apply to this interpreter).
+#[derive(Debug, Clone, Default)]
+pub struct DependencyGraph {
+    packages: BTreeMap<String, DistInfo>,
+}
+
+/// One node of a rendered dependency tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeNode {
+    /// Normalized package name.
+    pub name: String,
+    /// Installed version, or `None` when the package is required but missing.
+    pub version: Option<String>,
+    /// Version specifier on the edge from the parent node (empty for roots).
+    pub spec: String,
+    /// `true` when the package already appears among this node's ancestors;
+    /// its children are not expanded again.
+    pub cycle: bool,
+    pub children: Vec<TreeNode>,
+}
+
+/// A [`TreeNode`] flattened into a printable row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeRow {
+    /// Unique path of the node (`root/child/...`), used to track folding.
+    pub key: String,
+    /// Nesting depth, `0` for roots.
+    pub depth: usize,
+    /// Box-drawing prefix (`│   ├── `) drawn before the label.
+    pub prefix: String,
+    pub name: String,
+    pub version: Option<String>,
+    pub spec: String,
+    pub cycle: bool,
+    pub has_children: bool,
+    /// `true` when the node has children that are currently hidden.
+    pub collapsed: bool,
+}
+
+impl TreeRow {
+    /// Human-readable label, e.g. `urllib3 2.2.1 [<3,>=1.21.1]`.
+    pub fn label(&self) -> String {
+        let mut label = match &self.version {
+            Some(version) => format!("{} {}", self.name, version),
+            None => self.name.clone(),
+        };
+        if !self.spec.is_empty() {
+            label.push_str(&format!(" [{}]", self.spec));
+        }
+        if self.version.is_none() {
+            label.push_str(" (missing)");
+        }
+        if self.cycle {
+            label.push_str(" (cycle)");
+        }
+        label
+    }
+}
+
+impl DependencyGraph {
+    /// Build a graph from already parsed `.dist-info` entries.
+    pub fn new(dists: Vec<DistInfo>) -> Self {
+        DependencyGraph {
+            packages: dists.into_iter().map(|d| (d.name.clone(), d)).collect(),
+        }
+    }
+
+    /// Build a graph from the `.dist-info` directories in `site_pkgs`.
+    pub async fn load(site_pkgs: &Path) -> Self {
+        Self::new(distinfo::read_site_packages(site_pkgs).await)
+    }
+
+    /// Number of installed packages in the graph.
+    pub fn len(&self) -> usize {
+        self.packages.len()
+    }
+
+    /// Returns `true` when no packages are installed.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    /// Look up an installed package by (any spelling of) its name.
+    pub fn get(&self, name: &str) -> Option<&DistInfo> {
+        self.packages.get(&normalize_name(name))
+    }
+
+    /// Requirements of `name` that apply to this environment.
+    ///
+    /// Includes missing requirements without a marker so broken environments
+    /// are visible.
+    pub fn requirements(&self, name: &str) -> Vec<&Requirement> {
+        self.get(name)
+            .map(|dist| {
+                dist.requires
+                    .iter()
+                    .filter(|req| !req.is_extra())
+                    .filter(|req| req.marker.is_none() || self.packages.contains_key(&req.name))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Installed packages that depend on `name`, with the specifier they use.
+    pub fn dependents(&self, name: &str) -> Vec<(&DistInfo, &Requirement)> {
+        let name = normalize_name(name);
+        self.packages
+            .values()
+            .filter_map(|dist| {
+                self.requirements(&dist.name)
+                    .into_iter()
+                    .find(|req| req.name == name)
+                    .map(|req| (dist, req))
+            })
+            .collect()
+    }
+
+    /// Installed packages that no other installed package depends on, i.e.
+    /// the ones that were most likely installed explicitly.
+    pub fn roots(&self) -> Vec<&DistInfo> {
+        let required: HashSet<&str> = self
+            .packages
+            .keys()
+            .flat_map(|name| self.requirements(name))
+            .map(|req| req.name.as_str())
+            .collect();
+        self.packages
+            .values()
+            .filter(|dist| !required.contains(dist.name.as_str()))
+            .collect()
+    }
+
+    /// Build the full dependency tree, one top-level node per root.
+    ///
+    /// Packages that are only reachable through a dependency cycle are added
+    /// as extra top-level nodes so every installed package appears.
+    pub fn tree(&self) -> Vec<TreeNode> {
+        let mut nodes: Vec<TreeNode> = self
+            .roots()
+            .into_iter()
+            .map(|dist| self.forward_node(&dist.name, String::new(), &mut Vec::new()))
+            .collect();
+
+        let mut seen = HashSet::new();
+        nodes.iter().for_each(|node| collect_names(node, &mut seen));
+        for name in self.packages.keys() {
+            if !seen.contains(name) {
+                let node = self.forward_node(name, String::new(), &mut Vec::new());
+                collect_names(&node, &mut seen);
+                nodes.push(node);
+            }
+        }
+        nodes
+    }
+
+    /// Build the reverse tree for `name`: its children are the packages that
+    /// depend on it, recursively.  Returns `None` if `name` is not installed.
+    pub fn reverse_tree(&self, name: &str) -> Option<TreeNode> {
+        let dist = self.get(name)?;
+        Some(self.reverse_node(&dist.name, String::new(), &mut Vec::new()))
+    }
+
+    fn forward_node(&self, name: &str, spec: String, ancestors: &mut Vec<String>) -> TreeNode {
+        let version = self.packages.get(name).map(|d| d.version.clone());
+        let cycle = ancestors.iter().any(|a| a == name);
+        let mut node = TreeNode {
+            name: name.to_string(),
+            version,
+            spec,
+            cycle,
+            children: Vec::new(),
+        };
+        if !cycle {
+            ancestors.push(name.to_string());
+            let mut requirements = self.requirements(name);
+            requirements.sort_by(|a, b| a.name.cmp(&b.name));
+            node.children = requirements
+                .into_iter()
+                .map(|req| self.forward_node(&req.name, req.spec.clone(), ancestors))
+                .collect();
+            ancestors.pop();
+        }
+        node
+    }
+
+    fn reverse_node(&self, name: &str, spec: String, ancestors: &mut Vec<String>) -> TreeNode {
+        let version = self.packages.get(name).map(|d| d.version.clone());
+        let cycle = ancestors.iter().any(|a| a == name);
+        let mut node = TreeNode {
+            name: name.to_string(),
+            version,
+            spec,
+            cycle,
+            children: Vec::new(),
+        };
+        if !cycle {
+            ancestors.push(name.to_string());
+            node.children = self
+                .dependents(name)
+                .into_iter()
+                .map(|(dist, req)| self.reverse_node(&dist.name, req.spec.clone(), ancestors))
+                .collect();
+            ancestors.pop();
+        }
+        node
+    }
+}
+
+fn collect_names(node: &TreeNode, seen: &mut HashSet<String>) {
+    seen.insert(node.name.clone());
+    node.children
+        .iter()
+        .for_each(|child| collect_names(child, seen));
+}
+
+/// Flatten `nodes` into rows, skipping the children of any node whose key is
+/// in `collapsed`.
+pub fn flatten(nodes: &[TreeNode], collapsed: &HashSet<String>) -> Vec<TreeRow> {
+    let mut rows = Vec::new();
+    for node in nodes {
+        flatten_node(node, "", 0, "", true, collapsed, &mut rows);
+    }
+    rows
+}
+
+fn flatten_node(
+    node: &TreeNode,
+    parent_key: &str,
+    depth: usize,
+    indent: &str,
+    is_last: bool,
+    collapsed: &HashSet<String>,
+    rows: &mut Vec<TreeRow>,
+) {
+    let key = if parent_key.is_empty() {
+        node.name.clone()
+    } else {
+        format!("{}/{}", parent_key, node.name)
+    };
+    let is_collapsed = !node.children.is_empty() && collapsed.contains(&key);
+    let prefix = if depth == 0 {
+        String::new()
+    } else {
+        format!("{}{}", indent, if is_last { "└── " } else { "├── " })
+    };
+    rows.push(TreeRow {
+        key: key.clone(),
+        depth,
+        prefix,
+        name: node.name.clone(),
+        version: node.version.clone(),
+        spec: node.spec.clone(),
+        cycle: node.cycle,
+        has_children: !node.children.is_empty(),
+        collapsed: is_collapsed,
+    });
+    if is_collapsed {
+        return;
+    }
+    let child_indent = if depth == 0 {
+        String::new()
+    } else {
+        format!("{}{}", indent, if is_last { "    " } else { "│   " })
+    };
+    let count = node.children.len();
+    for (i, child) in node.children.iter().enumerate() {
+        flatten_node(
+            child,
+            &key,
+            depth + 1,
+            &child_indent,
+            i + 1 == count,
+            collapsed,
+            rows,
+        );
+    }
+}
+
+/// Render `nodes` as text lines using box-drawing characters.
+pub fn render(nodes: &[TreeNode]) -> Vec<String> {
+    flatten(nodes, &HashSet::new())
+        .into_iter()
+        .map(|row| format!("{}{}", row.prefix, row.label()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger;
+    use crate::virtualenv::distinfo::tests::write_dist_info;
+
+    fn dist(name: &str, version: &str, requires: &[&str]) -> DistInfo {
+        DistInfo {
+            name: name.to_string(),
+            version: version.to_string(),
+            requires: requires
+                .iter()
+                .filter_map(|r| Requirement::parse(r))
+                .collect(),
+            path: Default::default(),
+        }
+    }
+
+    fn sample_graph() -> DependencyGraph {
+        DependencyGraph::new(vec![
+            dist(
+                "requests",
+                "2.31.0",
+                &[
+                    "idna (<4,>=2.5)",
+                    "urllib3 (<3,>=1.21.1)",
+                    "PySocks (!=1.5.7,>=1.5.6) ; extra == 'socks'",
+                    "win-inet-pton ; sys_platform == \"win32\"",
+                ],
+            ),
+            dist("idna", "3.6", &[]),
+            dist("urllib3", "2.2.1", &[]),
+            dist("httpx", "0.27.0", &["idna", "sniffio"]),
+        ])
+    }
+
+    #[test]
+    fn test_requirements_skip_extras_and_inapplicable_markers() {
+        let graph = sample_graph();
+        let names: Vec<&str> = graph
+            .requirements("requests")
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["idna", "urllib3"]);
+    }
+
+    #[test]
+    fn test_roots() {
+        let graph = sample_graph();
+        let roots: Vec<&str> = graph.roots().iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(roots, vec!["httpx", "requests"]);
+    }
+
+    #[test]
+    fn test_dependents() {
+        let graph = sample_graph();
+        let dependents: Vec<&str> = graph
+            .dependents("IDNA")
+            .iter()
+            .map(|(d, _)| d.name.as_str())
+            .collect();
+        assert_eq!(dependents, vec!["httpx", "requests"]);
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let graph = sample_graph();
+        let lines = render(&graph.tree());
+        assert_eq!(
+            lines,
+            vec![
+                "httpx 0.27.0",
+                "├── idna 3.6",
+                "└── sniffio (missing)",
+                "requests 2.31.0",
+                "├── idna 3.6 [<4,>=2.5]",
+                "└── urllib3 2.2.1 [<3,>=1.21.1]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_reverse_tree() {
+        let graph = sample_graph();
+        let node = graph.reverse_tree("idna").unwrap();
+        let lines = render(&[node]);
+        assert_eq!(
+            lines,
+            vec![
+                "idna 3.6",
+                "├── httpx 0.27.0",
+                "└── requests 2.31.0 [<4,>=2.5]"
+            ]
+        );
+        assert!(graph.reverse_tree("missing").is_none());
+    }
+
+    #[test]
+    fn test_cycle_is_marked_and_included() {
+        let graph = DependencyGraph::new(vec![dist("a", "1.0", &["b"]), dist("b", "1.0", &["a"])]);
+        let lines = render(&graph.tree());
+        assert_eq!(lines, vec!["a 1.0", "└── b 1.0", "    └── a 1.0 (cycle)"]);
+    }
+
+    #[test]
+    fn test_flatten_collapsed() {
+        let graph = sample_graph();
+        let collapsed: HashSet<String> = ["requests".to_string()].into_iter().collect();
+        let rows = flatten(&graph.tree(), &collapsed);
+        assert_eq!(rows.len(), 4);
+        let requests = rows.iter().find(|r| r.key == "requests").unwrap();
+        assert!(requests.collapsed);
+        assert!(requests.has_children);
+        assert!(rows.iter().any(|r| r.key == "httpx/idna" && r.depth == 1));
+    }
+
+    #[test]
+    fn test_nested_prefixes() {
+        let graph = DependencyGraph::new(vec![
+            dist("app", "1.0", &["lib", "util"]),
+            dist("lib", "1.0", &["core"]),
+            dist("core", "1.0", &[]),
+            dist("util", "1.0", &[]),
+        ]);
+        let lines = render(&graph.tree());
+        assert_eq!(
+            lines,
+            vec!["app 1.0", "├── lib 1.0", "│   └── core 1.0", "└── util 1.0",]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_from_site_packages() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        write_dist_info(tmp.path(), "flask", "3.0.0", &["Werkzeug>=3.0.0"]).await;
+        write_dist_info(tmp.path(), "werkzeug", "3.0.1", &[]).await;
+
+        let graph = DependencyGraph::load(tmp.path()).await;
+        assert_eq!(graph.len(), 2);
+        assert_eq!(
+            render(&graph.tree()),
+            vec!["flask 3.0.0", "└── werkzeug 3.0.1 [>=3.0.0]"]
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{
+    error::{PylotError, Result},
+    utils::format_size,
+};
+use comfy_table::{
+    modifiers::UTF8_SOLID_INNER_BORDERS, presets::UTF8_FULL, CellAlignment, ContentArrangement,
+    Table,
+};
+use std::{
+    io::Write,
+    path::{Component, Path},
+    str::FromStr,
+};
+use tokio::fs as async_fs;
+
+/// Disk usage of a virtual environment, broken down by area.
+///
+/// Files hard-linked from the uv cache are counted in full, so the numbers
+/// describe what deleting the environment would show in `du`, not how much
+/// space it frees.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct DiskUsage {
+    /// Bytes below `site-packages`, excluding bytecode caches.
+    pub site_packages: u64,
+    /// Bytes in `bin` (or `Scripts` on Windows).
+    pub bin: u64,
+    /// Bytes in `__pycache__` directories.
+    pub cache: u64,
+    /// Everything else, e.g. `pyvenv.cfg` and `include`.
+    pub other: u64,
+}
+
+impl DiskUsage {
+    /// Total size of the environment in bytes.
+    pub fn total(&self) -> u64 {
+        self.site_packages + self.bin + self.cache + self.other
+    }
+
+    /// Add the size of the file at `relative` (relative to the venv root).
+    fn add(&mut self, relative: &Path, len: u64) {
+        let mut components = relative.components().filter_map(|c| match c {
+            Component::Normal(part) => Some(part.to_string_lossy()),
+            _ => None,
+        });
+        let first = components.next().unwrap_or_default();
+        let rest: Vec<_> = components.collect();
+        if first == "__pycache__" || rest.iter().any(|c| c == "__pycache__") {
+            self.cache += len;
+        } else if first == "bin" || first == "Scripts" {
+            self.bin += len;
+        } else if rest.iter().any(|c| c == "site-packages") {
+            self.site_packages += len;
+        } else {
+            self.other += len;
+        }
+    }
+}
+
+/// Walk `venv_path` and add up the size of every file below it.
+///
+/// Symlinks are not followed, so the interpreter link in `bin` counts as the
+/// size of the link itself.  Unreadable entries are skipped.
+pub async fn measure(venv_path: &Path) -> DiskUsage {
+    let mut usage = DiskUsage::default();
+    let mut pending = vec![venv_path.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(mut entries) = async_fs::read_dir(&dir).await else {
+            continue;
+        };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            let Ok(meta) = async_fs::symlink_metadata(&path).await else {
+                continue;
+            };
+            if meta.is_dir() {
+                pending.push(path);
+            } else if let Ok(relative) = path.strip_prefix(venv_path) {
+                usage.add(relative, meta.len());
+            }
+        }
+    }
+    usage
+}
+
+/// Column used to order the rows of the disk usage report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SizeSort {
+    /// Alphabetical by environment name.
+    Name,
+    /// Largest total first.
+    #[default]
+    Size,
+    /// Largest `site-packages` first.
+    SitePackages,
+    /// Largest `bin` first.
+    Bin,
+    /// Largest bytecode cache first.
+    Cache,
+}
+
+impl SizeSort {
+    /// Accepted values, as used on the command line.
+    pub const VALUES: [&'static str; 5] = ["name", "size", "site-packages", "bin", "cache"];
+
+    /// Sort `entries` in place by this column.
+    pub fn sort(self, entries: &mut [(String, DiskUsage)]) {
+        entries.sort_by(|(a_name, a), (b_name, b)| {
+            let by_size = |f: fn(&DiskUsage) -> u64| f(b).cmp(&f(a));
+            match self {
+                SizeSort::Name => a_name.cmp(b_name),
+                SizeSort::Size => by_size(DiskUsage::total),
+                SizeSort::SitePackages => by_size(|u| u.site_packages),
+                SizeSort::Bin => by_size(|u| u.bin),
+                SizeSort::Cache => by_size(|u| u.cache),
+            }
+            .then_with(|| a_name.cmp(b_name))
+        });
+    }
+}
+
+impl FromStr for SizeSort {
+    type Err = PylotError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "name" => Ok(SizeSort::Name),
+            "size" | "total" => Ok(SizeSort::Size),
+            "site-packages" => Ok(SizeSort::SitePackages),
+            "bin" => Ok(SizeSort::Bin),
+            "cache" => Ok(SizeSort::Cache),
+            other => Err(PylotError::Other(format!(
+                "Unknown sort column '{}', expected one of: {}",
+                other,
+                SizeSort::VALUES.join(", ")
+            ))),
+        }
+    }
+}
+
+/// Print a disk usage table with one row per environment.
+///
+/// A total row is added when more than one environment is listed.
+pub fn print_disk_usage_table_to<W: Write>(writer: &mut W, entries: &[(String, DiskUsage)]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_SOLID_INNER_BORDERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            "Name",
+            "Site-packages",
+            "Bin",
+            "Cache",
+            "Other",
+            "Total",
+        ]);
+    let mut sum = DiskUsage::default();
+    for (name, usage) in entries {
+        table.add_row(usage_row(name, usage));
+        sum.site_packages += usage.site_packages;
+        sum.bin += usage.bin;
+        sum.cache += usage.cache;
+        sum.other += usage.other;
+    }
+    if entries.len() > 1 {
+        table.add_row(usage_row("Total", &sum));
+    }
+    for index in 1..=5 {
+        if let Some(column) = table.column_mut(index) {
+            column.set_cell_alignment(CellAlignment::Right);
+        }
+    }
+    let _ = writeln!(writer, "{}", table);
+}
+
+fn usage_row(name: &str, usage: &DiskUsage) -> Vec<String> {
+    vec![
+        name.to_string(),
+        format_size(usage.site_packages),
+        format_size(usage.bin),
+        format_size(usage.cache),
+        format_size(usage.other),
+        format_size(usage.total()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger;
+
+    async fn write_file(path: &Path, len: usize) {
+        async_fs::create_dir_all(path.parent().unwrap())
+            .await
+            .unwrap();
+        async_fs::write(path, vec![b'x'; len]).await.unwrap();
+    }
+
+    fn usage(site_packages: u64, bin: u64, cache: u64) -> DiskUsage {
+        DiskUsage {
+            site_packages,
+            bin,
+            cache,
+            other: 0,
+        }
+    }
+
+    // ── measure ──────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_measure_breakdown() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let site = tmp.path().join("lib/python3.12/site-packages");
+        write_file(&site.join("requests/__init__.py"), 100).await;
+        write_file(&site.join("requests/__pycache__/__init__.pyc"), 40).await;
+        write_file(&tmp.path().join("bin/activate"), 10).await;
+        write_file(&tmp.path().join("pyvenv.cfg"), 5).await;
+
+        let usage = measure(tmp.path()).await;
+        assert_eq!(usage.site_packages, 100);
+        assert_eq!(usage.cache, 40);
+        assert_eq!(usage.bin, 10);
+        assert_eq!(usage.other, 5);
+        assert_eq!(usage.total(), 155);
+    }
+
+    #[tokio::test]
+    async fn test_measure_windows_layout() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        write_file(&tmp.path().join("Lib/site-packages/six.py"), 30).await;
+        write_file(&tmp.path().join("Scripts/python.exe"), 20).await;
+
+        let usage = measure(tmp.path()).await;
+        assert_eq!(usage.site_packages, 30);
+        assert_eq!(usage.bin, 20);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_measure_does_not_follow_symlinks() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let target = tempfile::tempdir().unwrap();
+        write_file(&target.path().join("python3.12"), 10_000).await;
+        async_fs::create_dir_all(tmp.path().join("bin"))
+            .await
+            .unwrap();
+        std::os::unix::fs::symlink(
+            target.path().join("python3.12"),
+            tmp.path().join("bin/python"),
+        )
+        .unwrap();
+
+        let usage = measure(tmp.path()).await;
+        assert!(usage.bin < 10_000);
+    }
+
+    #[tokio::test]
+    async fn test_measure_missing_dir() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        assert_eq!(
+            measure(Path::new("/nonexistent/venv")).await,
+            DiskUsage::default()
+        );
+    }
+
+    // ── SizeSort ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_size_sort_from_str() {
+        assert_eq!("name".parse::<SizeSort>().unwrap(), SizeSort::Name);
+        assert_eq!("SIZE".parse::<SizeSort>().unwrap(), SizeSort::Size);
+        assert_eq!(
+            "site-packages".parse::<SizeSort>().unwrap(),
+            SizeSort::SitePackages
+        );
+        assert!("bogus".parse::<SizeSort>().is_err());
+        for value in SizeSort::VALUES {
+            assert!(value.parse::<SizeSort>().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_size_sort_orders_entries() {
+        let mut entries = vec![
+            ("b".to_string(), usage(10, 5, 0)),
+            ("a".to_string(), usage(1, 1, 50)),
+            ("c".to_string(), usage(30, 0, 0)),
+        ];
+        SizeSort::Size.sort(&mut entries);
+        assert_eq!(entries[0].0, "a");
+        SizeSort::SitePackages.sort(&mut entries);
+        assert_eq!(entries[0].0, "c");
+        SizeSort::Bin.sort(&mut entries);
+        assert_eq!(entries[0].0, "b");
+        SizeSort::Name.sort(&mut entries);
+        let names: Vec<_> = entries.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+    }
+
+    // ── print_disk_usage_table_to ────────────────────────────────────────────
+
+    #[test]
+    fn test_print_disk_usage_table() {
+        let entries = vec![
+            ("env1".to_string(), usage(2048, 512, 0)),
+            ("env2".to_string(), usage(1024, 0, 0)),
+        ];
+        let mut output = Vec::new();
+        print_disk_usage_table_to(&mut output, &entries);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Site-packages"));
+        assert!(output.contains("env1"));
+        assert!(output.contains("2.5 KiB"));
+        assert!(output.contains("Total"));
+        assert!(output.contains("3.5 KiB"));
+    }
+
+    #[test]
+    fn test_print_disk_usage_table_single_has_no_total_row() {
+        let entries = vec![("env1".to_string(), usage(1, 0, 0))];
+        let mut output = Vec::new();
+        print_disk_usage_table_to(&mut output, &entries);
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches("Total").count(), 1);
+    }
+}
@@ -0,0 +1,633 @@
+use std::path::{Path, PathBuf};
+use tokio::fs as async_fs;
+
+/// A single `Requires-Dist` entry from a package's `METADATA` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Requirement {
+    /// Normalized name of the required package.
+    pub name: String,
+    /// Version specifier, e.g. `">=2.0,<3"`.  Empty when unconstrained.
+    pub spec: String,
+    /// Environment marker following the `;`, if any.
+    pub marker: Option<String>,
+}
+
+impl Requirement {
+    /// Parse a `Requires-Dist` value such as
+    /// `charset-normalizer (<4,>=2)` or `PySocks!=1.5.7,>=1.5.6; extra == "socks"`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let (req, marker) = match value.split_once(';') {
+            Some((req, marker)) => (req.trim(), Some(marker.trim().to_string())),
+            None => (value.trim(), None),
+        };
+        let name_end = req
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+            .unwrap_or(req.len());
+        if name_end == 0 {
+            return None;
+        }
+        let mut rest = req[name_end..].trim();
+        // Skip extras, e.g. `requests[socks]`.
+        if rest.starts_with('[') {
+            rest = rest
+                .find(']')
+                .map(|end| rest[end + 1..].trim())
+                .unwrap_or("");
+        }
+        let spec = rest
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .replace(' ', "");
+        Some(Requirement {
+            name: normalize_name(&req[..name_end]),
+            spec,
+            marker: marker.filter(|m| !m.is_empty()),
+        })
+    }
+
+    /// Returns `true` when the requirement only applies to an optional extra.
+    pub fn is_extra(&self) -> bool {
+        self.marker
+            .as_deref()
+            .map(|m| m.replace(' ', "").contains("extra=="))
+            .unwrap_or(false)
+    }
+}
+
+/// Metadata read from one `.dist-info` directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistInfo {
+    /// Normalized package name.
+    pub name: String,
+    /// Installed version.
+    pub version: String,
+    /// Declared dependencies (`Requires-Dist`).
+    pub requires: Vec<Requirement>,
+    /// Path of the `.dist-info` directory.
+    pub path: PathBuf,
+}
+
+impl DistInfo {
+    /// Read `METADATA` from the `.dist-info` directory at `path`.
+    ///
+    /// Falls back to the directory name for the name and version when the file
+    /// is missing or incomplete.
+    pub async fn read(path: &Path) -> Self {
+        let dir_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let (dir_pkg, dir_version) = dir_name
+            .trim_end_matches(".dist-info")
+            .rsplit_once('-')
+            .map(|(n, v)| (n.to_string(), v.to_string()))
+            .unwrap_or_else(|| {
+                (
+                    dir_name.trim_end_matches(".dist-info").to_string(),
+                    String::new(),
+                )
+            });
+
+        let text = async_fs::read_to_string(path.join("METADATA"))
+            .await
+            .unwrap_or_default();
+        let headers = parse_headers(&text);
+        let field = |key: &str| {
+            headers
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(key))
+                .map(|(_, v)| v.clone())
+        };
+
+        DistInfo {
+            name: normalize_name(&field("Name").unwrap_or(dir_pkg)),
+            version: field("Version").unwrap_or(dir_version),
+            requires: headers
+                .iter()
+                .filter(|(k, _)| k.eq_ignore_ascii_case("Requires-Dist"))
+                .filter_map(|(_, v)| Requirement::parse(v))
+                .collect(),
+            path: path.to_path_buf(),
+        }
+    }
+}
+
+/// An entry from a package's `entry_points.txt`, e.g. a console script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryPoint {
+    /// Section the entry belongs to, e.g. `console_scripts`.
+    pub group: String,
+    /// Name of the entry point, e.g. the script name.
+    pub name: String,
+    /// Object reference, e.g. `black:patched_main`.
+    pub value: String,
+}
+
+impl EntryPoint {
+    /// Returns `true` for entries that install an executable script.
+    pub fn is_script(&self) -> bool {
+        matches!(self.group.as_str(), "console_scripts" | "gui_scripts")
+    }
+}
+
+/// Descriptive metadata of an installed package, read from the files of its
+/// `.dist-info` directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageDetails {
+    /// Normalized package name.
+    pub name: String,
+    /// Installed version.
+    pub version: String,
+    /// One-line `Summary` from `METADATA`.
+    pub summary: Option<String>,
+    /// License expression, `License` field or license classifier.
+    pub license: Option<String>,
+    /// `Home-page`, or the homepage/source entry of `Project-URL`.
+    pub homepage: Option<String>,
+    /// Tool that installed the package (`INSTALLER`), e.g. `uv` or `pip`.
+    pub installer: Option<String>,
+    /// `true` when the package was installed explicitly (`REQUESTED` exists)
+    /// rather than pulled in as a dependency.
+    pub requested: bool,
+    /// Size on disk of the files listed in `RECORD`, if it exists.
+    pub size: Option<u64>,
+    /// Entry points declared in `entry_points.txt`.
+    pub entry_points: Vec<EntryPoint>,
+}
+
+impl PackageDetails {
+    /// Names of the executable scripts the package installs.
+    pub fn scripts(&self) -> Vec<&str> {
+        self.entry_points
+            .iter()
+            .filter(|ep| ep.is_script())
+            .map(|ep| ep.name.as_str())
+            .collect()
+    }
+}
+
+impl DistInfo {
+    /// Read the descriptive metadata of this package.
+    ///
+    /// Missing files leave the corresponding fields empty.
+    pub async fn details(&self) -> PackageDetails {
+        let text = async_fs::read_to_string(self.path.join("METADATA"))
+            .await
+            .unwrap_or_default();
+        let headers = parse_headers(&text);
+        let field = |key: &str| {
+            headers
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(key))
+                .map(|(_, v)| v.clone())
+                .filter(|v| !v.is_empty() && v != "UNKNOWN")
+        };
+        let license = field("License-Expression")
+            // Some packages put the whole license text in `License`.
+            .or_else(|| field("License").filter(|l| l.len() <= 40))
+            .or_else(|| {
+                header_values(&headers, "Classifier")
+                    .filter(|c| c.starts_with("License ::"))
+                    .filter_map(|c| c.rsplit("::").next())
+                    .map(|c| c.trim().to_string())
+                    .next()
+            });
+        let homepage = field("Home-page").or_else(|| {
+            let urls: Vec<(&str, &str)> = header_values(&headers, "Project-URL")
+                .filter_map(|v| v.split_once(','))
+                .map(|(label, url)| (label.trim(), url.trim()))
+                .collect();
+            ["homepage", "home", "source", "repository"]
+                .iter()
+                .find_map(|want| {
+                    urls.iter()
+                        .find(|(label, _)| label.eq_ignore_ascii_case(want))
+                        .map(|(_, url)| url.to_string())
+                })
+                .or_else(|| urls.first().map(|(_, url)| url.to_string()))
+        });
+        let installer = async_fs::read_to_string(self.path.join("INSTALLER"))
+            .await
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        let requested = async_fs::metadata(self.path.join("REQUESTED"))
+            .await
+            .is_ok();
+        let entry_points = async_fs::read_to_string(self.path.join("entry_points.txt"))
+            .await
+            .map(|text| parse_entry_points(&text))
+            .unwrap_or_default();
+
+        PackageDetails {
+            name: self.name.clone(),
+            version: self.version.clone(),
+            summary: field("Summary"),
+            license,
+            homepage,
+            installer,
+            requested,
+            size: self.record_size().await,
+            entry_points,
+        }
+    }
+
+    /// Sum the sizes of the files listed in `RECORD`.
+    ///
+    /// Entries without a recorded size (such as `RECORD` itself) are measured
+    /// on disk.  Returns `None` when `RECORD` is missing.
+    pub async fn record_size(&self) -> Option<u64> {
+        let text = async_fs::read_to_string(self.path.join("RECORD"))
+            .await
+            .ok()?;
+        let base = self.path.parent().unwrap_or(&self.path);
+        let mut total = 0;
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            let fields = split_record_line(line);
+            match fields.get(2).and_then(|size| size.parse::<u64>().ok()) {
+                Some(size) => total += size,
+                None => {
+                    if let Ok(meta) = async_fs::metadata(base.join(&fields[0])).await {
+                        total += meta.len();
+                    }
+                }
+            }
+        }
+        Some(total)
+    }
+}
+
+/// Find the `.dist-info` directory of `package` inside `site_pkgs` and read
+/// its `METADATA`.  The name is compared after PEP 503 normalization.
+pub async fn find_dist_info(site_pkgs: &Path, package: &str) -> Option<DistInfo> {
+    let wanted = normalize_name(package);
+    let mut entries = async_fs::read_dir(site_pkgs).await.ok()?;
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some(base) = file_name.strip_suffix(".dist-info") else {
+            continue;
+        };
+        let dir_pkg = base.rsplit_once('-').map(|(n, _)| n).unwrap_or(base);
+        if normalize_name(dir_pkg) == wanted {
+            return Some(DistInfo::read(&entry.path()).await);
+        }
+    }
+    None
+}
+
+/// All values of the header `key`, compared case-insensitively.
+fn header_values<'a>(
+    headers: &'a [(String, String)],
+    key: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    headers
+        .iter()
+        .filter(move |(k, _)| k.eq_ignore_ascii_case(key))
+        .map(|(_, v)| v.as_str())
+}
+
+/// Parse the INI style `entry_points.txt` file.
+pub fn parse_entry_points(text: &str) -> Vec<EntryPoint> {
+    let mut entry_points = Vec::new();
+    let mut group = String::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with(['#', ';']) {
+            continue;
+        }
+        if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            group = section.trim().to_string();
+        } else if let Some((name, value)) = line.split_once('=') {
+            entry_points.push(EntryPoint {
+                group: group.clone(),
+                name: name.trim().to_string(),
+                value: value.trim().to_string(),
+            });
+        }
+    }
+    entry_points
+}
+
+/// Split one CSV line of a `RECORD` file, honouring double-quoted fields.
+fn split_record_line(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+/// Read every `.dist-info` directory inside `site_pkgs`, sorted by name.
+///
+/// Returns an empty list if the directory cannot be read.
+pub async fn read_site_packages(site_pkgs: &Path) -> Vec<DistInfo> {
+    let mut dists = Vec::new();
+    let Ok(mut entries) = async_fs::read_dir(site_pkgs).await else {
+        return dists;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let is_dist_info = entry.file_name().to_string_lossy().ends_with(".dist-info");
+        let is_dir = entry.file_type().await.map(|t| t.is_dir()).unwrap_or(false);
+        if is_dist_info && is_dir {
+            dists.push(DistInfo::read(&entry.path()).await);
+        }
+    }
+    dists.sort_by(|a, b| a.name.cmp(&b.name));
+    dists
+}
+
+/// Parse the RFC 822 style header block of a `METADATA` file.
+///
+/// Parsing stops at the first blank line (the long description body).
+/// Continuation lines are appended to the previous value.
+pub fn parse_headers(text: &str) -> Vec<(String, String)> {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            headers.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    headers
+}
+
+/// Normalize a project name as described in PEP 503.
+pub fn normalize_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    let mut prev_sep = false;
+    for c in name.trim().chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !prev_sep {
+                normalized.push('-');
+            }
+            prev_sep = true;
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+            prev_sep = false;
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::logger;
+
+    /// Create `{site_pkgs}/{name}-{version}.dist-info/METADATA` declaring `requires`.
+    pub(crate) async fn write_dist_info(
+        site_pkgs: &Path,
+        name: &str,
+        version: &str,
+        requires: &[&str],
+    ) -> PathBuf {
+        let dir = site_pkgs.join(format!("{}-{}.dist-info", name.replace('-', "_"), version));
+        async_fs::create_dir_all(&dir).await.unwrap();
+        let mut metadata = format!(
+            "Metadata-Version: 2.1\nName: {}\nVersion: {}\n",
+            name, version
+        );
+        for req in requires {
+            metadata.push_str(&format!("Requires-Dist: {}\n", req));
+        }
+        metadata.push_str("\nLong description body\nName: not-a-header\n");
+        async_fs::write(dir.join("METADATA"), metadata)
+            .await
+            .unwrap();
+        dir
+    }
+
+    /// Add `file` with `contents` to the `.dist-info` directory at `dir`.
+    async fn write_file(dir: &Path, file: &str, contents: &str) {
+        async_fs::write(dir.join(file), contents).await.unwrap();
+    }
+
+    // ── Requirement::parse ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_requirement_parse_parenthesized_spec() {
+        let req = Requirement::parse("charset-normalizer (<4,>=2)").unwrap();
+        assert_eq!(req.name, "charset-normalizer");
+        assert_eq!(req.spec, "<4,>=2");
+        assert!(req.marker.is_none());
+    }
+
+    #[test]
+    fn test_requirement_parse_extra_marker() {
+        let req = Requirement::parse("PySocks!=1.5.7,>=1.5.6; extra == \"socks\"").unwrap();
+        assert_eq!(req.name, "pysocks");
+        assert_eq!(req.spec, "!=1.5.7,>=1.5.6");
+        assert!(req.is_extra());
+    }
+
+    #[test]
+    fn test_requirement_parse_with_extras_and_marker() {
+        let req = Requirement::parse("uvicorn[standard]>=0.20; python_version >= \"3.8\"").unwrap();
+        assert_eq!(req.name, "uvicorn");
+        assert_eq!(req.spec, ">=0.20");
+        assert_eq!(req.marker.as_deref(), Some("python_version >= \"3.8\""));
+        assert!(!req.is_extra());
+    }
+
+    #[test]
+    fn test_requirement_parse_bare_name() {
+        let req = Requirement::parse("idna").unwrap();
+        assert_eq!(req.name, "idna");
+        assert!(req.spec.is_empty());
+    }
+
+    #[test]
+    fn test_requirement_parse_empty() {
+        assert!(Requirement::parse("").is_none());
+        assert!(Requirement::parse(">=1.0").is_none());
+    }
+
+    // ── parse_headers ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_headers_stops_at_body_and_joins_continuations() {
+        let text = "Name: demo\nLicense: MIT\n  with extra terms\n\nBody: ignored\n";
+        let headers = parse_headers(text);
+        assert_eq!(
+            headers,
+            vec![
+                ("Name".to_string(), "demo".to_string()),
+                ("License".to_string(), "MIT with extra terms".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("Foo_Bar.baz"), "foo-bar-baz");
+        assert_eq!(normalize_name("a__b"), "a-b");
+    }
+
+    // ── read_site_packages ───────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_read_site_packages() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        write_dist_info(tmp.path(), "requests", "2.31.0", &["idna (<4,>=2.5)"]).await;
+        write_dist_info(tmp.path(), "idna", "3.6", &[]).await;
+        async_fs::create_dir_all(tmp.path().join("requests"))
+            .await
+            .unwrap();
+
+        let dists = read_site_packages(tmp.path()).await;
+        assert_eq!(dists.len(), 2);
+        assert_eq!(dists[0].name, "idna");
+        assert_eq!(dists[1].name, "requests");
+        assert_eq!(dists[1].version, "2.31.0");
+        assert_eq!(dists[1].requires[0].name, "idna");
+    }
+
+    #[tokio::test]
+    async fn test_dist_info_without_metadata_uses_dir_name() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("My_Pkg-1.0.0.dist-info");
+        async_fs::create_dir_all(&dir).await.unwrap();
+        let dist = DistInfo::read(&dir).await;
+        assert_eq!(dist.name, "my-pkg");
+        assert_eq!(dist.version, "1.0.0");
+        assert!(dist.requires.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_read_site_packages_missing_dir() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        assert!(read_site_packages(Path::new("/nonexistent/site-packages"))
+            .await
+            .is_empty());
+    }
+
+    // ── PackageDetails ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_entry_points() {
+        let text = "[console_scripts]\nblack = black:patched_main\n\n# comment\n[pytest11]\nplugin = pkg.plugin\n";
+        let eps = parse_entry_points(text);
+        assert_eq!(eps.len(), 2);
+        assert_eq!(eps[0].group, "console_scripts");
+        assert_eq!(eps[0].name, "black");
+        assert_eq!(eps[0].value, "black:patched_main");
+        assert!(eps[0].is_script());
+        assert!(!eps[1].is_script());
+    }
+
+    #[test]
+    fn test_split_record_line_quoted() {
+        assert_eq!(
+            split_record_line("\"a,b.py\",sha256=x,12"),
+            vec!["a,b.py", "sha256=x", "12"]
+        );
+        assert_eq!(split_record_line("RECORD,,"), vec!["RECORD", "", ""]);
+    }
+
+    #[tokio::test]
+    async fn test_details_reads_all_files() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = write_dist_info(tmp.path(), "black", "24.1.0", &[]).await;
+        write_file(
+            &dir,
+            "METADATA",
+            "Name: black\nVersion: 24.1.0\nSummary: The uncompromising code formatter.\n\
+             License: MIT\nProject-URL: Changelog, https://example.org/changes\n\
+             Project-URL: Homepage, https://example.org/black\n",
+        )
+        .await;
+        write_file(&dir, "INSTALLER", "uv\n").await;
+        write_file(&dir, "REQUESTED", "").await;
+        write_file(
+            &dir,
+            "entry_points.txt",
+            "[console_scripts]\nblack = black:main\n",
+        )
+        .await;
+        write_file(&dir, "black.py", "x = 1\n").await;
+        write_file(
+            &dir,
+            "RECORD",
+            "black-24.1.0.dist-info/black.py,sha256=abc,6\nblack-24.1.0.dist-info/METADATA,,\nblack-24.1.0.dist-info/RECORD,,\n",
+        )
+        .await;
+
+        let details = DistInfo::read(&dir).await.details().await;
+        assert_eq!(details.name, "black");
+        assert_eq!(details.version, "24.1.0");
+        assert_eq!(
+            details.summary.as_deref(),
+            Some("The uncompromising code formatter.")
+        );
+        assert_eq!(details.license.as_deref(), Some("MIT"));
+        assert_eq!(
+            details.homepage.as_deref(),
+            Some("https://example.org/black")
+        );
+        assert_eq!(details.installer.as_deref(), Some("uv"));
+        assert!(details.requested);
+        assert_eq!(details.scripts(), vec!["black"]);
+        let metadata_len = async_fs::metadata(dir.join("METADATA"))
+            .await
+            .unwrap()
+            .len();
+        let record_len = async_fs::metadata(dir.join("RECORD")).await.unwrap().len();
+        assert_eq!(details.size, Some(6 + metadata_len + record_len));
+    }
+
+    #[tokio::test]
+    async fn test_details_license_classifier_and_missing_files() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = write_dist_info(tmp.path(), "idna", "3.6", &[]).await;
+        write_file(
+            &dir,
+            "METADATA",
+            "Name: idna\nVersion: 3.6\nHome-page: UNKNOWN\n\
+             Classifier: License :: OSI Approved :: BSD License\n",
+        )
+        .await;
+
+        let details = DistInfo::read(&dir).await.details().await;
+        assert_eq!(details.license.as_deref(), Some("BSD License"));
+        assert!(details.summary.is_none());
+        assert!(details.homepage.is_none());
+        assert!(details.installer.is_none());
+        assert!(!details.requested);
+        assert!(details.size.is_none());
+        assert!(details.entry_points.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_dist_info() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        write_dist_info(tmp.path(), "charset-normalizer", "3.3.2", &[]).await;
+        let dist = find_dist_info(tmp.path(), "Charset_Normalizer")
+            .await
+            .unwrap();
+        assert_eq!(dist.name, "charset-normalizer");
+        assert!(find_dist_info(tmp.path(), "requests").await.is_none());
+    }
+}
@@ -0,0 +1,675 @@
+use super::uvvenv::UvVenv;
+use crate::{
+    constants::{UNIX_PYTHON_EXEC, WIN_PYTHON_EXEC},
+    error::{PylotError, Result},
+    processes::{CommandRunner, CommandSpec},
+};
+use comfy_table::{
+    modifiers::UTF8_SOLID_INNER_BORDERS, presets::UTF8_FULL, ContentArrangement, Table,
+};
+use std::{
+    fmt,
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::fs as async_fs;
+
+/// How long to wait for the interpreter to report its version.
+const INTERPRETER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How serious a problem found by [`diagnose`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The environment works but something is off.
+    Warning,
+    /// The environment cannot be used as is.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A problem found in a virtual environment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// `pyvenv.cfg` is missing.
+    MissingConfig,
+    /// The `home` directory in `pyvenv.cfg` no longer exists.
+    DanglingHome { home: String },
+    /// The venv interpreter is missing, or is a symlink to a missing target.
+    MissingInterpreter {
+        path: PathBuf,
+        target: Option<PathBuf>,
+    },
+    /// The interpreter reports a different version than `pyvenv.cfg`.
+    VersionMismatch { recorded: String, actual: String },
+    /// An activate script still points at the environment's old location.
+    StaleActivatePath { script: PathBuf, recorded: String },
+    /// A `.dist-info` directory has no `RECORD`, so the package cannot be
+    /// uninstalled cleanly.
+    MissingRecord { package: String, version: String },
+}
+
+impl Issue {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Issue::MissingConfig
+            | Issue::DanglingHome { .. }
+            | Issue::MissingInterpreter { .. } => Severity::Error,
+            Issue::VersionMismatch { recorded, actual } => {
+                if major_minor(recorded) == major_minor(actual) {
+                    Severity::Warning
+                } else {
+                    Severity::Error
+                }
+            }
+            Issue::StaleActivatePath { .. } | Issue::MissingRecord { .. } => Severity::Warning,
+        }
+    }
+
+    /// Returns `true` when the only repair is recreating the environment.
+    pub fn needs_rebuild(&self) -> bool {
+        match self {
+            Issue::VersionMismatch { .. } => self.severity() == Severity::Error,
+            other => other.severity() == Severity::Error,
+        }
+    }
+
+    /// Suggested way to fix the problem.
+    pub fn suggestion(&self) -> String {
+        match self {
+            Issue::VersionMismatch { .. } if !self.needs_rebuild() => {
+                "Update the version in pyvenv.cfg".to_string()
+            }
+            Issue::StaleActivatePath { .. } => {
+                "Rewrite the path in the activate script".to_string()
+            }
+            Issue::MissingRecord { package, version } => {
+                format!(
+                    "Reinstall with 'uv pip install --reinstall {}=={}'",
+                    package, version
+                )
+            }
+            _ => "Rebuild the environment and reinstall its packages".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::MissingConfig => write!(f, "pyvenv.cfg is missing"),
+            Issue::DanglingHome { home } => {
+                write!(f, "Base interpreter directory '{}' does not exist", home)
+            }
+            Issue::MissingInterpreter {
+                path,
+                target: Some(target),
+            } => write!(
+                f,
+                "'{}' points to missing '{}'",
+                path.display(),
+                target.display()
+            ),
+            Issue::MissingInterpreter { path, target: None } => {
+                write!(f, "Interpreter '{}' is missing", path.display())
+            }
+            Issue::VersionMismatch { recorded, actual } => write!(
+                f,
+                "pyvenv.cfg records Python {} but the interpreter is {}",
+                recorded, actual
+            ),
+            Issue::StaleActivatePath { script, recorded } => write!(
+                f,
+                "'{}' activates '{}'",
+                script
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                recorded
+            ),
+            Issue::MissingRecord { package, version } => {
+                write!(f, "{} {} has no RECORD file", package, version)
+            }
+        }
+    }
+}
+
+/// The fields of `pyvenv.cfg` the doctor looks at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PyvenvCfg {
+    /// Directory of the base interpreter.
+    pub home: Option<String>,
+    /// Full Python version (`version_info` written by uv, `version` by venv).
+    pub version: Option<String>,
+}
+
+impl PyvenvCfg {
+    /// Parse the `key = value` lines of a `pyvenv.cfg` file.
+    pub fn parse(text: &str) -> Self {
+        let mut cfg = PyvenvCfg::default();
+        for (key, value) in text.lines().filter_map(|line| line.split_once('=')) {
+            let value = value.trim().to_string();
+            match key.trim() {
+                "home" => cfg.home = Some(value),
+                "version" | "version_info" => cfg.version = Some(value),
+                _ => {}
+            }
+        }
+        cfg
+    }
+
+    /// Read `pyvenv.cfg` from `venv_path`, or `None` if it does not exist.
+    pub async fn read(venv_path: &Path) -> Option<Self> {
+        async_fs::read_to_string(venv_path.join("pyvenv.cfg"))
+            .await
+            .ok()
+            .map(|text| Self::parse(&text))
+    }
+}
+
+/// Check the virtual environment at `venv_path` and return every problem found.
+///
+/// The interpreter is asked for its version through `runner`.
+pub async fn diagnose(runner: &dyn CommandRunner, venv_path: &Path) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let cfg = PyvenvCfg::read(venv_path).await;
+    match &cfg {
+        None => issues.push(Issue::MissingConfig),
+        Some(PyvenvCfg {
+            home: Some(home), ..
+        }) if !async_fs::try_exists(home).await.unwrap_or(false) => {
+            issues.push(Issue::DanglingHome { home: home.clone() })
+        }
+        _ => {}
+    }
+
+    let python = interpreter_path(venv_path).await;
+    if async_fs::metadata(&python).await.is_err() {
+        let target = async_fs::read_link(&python).await.ok();
+        issues.push(Issue::MissingInterpreter {
+            path: python,
+            target,
+        });
+    } else if let Some(recorded) = cfg.as_ref().and_then(|c| c.version.clone()) {
+        if let Some(actual) = interpreter_version(runner, &python).await {
+            if actual != recorded {
+                issues.push(Issue::VersionMismatch { recorded, actual });
+            }
+        }
+    }
+
+    issues.extend(stale_activate_scripts(venv_path).await);
+    issues.extend(missing_records(venv_path).await);
+    issues
+}
+
+/// Path of the venv interpreter, preferring whichever layout exists.
+pub async fn interpreter_path(venv_path: &Path) -> PathBuf {
+    let unix = venv_path.join(UNIX_PYTHON_EXEC);
+    let windows = venv_path.join(WIN_PYTHON_EXEC);
+    if async_fs::symlink_metadata(&unix).await.is_ok() {
+        unix
+    } else if async_fs::symlink_metadata(&windows).await.is_ok() || cfg!(target_os = "windows") {
+        windows
+    } else {
+        unix
+    }
+}
+
+/// Run `python` through `runner` and return the `major.minor.micro` version it
+/// reports.
+pub async fn interpreter_version(runner: &dyn CommandRunner, python: &Path) -> Option<String> {
+    let cmd = CommandSpec::new(
+        &python.to_string_lossy(),
+        &["-c", "import sys; print('%d.%d.%d' % sys.version_info[:3])"],
+    );
+    let output = tokio::time::timeout(INTERPRETER_TIMEOUT, runner.output(&cmd))
+        .await
+        .ok()?
+        .ok()?;
+    if !output.success() {
+        return None;
+    }
+    let version = output.stdout.trim().to_string();
+    (!version.is_empty()).then_some(version)
+}
+
+/// Activate scripts in `bin` (or `Scripts`) whose hard-coded `VIRTUAL_ENV`
+/// points somewhere other than `venv_path`.
+async fn stale_activate_scripts(venv_path: &Path) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let current = canonical(venv_path).await;
+    for dir in ["bin", "Scripts"] {
+        let Ok(mut entries) = async_fs::read_dir(venv_path.join(dir)).await else {
+            continue;
+        };
+        let mut scripts = Vec::new();
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            if entry.file_name().to_string_lossy().starts_with("activate") {
+                scripts.push(entry.path());
+            }
+        }
+        scripts.sort();
+        for script in scripts {
+            let Ok(text) = async_fs::read_to_string(&script).await else {
+                continue;
+            };
+            let Some(recorded) = text.lines().find_map(recorded_virtual_env) else {
+                continue;
+            };
+            if canonical(Path::new(&recorded)).await != current {
+                issues.push(Issue::StaleActivatePath { script, recorded });
+            }
+        }
+    }
+    issues
+}
+
+/// Extract the absolute path assigned to `VIRTUAL_ENV` on `line`, if any.
+///
+/// Handles `VIRTUAL_ENV='/p'` (sh), `set -gx VIRTUAL_ENV '/p'` (fish),
+/// `setenv VIRTUAL_ENV '/p'` (csh) and `set "VIRTUAL_ENV=C:\p"` (cmd).
+pub fn recorded_virtual_env(line: &str) -> Option<String> {
+    let start = line.find("VIRTUAL_ENV")? + "VIRTUAL_ENV".len();
+    let rest = line[start..].trim_start_matches([' ', '\t', '=']);
+    let value = match rest.chars().next()? {
+        quote @ ('\'' | '"') => rest[1..].split(quote).next()?,
+        // cmd: the quote opens before the variable name.
+        _ if line[..start].trim_end().ends_with("\"VIRTUAL_ENV") => rest.split('"').next()?,
+        _ => return None,
+    };
+    let is_absolute = value.starts_with('/')
+        || (value.len() > 2
+            && value.as_bytes()[1] == b':'
+            && value.as_bytes()[0].is_ascii_alphabetic());
+    is_absolute.then(|| value.to_string())
+}
+
+/// `.dist-info` directories without a `RECORD` file.
+async fn missing_records(venv_path: &Path) -> Vec<Issue> {
+    let Some(site_pkgs) = find_site_packages(venv_path).await else {
+        return Vec::new();
+    };
+    let mut issues = Vec::new();
+    for dist in super::distinfo::read_site_packages(&site_pkgs).await {
+        if async_fs::metadata(dist.path.join("RECORD")).await.is_err() {
+            issues.push(Issue::MissingRecord {
+                package: dist.name,
+                version: dist.version,
+            });
+        }
+    }
+    issues
+}
+
+/// Rewrite the stale `VIRTUAL_ENV` paths reported in `issues` to `venv_path`.
+///
+/// Returns the number of scripts rewritten.
+///
+/// # Errors
+///
+/// Returns [`PylotError::Io`] if a script cannot be read or written.
+pub async fn fix_activate_scripts(venv_path: &Path, issues: &[Issue]) -> Result<usize> {
+    let current = venv_path.to_string_lossy().to_string();
+    let mut fixed = 0;
+    for issue in issues {
+        if let Issue::StaleActivatePath { script, recorded } = issue {
+            let text = async_fs::read_to_string(script)
+                .await
+                .map_err(PylotError::Io)?;
+            async_fs::write(script, text.replace(recorded.as_str(), &current))
+                .await
+                .map_err(PylotError::Io)?;
+            fixed += 1;
+        }
+    }
+    Ok(fixed)
+}
+
+/// Replace the recorded version in `pyvenv.cfg` with `version`.
+///
+/// # Errors
+///
+/// Returns [`PylotError::Io`] if the file cannot be read or written.
+pub async fn fix_recorded_version(venv_path: &Path, version: &str) -> Result<()> {
+    let cfg_path = venv_path.join("pyvenv.cfg");
+    let text = async_fs::read_to_string(&cfg_path)
+        .await
+        .map_err(PylotError::Io)?;
+    let updated: Vec<String> = text
+        .lines()
+        .map(|line| match line.split_once('=') {
+            Some((key, _)) if matches!(key.trim(), "version" | "version_info") => {
+                format!("{}= {}", key, version)
+            }
+            _ => line.to_string(),
+        })
+        .collect();
+    async_fs::write(&cfg_path, updated.join("\n") + "\n")
+        .await
+        .map_err(PylotError::Io)
+}
+
+/// Python version to rebuild the environment with: the `major.minor` from
+/// `pyvenv.cfg`, or the `lib/pythonX.Y` directory the packages live in.
+pub async fn rebuild_version(venv_path: &Path) -> Option<String> {
+    if let Some(version) = PyvenvCfg::read(venv_path).await.and_then(|c| c.version) {
+        return Some(major_minor(&version));
+    }
+    let site_pkgs = find_site_packages(venv_path).await?;
+    let lib_dir = site_pkgs
+        .parent()?
+        .file_name()?
+        .to_string_lossy()
+        .to_string();
+    lib_dir.strip_prefix("python").map(str::to_string)
+}
+
+/// Print one row per problem, with the suggested fix.
+pub fn print_report_to<W: Write>(writer: &mut W, entries: &[(String, Vec<Issue>)]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_SOLID_INNER_BORDERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Name", "Severity", "Problem", "Suggested fix"]);
+    for (name, issues) in entries {
+        for issue in issues {
+            table.add_row(vec![
+                name.clone(),
+                issue.severity().to_string(),
+                issue.to_string(),
+                issue.suggestion(),
+            ]);
+        }
+    }
+    let _ = writeln!(writer, "{}", table);
+}
+
+fn major_minor(version: &str) -> String {
+    version.split('.').take(2).collect::<Vec<_>>().join(".")
+}
+
+async fn canonical(path: &Path) -> PathBuf {
+    async_fs::canonicalize(path)
+        .await
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+async fn find_site_packages(venv_path: &Path) -> Option<PathBuf> {
+    UvVenv::find_site_packages(&venv_path.to_string_lossy()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger;
+    use crate::processes::{CommandOutput, RecordingRunner};
+    use crate::virtualenv::distinfo::tests::write_dist_info;
+
+    /// Lay out a minimal venv at `root` recording `home` in `pyvenv.cfg`.
+    async fn make_venv(root: &Path, home: &str) {
+        async_fs::create_dir_all(root.join("bin")).await.unwrap();
+        async_fs::write(
+            root.join("pyvenv.cfg"),
+            format!(
+                "home = {}\nimplementation = CPython\nversion_info = 3.12.1\n",
+                home
+            ),
+        )
+        .await
+        .unwrap();
+        async_fs::write(
+            root.join("bin/activate"),
+            format!(
+                "if [ -n \"${{VIRTUAL_ENV:-}}\" ]; then\n  :\nfi\nVIRTUAL_ENV='{}'\nexport VIRTUAL_ENV\n",
+                root.display()
+            ),
+        )
+        .await
+        .unwrap();
+    }
+
+    // ── pyvenv.cfg ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_pyvenv_cfg_parse() {
+        let cfg = PyvenvCfg::parse(
+            "home = /usr/bin\ninclude-system-site-packages = false\nversion = 3.11.4\n",
+        );
+        assert_eq!(cfg.home.as_deref(), Some("/usr/bin"));
+        assert_eq!(cfg.version.as_deref(), Some("3.11.4"));
+        let cfg = PyvenvCfg::parse("version_info = 3.12.1\n");
+        assert_eq!(cfg.version.as_deref(), Some("3.12.1"));
+    }
+
+    // ── recorded_virtual_env ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_recorded_virtual_env_shells() {
+        assert_eq!(
+            recorded_virtual_env("VIRTUAL_ENV='/home/u/venvs/a'").as_deref(),
+            Some("/home/u/venvs/a")
+        );
+        assert_eq!(
+            recorded_virtual_env("set -gx VIRTUAL_ENV \"/home/u/venvs/a\"").as_deref(),
+            Some("/home/u/venvs/a")
+        );
+        assert_eq!(
+            recorded_virtual_env("setenv VIRTUAL_ENV '/home/u/venvs/a'").as_deref(),
+            Some("/home/u/venvs/a")
+        );
+        assert_eq!(
+            recorded_virtual_env("@set \"VIRTUAL_ENV=C:\\venvs\\a\"").as_deref(),
+            Some("C:\\venvs\\a")
+        );
+    }
+
+    #[test]
+    fn test_recorded_virtual_env_ignores_other_lines() {
+        assert!(recorded_virtual_env("if [ -n \"${VIRTUAL_ENV:-}\" ]; then").is_none());
+        assert!(recorded_virtual_env("export VIRTUAL_ENV").is_none());
+        assert!(recorded_virtual_env("VIRTUAL_ENV=\"$(dirname \"$0\")\"").is_none());
+        assert!(recorded_virtual_env("PATH=\"$VIRTUAL_ENV/bin:$PATH\"").is_none());
+    }
+
+    // ── Issue ────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_version_mismatch_severity() {
+        let patch = Issue::VersionMismatch {
+            recorded: "3.12.1".to_string(),
+            actual: "3.12.4".to_string(),
+        };
+        assert_eq!(patch.severity(), Severity::Warning);
+        assert!(!patch.needs_rebuild());
+        let minor = Issue::VersionMismatch {
+            recorded: "3.11.9".to_string(),
+            actual: "3.12.4".to_string(),
+        };
+        assert_eq!(minor.severity(), Severity::Error);
+        assert!(minor.needs_rebuild());
+        assert!(minor.to_string().contains("3.11.9"));
+    }
+
+    #[test]
+    fn test_missing_record_suggestion() {
+        let issue = Issue::MissingRecord {
+            package: "six".to_string(),
+            version: "1.16.0".to_string(),
+        };
+        assert!(!issue.needs_rebuild());
+        assert!(issue.suggestion().contains("six==1.16.0"));
+    }
+
+    // ── diagnose ─────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_diagnose_missing_config_and_interpreter() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let issues = diagnose(&RecordingRunner::new(), tmp.path()).await;
+        assert!(issues.contains(&Issue::MissingConfig));
+        assert!(issues
+            .iter()
+            .any(|i| matches!(i, Issue::MissingInterpreter { target: None, .. })));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_diagnose_dangling_home_and_symlink() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let venv = tmp.path().join("env");
+        make_venv(&venv, "/nonexistent/python/bin").await;
+        std::os::unix::fs::symlink(
+            "/nonexistent/python/bin/python3.12",
+            venv.join("bin/python"),
+        )
+        .unwrap();
+
+        let issues = diagnose(&RecordingRunner::new(), &venv).await;
+        assert!(issues.contains(&Issue::DanglingHome {
+            home: "/nonexistent/python/bin".to_string()
+        }));
+        assert!(issues.contains(&Issue::MissingInterpreter {
+            path: venv.join("bin/python"),
+            target: Some(PathBuf::from("/nonexistent/python/bin/python3.12")),
+        }));
+        assert!(issues
+            .iter()
+            .all(|i| !matches!(i, Issue::StaleActivatePath { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_and_fix_stale_activate_path() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let old = tmp.path().join("old");
+        let venv = tmp.path().join("new");
+        make_venv(&old, "/usr/bin").await;
+        async_fs::rename(&old, &venv).await.unwrap();
+
+        let issues = diagnose(&RecordingRunner::new(), &venv).await;
+        let stale: Vec<_> = issues
+            .iter()
+            .filter(|i| matches!(i, Issue::StaleActivatePath { .. }))
+            .cloned()
+            .collect();
+        assert_eq!(stale.len(), 1);
+
+        assert_eq!(fix_activate_scripts(&venv, &stale).await.unwrap(), 1);
+        let text = async_fs::read_to_string(venv.join("bin/activate"))
+            .await
+            .unwrap();
+        assert!(text.contains(&format!("VIRTUAL_ENV='{}'", venv.display())));
+        assert!(diagnose(&RecordingRunner::new(), &venv)
+            .await
+            .iter()
+            .all(|i| !matches!(i, Issue::StaleActivatePath { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_version_mismatch() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        make_venv(tmp.path(), "/").await;
+        fix_recorded_version(tmp.path(), "2.7.18").await.unwrap();
+        let python = interpreter_path(tmp.path()).await;
+        async_fs::create_dir_all(python.parent().unwrap())
+            .await
+            .unwrap();
+        async_fs::write(&python, "").await.unwrap();
+        let runner =
+            RecordingRunner::new().respond("sys.version_info", CommandOutput::ok("3.12.1\n"));
+
+        let issues = diagnose(&runner, tmp.path()).await;
+        assert!(issues.contains(&Issue::VersionMismatch {
+            recorded: "2.7.18".to_string(),
+            actual: "3.12.1".to_string(),
+        }));
+        assert_eq!(
+            runner.calls()[0].program,
+            python.to_string_lossy().to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_missing_record() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        let site_pkgs = tmp.path().join("lib/python3.12/site-packages");
+        let with_record = write_dist_info(&site_pkgs, "idna", "3.6", &[]).await;
+        async_fs::write(with_record.join("RECORD"), "")
+            .await
+            .unwrap();
+        write_dist_info(&site_pkgs, "six", "1.16.0", &[]).await;
+
+        let issues = diagnose(&RecordingRunner::new(), tmp.path()).await;
+        let missing: Vec<_> = issues
+            .iter()
+            .filter(|i| matches!(i, Issue::MissingRecord { .. }))
+            .collect();
+        assert_eq!(
+            missing,
+            vec![&Issue::MissingRecord {
+                package: "six".to_string(),
+                version: "1.16.0".to_string()
+            }]
+        );
+    }
+
+    // ── fixes ────────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_fix_recorded_version() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        make_venv(tmp.path(), "/usr/bin").await;
+        fix_recorded_version(tmp.path(), "3.12.4").await.unwrap();
+        let cfg = PyvenvCfg::read(tmp.path()).await.unwrap();
+        assert_eq!(cfg.version.as_deref(), Some("3.12.4"));
+        assert_eq!(cfg.home.as_deref(), Some("/usr/bin"));
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_version() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp = tempfile::tempdir().unwrap();
+        make_venv(tmp.path(), "/usr/bin").await;
+        assert_eq!(rebuild_version(tmp.path()).await.as_deref(), Some("3.12"));
+
+        let bare = tempfile::tempdir().unwrap();
+        async_fs::create_dir_all(bare.path().join("lib/python3.10/site-packages"))
+            .await
+            .unwrap();
+        assert_eq!(rebuild_version(bare.path()).await.as_deref(), Some("3.10"));
+        let empty = tempfile::tempdir().unwrap();
+        assert!(rebuild_version(empty.path()).await.is_none());
+    }
+
+    #[test]
+    fn test_print_report() {
+        let entries = vec![(
+            "env1".to_string(),
+            vec![Issue::DanglingHome {
+                home: "/gone".to_string(),
+            }],
+        )];
+        let mut output = Vec::new();
+        print_report_to(&mut output, &entries);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("env1"));
+        assert!(output.contains("error"));
+        assert!(output.contains("/gone"));
+        assert!(output.contains("Rebuild"));
+    }
+}
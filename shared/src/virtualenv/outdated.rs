@@ -0,0 +1,841 @@
+use super::distinfo::normalize_name;
+use crate::{
+    constants::SIMPLE_API_JSON_ACCEPT,
+    error::{PylotError, Result},
+    processes::{CommandRunner, CommandSpec},
+    utils,
+};
+use comfy_table::{
+    modifiers::UTF8_SOLID_INNER_BORDERS, presets::UTF8_FULL, ContentArrangement, Table,
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, SystemTime},
+};
+
+/// Maximum number of index requests that are in flight at the same time.
+const MAX_CONCURRENT_REQUESTS: usize = 8;
+/// Timeout applied to every request sent to the package index.
+const REQUEST_TIMEOUT_SECS: u64 = 15;
+
+/// An installed package for which the index has a newer release.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutdatedPackage {
+    /// Normalized package name.
+    pub name: String,
+    /// Version currently installed in the environment.
+    #[serde(rename = "version")]
+    pub installed: String,
+    /// Newest version available on the index.
+    #[serde(rename = "latest_version")]
+    pub latest: String,
+}
+
+/// Name of the file, inside the venvs directory, that keeps lookups between
+/// runs.
+pub const CACHE_FILE: &str = ".pylot-outdated-cache.json";
+
+/// Index lookups and `uv pip list --outdated` results, kept in [`CACHE_FILE`]
+/// so separate `pylot venv outdated` runs and the TUI reuse them until they
+/// expire.  Index lookups are keyed by project URL, which includes the index
+/// URL, so switching indexes never serves the other index's versions.
+///
+/// A missing or unreadable file is an empty cache.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OutdatedCache {
+    #[serde(default)]
+    versions: TtlCache<Vec<String>>,
+    #[serde(default)]
+    uv: TtlCache<Vec<OutdatedPackage>>,
+}
+
+impl OutdatedCache {
+    /// Read the cache kept in `dir`.
+    pub async fn load(dir: &Path) -> Self {
+        match tokio::fs::read_to_string(dir.join(CACHE_FILE)).await {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                log::debug!("Ignoring invalid outdated cache: {}", e);
+                OutdatedCache::default()
+            }),
+            Err(_) => OutdatedCache::default(),
+        }
+    }
+
+    /// Drop the entries older than `ttl` and write the rest to `dir`.
+    /// Failing to is only logged, as the next check simply asks again.
+    pub async fn save(mut self, dir: &Path, ttl: Duration) {
+        self.versions.expire(ttl);
+        self.uv.expire(ttl);
+        let Ok(content) = serde_json::to_string(&self) else {
+            return;
+        };
+        if let Err(e) = utils::write_file_atomic(&dir.join(CACHE_FILE), &content).await {
+            log::debug!("Cannot write outdated cache: {}", e);
+        }
+    }
+}
+
+/// A map whose entries expire after a caller-supplied time-to-live.  Entries
+/// carry the wall-clock time they were stored at, so they stay valid across
+/// processes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TtlCache<V> {
+    entries: HashMap<String, (SystemTime, V)>,
+}
+
+impl<V> Default for TtlCache<V> {
+    fn default() -> Self {
+        TtlCache {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<V: Clone> TtlCache<V> {
+    fn get(&self, key: &str, ttl: Duration) -> Option<V> {
+        self.entries
+            .get(key)
+            .filter(|(stored_at, _)| is_fresh(*stored_at, ttl))
+            .map(|(_, value)| value.clone())
+    }
+
+    fn insert(&mut self, key: String, value: V) {
+        self.entries.insert(key, (SystemTime::now(), value));
+    }
+
+    fn expire(&mut self, ttl: Duration) {
+        self.entries
+            .retain(|_, (stored_at, _)| is_fresh(*stored_at, ttl));
+    }
+}
+
+/// Entries stored in the future, e.g. after the clock was turned back, are
+/// stale.
+fn is_fresh(stored_at: SystemTime, ttl: Duration) -> bool {
+    SystemTime::now()
+        .duration_since(stored_at)
+        .is_ok_and(|age| age < ttl)
+}
+
+/// Outcome of checking an environment against the index.
+#[derive(Debug, Default)]
+pub struct OutdatedReport {
+    /// Packages with a newer release, sorted by name.
+    pub outdated: Vec<OutdatedPackage>,
+    /// Packages that could not be looked up, with the reason.
+    pub failed: Vec<(String, PylotError)>,
+}
+
+/// Client for a PEP 691 (JSON) simple-API package index.
+///
+/// The base URL is taken verbatim, so tests can point it at a local stub
+/// server instead of PyPI.
+#[derive(Clone)]
+pub struct PackageIndex {
+    url: String,
+    ttl: Duration,
+    agent: ureq::Agent,
+    cache: Arc<Mutex<OutdatedCache>>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl PackageIndex {
+    /// Create a client for the index at `url`, caching lookups in memory for
+    /// `ttl`.
+    pub fn new(url: &str, ttl: Duration) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(REQUEST_TIMEOUT_SECS)))
+            .build()
+            .into();
+        PackageIndex {
+            url: url.trim_end_matches('/').to_string(),
+            ttl,
+            agent,
+            cache: Arc::default(),
+            cache_dir: None,
+        }
+    }
+
+    /// Also keep lookups in the [`OutdatedCache`] of `dir`, which
+    /// [`outdated`](Self::outdated) reads before and writes after checking.
+    pub fn with_cache_dir(mut self, dir: &Path) -> Self {
+        self.cache_dir = Some(dir.to_path_buf());
+        self
+    }
+
+    /// Return every non-yanked version the index lists for `package`.
+    ///
+    /// Returns an empty list when the index does not know the package (HTTP
+    /// 404), which is the case for locally built or private packages.
+    pub async fn versions(&self, package: &str) -> Result<Vec<String>> {
+        let project = normalize_name(package);
+        let url = format!("{}/{}/", self.url, project);
+        if let Some(versions) = self.lock_cache().versions.get(&url, self.ttl) {
+            return Ok(versions);
+        }
+
+        let agent = self.agent.clone();
+        let request_url = url.clone();
+        let body = tokio::task::spawn_blocking(move || fetch_project_page(&agent, &request_url))
+            .await
+            .map_err(|e| PylotError::Network(format!("Index request panicked: {}", e)))??;
+
+        let versions = match body {
+            Some(body) => available_versions(&project, &body)?,
+            None => Vec::new(),
+        };
+        self.lock_cache().versions.insert(url, versions.clone());
+        Ok(versions)
+    }
+
+    fn lock_cache(&self) -> MutexGuard<'_, OutdatedCache> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Return the newest release of `package` that is newer than `installed`.
+    pub async fn newer_version(&self, package: &str, installed: &str) -> Result<Option<String>> {
+        let versions = self.versions(package).await?;
+        Ok(pick_latest(&versions, installed))
+    }
+
+    /// Compare `installed` (`"name version"` entries, as produced by the
+    /// package scan of a [`UvVenv`](super::uvvenv::UvVenv)) against the index.
+    ///
+    /// Lookups run concurrently.  A failed lookup is recorded in the report
+    /// and the other packages are still checked; only when every lookup
+    /// fails is the first failure returned as the error.
+    pub async fn outdated(&self, installed: &[String]) -> Result<OutdatedReport> {
+        if let Some(dir) = &self.cache_dir {
+            *self.lock_cache() = OutdatedCache::load(dir).await;
+        }
+
+        let lookups: Vec<_> = installed
+            .iter()
+            .filter_map(|entry| entry.split_once(' '))
+            .map(|(name, version)| {
+                let index = self.clone();
+                let name = name.to_string();
+                let version = version.to_string();
+                async move {
+                    let latest = index.newer_version(&name, &version).await;
+                    (name, version, latest)
+                }
+            })
+            .collect();
+
+        let results: Vec<_> = futures::stream::iter(lookups)
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await;
+
+        if let Some(dir) = &self.cache_dir {
+            let cache = self.lock_cache().clone();
+            cache.save(dir, self.ttl).await;
+        }
+
+        let checked = results.len();
+        let mut report = OutdatedReport::default();
+        for (name, installed, latest) in results {
+            match latest {
+                Ok(Some(latest)) => report.outdated.push(OutdatedPackage {
+                    name,
+                    installed,
+                    latest,
+                }),
+                Ok(None) => {}
+                Err(e) => report.failed.push((name, e)),
+            }
+        }
+        if checked > 0 && report.failed.len() == checked {
+            report.failed.sort_by(|a, b| a.0.cmp(&b.0));
+            return Err(report.failed.swap_remove(0).1);
+        }
+        report.outdated.sort_by(|a, b| a.name.cmp(&b.name));
+        report.failed.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(report)
+    }
+}
+
+fn fetch_project_page(agent: &ureq::Agent, url: &str) -> Result<Option<String>> {
+    match agent
+        .get(url)
+        .header("Accept", SIMPLE_API_JSON_ACCEPT)
+        .call()
+    {
+        Ok(mut response) => response
+            .body_mut()
+            .read_to_string()
+            .map(Some)
+            .map_err(|e| PylotError::Network(format!("Failed to read {}: {}", url, e))),
+        Err(ureq::Error::StatusCode(404)) => Ok(None),
+        Err(e) => Err(PylotError::Network(format!(
+            "Failed to query {}: {}",
+            url, e
+        ))),
+    }
+}
+
+/// Ask uv for the outdated packages of the environment that owns `python`,
+/// running the uv executable `uv` through `runner`.
+///
+/// Results are cached for `ttl` per interpreter and installed package set, so
+/// the cache is bypassed automatically once packages change.  With a
+/// `cache_dir` they are kept in its [`OutdatedCache`] between runs.
+pub async fn uv_outdated(
+    runner: &dyn CommandRunner,
+    uv: &str,
+    python: &str,
+    index_url: &str,
+    installed: &[String],
+    ttl: Duration,
+    cache_dir: Option<&Path>,
+) -> Result<Vec<OutdatedPackage>> {
+    let mut cache = match cache_dir {
+        Some(dir) => OutdatedCache::load(dir).await,
+        None => OutdatedCache::default(),
+    };
+    let key = format!("{}|{}|{}", python, index_url, installed.join(","));
+    if let Some(outdated) = cache.uv.get(&key, ttl) {
+        return Ok(outdated);
+    }
+
+    let args = [
+        "pip",
+        "list",
+        "--outdated",
+        "--format",
+        "json",
+        "--python",
+        python,
+        "--index-url",
+        index_url,
+    ];
+    let output = runner.output(&CommandSpec::new(uv, &args)).await?;
+    if !output.success() {
+        return Err(PylotError::CommandExecution(
+            output.stderr.trim().to_string(),
+        ));
+    }
+
+    let outdated = parse_uv_outdated_json(&output.stdout)?;
+    if let Some(dir) = cache_dir {
+        cache.uv.insert(key, outdated.clone());
+        cache.save(dir, ttl).await;
+    }
+    Ok(outdated)
+}
+
+/// Parse the output of `uv pip list --outdated --format json`.
+pub fn parse_uv_outdated_json(body: &str) -> Result<Vec<OutdatedPackage>> {
+    let body = body.trim();
+    if body.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut outdated: Vec<OutdatedPackage> = serde_json::from_str(body)
+        .map_err(|e| PylotError::Other(format!("Invalid uv output: {}", e)))?;
+    for pkg in &mut outdated {
+        pkg.name = normalize_name(&pkg.name);
+    }
+    outdated.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(outdated)
+}
+
+/// Print outdated packages as a table, optionally prefixed with an
+/// `Environment` column.
+pub fn print_outdated_table_to<W: Write>(
+    writer: &mut W,
+    entries: &[(String, Vec<OutdatedPackage>)],
+    with_env: bool,
+) {
+    let mut header = vec!["Package", "Installed", "Latest"];
+    if with_env {
+        header.insert(0, "Environment");
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_SOLID_INNER_BORDERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(header);
+    for (env, packages) in entries {
+        for pkg in packages {
+            let mut row = vec![pkg.name.clone(), pkg.installed.clone(), pkg.latest.clone()];
+            if with_env {
+                row.insert(0, env.clone());
+            }
+            table.add_row(row);
+        }
+    }
+    let _ = writeln!(writer, "{}", table);
+}
+
+// ── Simple-API JSON ──────────────────────────────────────────────────────────
+
+#[derive(serde::Deserialize)]
+struct SimpleProject {
+    #[serde(default)]
+    versions: Vec<String>,
+    #[serde(default)]
+    files: Vec<SimpleFile>,
+}
+
+#[derive(serde::Deserialize)]
+struct SimpleFile {
+    filename: String,
+    #[serde(default)]
+    yanked: serde_json::Value,
+}
+
+impl SimpleFile {
+    fn is_yanked(&self) -> bool {
+        !matches!(
+            self.yanked,
+            serde_json::Value::Null | serde_json::Value::Bool(false)
+        )
+    }
+}
+
+/// Extract the non-yanked versions from a PEP 691 project page.
+///
+/// Uses the PEP 700 `versions` key when present and falls back to parsing
+/// distribution filenames otherwise.  A version is dropped only when every one
+/// of its files is yanked.
+fn available_versions(project: &str, body: &str) -> Result<Vec<String>> {
+    let page: SimpleProject = serde_json::from_str(body).map_err(|e| {
+        PylotError::Network(format!("Invalid index response for {}: {}", project, e))
+    })?;
+
+    let mut live: HashMap<String, bool> = HashMap::new();
+    for file in &page.files {
+        if let Some(version) = version_from_filename(&file.filename) {
+            *live.entry(version).or_insert(false) |= !file.is_yanked();
+        }
+    }
+
+    let versions = if page.versions.is_empty() {
+        live.iter()
+            .filter(|(_, has_live_file)| **has_live_file)
+            .map(|(version, _)| version.clone())
+            .collect()
+    } else {
+        page.versions
+            .into_iter()
+            .filter(|version| live.get(version).copied().unwrap_or(true))
+            .collect()
+    };
+    Ok(versions)
+}
+
+/// Return the version part of a wheel or sdist filename.
+fn version_from_filename(filename: &str) -> Option<String> {
+    if let Some(stem) = filename.strip_suffix(".whl") {
+        return stem.split('-').nth(1).map(str::to_string);
+    }
+    let stem = [".tar.gz", ".tar.bz2", ".tgz", ".zip"]
+        .iter()
+        .find_map(|ext| filename.strip_suffix(ext))?;
+    stem.rsplit_once('-')
+        .map(|(_, version)| version.to_string())
+}
+
+/// Return the newest entry of `versions` that is newer than `installed`.
+///
+/// Pre-releases are only considered when the installed version is itself a
+/// pre-release.
+pub fn pick_latest(versions: &[String], installed: &str) -> Option<String> {
+    let current = PackageVersion::parse(installed)?;
+    let allow_pre = current.is_prerelease();
+    versions
+        .iter()
+        .filter_map(|v| PackageVersion::parse(v).map(|parsed| (parsed, v)))
+        .filter(|(parsed, _)| allow_pre || !parsed.is_prerelease())
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .filter(|(latest, _)| *latest > current)
+        .map(|(_, v)| v.clone())
+}
+
+// ── PEP 440 versions ─────────────────────────────────────────────────────────
+
+/// A parsed PEP 440 version, sufficient for ordering releases.
+///
+/// Local version labels (`+local`) are ignored.
+#[derive(Debug, Clone)]
+pub struct PackageVersion {
+    epoch: u64,
+    release: Vec<u64>,
+    pre: Option<(u8, u64)>,
+    post: Option<u64>,
+    dev: Option<u64>,
+}
+
+impl PackageVersion {
+    /// Parse `version`, returning `None` when it is not a valid PEP 440 version.
+    pub fn parse(version: &str) -> Option<Self> {
+        let lower = version.trim().to_lowercase();
+        let public = lower.split('+').next()?;
+        let public = public.strip_prefix('v').unwrap_or(public);
+        let (epoch, rest) = match public.split_once('!') {
+            Some((epoch, rest)) => (epoch.parse().ok()?, rest),
+            None => (0, public),
+        };
+
+        let release_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let release: Vec<u64> = rest[..release_end]
+            .trim_end_matches('.')
+            .split('.')
+            .map(|part| part.parse().ok())
+            .collect::<Option<_>>()?;
+
+        let mut parsed = PackageVersion {
+            epoch,
+            release,
+            pre: None,
+            post: None,
+            dev: None,
+        };
+
+        let mut suffix = rest[release_end..].chars().peekable();
+        while suffix.peek().is_some() {
+            while suffix.next_if(|c| matches!(c, '.' | '-' | '_')).is_some() {}
+            let label: String =
+                std::iter::from_fn(|| suffix.next_if(char::is_ascii_alphabetic)).collect();
+            while suffix.next_if(|c| matches!(c, '.' | '-' | '_')).is_some() {}
+            let digits: String =
+                std::iter::from_fn(|| suffix.next_if(char::is_ascii_digit)).collect();
+            if label.is_empty() && digits.is_empty() {
+                break;
+            }
+            let number = if digits.is_empty() {
+                0
+            } else {
+                digits.parse().ok()?
+            };
+            match label.as_str() {
+                "a" | "alpha" => parsed.pre = Some((0, number)),
+                "b" | "beta" => parsed.pre = Some((1, number)),
+                "rc" | "c" | "pre" | "preview" => parsed.pre = Some((2, number)),
+                "post" | "rev" | "r" | "" => parsed.post = Some(number),
+                "dev" => parsed.dev = Some(number),
+                _ => return None,
+            }
+        }
+        Some(parsed)
+    }
+
+    /// Returns `true` for alpha, beta, release-candidate and dev releases.
+    pub fn is_prerelease(&self) -> bool {
+        self.pre.is_some() || self.dev.is_some()
+    }
+
+    fn pre_key(&self) -> (u8, u64) {
+        match (self.pre, self.post, self.dev) {
+            // A bare dev release sorts before every pre-release of the same version.
+            (None, None, Some(_)) => (0, 0),
+            (Some((kind, n)), _, _) => (kind + 1, n),
+            _ => (u8::MAX, 0),
+        }
+    }
+
+    fn dev_key(&self) -> (u8, u64) {
+        match self.dev {
+            Some(n) => (0, n),
+            None => (1, 0),
+        }
+    }
+}
+
+impl Ord for PackageVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.release.len().max(other.release.len());
+        let padded = |r: &[u64]| {
+            (0..len)
+                .map(|i| r.get(i).copied().unwrap_or(0))
+                .collect::<Vec<_>>()
+        };
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| padded(&self.release).cmp(&padded(&other.release)))
+            .then_with(|| self.pre_key().cmp(&other.pre_key()))
+            .then_with(|| self.post.cmp(&other.post))
+            .then_with(|| self.dev_key().cmp(&other.dev_key()))
+    }
+}
+
+impl PartialOrd for PackageVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for PackageVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PackageVersion {}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{logger, testsupport};
+    use std::net::TcpListener;
+
+    /// Serve `routes` (path → JSON body) as a simple-API index and return its
+    /// base URL.  Unknown paths answer with 404.
+    pub(crate) fn spawn_stub_index(routes: Vec<(&'static str, &'static str)>) -> String {
+        let routes = routes
+            .into_iter()
+            .map(|(path, body)| (path.to_string(), body.as_bytes().to_vec()))
+            .collect();
+        let url = testsupport::spawn_stub_server(Some(SIMPLE_API_JSON_ACCEPT), routes);
+        format!("{}/simple", url)
+    }
+
+    const REQUESTS_PAGE: &str = r#"{
+        "meta": {"api-version": "1.1"},
+        "name": "requests",
+        "versions": ["2.30.0", "2.31.0", "2.32.0", "3.0.0b1"],
+        "files": [
+            {"filename": "requests-2.30.0-py3-none-any.whl", "yanked": false},
+            {"filename": "requests-2.31.0-py3-none-any.whl", "yanked": false},
+            {"filename": "requests-2.32.0-py3-none-any.whl", "yanked": "broken"},
+            {"filename": "requests-3.0.0b1-py3-none-any.whl", "yanked": false}
+        ]
+    }"#;
+
+    // ── PackageVersion ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_version_ordering() {
+        let ordered = [
+            "1.0.dev0",
+            "1.0a1",
+            "1.0b2",
+            "1.0rc1",
+            "1.0",
+            "1.0.post1",
+            "1.1",
+            "1!0.1",
+        ];
+        for pair in ordered.windows(2) {
+            let a = PackageVersion::parse(pair[0]).unwrap();
+            let b = PackageVersion::parse(pair[1]).unwrap();
+            assert!(a < b, "{} should sort before {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_version_padding_is_equal() {
+        assert_eq!(
+            PackageVersion::parse("1.0").unwrap(),
+            PackageVersion::parse("1.0.0").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_version_ignores_local_label() {
+        assert_eq!(
+            PackageVersion::parse("2.1.0+cpu").unwrap(),
+            PackageVersion::parse("2.1.0").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_version_invalid() {
+        assert!(PackageVersion::parse("not-a-version").is_none());
+        assert!(PackageVersion::parse("1.0foo").is_none());
+    }
+
+    #[test]
+    fn test_version_prerelease() {
+        assert!(PackageVersion::parse("1.0rc1").unwrap().is_prerelease());
+        assert!(PackageVersion::parse("1.0.dev3").unwrap().is_prerelease());
+        assert!(!PackageVersion::parse("1.0.post1").unwrap().is_prerelease());
+    }
+
+    // ── pick_latest ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_pick_latest_skips_prereleases() {
+        let versions = vec!["1.0".to_string(), "1.1".to_string(), "2.0rc1".to_string()];
+        assert_eq!(pick_latest(&versions, "1.0"), Some("1.1".to_string()));
+    }
+
+    #[test]
+    fn test_pick_latest_allows_prereleases_when_installed_is_pre() {
+        let versions = vec!["1.0".to_string(), "2.0rc1".to_string()];
+        assert_eq!(pick_latest(&versions, "2.0b1"), Some("2.0rc1".to_string()));
+    }
+
+    #[test]
+    fn test_pick_latest_up_to_date() {
+        let versions = vec!["1.0".to_string(), "1.1".to_string()];
+        assert_eq!(pick_latest(&versions, "1.1"), None);
+    }
+
+    // ── Simple-API parsing ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_available_versions_drops_yanked() {
+        let versions = available_versions("requests", REQUESTS_PAGE).unwrap();
+        assert!(versions.contains(&"2.31.0".to_string()));
+        assert!(!versions.contains(&"2.32.0".to_string()));
+    }
+
+    #[test]
+    fn test_available_versions_from_filenames() {
+        let body = r#"{"files": [
+            {"filename": "my_pkg-0.1.0.tar.gz"},
+            {"filename": "my_pkg-0.2.0-py3-none-any.whl"}
+        ]}"#;
+        let mut versions = available_versions("my-pkg", body).unwrap();
+        versions.sort();
+        assert_eq!(versions, vec!["0.1.0", "0.2.0"]);
+    }
+
+    #[test]
+    fn test_available_versions_invalid_json() {
+        assert!(available_versions("requests", "<html></html>").is_err());
+    }
+
+    // ── uv output parsing ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_uv_outdated_json() {
+        let body = r#"[
+            {"name": "urllib3", "version": "1.26.0", "latest_version": "2.2.1", "latest_filetype": "wheel"},
+            {"name": "Flask", "version": "2.0.0", "latest_version": "3.0.3", "latest_filetype": "wheel"}
+        ]"#;
+        let outdated = parse_uv_outdated_json(body).unwrap();
+        assert_eq!(outdated.len(), 2);
+        assert_eq!(outdated[0].name, "flask");
+        assert_eq!(outdated[0].installed, "2.0.0");
+        assert_eq!(outdated[0].latest, "3.0.3");
+    }
+
+    #[test]
+    fn test_parse_uv_outdated_json_empty() {
+        assert!(parse_uv_outdated_json("").unwrap().is_empty());
+        assert!(parse_uv_outdated_json("[]").unwrap().is_empty());
+    }
+
+    // ── PackageIndex against a stub server ───────────────────────────────────
+
+    #[tokio::test]
+    async fn test_index_outdated_with_stub_server() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let url = spawn_stub_index(vec![("/simple/requests/", REQUESTS_PAGE)]);
+        let index = PackageIndex::new(&url, Duration::from_secs(60));
+        let installed = vec![
+            "requests 2.30.0".to_string(),
+            "local-only 0.1.0".to_string(),
+        ];
+        let report = index.outdated(&installed).await.unwrap();
+        assert_eq!(
+            report.outdated,
+            vec![OutdatedPackage {
+                name: "requests".to_string(),
+                installed: "2.30.0".to_string(),
+                latest: "2.31.0".to_string(),
+            }]
+        );
+        assert!(report.failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_index_outdated_reports_failed_lookups() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let url = spawn_stub_index(vec![
+            ("/simple/requests/", REQUESTS_PAGE),
+            ("/simple/broken/", "<html></html>"),
+        ]);
+        let index = PackageIndex::new(&url, Duration::from_secs(60));
+        let installed = vec!["requests 2.30.0".to_string(), "broken 1.0".to_string()];
+        let report = index.outdated(&installed).await.unwrap();
+        assert_eq!(report.outdated.len(), 1);
+        assert_eq!(report.outdated[0].name, "requests");
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, "broken");
+
+        // Nothing could be checked at all.
+        let result = index.outdated(&["broken 1.0".to_string()]).await;
+        assert!(matches!(result, Err(PylotError::Network(_))));
+    }
+
+    #[tokio::test]
+    async fn test_index_outdated_persists_cache() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let url = spawn_stub_index(vec![("/simple/requests/", REQUESTS_PAGE)]);
+        let ttl = Duration::from_secs(60);
+        PackageIndex::new(&url, ttl)
+            .with_cache_dir(tmp_dir.path())
+            .outdated(&["requests 2.30.0".to_string()])
+            .await
+            .unwrap();
+
+        let cache = OutdatedCache::load(tmp_dir.path()).await;
+        let versions = cache
+            .versions
+            .get(&format!("{}/requests/", url), ttl)
+            .unwrap();
+        assert!(versions.contains(&"2.31.0".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_index_unknown_package_has_no_versions() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let url = spawn_stub_index(vec![]);
+        let index = PackageIndex::new(&url, Duration::from_secs(60));
+        assert!(index.versions("missing").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_index_unreachable_is_network_error() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/simple", listener.local_addr().unwrap());
+        drop(listener);
+        let index = PackageIndex::new(&url, Duration::from_secs(60));
+        let result = index.versions("requests").await;
+        assert!(matches!(result, Err(PylotError::Network(_))));
+    }
+
+    #[test]
+    fn test_print_outdated_table_to() {
+        let entries = vec![(
+            "myenv".to_string(),
+            vec![OutdatedPackage {
+                name: "requests".to_string(),
+                installed: "2.30.0".to_string(),
+                latest: "2.31.0".to_string(),
+            }],
+        )];
+        let mut out = Vec::new();
+        print_outdated_table_to(&mut out, &entries, false);
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("requests"));
+        assert!(text.contains("2.31.0"));
+        assert!(!text.contains("Environment"));
+    }
+
+    #[test]
+    fn test_ttl_cache_expires() {
+        let mut cache: TtlCache<u8> = TtlCache::default();
+        cache.insert("k".to_string(), 1);
+        assert_eq!(cache.get("k", Duration::from_secs(60)), Some(1));
+        assert_eq!(cache.get("k", Duration::ZERO), None);
+        cache.expire(Duration::ZERO);
+        assert!(cache.entries.is_empty());
+    }
+}
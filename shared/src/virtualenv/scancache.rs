@@ -0,0 +1,208 @@
+use super::{diskusage::DiskUsage, uvvenv::UvVenv};
+use crate::utils;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+use tokio::fs;
+
+/// Name of the cache file inside the venvs directory.  Environment names
+/// cannot contain dots, so it never clashes with one.
+pub const CACHE_FILE: &str = ".pylot-scan-cache.json";
+
+/// What a scan of one environment found, valid while its directories keep
+/// the modification time it was read at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedVenv {
+    pub modified: SystemTime,
+    pub python_version: String,
+    pub package_count: Option<usize>,
+    pub installed_packages: Vec<String>,
+    pub disk_usage: DiskUsage,
+}
+
+impl CachedVenv {
+    /// Capture the metadata of a fully scanned `venv`.
+    pub fn from_venv(venv: &UvVenv<'_>, modified: SystemTime) -> Self {
+        CachedVenv {
+            modified,
+            python_version: venv.python_version.clone(),
+            package_count: venv.package_count,
+            installed_packages: venv.installed_packages.clone(),
+            disk_usage: venv.disk_usage.unwrap_or_default(),
+        }
+    }
+
+    /// Fill `venv` with the cached metadata instead of scanning it.
+    pub fn apply_to(&self, venv: &mut UvVenv<'_>) {
+        venv.python_version = self.python_version.clone();
+        venv.package_count = self.package_count;
+        venv.installed_packages = self.installed_packages.clone();
+        venv.disk_usage = Some(self.disk_usage);
+    }
+}
+
+/// Metadata of the environments under a venvs directory, keyed by name, so
+/// [`VenvManager::list`](super::venvmanager::VenvManager::list) only rescans
+/// the ones that changed.
+///
+/// Kept in [`CACHE_FILE`] next to the environments.  A missing or unreadable
+/// file is an empty cache.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScanCache {
+    venvs: HashMap<String, CachedVenv>,
+}
+
+impl ScanCache {
+    /// Read the cache of `venvs_path`.
+    pub async fn load(venvs_path: &Path) -> Self {
+        match fs::read_to_string(venvs_path.join(CACHE_FILE)).await {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                log::debug!("Ignoring invalid scan cache: {}", e);
+                ScanCache::default()
+            }),
+            Err(_) => ScanCache::default(),
+        }
+    }
+
+    /// Write the cache of `venvs_path`.  Failing to is only logged, as the
+    /// next scan simply reads the environments again.  The file is replaced
+    /// atomically, so a concurrent `load` never sees half of it.
+    pub async fn save(&self, venvs_path: &Path) {
+        let Ok(content) = serde_json::to_string(self) else {
+            return;
+        };
+        if let Err(e) = utils::write_file_atomic(&venvs_path.join(CACHE_FILE), &content).await {
+            log::debug!("Cannot write scan cache: {}", e);
+        }
+    }
+
+    /// The entry for `name` if it was read at `modified`.
+    pub fn get(&self, name: &str, modified: SystemTime) -> Option<&CachedVenv> {
+        self.venvs
+            .get(name)
+            .filter(|entry| entry.modified == modified)
+    }
+
+    pub fn insert(&mut self, name: String, entry: CachedVenv) {
+        self.venvs.insert(name, entry);
+    }
+
+    /// Add `entry` for `name` to the cache of `venvs_path`, e.g. once an
+    /// environment a lazy listing skipped has been read in full.
+    pub async fn update(venvs_path: &Path, name: &str, entry: CachedVenv) {
+        let mut cache = ScanCache::load(venvs_path).await;
+        if cache.get(name, entry.modified) == Some(&entry) {
+            return;
+        }
+        cache.insert(name.to_string(), entry);
+        cache.save(venvs_path).await;
+    }
+}
+
+/// The latest modification time of the venv directory, its `pyvenv.cfg` and
+/// its `site-packages`, which change whenever the environment is recreated or
+/// a package is added, removed or upgraded.  `None` if none can be read.
+///
+/// Files rewritten inside a package, such as bytecode compiled on import, do
+/// not count, so a cached size can lag behind until one of the above changes.
+pub async fn modified(venv_path: &Path, site_packages: Option<&Path>) -> Option<SystemTime> {
+    let paths = [
+        Some(venv_path.to_path_buf()),
+        Some(venv_path.join("pyvenv.cfg")),
+        site_packages.map(PathBuf::from),
+    ];
+    let mut latest = None;
+    for path in paths.into_iter().flatten() {
+        if let Ok(time) = fs::metadata(&path).await.and_then(|m| m.modified()) {
+            latest = latest.max(Some(time));
+        }
+    }
+    latest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger;
+    use std::time::Duration;
+    use tempfile::tempdir;
+
+    fn entry(modified: SystemTime) -> CachedVenv {
+        CachedVenv {
+            modified,
+            python_version: "3.12.1".to_string(),
+            package_count: Some(1),
+            installed_packages: vec!["six 1.16.0".to_string()],
+            disk_usage: DiskUsage {
+                site_packages: 2048,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_roundtrip() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp_dir = tempdir().unwrap();
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut cache = ScanCache::default();
+        cache.insert("api".to_string(), entry(modified));
+        cache.save(tmp_dir.path()).await;
+
+        let loaded = ScanCache::load(tmp_dir.path()).await;
+        assert_eq!(loaded, cache);
+        assert_eq!(loaded.get("api", modified), Some(&entry(modified)));
+        // A changed directory is a miss.
+        assert_eq!(loaded.get("api", modified + Duration::from_secs(1)), None);
+        assert_eq!(loaded.get("web", modified), None);
+    }
+
+    #[tokio::test]
+    async fn test_update_keeps_other_entries() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp_dir = tempdir().unwrap();
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut cache = ScanCache::default();
+        cache.insert("api".to_string(), entry(modified));
+        cache.save(tmp_dir.path()).await;
+
+        ScanCache::update(tmp_dir.path(), "web", entry(modified)).await;
+        let loaded = ScanCache::load(tmp_dir.path()).await;
+        assert!(loaded.get("api", modified).is_some());
+        assert_eq!(loaded.get("web", modified), Some(&entry(modified)));
+    }
+
+    #[tokio::test]
+    async fn test_load_missing_or_invalid_is_empty() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp_dir = tempdir().unwrap();
+        assert_eq!(ScanCache::load(tmp_dir.path()).await, ScanCache::default());
+        fs::write(tmp_dir.path().join(CACHE_FILE), "not json")
+            .await
+            .unwrap();
+        assert_eq!(ScanCache::load(tmp_dir.path()).await, ScanCache::default());
+    }
+
+    #[tokio::test]
+    async fn test_modified_takes_latest() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp_dir = tempdir().unwrap();
+        let site = tmp_dir.path().join("lib");
+        fs::create_dir(&site).await.unwrap();
+        let dir_time = fs::metadata(tmp_dir.path())
+            .await
+            .unwrap()
+            .modified()
+            .unwrap();
+        let site_time = fs::metadata(&site).await.unwrap().modified().unwrap();
+
+        assert_eq!(
+            modified(tmp_dir.path(), Some(&site)).await,
+            Some(dir_time.max(site_time))
+        );
+        assert_eq!(modified(&tmp_dir.path().join("gone"), None).await, None);
+    }
+}
@@ -1,19 +1,30 @@
 use crate::error::{PylotError, Result};
 use crate::{
-    constants::{DEFAULT_VENV_HOME, ERROR_VENV_NOT_EXISTS, POWERSHELL_CMD, PWSH_CMD, SH_CMD},
-    processes, settings, utils, uvctrl,
+    constants::{
+        DEFAULT_PYTHON_VERSION, DEFAULT_VENV_HOME, ERROR_VENV_NOT_EXISTS, LAST_USED_FILE,
+        POWERSHELL_CMD, PWSH_CMD, SH_CMD, UNIX_PYTHON_EXEC, WIN_PYTHON_EXEC,
+    },
+    context::Pylot,
+    deptree, diskusage, distinfo, doctor, outdated,
+    processes::{self, CommandSpec},
+    scancache, settings, utils,
     venvtraits::{Activate, Create, Delete},
 };
 use colored::Colorize;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
 use tokio::fs as async_fs;
 
 /// A Python virtual environment managed by Astral UV.
 ///
 /// `UvVenv` is the concrete implementation of the [`Create`], [`Delete`], and
 /// [`Activate`] traits.  Instances are typically obtained via
-/// [`VenvManager::list`](crate::virtualenv::venvmanager::VenvManager::list) or
-/// [`VenvManager::find_venv`](crate::virtualenv::venvmanager::VenvManager::find_venv).
+/// [`VenvManager::list`](crate::virtualenv::venvmanager::VenvManager::list),
+/// [`VenvManager::find_venv`](crate::virtualenv::venvmanager::VenvManager::find_venv)
+/// or [`Pylot::venv`], and carry the settings and runner of that context.
 pub struct UvVenv<'a> {
     /// The name of the virtual environment (used as the directory name).
     pub name: Cow<'a, str>,
@@ -26,12 +37,19 @@ pub struct UvVenv<'a> {
     /// When `true`, the configured [`default_pkgs`](crate::cfg::settings::Settings::default_pkgs)
     /// are also installed during creation.
     pub default: bool,
-    pub settings: settings::Settings,
+    /// The context the environment belongs to: its settings, and the runner
+    /// for `uv` and the activation shell.
+    pub ctx: Pylot,
     /// Number of installed packages (populated on demand by scanning the
     /// environment's `site-packages` directory for `.dist-info` entries).
     pub package_count: Option<usize>,
     /// Sorted list of installed package display strings (`"name version"`).
     pub installed_packages: Vec<String>,
+    /// Size on disk (populated on demand by walking the environment directory).
+    pub disk_usage: Option<diskusage::DiskUsage>,
+    /// When Pylot last activated the environment, or when it last changed if
+    /// it never did (populated on demand, see [`UvVenv::set_last_used`]).
+    pub last_used: Option<SystemTime>,
 }
 
 impl<'a> Create for UvVenv<'a> {
@@ -40,10 +58,10 @@ impl<'a> Create for UvVenv<'a> {
         Self::validate_venv_name(&self.name)?;
 
         // Build full path without changing CWD
-        let venvs_path = if self.settings.venvs_path.is_empty() {
+        let venvs_path = if self.ctx.settings.venvs_path.is_empty() {
             DEFAULT_VENV_HOME
         } else {
-            &self.settings.venvs_path
+            &self.ctx.settings.venvs_path
         };
         let path = shellexpand::tilde(venvs_path).to_string();
 
@@ -56,40 +74,12 @@ impl<'a> Create for UvVenv<'a> {
         log::info!("Creating virtual environment: {}", self.name);
 
         // Execute uv venv command in the target directory
-        let mut child = tokio::process::Command::new("uv")
-            .args(args)
-            .current_dir(&path)
-            .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped())
-            .spawn()
-            .map_err(|e| {
-                PylotError::CommandExecution(format!("Failed to spawn uv command: {}", e))
-            })?;
-
-        let stdout = child
-            .stdout
-            .take()
-            .ok_or_else(|| PylotError::CommandExecution("Failed to open stdout".to_string()))?;
-        let stderr = child
-            .stderr
-            .take()
-            .ok_or_else(|| PylotError::CommandExecution("Failed to open stderr".to_string()))?;
-
-        let stdout_reader = tokio::io::BufReader::new(stdout);
-        let stderr_reader = tokio::io::BufReader::new(stderr);
-
-        processes::run_command_with_handlers(
-            stdout_reader,
-            stderr_reader,
-            |line| log::info!("{}", line),
-            |line| log::warn!("{}", line),
-        )
-        .await
-        .map_err(|e| PylotError::CommandExecution(e.to_string()))?;
+        let cmd = CommandSpec::uv(&self.ctx.settings, &args).current_dir(&path);
+        processes::run_logged(self.ctx.runner.as_ref(), &cmd).await?;
 
         let mut pkgs = self.packages.clone();
         if self.default {
-            let default_pkgs = self.settings.default_pkgs.clone();
+            let default_pkgs = self.ctx.settings.default_pkgs.clone();
             pkgs.extend(default_pkgs);
         }
 
@@ -99,7 +89,7 @@ impl<'a> Create for UvVenv<'a> {
                 Self::validate_package_name(pkg)?;
             }
 
-            let venv_path = shellexpand::tilde(&self.settings.venvs_path).to_string();
+            let venv_path = shellexpand::tilde(&self.ctx.settings.venvs_path).to_string();
             self.install_packages(pkgs, venv_path).await?;
         }
 
@@ -112,7 +102,7 @@ impl<'a> Delete for UvVenv<'a> {
         // Validate venv name
         Self::validate_venv_name(&self.name)?;
 
-        let path = shellexpand::tilde(&self.settings.venvs_path).to_string();
+        let path = shellexpand::tilde(&self.ctx.settings.venvs_path).to_string();
         let venv_path = format!("{}/{}", path, self.name);
 
         if !async_fs::try_exists(&venv_path).await.unwrap_or(false) {
@@ -154,6 +144,7 @@ impl<'a> Activate for UvVenv<'a> {
             return Err(PylotError::VenvNotFound(ERROR_VENV_NOT_EXISTS.to_string()));
         }
 
+        self.touch_last_used().await;
         log::info!("\nActivating virtual environment: {}", self.name);
         log::warn!(
             "{} {}",
@@ -167,12 +158,13 @@ impl<'a> Activate for UvVenv<'a> {
 }
 
 impl<'a> UvVenv<'a> {
-    /// Create a new `UvVenv` with the given metadata.
+    /// Create a new `UvVenv` in `ctx` with the given metadata.
     ///
     /// `path`, `python_version`, `packages`, and `default` may be empty /
     /// `false` when building a lightweight handle used only for name-based
     /// lookups.
     pub fn new(
+        ctx: &Pylot,
         name: Cow<'a, str>,
         path: String,
         python_version: String,
@@ -185,9 +177,11 @@ impl<'a> UvVenv<'a> {
             python_version,
             packages,
             default,
-            settings: settings::Settings::get_settings(),
+            ctx: ctx.clone(),
             package_count: None,
             installed_packages: Vec::new(),
+            disk_usage: None,
+            last_used: None,
         }
     }
 
@@ -257,29 +251,160 @@ impl<'a> UvVenv<'a> {
     /// Scan the venv's `site-packages` for `.dist-info` directories.
     /// Populates `self.package_count` and `self.installed_packages` (sorted).
     pub(crate) async fn count_packages(&mut self) {
-        // Unix layout: {path}/lib/pythonX.Y/site-packages/
-        if let Ok(mut lib_entries) = async_fs::read_dir(format!("{}/lib", self.path)).await {
+        if let Some(site_pkgs) = Self::find_site_packages(&self.path).await {
+            if let Some(pkgs) = Self::collect_dist_info_packages(&site_pkgs).await {
+                self.package_count = Some(pkgs.len());
+                self.installed_packages = pkgs;
+            }
+        }
+    }
+
+    /// Read when the environment was last used: when Pylot last activated it,
+    /// or else when its directory, `pyvenv.cfg` or `site-packages` last
+    /// changed.  Access times are no help here, as listing the environment
+    /// reads the same files.
+    pub async fn set_last_used(&mut self) {
+        self.last_used = match self.last_activated().await {
+            Some(time) => Some(time),
+            None => {
+                let site_packages = self.site_packages_dir().await;
+                crate::scancache::modified(Path::new(&self.dir()), site_packages.as_deref()).await
+            }
+        };
+    }
+
+    /// When Pylot last activated the environment, if it ever did.
+    pub(crate) async fn last_activated(&self) -> Option<SystemTime> {
+        let marker = Path::new(&self.dir()).join(LAST_USED_FILE);
+        async_fs::metadata(marker)
+            .await
+            .and_then(|m| m.modified())
+            .ok()
+    }
+
+    /// `path`, or the directory the settings place the environment in when
+    /// it is not set.
+    fn dir(&self) -> String {
+        if self.path.is_empty() {
+            self.venv_dir()
+        } else {
+            self.path.clone()
+        }
+    }
+
+    /// Record that the environment is being used, for [`UvVenv::set_last_used`].
+    async fn touch_last_used(&self) {
+        let marker = Path::new(&self.venv_dir()).join(LAST_USED_FILE);
+        if let Err(e) = async_fs::write(&marker, "").await {
+            log::debug!("Cannot write '{}': {}", marker.display(), e);
+        }
+    }
+
+    /// Read the package list and disk usage if they were left out, e.g. by a
+    /// [`ScanMode::Lazy`](crate::venvmanager::ScanMode::Lazy) listing.  An
+    /// environment without `site-packages` ends up with no packages.
+    ///
+    /// What was read is added to the [`ScanCache`](crate::scancache::ScanCache)
+    /// of the venvs directory, so the next listing need not read it again.
+    pub async fn load_packages(&mut self) {
+        if self.package_count.is_some() && self.disk_usage.is_some() {
+            return;
+        }
+        let dir = self.dir();
+        let site_packages = self.site_packages_dir().await;
+        // Taken first, so a change made while reading makes the entry stale.
+        let modified = scancache::modified(Path::new(&dir), site_packages.as_deref()).await;
+        if self.package_count.is_none() {
+            self.count_packages().await;
+            self.package_count.get_or_insert(0);
+        }
+        if self.disk_usage.is_none() {
+            self.measure_disk_usage().await;
+        }
+        if let (Some(modified), Some(venvs_path)) = (modified, Path::new(&dir).parent()) {
+            let entry = scancache::CachedVenv::from_venv(self, modified);
+            scancache::ScanCache::update(venvs_path, &self.name, entry).await;
+        }
+    }
+
+    /// Walk the venv directory and populate `self.disk_usage`.
+    pub async fn measure_disk_usage(&mut self) -> diskusage::DiskUsage {
+        let path = if self.path.is_empty() {
+            self.venv_dir()
+        } else {
+            self.path.clone()
+        };
+        let usage = diskusage::measure(Path::new(&path)).await;
+        self.disk_usage = Some(usage);
+        usage
+    }
+
+    /// Return the `site-packages` directory of this virtual environment, or
+    /// `None` if it cannot be found.
+    pub async fn site_packages_dir(&self) -> Option<PathBuf> {
+        if self.path.is_empty() {
+            Self::find_site_packages(&self.venv_dir()).await
+        } else {
+            Self::find_site_packages(&self.path).await
+        }
+    }
+
+    /// Locate `site-packages` below `venv_path`, trying the Unix layout
+    /// (`lib/pythonX.Y/site-packages`) before the Windows one
+    /// (`Lib/site-packages`).
+    pub(crate) async fn find_site_packages(venv_path: &str) -> Option<PathBuf> {
+        if let Ok(mut lib_entries) = async_fs::read_dir(format!("{}/lib", venv_path)).await {
             while let Ok(Some(entry)) = lib_entries.next_entry().await {
                 if entry.file_type().await.map(|t| t.is_dir()).unwrap_or(false) {
                     let name = entry.file_name().to_string_lossy().to_string();
-                    if name.starts_with("python") {
-                        let site_pkgs = entry.path().join("site-packages");
-                        if let Some(pkgs) = Self::collect_dist_info_packages(&site_pkgs).await {
-                            self.package_count = Some(pkgs.len());
-                            self.installed_packages = pkgs;
-                            return;
-                        }
+                    let site_pkgs = entry.path().join("site-packages");
+                    if name.starts_with("python") && async_fs::metadata(&site_pkgs).await.is_ok() {
+                        return Some(site_pkgs);
                     }
                 }
             }
         }
-        // Windows layout: {path}/Lib/site-packages/
-        let win_path = std::path::Path::new(&self.path)
-            .join("Lib")
-            .join("site-packages");
-        if let Some(pkgs) = Self::collect_dist_info_packages(&win_path).await {
-            self.package_count = Some(pkgs.len());
-            self.installed_packages = pkgs;
+        let win_path = Path::new(venv_path).join("Lib").join("site-packages");
+        async_fs::metadata(&win_path).await.ok().map(|_| win_path)
+    }
+
+    /// Build the dependency graph of the installed packages from their
+    /// `METADATA` files.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PylotError::PathError`] when no `site-packages` directory
+    /// exists.
+    pub async fn dependency_graph(&self) -> Result<deptree::DependencyGraph> {
+        let site_pkgs = self.site_packages_dir().await.ok_or_else(|| {
+            PylotError::PathError(format!(
+                "No site-packages directory found for '{}'",
+                self.name
+            ))
+        })?;
+        Ok(deptree::DependencyGraph::load(&site_pkgs).await)
+    }
+
+    /// Read the descriptive metadata of the installed `package` from its
+    /// `.dist-info` directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PylotError::PathError`] when no `site-packages` directory
+    /// exists, or [`PylotError::Other`] when the package is not installed.
+    pub async fn package_details(&self, package: &str) -> Result<distinfo::PackageDetails> {
+        let site_pkgs = self.site_packages_dir().await.ok_or_else(|| {
+            PylotError::PathError(format!(
+                "No site-packages directory found for '{}'",
+                self.name
+            ))
+        })?;
+        match distinfo::find_dist_info(&site_pkgs, package).await {
+            Some(dist) => Ok(dist.details().await),
+            None => Err(PylotError::Other(format!(
+                "Package '{}' is not installed in '{}'",
+                package, self.name
+            ))),
         }
     }
 
@@ -327,7 +452,7 @@ impl<'a> UvVenv<'a> {
         for pkg in &pkgs {
             Self::validate_package_name(pkg)?;
         }
-        let venvs_path = shellexpand::tilde(&self.settings.venvs_path).to_string();
+        let venvs_path = shellexpand::tilde(&self.ctx.settings.venvs_path).to_string();
         self.install_packages(pkgs, venvs_path).await
     }
 
@@ -374,7 +499,7 @@ impl<'a> UvVenv<'a> {
 
         log::info!("Installing from requirements file: {}", expanded_path);
 
-        let venvs_path = shellexpand::tilde(&self.settings.venvs_path).to_string();
+        let venvs_path = shellexpand::tilde(&self.ctx.settings.venvs_path).to_string();
         let activate_script = if cfg!(target_os = "windows") {
             format!("{}/{}/scripts/activate.ps1", venvs_path, self.name)
         } else {
@@ -382,57 +507,28 @@ impl<'a> UvVenv<'a> {
         };
 
         let (cmd, args) = if cfg!(target_os = "windows") {
-            let pwsh_cmd = if uvctrl::check(PWSH_CMD).await.is_ok() {
-                PWSH_CMD
-            } else {
-                POWERSHELL_CMD
-            };
             let command = format!(
-                "{} ; uv pip install -r \"{}\"",
-                activate_script, expanded_path
+                "{} ; {} pip install -r \"{}\"",
+                activate_script,
+                self.uv_in_shell(),
+                expanded_path
             );
-            (pwsh_cmd, vec!["-Command".to_string(), command])
+            (self.powershell(), vec!["-Command".to_string(), command])
         } else {
             let command = format!(
-                ". {} && uv pip install -r \"{}\"",
-                activate_script, expanded_path
+                ". {} && {} pip install -r \"{}\"",
+                activate_script,
+                self.uv_in_shell(),
+                expanded_path
             );
             (SH_CMD, vec!["-c".to_string(), command])
         };
 
-        let mut child = tokio::process::Command::new(cmd)
-            .args(&args)
-            .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped())
-            .spawn()
+        processes::run_logged(self.ctx.runner.as_ref(), &CommandSpec::new(cmd, &args))
+            .await
             .map_err(|e| {
-                PylotError::CommandExecution(format!("Failed to install from requirements: {}", e))
-            })?;
-
-        let stdout = child
-            .stdout
-            .take()
-            .ok_or_else(|| PylotError::CommandExecution("Failed to open stdout".to_string()))?;
-        let stderr = child
-            .stderr
-            .take()
-            .ok_or_else(|| PylotError::CommandExecution("Failed to open stderr".to_string()))?;
-
-        let stdout_reader = tokio::io::BufReader::new(stdout);
-        let stderr_reader = tokio::io::BufReader::new(stderr);
-
-        processes::run_command_with_handlers(
-            stdout_reader,
-            stderr_reader,
-            |line| log::info!("{}", line),
-            |line| log::warn!("{}", line),
-        )
-        .await
-        .map_err(|e| {
-            PylotError::CommandExecution(format!("Error installing from requirements: {}", e))
-        })?;
-
-        Ok(())
+                PylotError::CommandExecution(format!("Error installing from requirements: {}", e))
+            })
     }
 
     /// Remove packages from this virtual environment.
@@ -445,85 +541,258 @@ impl<'a> UvVenv<'a> {
         for pkg in &pkgs {
             Self::validate_package_name(pkg)?;
         }
-        let venvs_path = shellexpand::tilde(&self.settings.venvs_path).to_string();
+        let venvs_path = shellexpand::tilde(&self.ctx.settings.venvs_path).to_string();
         self.uninstall_packages(pkgs, venvs_path).await
     }
 
-    /// Uninstall packages without shell command injection
-    async fn uninstall_packages(&self, pkgs: Vec<String>, venv_path: String) -> Result<()> {
-        log::info!("{} {}", "Uninstalling package(s):", pkgs.join(", "));
+    /// Upgrade packages in this virtual environment to their latest versions.
+    ///
+    /// Validates all package names then runs `uv pip install --upgrade` inside
+    /// the venv.
+    pub async fn upgrade_packages(&self, pkgs: Vec<String>) -> Result<()> {
+        if pkgs.is_empty() {
+            return Ok(());
+        }
+        for pkg in &pkgs {
+            Self::validate_package_name(pkg)?;
+        }
+        let venvs_path = shellexpand::tilde(&self.ctx.settings.venvs_path).to_string();
+        log::info!("{} {}", "Upgrading package(s):", pkgs.join(", "));
+        self.run_pip(&["install", "--upgrade"], &pkgs, venvs_path)
+            .await
+            .map_err(|e| PylotError::CommandExecution(format!("Error upgrading packages: {}", e)))
+    }
 
-        let activate_script = if cfg!(target_os = "windows") {
-            format!("{}/{}/scripts/activate.ps1", venv_path, self.name)
-        } else {
-            format!("{}/{}/bin/activate", venv_path, self.name)
-        };
+    /// Upgrade every package currently installed in this virtual environment.
+    ///
+    /// The installed packages are discovered by scanning `site-packages`, so
+    /// `self.path` is filled in from the configured venvs directory when empty.
+    pub async fn upgrade_all_packages(&mut self) -> Result<()> {
+        if self.path.is_empty() {
+            self.path = self.venv_dir();
+        }
+        self.count_packages().await;
+        let names: Vec<String> = self
+            .installed_packages
+            .iter()
+            .filter_map(|p| p.split_whitespace().next())
+            .map(str::to_string)
+            .collect();
+        if names.is_empty() {
+            log::warn!("No installed packages found in '{}'", self.name);
+            return Ok(());
+        }
+        self.upgrade_packages(names).await
+    }
 
-        let (cmd, args) = if cfg!(target_os = "windows") {
-            let pwsh_cmd = if uvctrl::check(PWSH_CMD).await.is_ok() {
-                PWSH_CMD
-            } else {
-                POWERSHELL_CMD
-            };
-
-            let mut command_parts = vec![activate_script.clone(), ";".to_string()];
-            command_parts.push("uv".to_string());
-            command_parts.push("pip".to_string());
-            command_parts.push("uninstall".to_string());
-            command_parts.extend(pkgs.iter().cloned());
+    /// Check this virtual environment for problems, see [`doctor::diagnose`].
+    pub async fn diagnose(&self) -> Vec<doctor::Issue> {
+        doctor::diagnose(self.ctx.runner.as_ref(), Path::new(&self.venv_dir())).await
+    }
 
-            (
-                pwsh_cmd,
-                vec!["-Command".to_string(), command_parts.join(" ")],
-            )
-        } else {
-            let mut command_parts =
-                vec![".".to_string(), activate_script.clone(), "&&".to_string()];
-            command_parts.push("uv".to_string());
-            command_parts.push("pip".to_string());
-            command_parts.push("uninstall".to_string());
-            command_parts.extend(pkgs.iter().cloned());
+    /// Repair the problems in `issues`.
+    ///
+    /// When any issue needs a rebuild the environment is recreated with the
+    /// same Python minor version and its installed packages are reinstalled.
+    /// Otherwise stale activate scripts are rewritten, the recorded version is
+    /// updated and packages without a `RECORD` are reinstalled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be rewritten or a `uv` command fails.
+    pub async fn repair(&self, issues: &[doctor::Issue]) -> Result<()> {
+        Self::validate_venv_name(&self.name)?;
+        let venv_dir = self.venv_dir();
+        let venv_path = Path::new(&venv_dir);
+        if issues.iter().any(doctor::Issue::needs_rebuild) {
+            return self.rebuild().await;
+        }
 
-            (SH_CMD, vec!["-c".to_string(), command_parts.join(" ")])
+        // Activate scripts must be fixed first: `uv pip` runs inside them.
+        let fixed = doctor::fix_activate_scripts(venv_path, issues).await?;
+        if fixed > 0 {
+            log::info!("Rewrote {} activate script(s) in '{}'", fixed, self.name);
+        }
+        for issue in issues {
+            if let doctor::Issue::VersionMismatch { actual, .. } = issue {
+                doctor::fix_recorded_version(venv_path, actual).await?;
+                log::info!("Updated the recorded Python version to {}", actual);
+            }
+        }
+        let reinstall: Vec<String> = issues
+            .iter()
+            .filter_map(|issue| match issue {
+                doctor::Issue::MissingRecord { package, version } => {
+                    Some(format!("{}=={}", package, version))
+                }
+                _ => None,
+            })
+            .collect();
+        if !reinstall.is_empty() {
+            for pkg in &reinstall {
+                Self::validate_package_name(pkg)?;
+            }
+            let venvs_path = shellexpand::tilde(&self.ctx.settings.venvs_path).to_string();
+            self.run_pip(&["install", "--reinstall"], &reinstall, venvs_path)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Recreate this environment and reinstall the packages found in its
+    /// `site-packages`, pinned to their installed versions.
+    ///
+    /// The old environment is moved to a hidden sibling directory while the
+    /// new one is created, and moved back when the rebuild fails.
+    async fn rebuild(&self) -> Result<()> {
+        let venv_dir = self.venv_dir();
+        let venv_path = Path::new(&venv_dir);
+        let version = match doctor::rebuild_version(venv_path).await {
+            Some(version) => version,
+            None if !self.python_version.is_empty() => self.python_version.clone(),
+            None => DEFAULT_PYTHON_VERSION.to_string(),
+        };
+        let packages: Vec<String> = match Self::find_site_packages(&venv_dir).await {
+            Some(site_pkgs) => distinfo::read_site_packages(&site_pkgs)
+                .await
+                .into_iter()
+                .map(|dist| format!("{}=={}", dist.name, dist.version))
+                .collect(),
+            None => Vec::new(),
         };
+        for pkg in &packages {
+            Self::validate_package_name(pkg)?;
+        }
 
-        let mut child = tokio::process::Command::new(cmd)
-            .args(&args)
-            .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped())
-            .spawn()
-            .map_err(|e| {
-                PylotError::CommandExecution(format!("Failed to uninstall packages: {}", e))
-            })?;
-
-        let stdout = child
-            .stdout
-            .take()
-            .ok_or_else(|| PylotError::CommandExecution("Failed to open stdout".to_string()))?;
-        let stderr = child
-            .stderr
-            .take()
-            .ok_or_else(|| PylotError::CommandExecution("Failed to open stderr".to_string()))?;
-
-        let stdout_reader = tokio::io::BufReader::new(stdout);
-        let stderr_reader = tokio::io::BufReader::new(stderr);
-
-        processes::run_command_with_handlers(
-            stdout_reader,
-            stderr_reader,
-            |line| log::info!("{}", line),
-            |line| log::warn!("{}", line),
+        log::info!(
+            "Rebuilding '{}' with Python {} and {} package(s)",
+            self.name,
+            version,
+            packages.len()
+        );
+        let backup = Path::new(&venv_dir).with_file_name(format!(".{}.rebuild", self.name));
+        if async_fs::try_exists(&backup).await.unwrap_or(false) {
+            return Err(PylotError::Other(format!(
+                "'{}' exists, probably from an interrupted rebuild; restore or remove it first",
+                backup.display()
+            )));
+        }
+        async_fs::rename(venv_path, &backup)
+            .await
+            .map_err(PylotError::Io)?;
+        let result = UvVenv::new(
+            &self.ctx,
+            self.name.clone(),
+            String::new(),
+            version,
+            packages,
+            false,
         )
-        .await
-        .map_err(|e| PylotError::CommandExecution(format!("Error uninstalling packages: {}", e)))?;
+        .create()
+        .await;
+        match result {
+            Ok(()) => {
+                if let Err(e) = async_fs::remove_dir_all(&backup).await {
+                    log::warn!("Could not remove '{}': {}", backup.display(), e);
+                }
+                Ok(())
+            }
+            Err(e) => {
+                if async_fs::try_exists(venv_path).await.unwrap_or(false) {
+                    async_fs::remove_dir_all(venv_path)
+                        .await
+                        .map_err(PylotError::Io)?;
+                }
+                async_fs::rename(&backup, venv_path)
+                    .await
+                    .map_err(PylotError::Io)?;
+                log::warn!(
+                    "Rebuilding '{}' failed, kept the old environment",
+                    self.name
+                );
+                Err(e)
+            }
+        }
+    }
 
-        Ok(())
+    /// Return the absolute path of this virtual environment inside the
+    /// configured venvs directory.
+    pub fn venv_dir(&self) -> String {
+        let path = shellexpand::tilde(&self.ctx.settings.venvs_path).to_string();
+        format!("{}/{}", path.trim_end_matches('/'), self.name)
+    }
+
+    /// Return the installed packages that have a newer release available.
+    ///
+    /// Depending on [`Settings::outdated_source`](settings::Settings::outdated_source)
+    /// the configured package index is queried directly, or `uv pip list
+    /// --outdated` is run against the environment's interpreter.  Lookups are
+    /// cached in the venvs directory for
+    /// [`outdated_cache_ttl`](settings::Settings::outdated_cache_ttl) seconds,
+    /// see [`outdated::OutdatedCache`].
+    pub async fn outdated_packages(&mut self) -> Result<outdated::OutdatedReport> {
+        if self.path.is_empty() {
+            self.path = self.venv_dir();
+        }
+        self.count_packages().await;
+        let index_url = self.ctx.settings.index_url();
+        let ttl = Duration::from_secs(self.ctx.settings.outdated_cache_ttl);
+        let cache_dir = PathBuf::from(shellexpand::tilde(&self.ctx.settings.venvs_path).as_ref());
+        match self.ctx.settings.outdated_source {
+            settings::OutdatedSource::Index => {
+                outdated::PackageIndex::new(&index_url, ttl)
+                    .with_cache_dir(&cache_dir)
+                    .outdated(&self.installed_packages)
+                    .await
+            }
+            settings::OutdatedSource::Uv => {
+                let exec = if cfg!(windows) {
+                    WIN_PYTHON_EXEC
+                } else {
+                    UNIX_PYTHON_EXEC
+                };
+                let python = format!("{}/{}", self.path, exec);
+                let outdated = outdated::uv_outdated(
+                    self.ctx.runner.as_ref(),
+                    &self.ctx.settings.uv_program(),
+                    &python,
+                    &index_url,
+                    &self.installed_packages,
+                    ttl,
+                    Some(&cache_dir),
+                )
+                .await?;
+                Ok(outdated::OutdatedReport {
+                    outdated,
+                    failed: Vec::new(),
+                })
+            }
+        }
+    }
+
+    /// Uninstall packages without shell command injection
+    async fn uninstall_packages(&self, pkgs: Vec<String>, venv_path: String) -> Result<()> {
+        log::info!("{} {}", "Uninstalling package(s):", pkgs.join(", "));
+        self.run_pip(&["uninstall"], &pkgs, venv_path)
+            .await
+            .map_err(|e| {
+                PylotError::CommandExecution(format!("Error uninstalling packages: {}", e))
+            })
     }
 
     /// Install packages without shell command injection
     async fn install_packages(&self, pkgs: Vec<String>, venv_path: String) -> Result<()> {
         log::info!("{} {}", "Installing package(s):", pkgs.join(", "));
+        self.run_pip(&["install"], &pkgs, venv_path)
+            .await
+            .map_err(|e| PylotError::CommandExecution(format!("Error installing packages: {}", e)))
+    }
 
+    /// Activate the venv and run `uv pip <pip_args> <pkgs>` inside it, streaming
+    /// the output to the log.
+    ///
+    /// Package names must be validated by the caller before reaching this point.
+    async fn run_pip(&self, pip_args: &[&str], pkgs: &[String], venv_path: String) -> Result<()> {
         // Determine activation script path
         let activate_script = if cfg!(target_os = "windows") {
             format!("{}/{}/scripts/activate.ps1", venv_path, self.name)
@@ -531,78 +800,62 @@ impl<'a> UvVenv<'a> {
             format!("{}/{}/bin/activate", venv_path, self.name)
         };
 
-        // Build command to activate venv and install packages
+        // Build command to activate venv and run uv pip
         // Note: We must concatenate packages into a single command string because both
         // PowerShell's -Command and sh's -c require a single string argument.
         // Package names are validated beforehand to prevent injection attacks.
-        let (cmd, args) = if cfg!(target_os = "windows") {
-            let pwsh_cmd = if uvctrl::check(PWSH_CMD).await.is_ok() {
-                PWSH_CMD
-            } else {
-                POWERSHELL_CMD
-            };
+        let mut uv_parts = vec![self.uv_in_shell(), "pip".to_string()];
+        uv_parts.extend(pip_args.iter().map(|a| a.to_string()));
+        uv_parts.extend(pkgs.iter().cloned());
 
+        let (cmd, args) = if cfg!(target_os = "windows") {
             // For PowerShell, we need to build a command string that activates and then runs uv
-            let mut command_parts = vec![activate_script.clone(), ";".to_string()];
-            command_parts.push("uv".to_string());
-            command_parts.push("pip".to_string());
-            command_parts.push("install".to_string());
-            command_parts.extend(pkgs.iter().cloned());
+            let mut command_parts = vec![activate_script, ";".to_string()];
+            command_parts.extend(uv_parts);
 
             (
-                pwsh_cmd,
+                self.powershell(),
                 vec!["-Command".to_string(), command_parts.join(" ")],
             )
         } else {
             // For Unix, we use command chaining with sh -c
-            let mut command_parts =
-                vec![".".to_string(), activate_script.clone(), "&&".to_string()];
-            command_parts.push("uv".to_string());
-            command_parts.push("pip".to_string());
-            command_parts.push("install".to_string());
-            command_parts.extend(pkgs.iter().cloned());
+            let mut command_parts = vec![".".to_string(), activate_script, "&&".to_string()];
+            command_parts.extend(uv_parts);
 
             (SH_CMD, vec!["-c".to_string(), command_parts.join(" ")])
         };
 
-        let mut child = tokio::process::Command::new(cmd)
-            .args(&args)
-            .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped())
-            .spawn()
-            .map_err(|e| {
-                PylotError::CommandExecution(format!("Failed to install packages: {}", e))
-            })?;
-
-        let stdout = child
-            .stdout
-            .take()
-            .ok_or_else(|| PylotError::CommandExecution("Failed to open stdout".to_string()))?;
-        let stderr = child
-            .stderr
-            .take()
-            .ok_or_else(|| PylotError::CommandExecution("Failed to open stderr".to_string()))?;
-
-        let stdout_reader = tokio::io::BufReader::new(stdout);
-        let stderr_reader = tokio::io::BufReader::new(stderr);
-
-        processes::run_command_with_handlers(
-            stdout_reader,
-            stderr_reader,
-            |line| log::info!("{}", line),
-            |line| log::warn!("{}", line),
-        )
-        .await
-        .map_err(|e| PylotError::CommandExecution(format!("Error installing packages: {}", e)))?;
+        processes::run_logged(self.ctx.runner.as_ref(), &CommandSpec::new(cmd, &args)).await
+    }
 
-        Ok(())
+    /// PowerShell 7 when installed, otherwise Windows PowerShell.
+    fn powershell(&self) -> &'static str {
+        if self.ctx.runner.which(PWSH_CMD).is_some() {
+            PWSH_CMD
+        } else {
+            POWERSHELL_CMD
+        }
+    }
+
+    /// The uv executable as written in a shell command line, quoted when the
+    /// configured `uv_path` contains spaces.
+    fn uv_in_shell(&self) -> String {
+        let uv = self.ctx.settings.uv_program();
+        match (
+            uv.contains(char::is_whitespace),
+            cfg!(target_os = "windows"),
+        ) {
+            (false, _) => uv,
+            (true, true) => format!("& '{}'", uv),
+            (true, false) => format!("'{}'", uv),
+        }
     }
 
     fn get_shell_cmd(&self) -> Result<(processes::Shell, Vec<String>, String)> {
         // Validate venv name to prevent command injection
         Self::validate_venv_name(&self.name)?;
 
-        let path = shellexpand::tilde(&self.settings.venvs_path).to_string();
+        let path = shellexpand::tilde(&self.ctx.settings.venvs_path).to_string();
         let shell = processes::get_parent_shell()?;
 
         let (cmd, path) = if cfg!(target_os = "windows") {
@@ -631,6 +884,7 @@ mod tests {
     use crate::logger;
 
     use super::*;
+    use std::sync::Arc;
 
     #[tokio::test]
     async fn test_validate_venv_name_valid() {
@@ -673,6 +927,7 @@ mod tests {
     async fn test_venv() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("test_venv"),
             "".to_string(),
             "3.8".to_string(),
@@ -687,6 +942,7 @@ mod tests {
     async fn test_venv_clean() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("test_venv_clean"),
             "".to_string(),
             "3.9".to_string(),
@@ -702,6 +958,7 @@ mod tests {
     async fn test_get_shell_cmd_windows() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("test_venv"),
             "".to_string(),
             "3.9".to_string(),
@@ -743,6 +1000,7 @@ mod tests {
             .unwrap();
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myenv"),
             dir.path().to_str().unwrap().to_string(),
             "".to_string(),
@@ -761,6 +1019,7 @@ mod tests {
         let dir = tempdir().unwrap();
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myenv"),
             dir.path().to_str().unwrap().to_string(),
             "original".to_string(),
@@ -787,6 +1046,7 @@ mod tests {
         .unwrap();
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myenv"),
             dir.path().to_str().unwrap().to_string(),
             "fallback".to_string(),
@@ -829,6 +1089,39 @@ mod tests {
         assert!(UvVenv::validate_venv_name("my env").is_err());
     }
 
+    // ── set_last_used ────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_set_last_used_prefers_activation_marker() {
+        use tempfile::tempdir;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempdir().unwrap();
+        let mut venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Borrowed("myenv"),
+            dir.path().to_str().unwrap().to_string(),
+            "3.11".to_string(),
+            vec![],
+            false,
+        );
+        venv.set_last_used().await;
+        let changed = std::fs::metadata(dir.path()).unwrap().modified().unwrap();
+        assert_eq!(venv.last_used, Some(changed));
+
+        let marker = dir.path().join(LAST_USED_FILE);
+        std::fs::write(&marker, "").unwrap();
+        let activated = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        std::fs::File::options()
+            .write(true)
+            .open(&marker)
+            .unwrap()
+            .set_modified(activated)
+            .unwrap();
+        venv.set_last_used().await;
+        assert_eq!(venv.last_used, Some(activated));
+    }
+
     // ── count_packages ───────────────────────────────────────────────────────
 
     #[tokio::test]
@@ -839,6 +1132,7 @@ mod tests {
         let dir = tempdir().unwrap();
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myenv"),
             dir.path().to_str().unwrap().to_string(),
             "3.11".to_string(),
@@ -876,6 +1170,7 @@ mod tests {
             .unwrap(); // not a dist-info dir
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myenv"),
             dir.path().to_str().unwrap().to_string(),
             "3.11".to_string(),
@@ -905,6 +1200,7 @@ mod tests {
             .unwrap();
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myenv"),
             dir.path().to_str().unwrap().to_string(),
             "3.11".to_string(),
@@ -918,6 +1214,109 @@ mod tests {
         assert_eq!(venv.installed_packages, vec!["numpy 1.26.0"]);
     }
 
+    // ── site_packages_dir / dependency_graph ─────────────────────────────────
+
+    #[tokio::test]
+    async fn test_site_packages_dir_windows_layout() {
+        use tempfile::tempdir;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempdir().unwrap();
+        let site_pkgs = dir.path().join("Lib").join("site-packages");
+        tokio::fs::create_dir_all(&site_pkgs).await.unwrap();
+
+        let venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Borrowed("myenv"),
+            dir.path().to_str().unwrap().to_string(),
+            "3.11".to_string(),
+            vec![],
+            false,
+        );
+        assert_eq!(venv.site_packages_dir().await, Some(site_pkgs));
+    }
+
+    #[tokio::test]
+    async fn test_dependency_graph_no_site_packages_is_error() {
+        use tempfile::tempdir;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempdir().unwrap();
+        let venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Borrowed("myenv"),
+            dir.path().to_str().unwrap().to_string(),
+            "3.11".to_string(),
+            vec![],
+            false,
+        );
+        let result = venv.dependency_graph().await;
+        assert!(matches!(result, Err(PylotError::PathError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_dependency_graph_unix_layout() {
+        use crate::virtualenv::distinfo::tests::write_dist_info;
+        use tempfile::tempdir;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempdir().unwrap();
+        let site_pkgs = dir
+            .path()
+            .join("lib")
+            .join("python3.12")
+            .join("site-packages");
+        write_dist_info(&site_pkgs, "flask", "3.0.0", &["werkzeug>=3.0.0"]).await;
+        write_dist_info(&site_pkgs, "werkzeug", "3.0.1", &[]).await;
+
+        let venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Borrowed("myenv"),
+            dir.path().to_str().unwrap().to_string(),
+            "3.12".to_string(),
+            vec![],
+            false,
+        );
+        let graph = venv.dependency_graph().await.unwrap();
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph.dependents("werkzeug").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_package_details() {
+        use crate::virtualenv::distinfo::tests::write_dist_info;
+        use tempfile::tempdir;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempdir().unwrap();
+        let site_pkgs = dir
+            .path()
+            .join("lib")
+            .join("python3.12")
+            .join("site-packages");
+        let dist_dir = write_dist_info(&site_pkgs, "flask", "3.0.0", &[]).await;
+        async_fs::write(dist_dir.join("INSTALLER"), "uv\n")
+            .await
+            .unwrap();
+
+        let venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Borrowed("myenv"),
+            dir.path().to_str().unwrap().to_string(),
+            "3.12".to_string(),
+            vec![],
+            false,
+        );
+        let details = venv.package_details("Flask").await.unwrap();
+        assert_eq!(details.name, "flask");
+        assert_eq!(details.version, "3.0.0");
+        assert_eq!(details.installer.as_deref(), Some("uv"));
+        assert!(!details.requested);
+
+        let result = venv.package_details("django").await;
+        assert!(matches!(result, Err(PylotError::Other(_))));
+    }
+
     // ── format_dist_info_name ────────────────────────────────────────────────
 
     #[test]
@@ -960,6 +1359,7 @@ mod tests {
     #[tokio::test]
     async fn test_add_packages_empty_list_returns_ok() {
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
@@ -973,6 +1373,7 @@ mod tests {
     #[tokio::test]
     async fn test_remove_packages_empty_list_returns_ok() {
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
@@ -985,6 +1386,7 @@ mod tests {
     #[tokio::test]
     async fn test_add_packages_rejects_invalid_package_name() {
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
@@ -998,6 +1400,7 @@ mod tests {
     #[tokio::test]
     async fn test_remove_packages_rejects_invalid_package_name() {
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
@@ -1008,6 +1411,117 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // ── upgrade_packages ─────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_upgrade_packages_empty_list_returns_ok() {
+        let venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Borrowed("myvenv"),
+            "".to_string(),
+            "3.11".to_string(),
+            vec![],
+            false,
+        );
+        assert!(venv.upgrade_packages(vec![]).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_packages_rejects_invalid_package_name() {
+        let venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Borrowed("myvenv"),
+            "".to_string(),
+            "3.11".to_string(),
+            vec![],
+            false,
+        );
+        let result = venv.upgrade_packages(vec!["evil && id".to_string()]).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_all_packages_without_packages_returns_ok() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Borrowed("myvenv"),
+            tmp.path().to_string_lossy().to_string(),
+            "3.11".to_string(),
+            vec![],
+            false,
+        );
+        // No site-packages directory means nothing to upgrade.
+        assert!(venv.upgrade_all_packages().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_venv_dir_joins_venvs_path_and_name() {
+        let venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Borrowed("myvenv"),
+            "".to_string(),
+            "3.11".to_string(),
+            vec![],
+            false,
+        );
+        let dir = venv.venv_dir();
+        assert!(dir.ends_with("/myvenv"));
+        assert!(!dir.contains("//myvenv"));
+    }
+
+    // ── outdated_packages ────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_outdated_packages_against_stub_index() {
+        use tempfile::tempdir;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempdir().unwrap();
+        let site_pkgs = dir
+            .path()
+            .join("lib")
+            .join("python3.12")
+            .join("site-packages");
+        tokio::fs::create_dir_all(site_pkgs.join("flask-3.0.0.dist-info"))
+            .await
+            .unwrap();
+        tokio::fs::create_dir_all(site_pkgs.join("flask_cors-4.0.0.dist-info"))
+            .await
+            .unwrap();
+
+        let url = outdated::tests::spawn_stub_index(vec![
+            (
+                "/simple/flask/",
+                r#"{"versions": ["2.3.0", "3.0.0", "3.1.0"], "files": []}"#,
+            ),
+            (
+                "/simple/flask-cors/",
+                r#"{"versions": ["4.0.0"], "files": []}"#,
+            ),
+        ]);
+        let mut venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Borrowed("myenv"),
+            dir.path().to_str().unwrap().to_string(),
+            "3.12".to_string(),
+            vec![],
+            false,
+        );
+        venv.ctx.settings.index_url = url;
+        venv.ctx.settings.venvs_path = dir.path().to_str().unwrap().to_string();
+        if std::env::var(crate::constants::INDEX_URL_ENV).is_ok() {
+            return;
+        }
+
+        let report = venv.outdated_packages().await.unwrap();
+        assert_eq!(report.outdated.len(), 1);
+        assert_eq!(report.outdated[0].name, "flask");
+        assert_eq!(report.outdated[0].latest, "3.1.0");
+        assert!(report.failed.is_empty());
+        assert!(dir.path().join(outdated::CACHE_FILE).exists());
+    }
+
     // ── validate_req_file_path ────────────────────────────────────────────────
 
     #[test]
@@ -1045,6 +1559,7 @@ mod tests {
         let dir = tempdir().unwrap();
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("nonexistent_venv"),
             "".to_string(),
             "3.11".to_string(),
@@ -1052,7 +1567,7 @@ mod tests {
             false,
         );
         // Point settings at temp dir; venv subdirectory does not exist.
-        venv.settings.venvs_path = dir.path().to_str().unwrap().to_string();
+        venv.ctx.settings.venvs_path = dir.path().to_str().unwrap().to_string();
 
         let result = venv.delete(std::io::Cursor::new(""), false).await;
         assert!(result.is_err());
@@ -1063,13 +1578,14 @@ mod tests {
     async fn test_delete_venv_invalid_name_rejected() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("bad/name"),
             "".to_string(),
             "3.11".to_string(),
             vec![],
             false,
         );
-        venv.settings.venvs_path = "/tmp".to_string();
+        venv.ctx.settings.venvs_path = "/tmp".to_string();
 
         let result = venv.delete(std::io::Cursor::new(""), false).await;
         assert!(result.is_err());
@@ -1089,13 +1605,14 @@ mod tests {
         tokio::fs::create_dir_all(&venv_dir).await.unwrap();
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
             vec![],
             false,
         );
-        venv.settings.venvs_path = dir.path().to_str().unwrap().to_string();
+        venv.ctx.settings.venvs_path = dir.path().to_str().unwrap().to_string();
 
         // confirm=true but user answers "n" → deletion skipped.
         let result = venv.delete(std::io::Cursor::new("n\n"), true).await;
@@ -1114,13 +1631,14 @@ mod tests {
         tokio::fs::create_dir_all(&venv_dir).await.unwrap();
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
             vec![],
             false,
         );
-        venv.settings.venvs_path = dir.path().to_str().unwrap().to_string();
+        venv.ctx.settings.venvs_path = dir.path().to_str().unwrap().to_string();
 
         // confirm=false → skip prompt, delete immediately.
         let result = venv.delete(std::io::Cursor::new(""), false).await;
@@ -1139,13 +1657,14 @@ mod tests {
         tokio::fs::create_dir_all(&venv_dir).await.unwrap();
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv2"),
             "".to_string(),
             "3.11".to_string(),
             vec![],
             false,
         );
-        venv.settings.venvs_path = dir.path().to_str().unwrap().to_string();
+        venv.ctx.settings.venvs_path = dir.path().to_str().unwrap().to_string();
 
         // confirm=true and user answers "y" → directory deleted.
         let result = venv.delete(std::io::Cursor::new("y\n"), true).await;
@@ -1159,6 +1678,7 @@ mod tests {
     async fn test_install_from_requirements_empty_path_is_error() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
@@ -1173,6 +1693,7 @@ mod tests {
     async fn test_install_from_requirements_injection_path_is_error() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
@@ -1187,6 +1708,7 @@ mod tests {
     async fn test_install_from_requirements_nonexistent_file_is_error() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
@@ -1204,6 +1726,7 @@ mod tests {
     async fn test_install_from_requirements_backslash_path_normalized() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
@@ -1224,13 +1747,14 @@ mod tests {
     async fn test_activate_invalid_name_is_error() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("bad/name"),
             "".to_string(),
             "3.11".to_string(),
             vec![],
             false,
         );
-        venv.settings.venvs_path = "/tmp".to_string();
+        venv.ctx.settings.venvs_path = "/tmp".to_string();
         let result = venv.activate().await;
         assert!(result.is_err());
     }
@@ -1243,6 +1767,7 @@ mod tests {
         let dir = tempdir().unwrap();
 
         let mut venv = UvVenv::new(
+            &Pylot::default(),
             Cow::Borrowed("myvenv"),
             "".to_string(),
             "3.11".to_string(),
@@ -1250,10 +1775,173 @@ mod tests {
             false,
         );
         // Point settings at temp dir; the venv (and its activate script) do not exist.
-        venv.settings.venvs_path = dir.path().to_str().unwrap().to_string();
+        venv.ctx.settings.venvs_path = dir.path().to_str().unwrap().to_string();
 
         let result = venv.activate().await;
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), PylotError::VenvNotFound(_)));
     }
+
+    // ── offline flows ─────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_create_install_delete_flow_with_recording_runner() {
+        use crate::processes::{CommandOutput, RecordingRunner};
+        use tempfile::tempdir;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempdir().unwrap();
+        let venvs_path = dir.path().to_str().unwrap().to_string();
+        // `uv venv` is what creates the directory, so the fake does it too.
+        let runner = Arc::new(RecordingRunner::new().respond_with("uv venv", |cmd| {
+            let cwd = cmd.cwd.clone().unwrap();
+            std::fs::create_dir_all(cwd.join(&cmd.args[1]).join("bin")).unwrap();
+            CommandOutput::ok("")
+        }));
+
+        let ctx = Pylot::default()
+            .with_runner(runner.clone())
+            .with_venvs_path(venvs_path.clone());
+        let venv = UvVenv::new(
+            &ctx,
+            Cow::Borrowed("flow"),
+            "".to_string(),
+            "3.12".to_string(),
+            vec!["numpy".to_string()],
+            false,
+        );
+
+        venv.create().await.unwrap();
+        venv.add_packages(vec!["requests".to_string()])
+            .await
+            .unwrap();
+        venv.remove_packages(vec!["numpy".to_string()])
+            .await
+            .unwrap();
+
+        let calls = runner.calls();
+        assert_eq!(calls.len(), 4);
+        assert_eq!(calls[0].to_string(), "uv venv flow --python 3.12");
+        assert_eq!(calls[0].cwd.as_deref(), Some(dir.path()));
+        if !cfg!(target_os = "windows") {
+            assert_eq!(
+                calls[1].to_string(),
+                format!(
+                    "sh -c . {}/flow/bin/activate && uv pip install numpy",
+                    venvs_path
+                )
+            );
+        }
+        let lines = runner.command_lines();
+        assert!(lines[1].ends_with("uv pip install numpy"));
+        assert!(lines[2].ends_with("uv pip install requests"));
+        assert!(lines[3].ends_with("uv pip uninstall numpy"));
+
+        venv.delete(std::io::Cursor::new(""), false).await.unwrap();
+        assert!(!dir.path().join("flow").exists());
+        // Deleting only touches the filesystem.
+        assert_eq!(runner.calls().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_create_reports_uv_error_line() {
+        use crate::processes::{CommandOutput, RecordingRunner};
+        use tempfile::tempdir;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempdir().unwrap();
+        let runner = RecordingRunner::new().respond(
+            "uv venv",
+            CommandOutput::failed(
+                2,
+                "Using CPython\nerror: No interpreter found for Python 3.99",
+            ),
+        );
+        let ctx = Pylot::default()
+            .with_runner(Arc::new(runner))
+            .with_venvs_path(dir.path().to_str().unwrap().to_string());
+        let venv = UvVenv::new(
+            &ctx,
+            Cow::Borrowed("flow"),
+            "".to_string(),
+            "3.99".to_string(),
+            vec!["numpy".to_string()],
+            false,
+        );
+
+        match venv.create().await {
+            Err(PylotError::CommandExecution(msg)) => {
+                assert!(msg.contains("No interpreter found"), "{}", msg)
+            }
+            other => panic!("expected CommandExecution, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_failure_keeps_old_venv() {
+        use crate::processes::{CommandOutput, RecordingRunner};
+        use tempfile::tempdir;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempdir().unwrap();
+        let old = dir.path().join("flow");
+        std::fs::create_dir_all(old.join("lib").join("python3.12").join("site-packages")).unwrap();
+        std::fs::write(old.join("pyvenv.cfg"), "version = 3.12.4\n").unwrap();
+        // uv leaves a half-created environment behind before failing.
+        let runner = RecordingRunner::new().respond_with("uv venv", |cmd| {
+            let cwd = cmd.cwd.clone().unwrap();
+            std::fs::create_dir_all(cwd.join(&cmd.args[1]).join("bin")).unwrap();
+            CommandOutput::failed(2, "error: No interpreter found for Python 3.12")
+        });
+        let ctx = Pylot::default()
+            .with_runner(Arc::new(runner))
+            .with_venvs_path(dir.path().to_str().unwrap().to_string());
+        let venv = UvVenv::new(
+            &ctx,
+            Cow::Borrowed("flow"),
+            "".to_string(),
+            "3.12".to_string(),
+            vec![],
+            false,
+        );
+
+        assert!(venv.rebuild().await.is_err());
+        assert!(old.join("pyvenv.cfg").exists());
+        assert!(!old.join("bin").exists());
+        assert!(!dir.path().join(".flow.rebuild").exists());
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_replaces_old_venv() {
+        use crate::processes::{CommandOutput, RecordingRunner};
+        use tempfile::tempdir;
+
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let dir = tempdir().unwrap();
+        let old = dir.path().join("flow");
+        std::fs::create_dir_all(&old).unwrap();
+        std::fs::write(old.join("pyvenv.cfg"), "version = 3.12.4\n").unwrap();
+        let runner = Arc::new(RecordingRunner::new().respond_with("uv venv", |cmd| {
+            let cwd = cmd.cwd.clone().unwrap();
+            std::fs::create_dir_all(cwd.join(&cmd.args[1]).join("bin")).unwrap();
+            CommandOutput::ok("")
+        }));
+        let ctx = Pylot::default()
+            .with_runner(runner.clone())
+            .with_venvs_path(dir.path().to_str().unwrap().to_string());
+        let venv = UvVenv::new(
+            &ctx,
+            Cow::Borrowed("flow"),
+            "".to_string(),
+            "3.12".to_string(),
+            vec![],
+            false,
+        );
+
+        venv.rebuild().await.unwrap();
+        assert_eq!(runner.command_lines(), ["uv venv flow --python 3.12"]);
+        assert!(old.join("bin").exists());
+        assert!(!old.join("pyvenv.cfg").exists());
+        assert!(!dir.path().join(".flow.rebuild").exists());
+    }
 }
@@ -1,50 +1,134 @@
-use super::uvvenv::{self, UvVenv};
+use super::{
+    scancache::{self, CachedVenv, ScanCache},
+    uvvenv::UvVenv,
+    venvquery::{VenvFilter, VenvSort},
+};
 use crate::{
-    constants::{UNIX_PYTHON3_EXEC, UNIX_PYTHON_EXEC, WIN_PYTHON_EXEC},
+    constants::{ERROR_VENV_NOT_EXISTS, UNIX_PYTHON3_EXEC, UNIX_PYTHON_EXEC, WIN_PYTHON_EXEC},
+    context::Pylot,
     error::{PylotError, Result},
-    settings,
+    utils,
 };
 use comfy_table::{
     modifiers::UTF8_SOLID_INNER_BORDERS, presets::UTF8_FULL, ContentArrangement, Table,
 };
+use futures::StreamExt;
 use std::{
     borrow::Cow,
     io::{stdout, BufRead, Write},
-    sync::LazyLock,
+    path::Path,
 };
 use tokio::fs;
 
-pub struct VenvManager;
+/// How many environments [`VenvManager::list`] reads at the same time.
+const SCAN_CONCURRENCY: usize = 8;
+
+/// How much of each environment [`VenvManager::list_with`] reads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScanMode {
+    /// Python version, package list and disk usage.
+    #[default]
+    Full,
+    /// Only the Python version of an environment that is not cached.  Its
+    /// package list and disk usage, which means walking the whole tree, are
+    /// left for [`UvVenv::load_packages`].
+    Lazy,
+}
+
+/// Whether `dir` contains a Python interpreter in the Unix or Windows layout.
+async fn has_python(dir: &Path) -> bool {
+    let python_paths = [
+        dir.join(WIN_PYTHON_EXEC),
+        dir.join(UNIX_PYTHON_EXEC),
+        dir.join(UNIX_PYTHON3_EXEC),
+    ];
+    futures::future::join_all(python_paths.iter().map(fs::try_exists))
+        .await
+        .into_iter()
+        .any(|r| r.unwrap_or(false))
+}
 
-/// Process-wide singleton for all virtual environment discovery and selection
-/// operations.
+/// Read the metadata of `venv`, or take it from `cache` if its directories
+/// are unchanged.  Returns the entry to cache, unless `mode` left the package
+/// list and disk usage unread.
+async fn scan(
+    mut venv: UvVenv<'static>,
+    cache: &ScanCache,
+    mode: ScanMode,
+) -> (UvVenv<'static>, Option<CachedVenv>) {
+    let site_packages = venv.site_packages_dir().await;
+    let modified = scancache::modified(Path::new(&venv.path), site_packages.as_deref()).await;
+    venv.last_used = venv.last_activated().await.or(modified);
+    if let Some(entry) = modified.and_then(|m| cache.get(&venv.name, m)) {
+        entry.apply_to(&mut venv);
+        return (venv, Some(entry.clone()));
+    }
+    venv.set_python_version().await;
+    if mode == ScanMode::Lazy {
+        return (venv, None);
+    }
+    venv.measure_disk_usage().await;
+    venv.count_packages().await;
+    let entry = modified.map(|m| CachedVenv::from_venv(&venv, m));
+    (venv, entry)
+}
+
+/// Discovery and selection of the virtual environments under the venvs root
+/// of a [`Pylot`] context.
 ///
-/// Obtain it via `VENVMANAGER.list()`, `VENVMANAGER.find_venv(…)`, etc.
-pub static VENVMANAGER: LazyLock<VenvManager> = LazyLock::new(VenvManager::new);
+/// Obtain one via [`Pylot::manager`], then `manager.list()`,
+/// `manager.find_venv(…)`, etc.
+pub struct VenvManager<'c> {
+    ctx: &'c Pylot,
+}
 
 /// Manages the lifecycle of virtual environments stored under
 /// [`Settings::venvs_path`](crate::cfg::settings::Settings::venvs_path).
 ///
 /// All methods are async and rely on the Tokio runtime.
-impl<'a> VenvManager {
-    fn new() -> Self {
-        VenvManager
+impl<'c> VenvManager<'c> {
+    pub fn new(ctx: &'c Pylot) -> Self {
+        VenvManager { ctx }
     }
 
-    /// Return all virtual environments found under the configured
+    /// Return all virtual environments found under the context's
     /// [`venvs_path`](crate::cfg::settings::Settings::venvs_path).
     ///
-    /// Each returned [`UvVenv`] has its Python version and package count
-    /// already populated.
-    pub async fn list(&'a self) -> Vec<UvVenv<'a>> {
-        let path = shellexpand::tilde(&settings::Settings::get_settings().venvs_path).to_string();
-        let mut venvs: Vec<UvVenv> = match fs::read_dir(&path).await {
+    /// Each returned [`UvVenv`] has its Python version, package count and
+    /// disk usage already populated.
+    pub async fn list(&self) -> Vec<UvVenv<'static>> {
+        self.list_with(ScanMode::Full).await
+    }
+
+    /// Like [`VenvManager::list`], reading as much as `mode` asks for.
+    ///
+    /// Environments are read concurrently.  What was read is kept in a
+    /// [`ScanCache`] in the venvs directory, and environments whose
+    /// directories have not changed since are taken from it instead.
+    pub async fn list_with(&self, mode: ScanMode) -> Vec<UvVenv<'static>> {
+        let path = self.ctx.venvs_path();
+        let venvs: Vec<UvVenv> = match fs::read_dir(&path).await {
             Ok(entries) => self.collect_venvs(entries).await,
-            Err(_) => Vec::new(),
+            Err(_) => return Vec::new(),
         };
-        for venv in &mut venvs {
-            venv.set_python_version().await;
-            venv.count_packages().await;
+        let cache = ScanCache::load(&path).await;
+        let scanned: Vec<_> = futures::stream::iter(venvs)
+            .map(|venv| scan(venv, &cache, mode))
+            .buffered(SCAN_CONCURRENCY)
+            .collect()
+            .await;
+
+        // Deleted environments drop out of the cache with this.
+        let mut fresh = ScanCache::default();
+        let mut venvs = Vec::with_capacity(scanned.len());
+        for (venv, entry) in scanned {
+            if let Some(entry) = entry {
+                fresh.insert(venv.name.to_string(), entry);
+            }
+            venvs.push(venv);
+        }
+        if fresh != cache {
+            fresh.save(&path).await;
         }
         venvs
     }
@@ -52,11 +136,37 @@ impl<'a> VenvManager {
     /// Return `true` if a directory named `name` exists inside the configured
     /// venvs directory.
     pub async fn check_if_exists(&self, name: &str) -> bool {
-        let path = shellexpand::tilde(&settings::Settings::get_settings().venvs_path).to_string();
-        let venv_path = format!("{}/{}", path, name);
+        let venv_path = self.ctx.venvs_path().join(name);
         fs::try_exists(&venv_path).await.unwrap_or(false)
     }
 
+    /// Return `true` if `name` is a directory inside the venvs directory with
+    /// a Python interpreter, i.e. an environment [`VenvManager::list`] returns.
+    pub async fn is_venv(&self, name: &str) -> bool {
+        has_python(&self.ctx.venvs_path().join(name)).await
+    }
+
+    /// Load the environment `name` with its Python version, package list and
+    /// disk usage populated, like an entry of [`VenvManager::list`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PylotError::InvalidVenvName`] for an invalid name, or
+    /// [`PylotError::VenvNotFound`] when no such environment exists.
+    pub async fn get(&self, name: &str) -> Result<UvVenv<'static>> {
+        UvVenv::validate_venv_name(name)?;
+        if !self.check_if_exists(name).await {
+            return Err(PylotError::VenvNotFound(ERROR_VENV_NOT_EXISTS.to_string()));
+        }
+        let mut venv = self.ctx.venv(name.to_string());
+        venv.path = venv.venv_dir();
+        venv.set_python_version().await;
+        venv.count_packages().await;
+        venv.measure_disk_usage().await;
+        venv.set_last_used().await;
+        Ok(venv)
+    }
+
     /// Resolve a virtual environment by name, or interactively prompt the user
     /// to pick one from a printed table.
     ///
@@ -67,30 +177,24 @@ impl<'a> VenvManager {
     /// Returns [`PylotError::VenvNotFound`] when no environments exist, or
     /// [`PylotError::Cancelled`] when the user chooses to cancel the
     /// interactive selection.
-    pub async fn find_venv<R: std::io::Read>(
-        &'a self,
+    pub async fn find_venv<'a, R: std::io::Read>(
+        &self,
         input: R,
         name: Option<&'a str>,
         method: &str,
     ) -> Result<UvVenv<'a>> {
         let venv = match name {
-            Some(n) => uvvenv::UvVenv::new(
-                Cow::Borrowed(n),
-                "".to_string(),
-                "".to_string(),
-                vec![],
-                false,
-            ),
+            Some(n) => self.ctx.venv(n),
             None => {
                 let mut venvs = self.list().await;
+                VenvSort::Name.sort(&mut venvs);
                 if venvs.is_empty() {
                     log::warn!("No virtual environments found");
                     return Err(PylotError::VenvNotFound(
                         "No virtual environments found".to_string(),
                     ));
                 }
-                self.print_venv_table_to(&mut std::io::stdout(), &mut venvs)
-                    .await;
+                self.print_venv_table_to(&mut std::io::stdout(), &venvs);
                 log::info!(
                     "{}{}{}",
                     "Please select a virtual environment to ",
@@ -98,13 +202,7 @@ impl<'a> VenvManager {
                     " (c to cancel):"
                 );
                 match self.get_index(input, venvs.len()) {
-                    Ok(index) => uvvenv::UvVenv::new(
-                        venvs[index - 1].name.clone(),
-                        "".to_string(),
-                        "".to_string(),
-                        vec![],
-                        false,
-                    ),
+                    Ok(index) => self.ctx.venv(venvs[index - 1].name.clone()),
                     Err(_) => {
                         return Err(PylotError::Cancelled);
                     }
@@ -137,36 +235,42 @@ impl<'a> VenvManager {
         }
     }
 
-    async fn collect_venvs(&'a self, mut entries: fs::ReadDir) -> Vec<UvVenv<'a>> {
+    async fn collect_venvs(&self, mut entries: fs::ReadDir) -> Vec<UvVenv<'static>> {
         let mut venvs = Vec::new();
 
         while let Ok(Some(entry)) = entries.next_entry().await {
             if let Ok(file_type) = entry.file_type().await {
                 if file_type.is_dir() {
                     let dir_path = entry.path();
-                    let python_paths = [
-                        dir_path.join(WIN_PYTHON_EXEC),
-                        dir_path.join(UNIX_PYTHON_EXEC),
-                        dir_path.join(UNIX_PYTHON3_EXEC),
-                    ];
 
                     if let Some(folder_name) = entry.file_name().to_str() {
-                        let has_python =
-                            futures::future::join_all(python_paths.iter().map(fs::try_exists))
+                        if folder_name.starts_with('.') {
+                            // Venv names cannot start with a dot, e.g. the
+                            // backup a rebuild keeps while it runs.
+                            continue;
+                        }
+                        if !has_python(&dir_path).await {
+                            // A venv whose interpreter went missing would
+                            // otherwise vanish from the list without a trace.
+                            if fs::try_exists(dir_path.join("pyvenv.cfg"))
                                 .await
-                                .into_iter()
-                                .any(|r| r.unwrap_or(false));
-
-                        if has_python {
-                            if let Some(path_str) = dir_path.to_str() {
-                                venvs.push(UvVenv::new(
-                                    Cow::Owned(folder_name.to_string()),
-                                    path_str.to_string(),
-                                    "".to_string(),
-                                    vec![],
-                                    false,
-                                ));
+                                .unwrap_or(false)
+                            {
+                                log::warn!(
+                                    "Skipping '{}': no Python interpreter found, run 'pylot venv doctor {}'",
+                                    folder_name,
+                                    folder_name
+                                );
                             }
+                        } else if let Some(path_str) = dir_path.to_str() {
+                            venvs.push(UvVenv::new(
+                                self.ctx,
+                                Cow::Owned(folder_name.to_string()),
+                                path_str.to_string(),
+                                "".to_string(),
+                                vec![],
+                                false,
+                            ));
                         }
                     }
                 }
@@ -176,32 +280,85 @@ impl<'a> VenvManager {
         venvs
     }
 
-    /// Print the venv table to stdout and return without prompting.
-    pub async fn print_venv_table(&self) {
+    /// Return the names of every directory under the configured venvs path
+    /// that looks like a virtual environment, including broken ones without a
+    /// working interpreter.  Sorted by name.
+    pub async fn list_names_including_broken(&self) -> Vec<String> {
+        let path = self.ctx.venvs_path();
+        let mut names = Vec::new();
+        let Ok(mut entries) = fs::read_dir(&path).await else {
+            return names;
+        };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let dir_path = entry.path();
+            let is_dir = entry.file_type().await.map(|t| t.is_dir()).unwrap_or(false);
+            let markers = [
+                dir_path.join("pyvenv.cfg"),
+                dir_path.join(WIN_PYTHON_EXEC),
+                dir_path.join(UNIX_PYTHON_EXEC),
+            ];
+            let mut is_venv = false;
+            for marker in &markers {
+                if fs::symlink_metadata(marker).await.is_ok() {
+                    is_venv = true;
+                    break;
+                }
+            }
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if is_dir && is_venv && !hidden {
+                names.push(entry.file_name().to_string_lossy().to_string());
+            }
+        }
+        names.sort();
+        names
+    }
+
+    /// Print the table of the environments that pass `filter`, ordered by
+    /// `sort`, to stdout and return without prompting.
+    pub async fn print_venv_table(&self, filter: &VenvFilter, sort: VenvSort) {
         let mut venvs = self.list().await;
         if venvs.is_empty() {
             log::info!("No virtual environments found");
-        } else {
-            self.print_venv_table_to(&mut std::io::stdout(), &mut venvs)
-                .await;
+            return;
+        }
+        venvs.retain(|venv| filter.matches(venv));
+        if venvs.is_empty() {
+            log::info!("No virtual environments match the filter");
+            return;
         }
+        sort.sort(&mut venvs);
+        self.print_venv_table_to(&mut std::io::stdout(), &venvs);
     }
 
-    async fn print_venv_table_to<W: Write>(&self, writer: &mut W, venvs: &mut [UvVenv<'a>]) {
+    fn print_venv_table_to<W: Write>(&self, writer: &mut W, venvs: &[UvVenv<'_>]) {
         let mut table = Table::new();
         table
             .load_preset(UTF8_FULL)
             .apply_modifier(UTF8_SOLID_INNER_BORDERS)
             .set_content_arrangement(ContentArrangement::Dynamic)
-            .set_header(vec!["Index", "Name", "Version"]);
-        for (index, venv) in venvs.iter_mut().enumerate() {
-            // set_python_version is already called by list(); call it here only
-            // in case venvs were constructed without going through list().
-            venv.set_python_version().await;
+            .set_header(vec![
+                "Index",
+                "Name",
+                "Version",
+                "Packages",
+                "Size",
+                "Last used",
+            ]);
+        let now = std::time::SystemTime::now();
+        for (index, venv) in venvs.iter().enumerate() {
             table.add_row(vec![
                 (index + 1).to_string(),
                 venv.name.clone().to_string(),
                 venv.python_version.clone(),
+                venv.package_count
+                    .map(|count| count.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                venv.disk_usage
+                    .map(|usage| utils::format_size(usage.total()))
+                    .unwrap_or_else(|| "-".to_string()),
+                venv.last_used
+                    .map(|time| utils::format_age(time, now))
+                    .unwrap_or_else(|| "-".to_string()),
             ]);
         }
         let _ = writeln!(writer, "{}", table);
@@ -217,10 +374,14 @@ mod tests {
     use super::*;
     use tempfile::tempdir;
 
+    fn manager(ctx: &Pylot) -> VenvManager<'_> {
+        ctx.manager()
+    }
+
     #[tokio::test]
     async fn test_list_venvs() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let result = VENVMANAGER.list().await;
+        let result = manager(&Pylot::default()).list().await;
         // Test that list operation completes without errors
         // We can't assert on the exact count as it depends on system state
         for venv in result {
@@ -229,17 +390,144 @@ mod tests {
         }
     }
 
+    /// A venv named `name` under `root` with numpy installed.
+    async fn make_venv(root: &Path, name: &str) -> std::path::PathBuf {
+        let env = root.join(name);
+        let site_pkgs = env.join("lib").join("python3.12").join("site-packages");
+        fs::create_dir_all(site_pkgs.join("numpy-2.0.0.dist-info"))
+            .await
+            .unwrap();
+        fs::create_dir_all(env.join("bin")).await.unwrap();
+        fs::write(env.join(UNIX_PYTHON_EXEC), "").await.unwrap();
+        fs::write(env.join("pyvenv.cfg"), "version = 3.12.4\n")
+            .await
+            .unwrap();
+        site_pkgs
+    }
+
+    #[tokio::test]
+    async fn test_list_uses_scan_cache() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp_dir = tempdir().unwrap();
+        let site_pkgs = make_venv(tmp_dir.path(), "env").await;
+        let ctx = Pylot::default().with_venvs_path(tmp_dir.path().to_str().unwrap());
+
+        let venvs = manager(&ctx).list().await;
+        assert_eq!(venvs[0].installed_packages, ["numpy 2.0.0"]);
+        assert!(fs::try_exists(tmp_dir.path().join(scancache::CACHE_FILE))
+            .await
+            .unwrap());
+
+        // An unchanged venv is taken from the cache as it was stored.
+        let modified = scancache::modified(&tmp_dir.path().join("env"), Some(&site_pkgs))
+            .await
+            .unwrap();
+        let mut cache = ScanCache::default();
+        let mut cached = CachedVenv::from_venv(&venvs[0], modified);
+        cached.python_version = "3.99".to_string();
+        cache.insert("env".to_string(), cached);
+        cache.save(tmp_dir.path()).await;
+        assert_eq!(manager(&ctx).list().await[0].python_version, "3.99");
+
+        // Installing a package changes site-packages, so it is read again.
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        fs::create_dir(site_pkgs.join("six-1.16.0.dist-info"))
+            .await
+            .unwrap();
+        let venvs = manager(&ctx).list().await;
+        assert_eq!(venvs[0].python_version, "3.12.4");
+        assert_eq!(venvs[0].installed_packages, ["numpy 2.0.0", "six 1.16.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_lazy_leaves_packages_unread() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp_dir = tempdir().unwrap();
+        for name in ["a", "b", "c"] {
+            make_venv(tmp_dir.path(), name).await;
+        }
+        let ctx = Pylot::default().with_venvs_path(tmp_dir.path().to_str().unwrap());
+
+        let mut venvs = manager(&ctx).list_with(ScanMode::Lazy).await;
+        assert_eq!(venvs.len(), 3);
+        for venv in &venvs {
+            assert_eq!(venv.python_version, "3.12.4");
+            assert_eq!(venv.disk_usage, None);
+            assert_eq!(venv.package_count, None);
+        }
+        // Nothing complete was read, so nothing was cached.
+        assert!(!fs::try_exists(tmp_dir.path().join(scancache::CACHE_FILE))
+            .await
+            .unwrap());
+
+        // Loading one completes and caches it, so the next lazy listing has
+        // its packages and size.
+        venvs[0].load_packages().await;
+        assert_eq!(venvs[0].installed_packages, ["numpy 2.0.0"]);
+        assert!(venvs[0].disk_usage.is_some());
+        let loaded = venvs[0].name.to_string();
+        let venvs = manager(&ctx).list_with(ScanMode::Lazy).await;
+        for venv in &venvs {
+            let cached = venv.name == loaded;
+            assert_eq!(venv.package_count.is_some(), cached, "{}", venv.name);
+            assert_eq!(venv.disk_usage.is_some(), cached, "{}", venv.name);
+        }
+    }
+
     #[tokio::test]
     async fn test_check_if_exists() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let exists = VENVMANAGER.check_if_exists("non_existent_venv").await;
+        let exists = manager(&Pylot::default())
+            .check_if_exists("non_existent_venv")
+            .await;
         assert!(!exists);
     }
 
+    #[tokio::test]
+    async fn test_get_populates_venv() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp_dir = tempdir().unwrap();
+        let env = tmp_dir.path().join("env");
+        let site_pkgs = env.join("lib").join("python3.12").join("site-packages");
+        fs::create_dir_all(site_pkgs.join("numpy-2.0.0.dist-info"))
+            .await
+            .unwrap();
+        fs::write(env.join("pyvenv.cfg"), "version = 3.12.4\n")
+            .await
+            .unwrap();
+        let ctx = Pylot::default().with_venvs_path(tmp_dir.path().to_str().unwrap());
+
+        let venv = manager(&ctx).get("env").await.unwrap();
+        assert_eq!(venv.python_version, "3.12.4");
+        assert_eq!(venv.installed_packages, ["numpy 2.0.0"]);
+        assert!(venv.disk_usage.is_some());
+
+        let missing = manager(&ctx).get("missing").await;
+        assert!(matches!(missing, Err(PylotError::VenvNotFound(_))));
+        let invalid = manager(&ctx).get("bad;name").await;
+        assert!(matches!(invalid, Err(PylotError::InvalidVenvName(_))));
+    }
+
+    #[tokio::test]
+    async fn test_is_venv_requires_interpreter() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp_dir = tempdir().unwrap();
+        let env = tmp_dir.path().join("env");
+        fs::create_dir_all(env.join("bin")).await.unwrap();
+        let ctx = Pylot::default().with_venvs_path(tmp_dir.path().to_str().unwrap());
+
+        assert!(!manager(&ctx).is_venv("env").await);
+        fs::write(env.join(UNIX_PYTHON_EXEC), "").await.unwrap();
+        assert!(manager(&ctx).is_venv("env").await);
+        assert!(!manager(&ctx).is_venv("missing").await);
+    }
+
     #[tokio::test]
     async fn test_find_venv_none() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let venv = VENVMANAGER.find_venv(io::stdin(), None, "activate").await;
+        let venv = manager(&Pylot::default())
+            .find_venv(io::stdin(), None, "activate")
+            .await;
         assert!(venv.is_ok() || venv.is_err());
     }
 
@@ -247,14 +535,16 @@ mod tests {
     async fn test_find_venv_none_cancel() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("c\n");
-        let venv = VENVMANAGER.find_venv(cursor, None, "activate").await;
+        let venv = manager(&Pylot::default())
+            .find_venv(cursor, None, "activate")
+            .await;
         assert!(venv.is_ok() || venv.is_err());
     }
 
     #[tokio::test]
     async fn test_find_venv_by_name() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let venv = VENVMANAGER
+        let venv = manager(&Pylot::default())
             .find_venv(io::stdin(), Some("test_venv"), "activate")
             .await;
         assert!(venv.is_ok());
@@ -266,23 +556,55 @@ mod tests {
         logger::initialize_logger(log::LevelFilter::Trace);
         let tmp_dir = tempdir().unwrap();
         let entries = fs::read_dir(tmp_dir.path()).await.unwrap();
-        let venvs = VENVMANAGER.collect_venvs(entries).await;
+        let venvs = manager(&Pylot::default()).collect_venvs(entries).await;
         assert!(venvs.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_collect_venvs_skips_broken_venv() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let tmp_dir = tempdir().unwrap();
+        let broken = tmp_dir.path().join("broken");
+        fs::create_dir_all(broken.join("bin")).await.unwrap();
+        fs::write(broken.join("pyvenv.cfg"), "home = /gone\n")
+            .await
+            .unwrap();
+        let healthy = tmp_dir.path().join("healthy");
+        fs::create_dir_all(healthy.join("bin")).await.unwrap();
+        fs::write(healthy.join(UNIX_PYTHON_EXEC), "").await.unwrap();
+
+        let entries = fs::read_dir(tmp_dir.path()).await.unwrap();
+        let venvs = manager(&Pylot::default()).collect_venvs(entries).await;
+        assert_eq!(venvs.len(), 1);
+        assert_eq!(venvs[0].name, "healthy");
+    }
+
+    #[tokio::test]
+    async fn test_list_names_including_broken() {
+        logger::initialize_logger(log::LevelFilter::Trace);
+        let names = manager(&Pylot::default())
+            .list_names_including_broken()
+            .await;
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+    }
+
     #[tokio::test]
     async fn test_print_table() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let mut venvs = vec![
+        let venvs = vec![
             UvVenv {
                 name: Cow::Borrowed("venv1"),
                 python_version: "3.10".to_string(),
                 path: "/some/path".to_string(),
                 packages: Vec::new(),
                 default: false,
-                settings: settings::Settings::get_settings(),
+                ctx: Pylot::default(),
                 package_count: None,
                 installed_packages: Vec::new(),
+                disk_usage: None,
+                last_used: None,
             },
             UvVenv {
                 name: Cow::Borrowed("venv2"),
@@ -290,29 +612,31 @@ mod tests {
                 path: "/other/path".to_string(),
                 packages: Vec::new(),
                 default: true,
-                settings: settings::Settings::get_settings(),
+                ctx: Pylot::default(),
                 package_count: None,
                 installed_packages: Vec::new(),
+                disk_usage: None,
+                last_used: None,
             },
         ];
-        VENVMANAGER
-            .print_venv_table_to(&mut std::io::stdout(), &mut venvs)
-            .await;
+        manager(&Pylot::default()).print_venv_table_to(&mut std::io::stdout(), &venvs);
     }
 
     #[tokio::test]
     async fn test_print_venv_table() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let mut venvs = vec![
+        let venvs = vec![
             UvVenv {
                 name: Cow::Borrowed("venv1"),
                 python_version: "3.10".to_string(),
                 path: "/some/path".to_string(),
                 packages: Vec::new(),
                 default: false,
-                settings: settings::Settings::get_settings(),
+                ctx: Pylot::default(),
                 package_count: None,
                 installed_packages: Vec::new(),
+                disk_usage: None,
+                last_used: None,
             },
             UvVenv {
                 name: Cow::Borrowed("venv2"),
@@ -320,29 +644,34 @@ mod tests {
                 path: "/other/path".to_string(),
                 packages: Vec::new(),
                 default: true,
-                settings: settings::Settings::get_settings(),
+                ctx: Pylot::default(),
                 package_count: None,
                 installed_packages: Vec::new(),
+                disk_usage: Some(crate::diskusage::DiskUsage {
+                    site_packages: 2048,
+                    ..Default::default()
+                }),
+                last_used: None,
             },
         ];
 
         let mut output = Vec::new();
-        VENVMANAGER
-            .print_venv_table_to(&mut output, &mut venvs)
-            .await;
+        manager(&Pylot::default()).print_venv_table_to(&mut output, &venvs);
 
         let output_str = String::from_utf8(output).unwrap();
         assert!(output_str.contains("venv1"));
         assert!(output_str.contains("3.10"));
         assert!(output_str.contains("venv2"));
         assert!(output_str.contains("3.11"));
+        assert!(output_str.contains("Size"));
+        assert!(output_str.contains("2.0 KiB"));
     }
 
     #[test]
     fn test_get_index_valid() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("2\n");
-        let index = VENVMANAGER.get_index(cursor, 5);
+        let index = manager(&Pylot::default()).get_index(cursor, 5);
         assert!(index.is_ok());
     }
 
@@ -350,7 +679,7 @@ mod tests {
     fn test_get_index_invalid() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("10\n");
-        let index = VENVMANAGER.get_index(cursor, 5);
+        let index = manager(&Pylot::default()).get_index(cursor, 5);
         assert!(index.is_err());
     }
 
@@ -358,7 +687,7 @@ mod tests {
     fn test_get_index_cancel() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("c\n");
-        let index = VENVMANAGER.get_index(cursor, 5);
+        let index = manager(&Pylot::default()).get_index(cursor, 5);
         assert!(index.is_err());
     }
 
@@ -366,7 +695,7 @@ mod tests {
     fn test_get_index_non_number() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("abc\n");
-        let index = VENVMANAGER.get_index(cursor, 5);
+        let index = manager(&Pylot::default()).get_index(cursor, 5);
         assert!(index.is_err());
     }
 
@@ -374,7 +703,7 @@ mod tests {
     fn test_get_index_quit() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("q\n");
-        let index = VENVMANAGER.get_index(cursor, 5);
+        let index = manager(&Pylot::default()).get_index(cursor, 5);
         assert!(index.is_err());
     }
 
@@ -383,7 +712,7 @@ mod tests {
         logger::initialize_logger(log::LevelFilter::Trace);
         // 0 is outside the valid range [1, size].
         let cursor = std::io::Cursor::new("0\n");
-        let index = VENVMANAGER.get_index(cursor, 5);
+        let index = manager(&Pylot::default()).get_index(cursor, 5);
         assert!(index.is_err());
     }
 
@@ -391,7 +720,7 @@ mod tests {
     fn test_get_index_exactly_size() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("5\n");
-        let index = VENVMANAGER.get_index(cursor, 5);
+        let index = manager(&Pylot::default()).get_index(cursor, 5);
         assert_eq!(index.unwrap(), 5);
     }
 
@@ -399,7 +728,7 @@ mod tests {
     fn test_get_index_one() {
         logger::initialize_logger(log::LevelFilter::Trace);
         let cursor = std::io::Cursor::new("1\n");
-        let index = VENVMANAGER.get_index(cursor, 3);
+        let index = manager(&Pylot::default()).get_index(cursor, 3);
         assert_eq!(index.unwrap(), 1);
     }
 
@@ -413,14 +742,31 @@ mod tests {
         let sub = tmp_dir.path().join("not_a_venv");
         fs::create_dir_all(&sub).await.unwrap();
         let entries = fs::read_dir(tmp_dir.path()).await.unwrap();
-        let venvs = VENVMANAGER.collect_venvs(entries).await;
+        let venvs = manager(&Pylot::default()).collect_venvs(entries).await;
         assert!(venvs.is_empty());
     }
 
     #[tokio::test]
-    async fn test_venvmanager_new_singleton() {
-        // Verify that VENVMANAGER can be accessed without panicking.
+    async fn test_managers_of_different_roots_are_independent() {
         logger::initialize_logger(log::LevelFilter::Trace);
-        let _ = &*VENVMANAGER;
+        let first = tempdir().unwrap();
+        let second = tempdir().unwrap();
+        let venv = first.path().join("only_in_first");
+        fs::create_dir_all(venv.join("bin")).await.unwrap();
+        fs::write(venv.join(UNIX_PYTHON_EXEC), "").await.unwrap();
+
+        let first_ctx = Pylot::default().with_venvs_path(first.path().to_str().unwrap());
+        let second_ctx = Pylot::default().with_venvs_path(second.path().to_str().unwrap());
+
+        let venvs = first_ctx.manager().list().await;
+        assert_eq!(venvs.len(), 1);
+        assert_eq!(venvs[0].name, "only_in_first");
+        assert_eq!(
+            venvs[0].ctx.settings.venvs_path,
+            first_ctx.settings.venvs_path
+        );
+        assert!(first_ctx.manager().check_if_exists("only_in_first").await);
+        assert!(second_ctx.manager().list().await.is_empty());
+        assert!(!second_ctx.manager().check_if_exists("only_in_first").await);
     }
 }
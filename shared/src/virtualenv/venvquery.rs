@@ -0,0 +1,289 @@
+use super::{distinfo::normalize_name, uvvenv::UvVenv};
+use crate::error::{PylotError, Result};
+use std::{cmp::Ordering, str::FromStr};
+
+/// Order of an environment listing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VenvSort {
+    /// Alphabetical by environment name.
+    #[default]
+    Name,
+    /// Newest Python version first.
+    Python,
+    /// Most installed packages first.
+    Packages,
+    /// Largest total size first.
+    Size,
+    /// Most recently used first, see [`UvVenv::last_used`].
+    LastUsed,
+}
+
+impl VenvSort {
+    /// Accepted values, as used on the command line.
+    pub const VALUES: [&'static str; 5] = ["name", "python", "packages", "size", "last-used"];
+
+    /// The value naming this order, e.g. `"last-used"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VenvSort::Name => "name",
+            VenvSort::Python => "python",
+            VenvSort::Packages => "packages",
+            VenvSort::Size => "size",
+            VenvSort::LastUsed => "last-used",
+        }
+    }
+
+    /// The order after this one when cycling through them, starting from
+    /// [`VenvSort::Size`] as the TUI did before the others existed.
+    pub fn next(self) -> Self {
+        match self {
+            VenvSort::Size => VenvSort::Name,
+            VenvSort::Name => VenvSort::Python,
+            VenvSort::Python => VenvSort::Packages,
+            VenvSort::Packages => VenvSort::LastUsed,
+            VenvSort::LastUsed => VenvSort::Size,
+        }
+    }
+
+    /// Sort `venvs` in place.  Ties are broken by name.
+    pub fn sort(self, venvs: &mut [UvVenv<'_>]) {
+        venvs.sort_by(|a, b| self.compare(a, b).then_with(|| a.name.cmp(&b.name)));
+    }
+
+    fn compare(self, a: &UvVenv<'_>, b: &UvVenv<'_>) -> Ordering {
+        let size = |venv: &UvVenv<'_>| venv.disk_usage.map(|u| u.total()).unwrap_or(0);
+        match self {
+            VenvSort::Name => Ordering::Equal,
+            VenvSort::Python => version_key(&b.python_version).cmp(&version_key(&a.python_version)),
+            VenvSort::Packages => b.installed_packages.len().cmp(&a.installed_packages.len()),
+            VenvSort::Size => size(b).cmp(&size(a)),
+            VenvSort::LastUsed => b.last_used.cmp(&a.last_used),
+        }
+    }
+}
+
+impl FromStr for VenvSort {
+    type Err = PylotError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "name" => Ok(VenvSort::Name),
+            "python" | "version" => Ok(VenvSort::Python),
+            "packages" => Ok(VenvSort::Packages),
+            "size" => Ok(VenvSort::Size),
+            "last-used" | "used" => Ok(VenvSort::LastUsed),
+            other => Err(PylotError::Other(format!(
+                "Unknown sort column '{}', expected one of: {}",
+                other,
+                VenvSort::VALUES.join(", ")
+            ))),
+        }
+    }
+}
+
+/// `"3.12.4"` as `[3, 12, 4]`, so `3.10` sorts above `3.9`.  Parts that are
+/// not numbers count as 0.
+fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map(|part| part.trim().parse().unwrap_or(0))
+        .collect()
+}
+
+/// Which environments a listing shows.  An empty filter shows all of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VenvFilter {
+    /// Fuzzy-matched against the name, see [`fuzzy_match`].
+    pub name: Option<String>,
+    /// Python version or version prefix, e.g. `3.11` matches `3.11.4`.
+    pub python: Option<String>,
+    /// Packages that must all be installed, by name.
+    pub has: Vec<String>,
+}
+
+impl VenvFilter {
+    /// Parse a search query such as `api python:3.11 has:numpy`.  Words
+    /// prefixed with `python:` or `has:` filter by version or package, the
+    /// rest is matched against the name.
+    pub fn parse(query: &str) -> Self {
+        let mut filter = VenvFilter::default();
+        let mut name = Vec::new();
+        for word in query.split_whitespace() {
+            if let Some(version) = word.strip_prefix("python:") {
+                filter.python = Some(version.to_string()).filter(|v| !v.is_empty());
+            } else if let Some(package) = word.strip_prefix("has:") {
+                if !package.is_empty() {
+                    filter.has.push(package.to_string());
+                }
+            } else {
+                name.push(word);
+            }
+        }
+        filter.name = Some(name.concat()).filter(|n| !n.is_empty());
+        filter
+    }
+
+    /// Whether this filter filters anything out.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.python.is_none() && self.has.is_empty()
+    }
+
+    /// Whether `venv` passes the filter.  The package list must have been
+    /// read for `has` to match.
+    pub fn matches(&self, venv: &UvVenv<'_>) -> bool {
+        let python_matches = self.python.as_deref().is_none_or(|wanted| {
+            venv.python_version == wanted
+                || venv.python_version.starts_with(&format!("{}.", wanted))
+        });
+        let installed: Vec<String> = venv
+            .installed_packages
+            .iter()
+            .filter_map(|entry| entry.split_whitespace().next())
+            .map(normalize_name)
+            .collect();
+        python_matches
+            && self
+                .name
+                .as_deref()
+                .is_none_or(|query| fuzzy_match(query, &venv.name))
+            && self
+                .has
+                .iter()
+                .all(|package| installed.contains(&normalize_name(package)))
+    }
+}
+
+/// Whether the characters of `query` appear in `name` in order, ignoring
+/// case, so `dsci` finds `data-science`.
+pub fn fuzzy_match(query: &str, name: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|wanted| name.any(|c| c == wanted))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diskusage::DiskUsage, Pylot};
+    use std::{
+        borrow::Cow,
+        time::{Duration, SystemTime},
+    };
+
+    fn venv(name: &str, python: &str, packages: &[&str], size: u64) -> UvVenv<'static> {
+        let mut venv = UvVenv::new(
+            &Pylot::default(),
+            Cow::Owned(name.to_string()),
+            format!("/venvs/{}", name),
+            python.to_string(),
+            vec![],
+            false,
+        );
+        venv.installed_packages = packages.iter().map(|p| p.to_string()).collect();
+        venv.disk_usage = Some(DiskUsage {
+            site_packages: size,
+            ..Default::default()
+        });
+        venv
+    }
+
+    fn names(venvs: &[UvVenv<'_>]) -> Vec<String> {
+        venvs.iter().map(|v| v.name.to_string()).collect()
+    }
+
+    // ── VenvSort ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_sort_orders() {
+        let mut venvs = vec![
+            venv("web", "3.9.18", &["flask 3.0.0"], 300),
+            venv("api", "3.12.4", &["fastapi 0.110.0", "numpy 2.0.0"], 100),
+            venv("data", "3.10.1", &[], 200),
+        ];
+        venvs[0].last_used = Some(SystemTime::UNIX_EPOCH + Duration::from_secs(20));
+        venvs[2].last_used = Some(SystemTime::UNIX_EPOCH + Duration::from_secs(10));
+
+        VenvSort::Name.sort(&mut venvs);
+        assert_eq!(names(&venvs), ["api", "data", "web"]);
+        VenvSort::Python.sort(&mut venvs);
+        assert_eq!(names(&venvs), ["api", "data", "web"]);
+        VenvSort::Packages.sort(&mut venvs);
+        assert_eq!(names(&venvs), ["api", "web", "data"]);
+        VenvSort::Size.sort(&mut venvs);
+        assert_eq!(names(&venvs), ["web", "data", "api"]);
+        // Never used sorts last.
+        VenvSort::LastUsed.sort(&mut venvs);
+        assert_eq!(names(&venvs), ["web", "data", "api"]);
+    }
+
+    #[test]
+    fn test_sort_from_str() {
+        for value in VenvSort::VALUES {
+            assert_eq!(value.parse::<VenvSort>().unwrap().as_str(), value);
+        }
+        assert_eq!("SIZE".parse::<VenvSort>().unwrap(), VenvSort::Size);
+        assert!("colour".parse::<VenvSort>().is_err());
+    }
+
+    #[test]
+    fn test_sort_next_cycles_through_all() {
+        let mut sort = VenvSort::Size;
+        let mut seen = Vec::new();
+        for _ in 0..VenvSort::VALUES.len() {
+            seen.push(sort.as_str());
+            sort = sort.next();
+        }
+        assert_eq!(sort, VenvSort::Size);
+        seen.sort();
+        let mut values = VenvSort::VALUES;
+        values.sort();
+        assert_eq!(seen, values);
+    }
+
+    // ── VenvFilter ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_filter_parse() {
+        assert_eq!(
+            VenvFilter::parse("da sci python:3.11 has:numpy has:Pandas"),
+            VenvFilter {
+                name: Some("dasci".to_string()),
+                python: Some("3.11".to_string()),
+                has: vec!["numpy".to_string(), "Pandas".to_string()],
+            }
+        );
+        assert!(VenvFilter::parse("  python: has: ").is_empty());
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let science = venv(
+            "data-science",
+            "3.11.4",
+            &["numpy 2.0.0", "typing_extensions 4.12.0"],
+            0,
+        );
+        let web = venv("web", "3.12.1", &["flask 3.0.0"], 0);
+
+        let filter = VenvFilter::parse("python:3.11 has:NumPy");
+        assert!(filter.matches(&science));
+        assert!(!filter.matches(&web));
+        // A version prefix only matches whole parts.
+        assert!(!VenvFilter::parse("python:3.1").matches(&science));
+        assert!(VenvFilter::parse("has:typing-extensions").matches(&science));
+        assert!(VenvFilter::parse("dsci").matches(&science));
+        assert!(!VenvFilter::parse("dsci").matches(&web));
+        assert!(VenvFilter::default().matches(&web));
+    }
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert!(fuzzy_match("dsci", "data-science"));
+        assert!(fuzzy_match("DS", "data-science"));
+        assert!(fuzzy_match("", "web"));
+        assert!(!fuzzy_match("wbe", "web"));
+        assert!(!fuzzy_match("webs", "web"));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::tasks::TaskId;
+
 /// UV management actions that can be triggered from the TUI
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UvAction {
@@ -13,6 +15,16 @@ pub enum ConfirmAction {
     DeleteVenv(String),
     /// Uninstall Astral UV.
     UninstallUv,
+    /// Uninstall a Python interpreter, listing the venvs that use it.
+    UninstallPython {
+        key: String,
+        version: String,
+        used_by: Vec<String>,
+    },
+    /// Uninstall a CLI tool installed with `uv tool`.
+    UninstallTool(String),
+    /// Cancel a queued or running background task, named by its label.
+    CancelTask { id: TaskId, label: String },
 }
 
 /// Venv management actions that can be triggered from the TUI
@@ -1,11 +1,24 @@
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Instant;
 
+use pylot_shared::context::Pylot;
+use pylot_shared::uvcache::CacheInfo;
+use pylot_shared::uvctrl::UvInfo;
+use pylot_shared::uvpython::PythonInstall;
+use pylot_shared::uvtool::ToolInstall;
+use pylot_shared::virtualenv::distinfo::PackageDetails;
+use pylot_shared::virtualenv::outdated::{OutdatedPackage, OutdatedReport};
 use pylot_shared::virtualenv::uvvenv::UvVenv;
+use pylot_shared::virtualenv::venvquery::{VenvFilter, VenvSort};
 
 use crate::actions::{UvAction, VenvAction};
 use crate::create_dialog::CreateDialog;
 use crate::dialogs::{ConfirmDialog, HelpDialog, PkgDialog};
+use crate::pkg_tree::PkgTree;
 use crate::tabs::Tab;
+use crate::tasks::{Task, TaskQueue};
+use crate::watch::VenvWatcher;
 
 /// How long (in seconds) a one-shot status message is shown before auto-dismissal.
 pub const STATUS_MESSAGE_TIMEOUT_SECS: u64 = 3;
@@ -16,12 +29,12 @@ pub struct App<'a> {
     pub venvs: Vec<UvVenv<'a>>,
     pub selected: usize,
     pub uv_installed: bool,
-    pub uv_version: Option<String>,
-    /// Latest UV version available, used to show an update indicator.
-    pub uv_latest_version: Option<String>,
-    /// Receiver end of the background UV info fetch (version + latest).
-    pub uv_info_rx:
-        Option<tokio::sync::oneshot::Receiver<(Option<String>, Option<String>)>>,
+    /// Version, path, install method and latest release of the installed uv.
+    pub uv_info: Option<UvInfo>,
+    /// Location and size of the uv cache, shown on the UV Info tab.
+    pub uv_cache: Option<CacheInfo>,
+    /// Receiver end of the background UV info fetch (version + latest + cache).
+    pub uv_info_rx: Option<UvInfoReceiver>,
     pub pending_action: Option<UvAction>,
     pub pending_venv_action: Option<VenvAction>,
     /// When `Some`, the create-venv dialog is open.
@@ -29,29 +42,91 @@ pub struct App<'a> {
     /// When `Some`, a yes/no confirmation overlay is open.
     pub confirm_dialog: Option<ConfirmDialog>,
     pub help_dialog: Option<HelpDialog>,
-    /// Receiver end of the channel used to collect a background task's result.
-    pub bg_rx: Option<tokio::sync::oneshot::Receiver<Result<(), String>>>,
-    /// Human-readable label for the running task, shown in the status bar.
-    pub bg_task_name: Option<String>,
+    /// Background jobs, running and finished, listed on the Tasks tab.
+    pub tasks: TaskQueue,
+    /// Index of the highlighted task on the Tasks tab.
+    pub task_selected: usize,
     /// One-shot status message (text, is_error, set_at) – cleared after 3 s or any keypress.
     pub status_message: Option<(String, bool, Instant)>,
     /// When `Some`, the add/remove-package dialog is open.
     pub pkg_dialog: Option<PkgDialog>,
     /// When `Some`, package search is active with this query string.
     pub pkg_search: Option<String>,
-    /// Scroll offset for the packages list in the detail panel.
+    /// Index of the highlighted package in the packages list of the detail panel.
     pub pkg_scroll: usize,
+    /// Outdated packages per venv name, filled in by the outdated check.
+    pub outdated: HashMap<String, Vec<OutdatedPackage>>,
+    /// Receiver end of the background outdated check (venv name + result).
+    pub outdated_rx: Option<OutdatedReceiver>,
+    /// Receiver end of the background read of the package lists and sizes
+    /// the lazy listing left out.
+    pub packages_rx: Option<PackagesReceiver>,
+    /// When `Some`, the packages pane shows the dependency tree instead of
+    /// the flat package list.
+    pub pkg_tree: Option<PkgTree>,
+    /// `(venv name, package name)` that `pkg_details` was last loaded for.
+    pub pkg_details_key: Option<(String, String)>,
+    /// Metadata of the highlighted package, read from its `.dist-info`.
+    pub pkg_details: Option<PackageDetails>,
+    /// Order of the environment list; `None` keeps the discovery order.
+    pub venv_sort: Option<VenvSort>,
+    /// When `Some`, the environment list only shows the venvs matching this
+    /// query, see [`VenvFilter::parse`].
+    pub venv_search: Option<String>,
+    /// `true` while the environment search query is being typed.
+    pub venv_search_input: bool,
+    /// Python interpreters reported by `uv python list`.
+    pub pythons: Vec<PythonInstall>,
+    /// Names of the venvs using each interpreter, keyed by interpreter key.
+    pub python_usage: HashMap<String, Vec<String>>,
+    /// Index of the highlighted interpreter on the Pythons tab.
+    pub python_selected: usize,
+    /// Error from the last interpreter listing, shown on the Pythons tab.
+    pub pythons_error: Option<String>,
+    /// Receiver end of the background interpreter listing.
+    pub pythons_rx: Option<PythonsReceiver>,
+    /// CLI tools reported by `uv tool list`.
+    pub tools: Vec<ToolInstall>,
+    /// Index of the highlighted tool on the Tools tab.
+    pub tool_selected: usize,
+    /// Error from the last tool listing, shown on the Tools tab.
+    pub tools_error: Option<String>,
+    /// Receiver end of the background tool listing.
+    pub tools_rx: Option<ToolsReceiver>,
+    /// Settings and runner shared with background tasks; its runner is a
+    /// `RecordingRunner` in tests.
+    pub ctx: Arc<Pylot>,
+    /// Reports venvs created, deleted or changed outside the TUI.
+    pub watcher: Option<VenvWatcher>,
 }
 
+/// Channel receiving the uv version details and the cache info.
+pub type UvInfoReceiver = tokio::sync::oneshot::Receiver<(Option<UvInfo>, Option<CacheInfo>)>;
+
+/// Channel receiving the result of a background outdated-package check.
+pub type OutdatedReceiver =
+    tokio::sync::oneshot::Receiver<(String, Result<OutdatedReport, String>)>;
+
+/// Channel receiving each venv once its package list and size are read.
+pub type PackagesReceiver = tokio::sync::mpsc::UnboundedReceiver<UvVenv<'static>>;
+
+/// Channel receiving the installed CLI tools.
+pub type ToolsReceiver = tokio::sync::oneshot::Receiver<Result<Vec<ToolInstall>, String>>;
+
+/// Channel receiving the interpreters and which venvs use each of them.
+pub type PythonsReceiver = tokio::sync::oneshot::Receiver<
+    Result<(Vec<PythonInstall>, HashMap<String, Vec<String>>), String>,
+>;
+
 impl<'a> App<'a> {
-    pub fn new(venvs: Vec<UvVenv<'a>>, uv_installed: bool, uv_version: Option<String>) -> Self {
+    pub fn new(venvs: Vec<UvVenv<'a>>, uv_installed: bool, uv_info: Option<UvInfo>) -> Self {
         App {
             tab: Tab::Environments,
             venvs,
             selected: 0,
             uv_installed,
-            uv_version,
-            uv_latest_version: None,
+            uv_info,
+            uv_cache: None,
             uv_info_rx: None,
             pending_action: None,
             pending_venv_action: None,
@@ -60,40 +135,93 @@ impl<'a> App<'a> {
             help_dialog: None,
             pkg_dialog: None,
             pkg_search: None,
-            bg_rx: None,
-            bg_task_name: None,
+            tasks: TaskQueue::default(),
+            task_selected: 0,
             status_message: None,
             pkg_scroll: 0,
+            outdated: HashMap::new(),
+            outdated_rx: None,
+            packages_rx: None,
+            pkg_tree: None,
+            pkg_details_key: None,
+            pkg_details: None,
+            venv_sort: None,
+            venv_search: None,
+            venv_search_input: false,
+            pythons: Vec::new(),
+            python_usage: HashMap::new(),
+            python_selected: 0,
+            pythons_error: None,
+            pythons_rx: None,
+            tools: Vec::new(),
+            tool_selected: 0,
+            tools_error: None,
+            tools_rx: None,
+            ctx: Arc::new(Pylot::default()),
+            watcher: None,
         }
     }
 
     pub fn next_tab(&mut self) {
-        self.tab = match self.tab {
-            Tab::Environments => Tab::UvInfo,
-            Tab::UvInfo => Tab::Environments,
-        };
+        let index = Tab::ALL.iter().position(|t| *t == self.tab).unwrap_or(0);
+        self.tab = Tab::ALL[(index + 1) % Tab::ALL.len()];
         self.selected = 0;
     }
 
     pub fn prev_tab(&mut self) {
-        self.next_tab();
+        let index = Tab::ALL.iter().position(|t| *t == self.tab).unwrap_or(0);
+        self.tab = Tab::ALL[(index + Tab::ALL.len() - 1) % Tab::ALL.len()];
+        self.selected = 0;
     }
 
     pub fn next_item(&mut self) {
-        if self.tab == Tab::Environments && !self.venvs.is_empty() {
-            self.selected = (self.selected + 1) % self.venvs.len();
+        let visible = self.visible_venvs();
+        if self.tab == Tab::Environments && !visible.is_empty() {
+            let pos = visible.iter().position(|&i| i == self.selected);
+            self.selected = visible[pos.map_or(0, |p| (p + 1) % visible.len())];
             self.pkg_scroll = 0;
         }
+        if self.tab == Tab::Pythons && !self.pythons.is_empty() {
+            self.python_selected = (self.python_selected + 1) % self.pythons.len();
+        }
+        if self.tab == Tab::Tools && !self.tools.is_empty() {
+            self.tool_selected = (self.tool_selected + 1) % self.tools.len();
+        }
+        if self.tab == Tab::Tasks && !self.tasks.tasks.is_empty() {
+            self.task_selected = (self.task_selected + 1) % self.tasks.tasks.len();
+        }
     }
 
     pub fn prev_item(&mut self) {
-        if self.tab == Tab::Environments && !self.venvs.is_empty() {
-            if self.selected == 0 {
-                self.selected = self.venvs.len() - 1;
+        let visible = self.visible_venvs();
+        if self.tab == Tab::Environments && !visible.is_empty() {
+            let pos = visible.iter().position(|&i| i == self.selected);
+            self.selected = match pos {
+                Some(0) | None => visible[visible.len() - 1],
+                Some(p) => visible[p - 1],
+            };
+            self.pkg_scroll = 0;
+        }
+        if self.tab == Tab::Pythons && !self.pythons.is_empty() {
+            if self.python_selected == 0 {
+                self.python_selected = self.pythons.len() - 1;
             } else {
-                self.selected -= 1;
+                self.python_selected -= 1;
+            }
+        }
+        if self.tab == Tab::Tools && !self.tools.is_empty() {
+            if self.tool_selected == 0 {
+                self.tool_selected = self.tools.len() - 1;
+            } else {
+                self.tool_selected -= 1;
+            }
+        }
+        if self.tab == Tab::Tasks && !self.tasks.tasks.is_empty() {
+            if self.task_selected == 0 {
+                self.task_selected = self.tasks.tasks.len() - 1;
+            } else {
+                self.task_selected -= 1;
             }
-            self.pkg_scroll = 0;
         }
     }
 
@@ -107,9 +235,24 @@ impl<'a> App<'a> {
         self.pending_venv_action.take()
     }
 
-    /// Returns `true` while a background task is in-flight.
+    /// Returns `true` while a background task is queued or running.
     pub fn is_busy(&self) -> bool {
-        self.bg_rx.is_some()
+        self.tasks.is_active()
+    }
+
+    /// The task highlighted on the Tasks tab, if any.
+    pub fn selected_task(&self) -> Option<&Task> {
+        self.tasks.tasks.get(self.task_selected)
+    }
+
+    /// The task Esc cancels: the highlighted one on the Tasks tab, otherwise
+    /// the one shown in the progress panel.
+    pub fn cancellable_task(&self) -> Option<&Task> {
+        let task = match self.tab {
+            Tab::Tasks => self.selected_task(),
+            _ => self.tasks.current(),
+        };
+        task.filter(|t| t.is_active() && !t.cancelling)
     }
 
     /// Returns `true` while the UV version info is being fetched in the background.
@@ -117,6 +260,200 @@ impl<'a> App<'a> {
         self.uv_info_rx.is_some()
     }
 
+    /// Returns `true` while the interpreter list is being fetched.
+    pub fn is_pythons_loading(&self) -> bool {
+        self.pythons_rx.is_some()
+    }
+
+    /// The interpreter highlighted on the Pythons tab, if any.
+    pub fn selected_python(&self) -> Option<&PythonInstall> {
+        self.pythons.get(self.python_selected)
+    }
+
+    /// Names of the venvs created from `install`.
+    pub fn python_users(&self, install: &PythonInstall) -> &[String] {
+        self.python_usage
+            .get(&install.key)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Replace the interpreter list, keeping the highlight in range.
+    pub fn set_pythons(
+        &mut self,
+        pythons: Vec<PythonInstall>,
+        usage: HashMap<String, Vec<String>>,
+    ) {
+        self.pythons = pythons;
+        self.python_usage = usage;
+        if self.python_selected >= self.pythons.len() {
+            self.python_selected = self.pythons.len().saturating_sub(1);
+        }
+    }
+
+    /// Returns `true` while the tool list is being fetched.
+    pub fn is_tools_loading(&self) -> bool {
+        self.tools_rx.is_some()
+    }
+
+    /// The tool highlighted on the Tools tab, if any.
+    pub fn selected_tool(&self) -> Option<&ToolInstall> {
+        self.tools.get(self.tool_selected)
+    }
+
+    /// Replace the tool list, keeping the highlight in range.
+    pub fn set_tools(&mut self, tools: Vec<ToolInstall>) {
+        self.tools = tools;
+        if self.tool_selected >= self.tools.len() {
+            self.tool_selected = self.tools.len().saturating_sub(1);
+        }
+    }
+
+    /// Returns `true` while an outdated-package check is running.
+    pub fn is_outdated_loading(&self) -> bool {
+        self.outdated_rx.is_some()
+    }
+
+    /// Return the outdated entry for `package` in venv `venv_name`, if the
+    /// venv has been checked and the package has a newer release.
+    pub fn outdated_package(&self, venv_name: &str, package: &str) -> Option<&OutdatedPackage> {
+        self.outdated
+            .get(venv_name)?
+            .iter()
+            .find(|pkg| pkg.name == package)
+    }
+
+    /// Name of the package highlighted in the detail panel: the tree cursor
+    /// in tree mode, otherwise the `pkg_scroll` row.  `None` while searching
+    /// or when the row is a missing dependency.
+    pub fn highlighted_package(&self) -> Option<String> {
+        let venv = self.venvs.get(self.selected)?;
+        if let Some(tree) = self.pkg_tree.as_ref() {
+            return tree
+                .selected_row()
+                .filter(|row| row.version.is_some())
+                .map(|row| row.name);
+        }
+        if self.pkg_search.is_some() {
+            return None;
+        }
+        venv.installed_packages
+            .get(self.pkg_scroll)
+            .and_then(|p| p.split_whitespace().next())
+            .map(str::to_string)
+    }
+
+    /// Details of the highlighted package, if they have been loaded.
+    pub fn highlighted_details(&self) -> Option<&PackageDetails> {
+        let venv = self.venvs.get(self.selected)?;
+        let (venv_name, package) = self.pkg_details_key.as_ref()?;
+        let highlighted = self.highlighted_package();
+        if venv_name != venv.name.as_ref() || highlighted.as_ref() != Some(package) {
+            return None;
+        }
+        self.pkg_details.as_ref()
+    }
+
+    /// Switch the environment list to the next order, starting with
+    /// largest-first, keeping the selected environment highlighted.
+    pub fn toggle_venv_sort(&mut self) {
+        self.venv_sort = Some(self.venv_sort.map_or(VenvSort::Size, VenvSort::next));
+        self.resort_venvs();
+    }
+
+    /// Re-apply `venv_sort`, keeping the selected environment highlighted.
+    fn resort_venvs(&mut self) {
+        let selected = self.venvs.get(self.selected).map(|v| v.name.to_string());
+        self.sort_venvs();
+        if let Some(name) = selected {
+            self.selected = self
+                .venvs
+                .iter()
+                .position(|v| v.name == name)
+                .unwrap_or(0);
+        }
+    }
+
+    /// Re-apply `venv_sort` to `venvs`.
+    pub fn sort_venvs(&mut self) {
+        let Some(sort) = self.venv_sort else {
+            return;
+        };
+        sort.sort(&mut self.venvs);
+    }
+
+    /// Indices into `venvs` of the environments the list shows, in order.
+    pub fn visible_venvs(&self) -> Vec<usize> {
+        let filter = self.venv_filter();
+        (0..self.venvs.len())
+            .filter(|&i| filter.matches(&self.venvs[i]))
+            .collect()
+    }
+
+    /// The selected environment, unless the search hides it.
+    pub fn selected_venv(&self) -> Option<&UvVenv<'a>> {
+        self.venvs
+            .get(self.selected)
+            .filter(|venv| self.venv_filter().matches(venv))
+    }
+
+    /// Move the selection to the first shown environment if the search hides
+    /// the selected one.
+    pub fn apply_venv_search(&mut self) {
+        if self.selected_venv().is_none() {
+            if let Some(&first) = self.visible_venvs().first() {
+                self.selected = first;
+                self.pkg_scroll = 0;
+                self.pkg_tree = None;
+            }
+        }
+    }
+
+    /// Indices into `venvs` of the environments whose package list or size
+    /// is still to be read: the selected one first, then every other one if
+    /// the sort, the search or the package search needs them.
+    pub fn venvs_to_load(&self) -> Vec<usize> {
+        let unread = |i: &usize| {
+            let venv = &self.venvs[*i];
+            venv.package_count.is_none() || venv.disk_usage.is_none()
+        };
+        let needs_all = matches!(self.venv_sort, Some(VenvSort::Packages | VenvSort::Size))
+            || !self.venv_filter().has.is_empty()
+            || self.pkg_search.as_deref().is_some_and(|q| !q.is_empty());
+        let mut indices: Vec<usize> = (0..self.venvs.len())
+            .filter(|&i| needs_all || i == self.selected)
+            .filter(unread)
+            .collect();
+        indices.sort_by_key(|&i| i != self.selected);
+        indices
+    }
+
+    /// Take the package lists and sizes of `loaded` into the listed venvs of
+    /// the same name that still lack them, then sort and filter again.
+    pub fn apply_loaded_packages(&mut self, loaded: Vec<UvVenv<'static>>) {
+        for venv in loaded {
+            let Some(target) = self.venvs.iter_mut().find(|v| v.name == venv.name) else {
+                continue;
+            };
+            if target.package_count.is_none() {
+                target.package_count = venv.package_count;
+                target.installed_packages = venv.installed_packages;
+            }
+            target.disk_usage = target.disk_usage.or(venv.disk_usage);
+        }
+        self.resort_venvs();
+        if self.venv_search.is_some() {
+            self.apply_venv_search();
+        }
+    }
+
+    fn venv_filter(&self) -> VenvFilter {
+        self.venv_search
+            .as_deref()
+            .map(VenvFilter::parse)
+            .unwrap_or_default()
+    }
+
     /// Scroll the packages list in the detail panel down by one row.
     /// `total` is the number of packages in the currently selected venv.
     pub fn scroll_pkg_down(&mut self, total: usize) {
@@ -134,9 +471,10 @@ impl<'a> App<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pylot_shared::diskusage::DiskUsage;
 
     fn make_app<'a>() -> App<'a> {
-        App::new(vec![], true, Some("uv 0.5.0".to_string()))
+        App::new(vec![], true, None)
     }
 
     #[test]
@@ -146,6 +484,12 @@ mod tests {
         app.next_tab();
         assert_eq!(app.tab, Tab::UvInfo);
         app.next_tab();
+        assert_eq!(app.tab, Tab::Pythons);
+        app.next_tab();
+        assert_eq!(app.tab, Tab::Tools);
+        app.next_tab();
+        assert_eq!(app.tab, Tab::Tasks);
+        app.next_tab();
         assert_eq!(app.tab, Tab::Environments);
     }
 
@@ -153,11 +497,73 @@ mod tests {
     fn test_prev_tab_cycling() {
         let mut app = make_app();
         app.prev_tab();
+        assert_eq!(app.tab, Tab::Tasks);
+        app.prev_tab();
+        assert_eq!(app.tab, Tab::Tools);
+        app.prev_tab();
+        assert_eq!(app.tab, Tab::Pythons);
+        app.prev_tab();
         assert_eq!(app.tab, Tab::UvInfo);
         app.prev_tab();
         assert_eq!(app.tab, Tab::Environments);
     }
 
+    #[test]
+    fn test_tool_navigation_wraps_and_clamps() {
+        let mut app = make_app();
+        app.tab = Tab::Tools;
+        app.set_tools(pylot_shared::uvtool::parse_list(
+            "black v24.2.0\n- black\nruff v0.3.0\n- ruff\n",
+        ));
+        app.prev_item();
+        assert_eq!(app.selected_tool().unwrap().name, "ruff");
+        app.next_item();
+        assert_eq!(app.selected_tool().unwrap().name, "black");
+        app.tool_selected = 1;
+        app.set_tools(pylot_shared::uvtool::parse_list("black v24.2.0\n"));
+        assert_eq!(app.tool_selected, 0);
+        app.set_tools(vec![]);
+        assert!(app.selected_tool().is_none());
+    }
+
+    fn python(key: &str, path: Option<&str>) -> PythonInstall {
+        PythonInstall::parse_line(&format!("{} {}", key, path.unwrap_or("<download available>")))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_python_navigation_wraps() {
+        let mut app = make_app();
+        app.set_pythons(
+            vec![
+                python("cpython-3.13.0-linux-x86_64-gnu", None),
+                python("cpython-3.12.3-linux-x86_64-gnu", Some("/usr/bin/python3.12")),
+            ],
+            HashMap::new(),
+        );
+        app.tab = Tab::Pythons;
+        app.prev_item();
+        assert_eq!(app.python_selected, 1);
+        app.next_item();
+        assert_eq!(app.python_selected, 0);
+        assert_eq!(app.selected_python().unwrap().version, "3.13.0");
+    }
+
+    #[test]
+    fn test_set_pythons_clamps_selection_and_tracks_users() {
+        let mut app = make_app();
+        let key = "cpython-3.12.3-linux-x86_64-gnu";
+        app.python_selected = 5;
+        let mut usage = HashMap::new();
+        usage.insert(key.to_string(), vec!["web".to_string()]);
+        app.set_pythons(vec![python(key, Some("/usr/bin/python3.12"))], usage);
+        assert_eq!(app.python_selected, 0);
+        let install = app.selected_python().unwrap().clone();
+        assert_eq!(app.python_users(&install), ["web".to_string()]);
+        app.set_pythons(vec![], HashMap::new());
+        assert!(app.selected_python().is_none());
+    }
+
     #[test]
     fn test_navigation_empty() {
         let mut app = make_app();
@@ -232,13 +638,16 @@ mod tests {
         assert!(!app.is_busy());
     }
 
+    fn pending_job() -> crate::tasks::Job {
+        use futures::FutureExt;
+        Arc::new(|_| futures::future::pending().boxed())
+    }
+
     #[test]
-    fn test_is_busy_true_when_rx_set() {
+    fn test_is_busy_true_while_task_queued() {
         let mut app = make_app();
-        let (tx, rx) = tokio::sync::oneshot::channel::<Result<(), String>>();
-        app.bg_rx = Some(rx);
+        app.tasks.push("Queued".to_string(), pending_job());
         assert!(app.is_busy());
-        drop(tx); // avoid leak warning
     }
 
     #[test]
@@ -250,12 +659,41 @@ mod tests {
     #[test]
     fn test_is_uv_info_loading_true_when_rx_set() {
         let mut app = make_app();
-        let (_tx, rx) =
-            tokio::sync::oneshot::channel::<(Option<String>, Option<String>)>();
+        let (_tx, rx) = tokio::sync::oneshot::channel();
         app.uv_info_rx = Some(rx);
         assert!(app.is_uv_info_loading());
     }
 
+    #[test]
+    fn test_is_outdated_loading_true_when_rx_set() {
+        let mut app = make_app();
+        assert!(!app.is_outdated_loading());
+        let (_tx, rx) = tokio::sync::oneshot::channel();
+        app.outdated_rx = Some(rx);
+        assert!(app.is_outdated_loading());
+    }
+
+    #[test]
+    fn test_outdated_package_lookup() {
+        let mut app = make_app();
+        assert!(app.outdated_package("env1", "requests").is_none());
+        app.outdated.insert(
+            "env1".to_string(),
+            vec![OutdatedPackage {
+                name: "requests".to_string(),
+                installed: "2.30.0".to_string(),
+                latest: "2.31.0".to_string(),
+            }],
+        );
+        assert_eq!(
+            app.outdated_package("env1", "requests")
+                .map(|p| p.latest.as_str()),
+            Some("2.31.0")
+        );
+        assert!(app.outdated_package("env1", "flask").is_none());
+        assert!(app.outdated_package("env2", "requests").is_none());
+    }
+
     #[test]
     fn test_confirm_dialog_none_by_default() {
         let app = make_app();
@@ -269,6 +707,7 @@ mod tests {
         use std::borrow::Cow;
         let venvs = vec![
             UvVenv::new(
+                &Pylot::default(),
                 Cow::Owned("env1".to_string()),
                 "".to_string(),
                 "3.11".to_string(),
@@ -276,6 +715,7 @@ mod tests {
                 false,
             ),
             UvVenv::new(
+                &Pylot::default(),
                 Cow::Owned("env2".to_string()),
                 "".to_string(),
                 "3.12".to_string(),
@@ -283,6 +723,7 @@ mod tests {
                 false,
             ),
             UvVenv::new(
+                &Pylot::default(),
                 Cow::Owned("env3".to_string()),
                 "".to_string(),
                 "3.10".to_string(),
@@ -290,7 +731,7 @@ mod tests {
                 false,
             ),
         ];
-        App::new(venvs, true, Some("uv 0.5.0".to_string()))
+        App::new(venvs, true, None)
     }
 
     #[test]
@@ -354,12 +795,185 @@ mod tests {
         assert!(matches!(app.status_message, Some((ref msg, false, _)) if msg == "done"));
     }
 
-    // ── App bg_task_name field ───────────────────────────────────────────────
+    // ── tasks ────────────────────────────────────────────────────────────────
 
     #[test]
-    fn test_bg_task_name_none_by_default() {
+    fn test_tasks_empty_by_default() {
         let app = make_app();
-        assert!(app.bg_task_name.is_none());
+        assert!(app.tasks.tasks.is_empty());
+        assert!(app.selected_task().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cancellable_task_follows_tab() {
+        let mut app = make_app();
+        let first = app.tasks.push("First".to_string(), pending_job());
+        let second = app.tasks.push("Second".to_string(), pending_job());
+        app.tasks.start(&app.ctx);
+        assert_eq!(app.cancellable_task().map(|t| t.id), Some(second));
+
+        app.tab = Tab::Tasks;
+        assert_eq!(app.cancellable_task().map(|t| t.id), Some(first));
+        app.next_item();
+        assert_eq!(app.cancellable_task().map(|t| t.id), Some(second));
+        app.next_item();
+        assert_eq!(app.task_selected, 0);
+
+        let ctx = app.ctx.clone();
+        app.tasks.cancel(first, &ctx);
+        assert!(app.cancellable_task().is_none());
+    }
+
+    // ── highlighted package ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_highlighted_package_follows_pkg_scroll() {
+        let mut app = make_app_with_venvs();
+        app.venvs[0].installed_packages =
+            vec!["flask 3.0.0".to_string(), "requests 2.31.0".to_string()];
+        assert_eq!(app.highlighted_package().as_deref(), Some("flask"));
+        app.scroll_pkg_down(2);
+        assert_eq!(app.highlighted_package().as_deref(), Some("requests"));
+        app.pkg_search = Some(String::new());
+        assert!(app.highlighted_package().is_none());
+    }
+
+    #[test]
+    fn test_highlighted_package_empty_list() {
+        let app = make_app_with_venvs();
+        assert!(app.highlighted_package().is_none());
+        assert!(make_app().highlighted_package().is_none());
+    }
+
+    #[test]
+    fn test_highlighted_details_requires_matching_key() {
+        let mut app = make_app_with_venvs();
+        app.venvs[0].installed_packages = vec!["flask 3.0.0".to_string()];
+        app.pkg_details = Some(PackageDetails {
+            name: "flask".to_string(),
+            ..Default::default()
+        });
+        assert!(app.highlighted_details().is_none());
+        app.pkg_details_key = Some(("env1".to_string(), "flask".to_string()));
+        assert_eq!(app.highlighted_details().unwrap().name, "flask");
+        app.pkg_details_key = Some(("env2".to_string(), "flask".to_string()));
+        assert!(app.highlighted_details().is_none());
+    }
+
+    // ── venv sort ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_venvs_to_load() {
+        let mut app = make_app_with_venvs();
+        app.selected = 1;
+        assert_eq!(app.venvs_to_load(), [1]);
+        // Sorting by size or package count needs every venv, selected first.
+        app.venv_sort = Some(VenvSort::Size);
+        assert_eq!(app.venvs_to_load(), [1, 0, 2]);
+        app.venv_sort = Some(VenvSort::Name);
+        app.venv_search = Some("python:3.12".to_string());
+        assert_eq!(app.venvs_to_load(), [1]);
+        app.venv_search = Some("has:numpy".to_string());
+        assert_eq!(app.venvs_to_load(), [1, 0, 2]);
+        app.venv_search = None;
+        app.pkg_search = Some("num".to_string());
+        assert_eq!(app.venvs_to_load(), [1, 0, 2]);
+
+        let mut loaded = pylot_shared::uvvenv::UvVenv::new(
+            &Pylot::default(),
+            std::borrow::Cow::Owned("env3".to_string()),
+            "".to_string(),
+            "3.10".to_string(),
+            vec![],
+            false,
+        );
+        loaded.package_count = Some(1);
+        loaded.installed_packages = vec!["numpy 2.0.0".to_string()];
+        loaded.disk_usage = Some(DiskUsage::default());
+        app.apply_loaded_packages(vec![loaded]);
+        assert_eq!(app.venvs[2].installed_packages, ["numpy 2.0.0"]);
+        assert_eq!(app.venvs_to_load(), [1, 0]);
+    }
+
+    #[test]
+    fn test_toggle_venv_sort_keeps_selection() {
+        let mut app = make_app_with_venvs();
+        let sizes = [10, 30, 20];
+        for (venv, size) in app.venvs.iter_mut().zip(sizes) {
+            venv.disk_usage = Some(DiskUsage {
+                site_packages: size,
+                ..Default::default()
+            });
+        }
+        app.selected = 0; // env1
+        app.toggle_venv_sort();
+        assert_eq!(app.venv_sort, Some(VenvSort::Size));
+        let names: Vec<_> = app.venvs.iter().map(|v| v.name.to_string()).collect();
+        assert_eq!(names, vec!["env2", "env3", "env1"]);
+        assert_eq!(app.selected, 2);
+
+        app.toggle_venv_sort();
+        assert_eq!(app.venv_sort, Some(VenvSort::Name));
+        let names: Vec<_> = app.venvs.iter().map(|v| v.name.to_string()).collect();
+        assert_eq!(names, vec!["env1", "env2", "env3"]);
+        assert_eq!(app.selected, 0);
+    }
+
+    #[test]
+    fn test_toggle_venv_sort_cycles_through_orders() {
+        let mut app = make_app_with_venvs();
+        let mut seen = Vec::new();
+        for _ in 0..5 {
+            app.toggle_venv_sort();
+            seen.push(app.venv_sort.unwrap());
+        }
+        assert_eq!(seen[2], VenvSort::Python);
+        let names: Vec<_> = app.venvs.iter().map(|v| v.name.to_string()).collect();
+        assert_eq!(seen[4], VenvSort::LastUsed);
+        assert_eq!(names, vec!["env1", "env2", "env3"]);
+        app.toggle_venv_sort();
+        assert_eq!(app.venv_sort, Some(VenvSort::Size));
+    }
+
+    // ── venv search ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_venv_search_filters_and_moves_selection() {
+        let mut app = make_app_with_venvs();
+        app.venvs[1].installed_packages = vec!["numpy 2.0.0".to_string()];
+        app.venvs[2].installed_packages = vec!["numpy 2.0.0".to_string()];
+        app.selected = 0;
+        app.pkg_scroll = 4;
+
+        app.venv_search = Some("has:numpy".to_string());
+        app.apply_venv_search();
+        assert_eq!(app.visible_venvs(), vec![1, 2]);
+        assert_eq!(app.selected, 1);
+        assert_eq!(app.pkg_scroll, 0);
+        app.next_item();
+        assert_eq!(app.selected, 2);
+        app.next_item();
+        assert_eq!(app.selected, 1);
+        app.prev_item();
+        assert_eq!(app.selected, 2);
+
+        // Nothing matches: the selection stays, but is not usable.
+        app.venv_search = Some("python:3.11 has:numpy".to_string());
+        app.apply_venv_search();
+        assert!(app.visible_venvs().is_empty());
+        assert!(app.selected_venv().is_none());
+
+        app.venv_search = Some("e3".to_string());
+        app.apply_venv_search();
+        assert_eq!(app.selected_venv().map(|v| v.name.as_ref()), Some("env3"));
+    }
+
+    #[test]
+    fn test_sort_venvs_without_sort_is_noop() {
+        let mut app = make_app_with_venvs();
+        app.venvs.swap(0, 2);
+        app.sort_venvs();
+        assert_eq!(app.venvs[0].name, "env3");
     }
 
     // ── pkg_scroll ───────────────────────────────────────────────────────────
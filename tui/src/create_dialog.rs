@@ -1,4 +1,10 @@
 use crate::create_field::CreateField;
+use pylot_shared::{
+    constants::DEFAULT_PYTHON_VERSION,
+    error::Result,
+    pyrequest::{self, PythonRequest},
+    uvpython::PythonInstall,
+};
 
 /// In-TUI form state for creating a new virtual environment
 pub struct CreateDialog {
@@ -12,7 +18,9 @@ pub struct CreateDialog {
     pub req_file: String,
     /// Cursor position (in characters) within `req_file`.
     pub req_file_cursor: usize,
-    /// Directory completions (filtered by any prefix typed after the last `/`).
+    /// Completions for the focused field: directory entries for `ReqFile`
+    /// (filtered by any prefix typed after the last `/`) or Python versions
+    /// for `Version`.
     pub completions: Vec<String>,
     /// Index of the currently highlighted completion entry.
     pub completion_selected: usize,
@@ -22,6 +30,13 @@ pub struct CreateDialog {
     /// (everything up to and including the last `/`).
     pub completions_dir: String,
     pub default_pkgs: bool,
+    /// Interpreters known to uv, used to validate the requested version.
+    /// Empty when they have not been listed, which skips that check.
+    pub pythons: Vec<PythonInstall>,
+    /// Versions offered in the `Version` dropdown.
+    pub version_choices: Vec<String>,
+    /// Validation error shown when the form was submitted with a bad version.
+    pub error: Option<String>,
 }
 
 impl CreateDialog {
@@ -38,9 +53,54 @@ impl CreateDialog {
             completion_scroll: 0,
             completions_dir: String::new(),
             default_pkgs: false,
+            pythons: Vec::new(),
+            version_choices: Vec::new(),
+            error: None,
         }
     }
 
+    /// Remember the available interpreters for validation and the version
+    /// dropdown.
+    pub fn set_pythons(&mut self, installs: &[PythonInstall]) {
+        self.pythons = installs.to_vec();
+        self.version_choices = pyrequest::version_choices(installs);
+    }
+
+    /// Whether a completion dropdown is showing for the focused field.
+    pub fn completions_active(&self) -> bool {
+        matches!(self.field, CreateField::ReqFile | CreateField::Version)
+            && !self.completions.is_empty()
+    }
+
+    /// Fill the version dropdown with the choices that start with what has
+    /// been typed.  An input that already equals the only match needs no
+    /// dropdown.
+    pub fn update_version_completions(&mut self) {
+        let typed = self.version.trim().to_lowercase();
+        let matches: Vec<String> = self
+            .version_choices
+            .iter()
+            .filter(|choice| choice.to_lowercase().starts_with(&typed))
+            .cloned()
+            .collect();
+        self.completions = if matches.len() == 1 && matches[0].to_lowercase() == typed {
+            Vec::new()
+        } else {
+            matches
+        };
+        self.completion_selected = 0;
+        self.completion_scroll = 0;
+        self.completions_dir = String::new();
+    }
+
+    /// Accept a version from the dropdown.
+    pub fn version_accept_completion(&mut self, entry: &str) {
+        self.version = entry.to_string();
+        self.completions.clear();
+        self.completion_selected = 0;
+        self.completion_scroll = 0;
+    }
+
     /// Push a character into the currently focused text field (no-op for bool field).
     /// For `ReqFile`, backslashes are normalized to forward slashes and the character
     /// is inserted at the current cursor position.
@@ -140,19 +200,36 @@ impl CreateDialog {
 
     /// Return the effective Python version: the user's input, or `DEFAULT_PYTHON_VERSION`
     /// if the version field was left blank.
-    pub fn effective_version(&self) -> String {
+    ///
+    /// The request is parsed, and resolved against `pythons` when they are
+    /// known, so a typo is reported before anything is created.
+    pub fn effective_version(&self) -> Result<String> {
         let v = self.version.trim();
-        if v.is_empty() {
-            pylot_shared::constants::DEFAULT_PYTHON_VERSION.to_string()
+        let v = if v.is_empty() {
+            DEFAULT_PYTHON_VERSION
         } else {
-            v.to_string()
+            v
+        };
+        let request = PythonRequest::parse(v)?;
+        if !self.pythons.is_empty() || matches!(request, PythonRequest::Path(_)) {
+            pyrequest::resolve(&request, &self.pythons)?;
         }
+        Ok(v.to_string())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pylot_shared::uvpython::parse_list;
+
+    fn pythons() -> Vec<PythonInstall> {
+        parse_list(
+            "cpython-3.13.1-linux-x86_64-gnu  <download available>\n\
+             cpython-3.12.3-linux-x86_64-gnu  /usr/bin/python3.12\n\
+             pypy-3.10.14-linux-x86_64-gnu    <download available>\n",
+        )
+    }
 
     #[test]
     fn test_create_dialog_new() {
@@ -210,15 +287,52 @@ mod tests {
     #[test]
     fn test_create_dialog_effective_version() {
         let d = CreateDialog::new("3.12");
-        assert_eq!(d.effective_version(), "3.12");
+        assert_eq!(d.effective_version().unwrap(), "3.12");
 
         let mut d2 = CreateDialog::new("3.12");
         d2.version = "  ".to_string();
         // Blank version falls back to DEFAULT_PYTHON_VERSION.
-        assert_eq!(
-            d2.effective_version(),
-            pylot_shared::constants::DEFAULT_PYTHON_VERSION
-        );
+        assert_eq!(d2.effective_version().unwrap(), DEFAULT_PYTHON_VERSION);
+    }
+
+    #[test]
+    fn test_create_dialog_effective_version_validates() {
+        let mut d = CreateDialog::new("3.x");
+        assert!(d.effective_version().is_err());
+
+        // Without a list of interpreters only the syntax is checked.
+        d.version = "3.21".to_string();
+        assert_eq!(d.effective_version().unwrap(), "3.21");
+
+        d.set_pythons(&pythons());
+        let err = d.effective_version().unwrap_err().to_string();
+        assert!(err.contains("did you mean '3.12'"));
+        d.version = ">=3.11".to_string();
+        assert_eq!(d.effective_version().unwrap(), ">=3.11");
+    }
+
+    #[test]
+    fn test_create_dialog_version_completions() {
+        let mut d = CreateDialog::new("3.12");
+        d.field = CreateField::Version;
+        d.set_pythons(&pythons());
+        assert_eq!(d.version_choices, vec!["3.13", "3.12", "pypy@3.10"]);
+
+        // Exact match of the only candidate: nothing to offer.
+        d.update_version_completions();
+        assert!(!d.completions_active());
+
+        d.version = "3.1".to_string();
+        d.update_version_completions();
+        assert_eq!(d.completions, vec!["3.13", "3.12"]);
+        assert!(d.completions_active());
+
+        d.version.clear();
+        d.update_version_completions();
+        assert_eq!(d.completions.len(), 3);
+        d.version_accept_completion("pypy@3.10");
+        assert_eq!(d.version, "pypy@3.10");
+        assert!(!d.completions_active());
     }
 
     #[test]
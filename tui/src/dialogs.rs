@@ -72,15 +72,37 @@ impl ConfirmDialog {
                 format!("Delete virtual environment '{}'?", name)
             }
             ConfirmAction::UninstallUv => "Uninstall Astral UV?".to_string(),
+            ConfirmAction::UninstallPython {
+                version, used_by, ..
+            } if !used_by.is_empty() => {
+                format!(
+                    "Uninstall Python {}? Used by {}",
+                    version,
+                    used_by.join(", ")
+                )
+            }
+            ConfirmAction::UninstallPython { version, .. } => {
+                format!("Uninstall Python {}?", version)
+            }
+            ConfirmAction::UninstallTool(name) => format!("Uninstall tool '{}'?", name),
+            ConfirmAction::CancelTask { label, .. } => {
+                let mut chars = label.chars();
+                let first = chars.next().map(|c| c.to_lowercase().to_string());
+                format!("Cancel {}{}?", first.unwrap_or_default(), chars.as_str())
+            }
         }
     }
 }
 
-// Specifies which help menu is currently active (environments or UV info)
+// Specifies which help menu is currently active (one per tab)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
 pub enum HelpMode {
     EnvHelp,
     UvHelp,
+    PythonHelp,
+    ToolHelp,
+    TaskHelp,
 }
 
 // Help dialog
@@ -96,14 +118,29 @@ impl HelpDialog {
         match help_menu {
             HelpMode::EnvHelp => HelpDialog {
                 help_mode: help_menu,
-                height: 21,
+                height: 25,
                 width: 60,
             },
             HelpMode::UvHelp => HelpDialog {
                 help_mode: help_menu,
-                height: 17,
+                height: 18,
                 width: 50,
             },
+            HelpMode::PythonHelp => HelpDialog {
+                help_mode: help_menu,
+                height: 16,
+                width: 56,
+            },
+            HelpMode::ToolHelp => HelpDialog {
+                help_mode: help_menu,
+                height: 17,
+                width: 56,
+            },
+            HelpMode::TaskHelp => HelpDialog {
+                help_mode: help_menu,
+                height: 16,
+                width: 56,
+            },
         }
     }
     // Create the help lines based on the active help menu, including global and footer lines
@@ -112,6 +149,9 @@ impl HelpDialog {
         match self.help_mode {
             HelpMode::EnvHelp => lines.extend(self.env_help_lines()),
             HelpMode::UvHelp => lines.extend(self.uv_help_lines()),
+            HelpMode::PythonHelp => lines.extend(self.python_help_lines()),
+            HelpMode::ToolHelp => lines.extend(self.tool_help_lines()),
+            HelpMode::TaskHelp => lines.extend(self.task_help_lines()),
         }
         lines.extend(self.footer_help_lines());
         lines
@@ -204,12 +244,32 @@ impl HelpDialog {
             Line::from(vec![
                 self.default_bullet_span(),
                 Span::styled("/    ", Style::default().fg(Color::Yellow)),
+                Span::raw(": Search environments (python:3.11 has:numpy)"),
+            ]),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("f    ", Style::default().fg(Color::Yellow)),
                 Span::raw(": Search for package in selected environment"),
             ]),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("o    ", Style::default().fg(Color::Yellow)),
+                Span::raw(": Check for outdated packages"),
+            ]),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("t    ", Style::default().fg(Color::Yellow)),
+                Span::raw(": Toggle dependency tree (Space folds)"),
+            ]),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("s    ", Style::default().fg(Color::Yellow)),
+                Span::raw(": Sort by size / name / Python / packages / use"),
+            ]),
             Line::from(vec![
                 self.default_bullet_span(),
                 Span::styled("j/k  ", Style::default().fg(Color::Yellow)),
-                Span::raw(": Scroll package list up/down"),
+                Span::raw(": Highlight package (shows its details)"),
             ]),
         ]
     }
@@ -242,6 +302,94 @@ impl HelpDialog {
                 Span::styled("u", Style::default().fg(Color::Yellow)),
                 Span::raw(": Update Astral UV to latest version"),
             ]),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("p", Style::default().fg(Color::Yellow)),
+                Span::raw(": Prune unused uv cache entries"),
+            ]),
+        ]
+    }
+
+    fn python_help_lines(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled(
+                    "Pythons:",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("i", Style::default().fg(Color::Yellow)),
+                Span::raw(": Install highlighted Python version"),
+            ]),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("d", Style::default().fg(Color::Yellow)),
+                Span::raw(": Uninstall highlighted Python version"),
+            ]),
+        ]
+    }
+
+    fn tool_help_lines(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled(
+                    "Tools:",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("u", Style::default().fg(Color::Yellow)),
+                Span::raw(": Upgrade highlighted tool"),
+            ]),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("U", Style::default().fg(Color::Yellow)),
+                Span::raw(": Upgrade all tools"),
+            ]),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("d", Style::default().fg(Color::Yellow)),
+                Span::raw(": Uninstall highlighted tool"),
+            ]),
+        ]
+    }
+
+    fn task_help_lines(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled(
+                    "Tasks:",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("r", Style::default().fg(Color::Yellow)),
+                Span::raw(": Retry highlighted failed task"),
+            ]),
+            Line::from(vec![
+                self.default_bullet_span(),
+                Span::styled("c", Style::default().fg(Color::Yellow)),
+                Span::raw(": Cancel highlighted task"),
+            ]),
         ]
     }
 }
@@ -323,13 +471,44 @@ mod tests {
         assert!(d.message().contains("Uninstall"));
     }
 
+    #[test]
+    fn test_confirm_dialog_message_uninstall_python() {
+        let unused = ConfirmDialog::new(ConfirmAction::UninstallPython {
+            key: "cpython-3.12.3-linux-x86_64-gnu".to_string(),
+            version: "3.12.3".to_string(),
+            used_by: vec![],
+        });
+        assert_eq!(unused.message(), "Uninstall Python 3.12.3?");
+        let used = ConfirmDialog::new(ConfirmAction::UninstallPython {
+            key: "cpython-3.12.3-linux-x86_64-gnu".to_string(),
+            version: "3.12.3".to_string(),
+            used_by: vec!["web".to_string(), "api".to_string()],
+        });
+        assert!(used.message().contains("Used by web, api"));
+    }
+
+    #[test]
+    fn test_confirm_dialog_message_uninstall_tool() {
+        let d = ConfirmDialog::new(ConfirmAction::UninstallTool("ruff".to_string()));
+        assert_eq!(d.message(), "Uninstall tool 'ruff'?");
+    }
+
+    #[test]
+    fn test_confirm_dialog_message_cancel_task() {
+        let d = ConfirmDialog::new(ConfirmAction::CancelTask {
+            id: 0,
+            label: "Creating 'api'".to_string(),
+        });
+        assert_eq!(d.message(), "Cancel creating 'api'?");
+    }
+
     // -─ HelpDialog ────────────────────────────────────────────────────────────
 
     #[test]
     fn test_help_dialog_new_env_help() {
         let d = HelpDialog::new(HelpMode::EnvHelp);
         assert_eq!(d.help_mode, HelpMode::EnvHelp);
-        assert_eq!(d.height, 21);
+        assert_eq!(d.height, 25);
         assert_eq!(d.width, 60);
     }
 
@@ -337,7 +516,7 @@ mod tests {
     fn test_help_dialog_new_uv_help() {
         let d = HelpDialog::new(HelpMode::UvHelp);
         assert_eq!(d.help_mode, HelpMode::UvHelp);
-        assert_eq!(d.height, 17);
+        assert_eq!(d.height, 18);
         assert_eq!(d.width, 50);
     }
 
@@ -362,6 +541,27 @@ mod tests {
         assert!(!lines.is_empty());
     }
 
+    #[test]
+    fn test_help_dialog_python_help_lines() {
+        let d = HelpDialog::new(HelpMode::PythonHelp);
+        assert_eq!(d.help_mode, HelpMode::PythonHelp);
+        assert!(!d.python_help_lines().is_empty());
+    }
+
+    #[test]
+    fn test_help_dialog_tool_help_lines() {
+        let d = HelpDialog::new(HelpMode::ToolHelp);
+        assert_eq!(d.help_mode, HelpMode::ToolHelp);
+        assert!(!d.tool_help_lines().is_empty());
+    }
+
+    #[test]
+    fn test_help_dialog_task_help_lines() {
+        let d = HelpDialog::new(HelpMode::TaskHelp);
+        assert_eq!(d.help_mode, HelpMode::TaskHelp);
+        assert!(!d.task_help_lines().is_empty());
+    }
+
     #[test]
     fn test_help_dialog_global_help_lines() {
         let d = HelpDialog::new(HelpMode::EnvHelp);
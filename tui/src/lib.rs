@@ -7,49 +7,68 @@ mod app;
 mod create_dialog;
 mod create_field;
 mod dialogs;
+mod pkg_tree;
 mod tabs;
+mod task_progress;
+mod tasks;
 mod ui;
+mod watch;
 
 use actions::{ConfirmAction, VenvAction};
 pub use app::{App, STATUS_MESSAGE_TIMEOUT_SECS};
 use create_dialog::CreateDialog;
 use dialogs::{ConfirmDialog, HelpDialog, PkgDialog, PkgDialogMode};
+use pkg_tree::PkgTree;
+use tasks::{TaskId, TaskState};
 
 use crossterm::{
-    event::{Event, EventStream, KeyCode, KeyEventKind},
+    event::{Event, EventStream, KeyCode, KeyEventKind, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use futures::StreamExt;
+use futures::{FutureExt, StreamExt, TryFutureExt};
 use pylot_shared::constants::DEFAULT_PYTHON_VERSION;
 use pylot_shared::uvvenv::UvVenv;
+use pylot_shared::venvmanager::ScanMode;
 use pylot_shared::venvtraits::{Activate, Create, Delete};
-use pylot_shared::{uvctrl, venvmanager};
+use pylot_shared::{uvcache, uvctrl, uvpython, uvtool, Pylot};
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::borrow::Cow;
+use std::future::Future;
 use std::io;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::oneshot;
+use tokio::sync::{mpsc, oneshot};
 
 /// Run the TUI application
 ///
+/// # Arguments
+/// * `ctx` - The settings and runner to manage environments with
+///
 /// # Returns
 /// * `Result<()>` - Ok if the TUI ran successfully
 ///
 /// # Examples
 /// ```no_run
+/// use pylot_shared::Pylot;
 /// use pylot_tui::run;
-/// run();
+/// run(Pylot::load());
 /// ```
-pub async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    let venvs = venvmanager::VENVMANAGER.list().await;
-    let uv_installed = uvctrl::check("uv").await.is_ok();
+pub async fn run(ctx: Pylot) -> Result<(), Box<dyn std::error::Error>> {
+    let venvs = ctx.manager().list_with(ScanMode::Lazy).await;
+    let uv_installed = uvctrl::check(&ctx, "uv").await.is_ok();
 
     // Start the TUI immediately; UV version info will be fetched in the
     // background and appear once available.
     let mut app = App::new(venvs, uv_installed, None);
+    app.ctx = ctx.into();
+    app.watcher = watch::VenvWatcher::start(&app.ctx.venvs_path());
+    watch_venvs(&mut app).await;
     if uv_installed {
         spawn_uv_info_task(&mut app);
+        spawn_pythons_task(&mut app);
+        spawn_tools_task(&mut app);
     }
 
     // Suppress all log output while the TUI is active so that mio/tokio trace
@@ -84,13 +103,7 @@ pub async fn run() -> Result<(), Box<dyn std::error::Error>> {
         if let Some(VenvAction::Activate) = venv_action {
             if !app.venvs.is_empty() {
                 let name = app.venvs[app.selected].name.to_string();
-                let venv = UvVenv::new(
-                    Cow::Owned(name),
-                    "".to_string(),
-                    "".to_string(),
-                    vec![],
-                    false,
-                );
+                let venv = app.ctx.venv(name);
                 match venv.activate().await {
                     Ok(_) => {}
                     Err(e) => {
@@ -102,13 +115,15 @@ pub async fn run() -> Result<(), Box<dyn std::error::Error>> {
         }
 
         // Refresh state before re-entering the TUI.
-        app.uv_installed = uvctrl::check("uv").await.is_ok();
-        app.uv_version = None;
-        app.uv_latest_version = None;
+        app.uv_installed = uvctrl::check(&app.ctx, "uv").await.is_ok();
+        app.uv_info = None;
+        app.venvs = app.ctx.manager().list_with(ScanMode::Lazy).await;
+        watch_venvs(&mut app).await;
         if app.uv_installed {
             spawn_uv_info_task(&mut app);
+            spawn_pythons_task(&mut app);
+        spawn_tools_task(&mut app);
         }
-        app.venvs = venvmanager::VENVMANAGER.list().await;
         if !app.venvs.is_empty() && app.selected >= app.venvs.len() {
             app.selected = app.venvs.len() - 1;
         }
@@ -216,48 +231,338 @@ fn update_completions(dialog: &mut CreateDialog) {
 /// Maximum number of completion entries shown at once in the dialog.
 const COMPLETION_MAX_SHOWN: usize = 6;
 
-/// Spawn a background task that fetches both the current and latest UV version
-/// and sends the results back via the `uv_info_rx` channel on `app`.
+/// Spawn a background task that fetches the UV version details (see
+/// [`uvctrl::info`]) and the cache location and size, and sends the results
+/// back via the `uv_info_rx` channel on `app`.
 ///
 /// Both queries run concurrently.  Only called when `app.uv_installed` is
 /// `true`.
 fn spawn_uv_info_task(app: &mut App) {
-    let (tx, rx) =
-        tokio::sync::oneshot::channel::<(Option<String>, Option<String>)>();
+    let ctx = app.ctx.clone();
+    let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
-        let (uv_version, uv_latest_version) =
-            tokio::join!(get_uv_version(), get_latest_uv_version());
-        let _ = tx.send((uv_version, uv_latest_version));
+        let (uv_info, uv_cache) = tokio::join!(uvctrl::info(&ctx), uvcache::info(&ctx));
+        let _ = tx.send((uv_info.ok(), uv_cache.ok()));
     });
     app.uv_info_rx = Some(rx);
 }
 
-/// Spawn a background task for a UV management operation and record it in `app`.
-fn spawn_uv_task(
-    app: &mut App,
-    name: &'static str,
-    fut: impl std::future::Future<Output = Result<(), String>> + Send + 'static,
-) {
-    let (tx, rx) = oneshot::channel::<Result<(), String>>();
+/// Spawn a background task that lists Python interpreters and works out which
+/// venvs use each one, sending the result back via `pythons_rx` on `app`.
+fn spawn_pythons_task(app: &mut App) {
+    let venvs: Vec<(String, String)> = app
+        .venvs
+        .iter()
+        .map(|v| (v.name.to_string(), v.path.clone()))
+        .collect();
+    let ctx = app.ctx.clone();
+    let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
-        let _ = tx.send(fut.await);
+        let result = match uvpython::list(&ctx, false).await {
+            Ok(installs) => {
+                let venvs: Vec<UvVenv> = venvs
+                    .into_iter()
+                    .map(|(name, path)| {
+                        UvVenv::new(&ctx, Cow::Owned(name), path, "".to_string(), vec![], false)
+                    })
+                    .collect();
+                let configs = uvpython::venv_configs(&venvs).await;
+                let usage = uvpython::venv_usage(&installs, &configs);
+                Ok((installs, usage))
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        let _ = tx.send(result);
     });
-    app.bg_rx = Some(rx);
-    app.bg_task_name = Some(name.to_string());
+    app.pythons_rx = Some(rx);
 }
 
-/// Spawn a background task for a venv operation and record it in `app`.
-fn spawn_venv_task(
-    app: &mut App,
-    label: String,
-    fut: impl std::future::Future<Output = pylot_shared::error::Result<()>> + Send + 'static,
-) {
-    let (tx, rx) = oneshot::channel::<Result<(), String>>();
+/// Spawn a background task that lists the installed CLI tools, sending the
+/// result back via `tools_rx` on `app`.
+fn spawn_tools_task(app: &mut App) {
+    let ctx = app.ctx.clone();
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let _ = tx.send(uvtool::list(&ctx).await.map_err(|e| e.to_string()));
+    });
+    app.tools_rx = Some(rx);
+}
+
+/// Spawn a background task that looks up outdated packages in the selected
+/// venv and sends the result back via the `outdated_rx` channel on `app`.
+fn spawn_outdated_task(app: &mut App) {
+    let name = app.venvs[app.selected].name.to_string();
+    let path = app.venvs[app.selected].path.clone();
+    let ctx = app.ctx.clone();
+    let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
-        let _ = tx.send(fut.await.map_err(|e| e.to_string()));
+        let mut venv = UvVenv::new(
+            &ctx,
+            Cow::Owned(name.clone()),
+            path,
+            "".to_string(),
+            vec![],
+            false,
+        );
+        let result = venv.outdated_packages().await.map_err(|e| e.to_string());
+        let _ = tx.send((name, result));
+    });
+    app.outdated_rx = Some(rx);
+}
+
+/// Watch the directories of every listed venv, see [`watch::VenvWatcher`].
+async fn watch_venvs(app: &mut App<'_>) {
+    let Some(watcher) = app.watcher.as_mut() else {
+        return;
+    };
+    for venv in &app.venvs {
+        let site_packages = venv.site_packages_dir().await;
+        watcher.watch_venv(Path::new(&venv.path), site_packages.as_deref());
+    }
+}
+
+/// Reload the venvs named in `names`, adding new ones and dropping deleted
+/// ones, while the rest of the list stays as it is.  The selection follows
+/// the selected venv and its package scroll is kept where possible.
+async fn refresh_venvs(app: &mut App<'_>, names: &[String]) {
+    let selected = app.venvs.get(app.selected).map(|v| v.name.to_string());
+    for name in names {
+        let manager = app.ctx.manager();
+        let venv = match manager.is_venv(name).await {
+            true => manager.get(name).await.ok(),
+            false => None,
+        };
+        let index = app.venvs.iter().position(|v| v.name == name.as_str());
+        if let Some(watcher) = app.watcher.as_mut() {
+            match &venv {
+                Some(venv) => {
+                    let site_packages = venv.site_packages_dir().await;
+                    watcher.watch_venv(Path::new(&venv.path), site_packages.as_deref());
+                }
+                // Keep an eye on a directory that is still being created.
+                None if manager.check_if_exists(name).await => {
+                    watcher.watch_venv(&app.ctx.venvs_path().join(name), None);
+                }
+                None => watcher.unwatch_venv(name),
+            }
+        }
+        match (index, venv) {
+            (Some(i), Some(venv)) => app.venvs[i] = venv,
+            (None, Some(venv)) => app.venvs.push(venv),
+            (Some(i), None) => {
+                app.venvs.remove(i);
+            }
+            (None, None) => {}
+        }
+        // Its packages may have changed.
+        app.outdated.remove(name);
+    }
+    app.sort_venvs();
+
+    let index = selected.and_then(|name| app.venvs.iter().position(|v| v.name == name));
+    match index {
+        Some(i) => app.selected = i,
+        None => {
+            app.selected = app.selected.min(app.venvs.len().saturating_sub(1));
+            app.pkg_scroll = 0;
+        }
+    }
+    if app.venv_search.is_some() {
+        app.apply_venv_search();
+    }
+    if let Some(venv) = app.venvs.get(app.selected) {
+        app.pkg_scroll = app
+            .pkg_scroll
+            .min(venv.installed_packages.len().saturating_sub(1));
+        if names.iter().any(|n| *n == venv.name) {
+            if app.pkg_tree.is_some() {
+                load_pkg_tree(app).await;
+            }
+            app.pkg_details_key = None;
+        }
+    }
+}
+
+/// Spawn a background task that reads the package lists and sizes the lazy
+/// listing left out for venvs not in the scan cache, see
+/// [`App::venvs_to_load`].  Each venv is sent back via `packages_rx` on `app`
+/// as soon as it is read.  Does nothing while such a task is running.
+fn spawn_packages_task(app: &mut App) {
+    if app.packages_rx.is_some() {
+        return;
+    }
+    let venvs: Vec<_> = app
+        .venvs_to_load()
+        .into_iter()
+        .map(|i| {
+            let venv = &app.venvs[i];
+            (
+                venv.name.to_string(),
+                venv.path.clone(),
+                venv.python_version.clone(),
+            )
+        })
+        .collect();
+    if venvs.is_empty() {
+        return;
+    }
+    let ctx = app.ctx.clone();
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        for (name, path, python_version) in venvs {
+            let mut venv = UvVenv::new(&ctx, Cow::Owned(name), path, python_version, vec![], false);
+            venv.load_packages().await;
+            if tx.send(venv).is_err() {
+                break;
+            }
+        }
     });
-    app.bg_rx = Some(rx);
-    app.bg_task_name = Some(label);
+    app.packages_rx = Some(rx);
+}
+
+/// Apply the venvs the packages task read since the last call.
+fn poll_packages_task(app: &mut App) {
+    let Some(rx) = app.packages_rx.as_mut() else {
+        return;
+    };
+    let mut loaded = Vec::new();
+    loop {
+        match rx.try_recv() {
+            Ok(venv) => loaded.push(venv),
+            Err(mpsc::error::TryRecvError::Empty) => break,
+            Err(mpsc::error::TryRecvError::Disconnected) => {
+                app.packages_rx = None;
+                break;
+            }
+        }
+    }
+    if !loaded.is_empty() {
+        app.apply_loaded_packages(loaded);
+    }
+}
+
+/// Build the dependency tree of the selected venv and show it in the
+/// packages pane.  Reports an error in the status bar if it cannot be read.
+async fn load_pkg_tree(app: &mut App<'_>) {
+    let Some(venv) = app.venvs.get(app.selected) else {
+        app.pkg_tree = None;
+        return;
+    };
+    match venv.dependency_graph().await {
+        Ok(graph) => app.pkg_tree = Some(PkgTree::new(venv.name.to_string(), graph)),
+        Err(e) => {
+            app.pkg_tree = None;
+            app.status_message = Some((format!("Error: {}", e), true, Instant::now()));
+        }
+    }
+}
+
+/// Load the metadata of the highlighted package when the highlight has moved
+/// since the last load.  Errors leave the details empty.
+async fn sync_pkg_details(app: &mut App<'_>) {
+    if app.tab != tabs::Tab::Environments {
+        return;
+    }
+    let key = app
+        .highlighted_package()
+        .and_then(|pkg| Some((app.venvs.get(app.selected)?.name.to_string(), pkg)));
+    if key == app.pkg_details_key {
+        return;
+    }
+    app.pkg_details = match (&key, app.venvs.get(app.selected)) {
+        (Some((_, pkg)), Some(venv)) => venv.package_details(pkg).await.ok(),
+        _ => None,
+    };
+    app.pkg_details_key = key;
+}
+
+/// Queue a UV management operation on `app`'s task queue, starting it right
+/// away when a slot is free.  `job` gets a context that reports to the task's
+/// progress and is called again if the task is retried.
+fn spawn_uv_task<F, Fut>(app: &mut App, name: &str, job: F) -> TaskId
+where
+    F: Fn(Arc<Pylot>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), String>> + Send + 'static,
+{
+    let id = app
+        .tasks
+        .push(name.to_string(), Arc::new(move |ctx| job(ctx).boxed()));
+    app.tasks.start(&app.ctx);
+    id
+}
+
+/// Queue a venv operation on `app`'s task queue, see [`spawn_uv_task`].
+fn spawn_venv_task<F, Fut>(app: &mut App, label: String, job: F) -> TaskId
+where
+    F: Fn(Arc<Pylot>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = pylot_shared::error::Result<()>> + Send + 'static,
+{
+    let job = move |ctx| job(ctx).map_err(|e| e.to_string()).boxed();
+    let id = app.tasks.push(label, Arc::new(job));
+    app.tasks.start(&app.ctx);
+    id
+}
+
+/// Queue an operation on the venv `venv`, see [`spawn_venv_task`].  It waits
+/// for any other task on `venv`; `creates` marks it as creating `venv`.
+fn spawn_task_on_venv<F, Fut>(
+    app: &mut App,
+    label: String,
+    venv: &str,
+    creates: bool,
+    job: F,
+) -> TaskId
+where
+    F: Fn(Arc<Pylot>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = pylot_shared::error::Result<()>> + Send + 'static,
+{
+    let job = move |ctx| job(ctx).map_err(|e| e.to_string()).boxed();
+    let id = app
+        .tasks
+        .push_on_venv(label, Some(venv.to_string()), creates, Arc::new(job));
+    app.tasks.start(&app.ctx);
+    id
+}
+
+/// Cancel task `id`, see [`TaskQueue::cancel`](tasks::TaskQueue::cancel).  A
+/// running task is reported as cancelled once its cleanup is done.
+fn cancel_task(app: &mut App, id: TaskId) {
+    let ctx = app.ctx.clone();
+    if !app.tasks.cancel(id, &ctx) {
+        return;
+    }
+    if let Some(task) = app
+        .tasks
+        .get(id)
+        .filter(|t| t.state == TaskState::Cancelled)
+    {
+        app.status_message = Some((format!("{} cancelled.", task.label), false, Instant::now()));
+    }
+}
+
+/// Ask before cancelling [`App::cancellable_task`], if there is one.
+fn open_cancel_dialog(app: &mut App) {
+    if let Some(task) = app.cancellable_task() {
+        let action = ConfirmAction::CancelTask {
+            id: task.id,
+            label: task.label.clone(),
+        };
+        app.confirm_dialog = Some(ConfirmDialog::new(action));
+    }
+}
+
+/// Quitting while tasks run would orphan their uv processes and leave partly
+/// created environments behind, so offer to cancel one instead.  When every
+/// running task is already being cancelled, say what is holding up the exit.
+fn cancel_before_quit(app: &mut App) {
+    open_cancel_dialog(app);
+    if app.confirm_dialog.is_none() {
+        app.status_message = Some((
+            "Waiting for running tasks to finish before quitting.".to_string(),
+            false,
+            Instant::now(),
+        ));
+    }
 }
 
 async fn run_app<B: ratatui::backend::Backend>(
@@ -270,55 +575,152 @@ where
     let mut events = EventStream::new();
 
     loop {
-        // --- Poll background task for completion ---
-        if let Some(rx) = app.bg_rx.as_mut() {
+        // --- Poll background tasks for progress and completion ---
+        let finished = app.tasks.poll();
+        for task in finished.iter().filter_map(|id| app.tasks.get(*id)) {
+            app.status_message = Some(match &task.state {
+                TaskState::Cancelled => {
+                    (format!("{} cancelled.", task.label), false, Instant::now())
+                }
+                TaskState::Failed(e) => (
+                    format!("{} failed: {}", task.label, e),
+                    true,
+                    Instant::now(),
+                ),
+                _ => (format!("{} completed.", task.label), false, Instant::now()),
+            });
+        }
+        if !finished.is_empty() {
+            // Refresh venv and UV state without leaving the TUI.  Packages
+            // may have changed, so earlier outdated results are dropped.
+            app.outdated.clear();
+            app.uv_installed = uvctrl::check(&app.ctx, "uv").await.is_ok();
+            app.uv_info = None;
+            app.venvs = app.ctx.manager().list_with(ScanMode::Lazy).await;
+            app.sort_venvs();
+            watch_venvs(app).await;
+            if app.uv_installed {
+                spawn_uv_info_task(app);
+                spawn_pythons_task(app);
+                spawn_tools_task(app);
+            }
+            if !app.venvs.is_empty() && app.selected >= app.venvs.len() {
+                app.selected = app.venvs.len() - 1;
+            }
+            if app.venv_search.is_some() {
+                app.apply_venv_search();
+            }
+            if app.pkg_tree.is_some() {
+                load_pkg_tree(app).await;
+            }
+            app.pkg_details_key = None;
+        }
+        // Finished tasks free their slots for queued ones.
+        app.tasks.start(&app.ctx);
+
+        // --- Reload venvs changed outside the TUI ---
+        let changed = app
+            .watcher
+            .as_mut()
+            .map(|w| w.changed(Instant::now()))
+            .unwrap_or_default();
+        if !changed.is_empty() {
+            refresh_venvs(app, &changed).await;
+        }
+
+        // --- Read package lists and sizes the lazy listing left out ---
+        poll_packages_task(app);
+        spawn_packages_task(app);
+        sync_pkg_details(app).await;
+        terminal.draw(|frame| ui::draw(frame, app))?;
+
+        // --- Poll UV info background task for completion ---
+        if let Some(rx) = app.uv_info_rx.as_mut() {
+            match rx.try_recv() {
+                Ok((uv_info, uv_cache)) => {
+                    app.uv_info_rx = None;
+                    app.uv_info = uv_info;
+                    app.uv_cache = uv_cache;
+                }
+                Err(oneshot::error::TryRecvError::Empty) => {}
+                Err(oneshot::error::TryRecvError::Closed) => {
+                    app.uv_info_rx = None;
+                }
+            }
+        }
+
+        // --- Poll interpreter listing background task for completion ---
+        if let Some(rx) = app.pythons_rx.as_mut() {
             match rx.try_recv() {
                 Ok(result) => {
-                    app.bg_rx = None;
-                    let task_name = app.bg_task_name.take().unwrap_or_default();
+                    app.pythons_rx = None;
                     match result {
-                        Ok(()) => {
-                            app.status_message =
-                                Some((format!("{} completed.", task_name), false, Instant::now()));
+                        Ok((pythons, usage)) => {
+                            app.pythons_error = None;
+                            app.set_pythons(pythons, usage);
                         }
-                        Err(e) => {
-                            app.status_message =
-                                Some((format!("Error: {}", e), true, Instant::now()));
-                        }
-                    }
-                    // Refresh venv and UV state without leaving the TUI.
-                    app.uv_installed = uvctrl::check("uv").await.is_ok();
-                    app.uv_version = None;
-                    app.uv_latest_version = None;
-                    if app.uv_installed {
-                        spawn_uv_info_task(app);
-                    }
-                    app.venvs = venvmanager::VENVMANAGER.list().await;
-                    if !app.venvs.is_empty() && app.selected >= app.venvs.len() {
-                        app.selected = app.venvs.len() - 1;
+                        Err(e) => app.pythons_error = Some(e),
                     }
                 }
-                Err(oneshot::error::TryRecvError::Empty) => {} // still running
+                Err(oneshot::error::TryRecvError::Empty) => {}
                 Err(oneshot::error::TryRecvError::Closed) => {
-                    app.bg_rx = None;
-                    app.bg_task_name = None;
+                    app.pythons_rx = None;
                 }
             }
         }
 
-        terminal.draw(|frame| ui::draw(frame, app))?;
+        // --- Poll tool listing background task for completion ---
+        if let Some(rx) = app.tools_rx.as_mut() {
+            match rx.try_recv() {
+                Ok(result) => {
+                    app.tools_rx = None;
+                    match result {
+                        Ok(tools) => {
+                            app.tools_error = None;
+                            app.set_tools(tools);
+                        }
+                        Err(e) => app.tools_error = Some(e),
+                    }
+                }
+                Err(oneshot::error::TryRecvError::Empty) => {}
+                Err(oneshot::error::TryRecvError::Closed) => {
+                    app.tools_rx = None;
+                }
+            }
+        }
 
-        // --- Poll UV info background task for completion ---
-        if let Some(rx) = app.uv_info_rx.as_mut() {
+        // --- Poll outdated check background task for completion ---
+        if let Some(rx) = app.outdated_rx.as_mut() {
             match rx.try_recv() {
-                Ok((uv_version, uv_latest_version)) => {
-                    app.uv_info_rx = None;
-                    app.uv_version = uv_version;
-                    app.uv_latest_version = uv_latest_version;
+                Ok((name, result)) => {
+                    app.outdated_rx = None;
+                    match result {
+                        Ok(report) => {
+                            let pkgs = report.outdated;
+                            let mut message = if pkgs.is_empty() {
+                                format!("All packages in '{}' are up to date.", name)
+                            } else {
+                                format!("{} outdated package(s) in '{}'.", pkgs.len(), name)
+                            };
+                            if !report.failed.is_empty() {
+                                message = format!(
+                                    "{} {} package(s) could not be checked.",
+                                    message,
+                                    report.failed.len()
+                                );
+                            }
+                            app.status_message = Some((message, false, Instant::now()));
+                            app.outdated.insert(name, pkgs);
+                        }
+                        Err(e) => {
+                            app.status_message =
+                                Some((format!("Error: {}", e), true, Instant::now()));
+                        }
+                    }
                 }
                 Err(oneshot::error::TryRecvError::Empty) => {}
                 Err(oneshot::error::TryRecvError::Closed) => {
-                    app.uv_info_rx = None;
+                    app.outdated_rx = None;
                 }
             }
         }
@@ -354,27 +756,34 @@ where
                     match dialog.action {
                         ConfirmAction::DeleteVenv(name) => {
                             let label = format!("Deleting '{}'", name);
-                            spawn_venv_task(app, label, async move {
+                            let venv = name.clone();
+                            spawn_task_on_venv(app, label, &venv, false, move |ctx| {
+                                let name = name.clone();
                                 // confirm=false: the confirmation dialog is the prompt.
-                                UvVenv::new(
-                                    Cow::Owned(name),
-                                    "".to_string(),
-                                    "".to_string(),
-                                    vec![],
-                                    false,
-                                )
-                                .delete(io::Cursor::new(""), false)
-                                .await
+                                async move { ctx.venv(name).delete(io::Cursor::new(""), false).await }
                             });
                         }
+                        ConfirmAction::UninstallPython { key, version, .. } => {
+                            let label = format!("Uninstalling Python {}", version);
+                            spawn_venv_task(app, label, move |ctx| {
+                                let key = key.clone();
+                                async move { uvpython::uninstall(&ctx, &key).await }
+                            });
+                        }
+                        ConfirmAction::UninstallTool(name) => {
+                            let label = format!("Uninstalling tool '{}'", name);
+                            spawn_venv_task(app, label, move |ctx| {
+                                let name = name.clone();
+                                async move { uvtool::uninstall(&ctx, &name).await }
+                            });
+                        }
+                        ConfirmAction::CancelTask { id, .. } => cancel_task(app, id),
                         ConfirmAction::UninstallUv => {
                             // Pressing 'y' is the user's confirmation – auto-reply "y\n"
                             // so uvctrl::uninstall's stdin prompt is satisfied.
-                            spawn_uv_task(
-                                app,
-                                "Uninstalling UV",
-                                uvctrl::uninstall(io::Cursor::new("y\n")),
-                            );
+                            spawn_uv_task(app, "Uninstalling UV", |ctx| async move {
+                                uvctrl::uninstall(&ctx, io::Cursor::new("y\n"), false).await
+                            });
                         }
                     }
                 }
@@ -397,10 +806,8 @@ where
                 continue;
             }
 
-            // ── Phase 2: Completion navigation (when ReqFile has completions) ──
-            let completions_active = dialog.field == create_field::CreateField::ReqFile
-                && !dialog.completions.is_empty();
-            if completions_active {
+            // ── Phase 2: Completion navigation (ReqFile or Version dropdown) ──
+            if dialog.completions_active() {
                 match key.code {
                     KeyCode::Down => {
                         if dialog.completion_selected + 1 < dialog.completions.len() {
@@ -428,8 +835,12 @@ where
                     KeyCode::Tab => {
                         // Accept the currently highlighted completion.
                         let sel = dialog.completions[dialog.completion_selected].clone();
-                        dialog.req_file_accept_completion(&sel);
-                        update_completions(dialog);
+                        if dialog.field == create_field::CreateField::Version {
+                            dialog.version_accept_completion(&sel);
+                        } else {
+                            dialog.req_file_accept_completion(&sel);
+                            update_completions(dialog);
+                        }
                         continue;
                     }
                     _ => {
@@ -467,6 +878,7 @@ where
 
             // ── Phase 4: Normal field handling ──
             let is_req_file_field = dialog.field == create_field::CreateField::ReqFile;
+            let is_version_field = dialog.field == create_field::CreateField::Version;
             match key.code {
                 KeyCode::Tab | KeyCode::Down => {
                     let next = dialog.field.next();
@@ -495,7 +907,15 @@ where
                     if dialog.field == create_field::CreateField::DefaultPkgs {
                         let name = dialog.name.trim().to_string();
                         if !name.is_empty() {
-                            let version = dialog.effective_version();
+                            // Keep the dialog open on a bad version so it can be fixed.
+                            let version = match dialog.effective_version() {
+                                Ok(version) => version,
+                                Err(e) => {
+                                    dialog.error = Some(e.to_string());
+                                    dialog.field = create_field::CreateField::Version;
+                                    continue;
+                                }
+                            };
                             let packages = dialog.parsed_packages();
                             let default_pkgs = dialog.default_pkgs;
                             // Normalize Windows paths before storing.
@@ -505,27 +925,39 @@ where
                             } else {
                                 Some(req_file)
                             };
+                            if app.tasks.is_creating(&name) {
+                                dialog.error = Some(format!("'{}' is already being created", name));
+                                dialog.field = create_field::CreateField::Name;
+                                continue;
+                            }
                             let label = format!("Creating '{}'", name);
                             app.create_dialog = None;
+                            let venv = name.clone();
                             // Spawn background task – TUI stays open.
-                            spawn_venv_task(app, label, async move {
+                            spawn_task_on_venv(app, label, &venv, true, move |ctx| {
                                 let venv = UvVenv::new(
-                                    Cow::Owned(name),
+                                    &ctx,
+                                    Cow::Owned(name.clone()),
                                     "".to_string(),
-                                    version,
-                                    packages,
+                                    version.clone(),
+                                    packages.clone(),
                                     default_pkgs,
                                 );
-                                venv.create().await?;
-                                if let Some(ref path) = req_file_opt {
-                                    venv.install_from_requirements(path).await.map_err(|e| {
-                                        pylot_shared::error::PylotError::Other(format!(
-                                            "Venv created; requirements install failed: {}",
-                                            e
-                                        ))
-                                    })?;
+                                let req_file_opt = req_file_opt.clone();
+                                async move {
+                                    venv.create().await?;
+                                    if let Some(ref path) = req_file_opt {
+                                        venv.install_from_requirements(path).await.map_err(
+                                            |e| {
+                                                pylot_shared::error::PylotError::Other(format!(
+                                                    "Venv created; requirements install failed: {}",
+                                                    e
+                                                ))
+                                            },
+                                        )?;
+                                    }
+                                    Ok(())
                                 }
-                                Ok(())
                             });
                             continue; // dialog is consumed; skip Phase 5
                         } else {
@@ -554,6 +986,11 @@ where
             if is_req_file_field {
                 update_completions(dialog);
             }
+            // Typing in the version field filters the version dropdown.
+            if is_version_field && matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace) {
+                dialog.error = None;
+                dialog.update_version_completions();
+            }
 
             continue; // dialog consumed the key; skip normal bindings
         }
@@ -572,30 +1009,18 @@ where
                             match dialog.mode {
                                 PkgDialogMode::Add => {
                                     let label = format!("Adding packages to '{}'", name);
-                                    spawn_venv_task(app, label, async move {
-                                        UvVenv::new(
-                                            Cow::Owned(name),
-                                            "".to_string(),
-                                            "".to_string(),
-                                            vec![],
-                                            false,
-                                        )
-                                        .add_packages(packages)
-                                        .await
+                                    let venv = name.clone();
+                                    spawn_task_on_venv(app, label, &venv, false, move |ctx| {
+                                        let (name, packages) = (name.clone(), packages.clone());
+                                        async move { ctx.venv(name).add_packages(packages).await }
                                     });
                                 }
                                 PkgDialogMode::Remove => {
                                     let label = format!("Removing packages from '{}'", name);
-                                    spawn_venv_task(app, label, async move {
-                                        UvVenv::new(
-                                            Cow::Owned(name),
-                                            "".to_string(),
-                                            "".to_string(),
-                                            vec![],
-                                            false,
-                                        )
-                                        .remove_packages(packages)
-                                        .await
+                                    let venv = name.clone();
+                                    spawn_task_on_venv(app, label, &venv, false, move |ctx| {
+                                        let (name, packages) = (name.clone(), packages.clone());
+                                        async move { ctx.venv(name).remove_packages(packages).await }
                                     });
                                 }
                             }
@@ -617,6 +1042,35 @@ where
             continue; // dialog consumed the key; skip normal bindings
         }
 
+        // --- Environment search captures all input while it is typed ---
+        if app.venv_search_input {
+            match key.code {
+                KeyCode::Esc => {
+                    app.venv_search = None;
+                    app.venv_search_input = false;
+                }
+                KeyCode::Enter => {
+                    app.venv_search_input = false;
+                    if app.venv_search.as_deref() == Some("") {
+                        app.venv_search = None;
+                    }
+                }
+                KeyCode::Backspace => {
+                    if let Some(ref mut q) = app.venv_search {
+                        q.pop();
+                    }
+                }
+                KeyCode::Char(c) => {
+                    if let Some(ref mut q) = app.venv_search {
+                        q.push(c);
+                    }
+                }
+                _ => {}
+            }
+            app.apply_venv_search();
+            continue; // search input consumed the key; skip normal bindings
+        }
+
         // --- Package search mode captures all input while active ---
         if app.pkg_search.is_some() {
             match key.code {
@@ -649,13 +1103,32 @@ where
             continue; // dialog consumed the key; skip normal bindings
         }
 
+        // --- Esc, q or Ctrl-C while busy offers to cancel a task ---
+        let ctrl_c =
+            key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+        let quit = matches!(key.code, KeyCode::Esc | KeyCode::Char('q'));
+        if (quit || ctrl_c) && app.is_busy() {
+            cancel_before_quit(app);
+            continue;
+        }
+
         // --- Normal (non-dialog) key bindings ---
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => break,
             KeyCode::Tab | KeyCode::Right => app.next_tab(),
             KeyCode::BackTab | KeyCode::Left => app.prev_tab(),
-            KeyCode::Down => app.next_item(),
-            KeyCode::Up => app.prev_item(),
+            KeyCode::Down => {
+                app.next_item();
+                if app.pkg_tree.is_some() {
+                    load_pkg_tree(app).await;
+                }
+            }
+            KeyCode::Up => {
+                app.prev_item();
+                if app.pkg_tree.is_some() {
+                    load_pkg_tree(app).await;
+                }
+            }
 
             // UV management – only active on the UV Info tab and when not busy.
             KeyCode::Char('i')
@@ -663,16 +1136,16 @@ where
             {
                 // Pressing 'i' is the user's confirmation – auto-reply "y\n" so
                 // uvctrl::install's interactive prompt is satisfied without a shell.
-                spawn_uv_task(
-                    app,
-                    "Installing UV",
-                    uvctrl::install(io::Cursor::new("y\n")),
-                );
+                spawn_uv_task(app, "Installing UV", |ctx| async move {
+                    uvctrl::install(&ctx, io::Cursor::new("y\n"), None).await
+                });
             }
             KeyCode::Char('u')
                 if app.tab == tabs::Tab::UvInfo && app.uv_installed && !app.is_busy() =>
             {
-                spawn_uv_task(app, "Updating UV", uvctrl::update());
+                spawn_uv_task(app, "Updating UV", |ctx| async move {
+                    uvctrl::update(&ctx, None).await
+                });
             }
             KeyCode::Char('d')
                 if app.tab == tabs::Tab::UvInfo && app.uv_installed && !app.is_busy() =>
@@ -680,15 +1153,71 @@ where
                 // Show a confirmation dialog before uninstalling.
                 app.confirm_dialog = Some(ConfirmDialog::new(ConfirmAction::UninstallUv));
             }
+            KeyCode::Char('p')
+                if app.tab == tabs::Tab::UvInfo && app.uv_installed && !app.is_busy() =>
+            {
+                // Pruning only drops entries uv no longer needs, so no confirmation.
+                spawn_uv_task(app, "Pruning UV cache", |ctx| async move {
+                    uvcache::prune(&ctx)
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| e.to_string())
+                });
+            }
 
-            // Venv management – only active on the Environments tab and when not busy.
-            KeyCode::Char('n') if app.tab == tabs::Tab::Environments && !app.is_busy() => {
-                app.create_dialog = Some(CreateDialog::new(DEFAULT_PYTHON_VERSION));
+            // Python management – only active on the Pythons tab.
+            KeyCode::Char('i') if app.tab == tabs::Tab::Pythons => {
+                if let Some(install) = app.selected_python().filter(|p| !p.is_installed()) {
+                    let key = install.key.clone();
+                    let label = format!("Installing Python {}", install.version);
+                    spawn_venv_task(app, label, move |ctx| {
+                        let key = key.clone();
+                        async move { uvpython::install(&ctx, &key).await }
+                    });
+                }
+            }
+            KeyCode::Char('d') if app.tab == tabs::Tab::Pythons => {
+                if let Some(install) = app.selected_python().filter(|p| p.is_installed()) {
+                    let action = ConfirmAction::UninstallPython {
+                        key: install.key.clone(),
+                        version: install.version.clone(),
+                        used_by: app.python_users(install).to_vec(),
+                    };
+                    app.confirm_dialog = Some(ConfirmDialog::new(action));
+                }
+            }
+
+            // Tool management – only active on the Tools tab.
+            KeyCode::Char('u') if app.tab == tabs::Tab::Tools => {
+                if let Some(tool) = app.selected_tool() {
+                    let names = vec![tool.name.clone()];
+                    let label = format!("Upgrading tool '{}'", tool.name);
+                    spawn_venv_task(app, label, move |ctx| {
+                        let names = names.clone();
+                        async move { uvtool::upgrade(&ctx, &names, false).await }
+                    });
+                }
+            }
+            KeyCode::Char('U') if app.tab == tabs::Tab::Tools && !app.tools.is_empty() => {
+                spawn_venv_task(app, "Upgrading all tools".to_string(), |ctx| async move {
+                    uvtool::upgrade(&ctx, &[], true).await
+                });
+            }
+            KeyCode::Char('d') if app.tab == tabs::Tab::Tools => {
+                if let Some(tool) = app.selected_tool() {
+                    let action = ConfirmAction::UninstallTool(tool.name.clone());
+                    app.confirm_dialog = Some(ConfirmDialog::new(action));
+                }
+            }
+
+            // Venv management – only active on the Environments tab.
+            KeyCode::Char('n') if app.tab == tabs::Tab::Environments => {
+                let mut dialog = CreateDialog::new(DEFAULT_PYTHON_VERSION);
+                dialog.set_pythons(&app.pythons);
+                app.create_dialog = Some(dialog);
             }
             KeyCode::Char('d')
-                if app.tab == tabs::Tab::Environments
-                    && !app.venvs.is_empty()
-                    && !app.is_busy() =>
+                if app.tab == tabs::Tab::Environments && app.selected_venv().is_some() =>
             {
                 let name = app.venvs[app.selected].name.to_string();
                 // Show a confirmation dialog before deleting.
@@ -699,202 +1228,359 @@ where
             }
             KeyCode::Enter
                 if app.tab == tabs::Tab::Environments
-                    && !app.venvs.is_empty()
+                    && app.selected_venv().is_some()
                     && !app.is_busy() =>
             {
-                // Activate still exits the TUI (exec on Unix).
+                // Activate still exits the TUI (exec on Unix), which would end
+                // the running tasks.
                 app.pending_venv_action = Some(VenvAction::Activate);
                 break;
             }
-            // Package list scrolling – active when a venv is selected.
-            KeyCode::Char('j') if app.tab == tabs::Tab::Environments && !app.venvs.is_empty() => {
-                let total = app.venvs[app.selected].installed_packages.len();
-                app.scroll_pkg_down(total);
+            // Package list scrolling – active when a venv is selected.  In tree
+            // mode j/k move the highlighted tree row instead.
+            KeyCode::Char('j')
+                if app.tab == tabs::Tab::Environments && app.selected_venv().is_some() =>
+            {
+                if let Some(tree) = app.pkg_tree.as_mut() {
+                    tree.next();
+                } else {
+                    let total = app.venvs[app.selected].installed_packages.len();
+                    app.scroll_pkg_down(total);
+                }
+            }
+            KeyCode::Char('k')
+                if app.tab == tabs::Tab::Environments && app.selected_venv().is_some() =>
+            {
+                if let Some(tree) = app.pkg_tree.as_mut() {
+                    tree.prev();
+                } else {
+                    app.scroll_pkg_up();
+                }
+            }
+            // Dependency tree – toggle the tree view and fold/unfold nodes.
+            KeyCode::Char('t')
+                if app.tab == tabs::Tab::Environments && app.selected_venv().is_some() =>
+            {
+                if app.pkg_tree.is_some() {
+                    app.pkg_tree = None;
+                } else {
+                    load_pkg_tree(app).await;
+                }
             }
-            KeyCode::Char('k') if app.tab == tabs::Tab::Environments && !app.venvs.is_empty() => {
-                app.scroll_pkg_up();
+            KeyCode::Char(' ') if app.tab == tabs::Tab::Environments => {
+                if let Some(tree) = app.pkg_tree.as_mut() {
+                    tree.toggle();
+                }
             }
-            // Add packages – active when a venv is selected and not busy.
+            // Add packages – active when a venv is selected.
             KeyCode::Char('i') | KeyCode::Char('a')
-                if app.tab == tabs::Tab::Environments
-                    && !app.venvs.is_empty()
-                    && !app.is_busy() =>
+                if app.tab == tabs::Tab::Environments && app.selected_venv().is_some() =>
             {
                 app.pkg_dialog = Some(PkgDialog::new(PkgDialogMode::Add));
             }
-            // Remove packages – active when a venv is selected and not busy.
+            // Remove packages – active when a venv is selected.
             KeyCode::Char('r')
+                if app.tab == tabs::Tab::Environments && app.selected_venv().is_some() =>
+            {
+                let mut dialog = PkgDialog::new(PkgDialogMode::Remove);
+                // Pre-fill the highlighted package.
+                if let Some(package) = app.highlighted_package() {
+                    dialog.input = package;
+                }
+                app.pkg_dialog = Some(dialog);
+            }
+            // Check for outdated packages – active when a venv is selected.
+            KeyCode::Char('o')
                 if app.tab == tabs::Tab::Environments
-                    && !app.venvs.is_empty()
-                    && !app.is_busy() =>
+                    && app.selected_venv().is_some()
+                    && !app.is_outdated_loading() =>
             {
-                app.pkg_dialog = Some(PkgDialog::new(PkgDialogMode::Remove));
+                spawn_outdated_task(app);
             }
-            // Search packages – active when a venv is selected.
-            KeyCode::Char('/') if app.tab == tabs::Tab::Environments && !app.venvs.is_empty() => {
-                app.pkg_search = Some(String::new());
+            // Sort the environment list by size, name, Python version, package
+            // count or last use.
+            KeyCode::Char('s') if app.tab == tabs::Tab::Environments && !app.venvs.is_empty() => {
+                app.toggle_venv_sort();
                 app.pkg_scroll = 0;
-            }
-            _ => {}
-        }
-    }
-    Ok(())
-}
-
-async fn get_uv_version() -> Option<String> {
-    use pylot_shared::infra::processes;
-    // Use `uv --version` (the flag form) – `uv version` is a project-version
-    // subcommand that exits non-zero outside a project.
-    let child = processes::create_child_cmd("uv", &["--version"], "").ok()?;
-    let output = child.wait_with_output().await.ok()?;
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if !stdout.is_empty() {
-            return Some(stdout);
-        }
-        // Fallback: some older builds wrote to stderr.
-        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        if !stderr.is_empty() {
-            Some(stderr)
-        } else {
-            None
-        }
-    } else {
-        None
-    }
-}
-
-/// Run `uv self update --dry-run` and parse its output to determine the latest
-/// available version of UV.
-///
-/// Two output shapes are handled:
-/// * Update available:  `Would update uv from v0.11.2 to v0.11.3`
-/// * Already current:   `success: You're already on version v0.11.3 of uv (the latest version).`
-///
-/// Returns `Some(version_string)` (e.g. `"0.11.3"`) in both cases, or `None`
-/// on any error.
-async fn get_latest_uv_version() -> Option<String> {
-    use pylot_shared::infra::processes;
-    let child = processes::create_child_cmd("uv", &["self", "update", "--dry-run"], "").ok()?;
-    let output = child.wait_with_output().await.ok()?;
-    // UV writes its dry-run output to stderr.
-    let text = String::from_utf8_lossy(&output.stderr);
-    for line in text.lines() {
-        // Update available: "Would update uv from v0.11.2 to v0.11.3"
-        if let Some(rest) = line.strip_prefix("Would update uv from ") {
-            // rest = "v0.11.2 to v0.11.3"
-            if let Some(version_part) = rest.split(" to ").nth(1) {
-                let version = version_part.trim().trim_start_matches('v').to_string();
-                if !version.is_empty() {
-                    return Some(version);
+                if app.pkg_tree.is_some() {
+                    load_pkg_tree(app).await;
                 }
             }
-        }
-        // Already on latest: "success: You're already on version v0.11.3 of uv (the latest version)."
-        if let Some(rest) = line.strip_prefix("success: You're already on version v") {
-            // rest = "0.11.3 of uv (the latest version)."
-            if let Some(version) = rest.split_whitespace().next() {
-                let version = version.trim_end_matches('.').to_string();
-                if !version.is_empty() {
-                    return Some(version);
+            // Task management – retry or cancel the highlighted task.
+            KeyCode::Char('r') if app.tab == tabs::Tab::Tasks => {
+                if let Some(id) = app.selected_task().filter(|t| t.can_retry()).map(|t| t.id) {
+                    app.tasks.retry(id);
+                    app.tasks.start(&app.ctx);
                 }
             }
+            KeyCode::Char('c') if app.tab == tabs::Tab::Tasks => open_cancel_dialog(app),
+            // Search packages – active when a venv is selected.  The search
+            // also points at other venvs with a match.
+            KeyCode::Char('f')
+                if app.tab == tabs::Tab::Environments && app.selected_venv().is_some() =>
+            {
+                app.pkg_search = Some(String::new());
+                app.pkg_scroll = 0;
+                app.pkg_tree = None;
+            }
+            // Fuzzy search environments by name, or filter them by Python
+            // version or package.
+            KeyCode::Char('/') if app.tab == tabs::Tab::Environments && !app.venvs.is_empty() => {
+                app.venv_search.get_or_insert_with(String::new);
+                app.venv_search_input = true;
+            }
+            _ => {}
         }
     }
-    None
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::app::App;
+    use pylot_shared::venvquery::VenvSort;
 
     fn make_empty_app<'a>() -> App<'a> {
-        App::new(vec![], true, Some("uv 0.5.0".to_string()))
+        App::new(vec![], true, None)
     }
 
-    // ── get_uv_version ───────────────────────────────────────────────────────
+    // ── spawn_uv_task ────────────────────────────────────────────────────────
 
     #[tokio::test]
-    async fn test_get_uv_version_returns_some_or_none() {
-        // We don't know whether `uv` is installed in the test environment, so
-        // just verify that the function returns without panicking.
-        let _version = get_uv_version().await;
+    async fn test_spawn_uv_task_sets_busy() {
+        let mut app = make_empty_app();
+        assert!(!app.is_busy());
+        spawn_uv_task(&mut app, "Test task", |_| async { Ok(()) });
+        assert!(app.is_busy());
+        assert_eq!(
+            app.tasks.current().map(|t| t.label.as_str()),
+            Some("Test task")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spawn_uv_info_task_uses_app_runner() {
+        use pylot_shared::processes::{CommandOutput, RecordingRunner};
+
+        let runner = Arc::new(
+            RecordingRunner::new()
+                .with_program("uv", "/opt/uv/bin/uv")
+                .respond("--version", CommandOutput::ok("uv 0.5.11 (c4d0caaee 2024-12-19)")),
+        );
+        let mut app = make_empty_app();
+        app.ctx = Arc::new(Pylot::default().with_runner(runner.clone()));
+        spawn_uv_info_task(&mut app);
+        let (info, _) = app.uv_info_rx.take().unwrap().await.unwrap();
+        let info = info.unwrap();
+        assert_eq!(info.version.to_string(), "0.5.11");
+        assert_eq!(info.path, std::path::PathBuf::from("/opt/uv/bin/uv"));
+        assert_eq!(
+            runner.command_lines()[..2],
+            ["uv --version", "uv self update --dry-run"]
+        );
     }
 
-    // ── get_latest_uv_version ────────────────────────────────────────────────
+    // ── spawn_venv_task ──────────────────────────────────────────────────────
 
     #[tokio::test]
-    async fn test_get_latest_uv_version_returns_some_or_none() {
-        // uv may or may not be installed and may or may not have an update
-        // available; the function must not panic in any case.
-        let _latest = get_latest_uv_version().await;
+    async fn test_spawn_outdated_task_sets_loading() {
+        let venvs = vec![UvVenv::new(
+            &Pylot::default(),
+            Cow::Owned("env1".to_string()),
+            "/nonexistent/env1".to_string(),
+            "3.12".to_string(),
+            vec![],
+            false,
+        )];
+        let mut app = App::new(venvs, true, None);
+        spawn_outdated_task(&mut app);
+        assert!(app.is_outdated_loading());
     }
 
-    #[test]
-    fn test_parse_dry_run_update_available() {
-        // Simulate parsing the "Would update" line directly.
-        let line = "Would update uv from v0.11.2 to v0.11.3";
-        let result: Option<String> = line
-            .strip_prefix("Would update uv from ")
-            .and_then(|rest| rest.split(" to ").nth(1))
-            .map(|v| v.trim().trim_start_matches('v').to_string())
-            .filter(|v| !v.is_empty());
-        assert_eq!(result, Some("0.11.3".to_string()));
+    #[tokio::test]
+    async fn test_spawn_venv_task_sets_busy() {
+        let mut app = make_empty_app();
+        assert!(!app.is_busy());
+        spawn_venv_task(&mut app, "Venv task".to_string(), |_| async {
+            Ok::<(), pylot_shared::error::PylotError>(())
+        });
+        assert!(app.is_busy());
+        assert_eq!(
+            app.tasks.current().map(|t| t.label.as_str()),
+            Some("Venv task")
+        );
     }
 
-    #[test]
-    fn test_parse_dry_run_already_current() {
-        // "success: You're already on version v0.11.3 of uv (the latest version)."
-        // → should yield "0.11.3"
-        let line = "success: You're already on version v0.11.3 of uv (the latest version).";
-        let result: Option<String> = line
-            .strip_prefix("success: You're already on version v")
-            .and_then(|rest| rest.split_whitespace().next())
-            .map(|v| v.trim_end_matches('.').to_string())
-            .filter(|v| !v.is_empty());
-        assert_eq!(result, Some("0.11.3".to_string()));
+    #[tokio::test]
+    async fn test_spawn_venv_task_queues_beyond_limit() {
+        let mut app = make_empty_app();
+        let mut ctx = Pylot::default();
+        ctx.settings.max_parallel_tasks = 2;
+        app.ctx = Arc::new(ctx);
+        for i in 0..3 {
+            spawn_venv_task(&mut app, format!("Task {}", i), |_| {
+                futures::future::pending::<pylot_shared::error::Result<()>>()
+            });
+        }
+        assert_eq!(app.tasks.running(), 2);
+        assert_eq!(app.tasks.queued(), 1);
     }
 
-    #[test]
-    fn test_parse_dry_run_unrecognised_line() {
-        // An unrecognised line yields nothing (no panic).
-        let line = "info: Checking for updates...";
-        let from_update: Option<String> = line
-            .strip_prefix("Would update uv from ")
-            .and_then(|rest| rest.split(" to ").nth(1))
-            .map(|v| v.trim().trim_start_matches('v').to_string())
-            .filter(|v| !v.is_empty());
-        let from_success: Option<String> = line
-            .strip_prefix("success: You're already on version v")
-            .and_then(|rest| rest.split_whitespace().next())
-            .map(|v| v.trim_end_matches('.').to_string())
-            .filter(|v| !v.is_empty());
-        assert_eq!(from_update.or(from_success), None);
+    #[tokio::test]
+    async fn test_spawn_task_on_venv_waits_for_same_venv() {
+        let mut app = make_empty_app();
+        let create =
+            spawn_task_on_venv(&mut app, "Creating 'api'".to_string(), "api", true, |_| {
+                futures::future::pending()
+            });
+        let add = spawn_task_on_venv(
+            &mut app,
+            "Adding packages to 'api'".to_string(),
+            "api",
+            false,
+            |_| futures::future::pending(),
+        );
+        assert_eq!(app.tasks.get(create).unwrap().state, TaskState::Running);
+        assert_eq!(app.tasks.get(add).unwrap().state, TaskState::Queued);
+        assert!(app.tasks.is_creating("api"));
     }
 
-    // ── spawn_uv_task ────────────────────────────────────────────────────────
+    // ── cancel_task ──────────────────────────────────────────────────────────
 
     #[tokio::test]
-    async fn test_spawn_uv_task_sets_busy() {
+    async fn test_open_cancel_dialog_targets_current_task() {
         let mut app = make_empty_app();
-        assert!(!app.is_busy());
-        spawn_uv_task(&mut app, "Test task", async { Ok(()) });
-        assert!(app.is_busy());
-        assert_eq!(app.bg_task_name.as_deref(), Some("Test task"));
+        spawn_venv_task(&mut app, "Creating 'api'".to_string(), |_| {
+            futures::future::pending()
+        });
+        let id = app.tasks.current().unwrap().id;
+        open_cancel_dialog(&mut app);
+        assert_eq!(
+            app.confirm_dialog.take().map(|d| d.action),
+            Some(ConfirmAction::CancelTask {
+                id,
+                label: "Creating 'api'".to_string(),
+            })
+        );
+
+        cancel_task(&mut app, id);
+        assert!(app.tasks.get(id).unwrap().cancelling);
+        // A running task is reported once its cleanup is done.
+        assert!(app.status_message.is_none());
+        open_cancel_dialog(&mut app);
+        assert!(app.confirm_dialog.is_none());
     }
 
-    // ── spawn_venv_task ──────────────────────────────────────────────────────
+    #[tokio::test]
+    async fn test_cancel_before_quit() {
+        let mut app = make_empty_app();
+        spawn_venv_task(&mut app, "Creating 'api'".to_string(), |_| {
+            futures::future::pending()
+        });
+        let id = app.tasks.current().unwrap().id;
+        cancel_before_quit(&mut app);
+        assert!(matches!(
+            app.confirm_dialog.take().map(|d| d.action),
+            Some(ConfirmAction::CancelTask { .. })
+        ));
+
+        cancel_task(&mut app, id);
+        cancel_before_quit(&mut app);
+        assert!(app.confirm_dialog.is_none());
+        assert!(app
+            .status_message
+            .as_ref()
+            .is_some_and(|(msg, _, _)| msg.contains("Waiting for running tasks")));
+    }
 
     #[tokio::test]
-    async fn test_spawn_venv_task_sets_busy() {
+    async fn test_cancel_task_queued_reports_cancelled() {
         let mut app = make_empty_app();
-        assert!(!app.is_busy());
-        spawn_venv_task(&mut app, "Venv task".to_string(), async {
-            Ok::<(), pylot_shared::error::PylotError>(())
+        let mut ctx = Pylot::default();
+        ctx.settings.max_parallel_tasks = 1;
+        app.ctx = Arc::new(ctx);
+        spawn_venv_task(&mut app, "Running".to_string(), |_| {
+            futures::future::pending()
         });
-        assert!(app.is_busy());
-        assert_eq!(app.bg_task_name.as_deref(), Some("Venv task"));
+        let queued = spawn_venv_task(&mut app, "Waiting".to_string(), |_| {
+            futures::future::pending()
+        });
+
+        cancel_task(&mut app, queued);
+        assert_eq!(app.tasks.get(queued).unwrap().state, TaskState::Cancelled);
+        assert!(
+            matches!(app.status_message, Some((ref msg, false, _)) if msg == "Waiting cancelled.")
+        );
+    }
+
+    // ── refresh_venvs ────────────────────────────────────────────────────────
+
+    fn make_fake_venv(root: &Path, name: &str) {
+        let bin = root.join(name).join("bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        std::fs::write(bin.join("python"), "").unwrap();
+        std::fs::write(root.join(name).join("pyvenv.cfg"), "version = 3.12.1\n").unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refresh_venvs_keeps_selection() {
+        let tmp = tempfile::tempdir().unwrap();
+        make_fake_venv(tmp.path(), "api");
+        make_fake_venv(tmp.path(), "web");
+        let ctx = Arc::new(Pylot::default().with_venvs_path(tmp.path().to_str().unwrap()));
+        let mut app = App::new(ctx.manager().list().await, true, None);
+        app.ctx = ctx;
+        app.venv_sort = Some(VenvSort::Name);
+        app.sort_venvs();
+        app.selected = 1;
+        assert_eq!(app.venvs[1].name, "web");
+
+        make_fake_venv(tmp.path(), "data");
+        std::fs::remove_dir_all(tmp.path().join("api")).unwrap();
+        refresh_venvs(&mut app, &["api".to_string(), "data".to_string()]).await;
+
+        let names: Vec<_> = app.venvs.iter().map(|v| v.name.to_string()).collect();
+        assert_eq!(names, ["data", "web"]);
+        assert_eq!(app.selected, 1);
+        assert_eq!(app.pkg_scroll, 0);
+    }
+
+    /// Run the packages task of `app` to the end.
+    async fn load_packages_in_background(app: &mut App<'_>) {
+        spawn_packages_task(app);
+        while app.packages_rx.is_some() {
+            poll_packages_task(app);
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_packages_task_reads_selected_venv() {
+        let tmp = tempfile::tempdir().unwrap();
+        for name in ["api", "web"] {
+            make_fake_venv(tmp.path(), name);
+            let site = tmp.path().join(name).join("lib/python3.12/site-packages");
+            std::fs::create_dir_all(site.join("six-1.16.0.dist-info")).unwrap();
+        }
+        let ctx = Arc::new(Pylot::default().with_venvs_path(tmp.path().to_str().unwrap()));
+        let mut app = App::new(ctx.manager().list_with(ScanMode::Lazy).await, true, None);
+        app.ctx = ctx;
+        app.venv_sort = Some(VenvSort::Name);
+        app.sort_venvs();
+        assert!(app.venvs[0].installed_packages.is_empty());
+
+        load_packages_in_background(&mut app).await;
+        assert_eq!(app.venvs[0].installed_packages, ["six 1.16.0"]);
+        assert!(app.venvs[0].disk_usage.is_some());
+        // Nothing needs the other venv yet.
+        assert_eq!(app.venvs[1].package_count, None);
+
+        // Filtering by package reads them all.
+        app.venv_search = Some("has:six".to_string());
+        load_packages_in_background(&mut app).await;
+        assert_eq!(app.venvs[1].installed_packages, ["six 1.16.0"]);
+        assert_eq!(app.visible_venvs(), [0, 1]);
     }
 
     // ── pause_for_enter is a side-effectful helper; just verify it compiles.  ─
@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use pylot_shared::deptree::{self, DependencyGraph, TreeNode, TreeRow};
+
+/// Collapsible dependency tree shown in the packages pane instead of the flat
+/// package list.
+///
+/// Top-level packages start folded, so the initial view lists what was most
+/// likely installed explicitly.
+pub struct PkgTree {
+    /// Name of the venv the tree was built for.
+    pub venv_name: String,
+    graph: DependencyGraph,
+    nodes: Vec<TreeNode>,
+    collapsed: HashSet<String>,
+    /// Index of the highlighted row among the visible rows.
+    pub cursor: usize,
+}
+
+impl PkgTree {
+    pub fn new(venv_name: String, graph: DependencyGraph) -> Self {
+        let nodes = graph.tree();
+        let collapsed = nodes.iter().map(|node| node.name.clone()).collect();
+        PkgTree {
+            venv_name,
+            graph,
+            nodes,
+            collapsed,
+            cursor: 0,
+        }
+    }
+
+    /// Rows that are currently visible (children of folded nodes are hidden).
+    pub fn rows(&self) -> Vec<TreeRow> {
+        deptree::flatten(&self.nodes, &self.collapsed)
+    }
+
+    /// Number of installed packages in the tree.
+    pub fn package_count(&self) -> usize {
+        self.graph.len()
+    }
+
+    /// Move the highlight down by one row.
+    pub fn next(&mut self) {
+        if self.cursor + 1 < self.rows().len() {
+            self.cursor += 1;
+        }
+    }
+
+    /// Move the highlight up by one row.
+    pub fn prev(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Fold or unfold the highlighted node.
+    pub fn toggle(&mut self) {
+        let Some(row) = self.selected_row() else {
+            return;
+        };
+        if !row.has_children {
+            return;
+        }
+        if !self.collapsed.remove(&row.key) {
+            self.collapsed.insert(row.key);
+        }
+    }
+
+    /// The highlighted row, if any.
+    pub fn selected_row(&self) -> Option<TreeRow> {
+        self.rows().into_iter().nth(self.cursor)
+    }
+
+    /// Names of the installed packages that depend on the highlighted package.
+    pub fn required_by(&self) -> Vec<String> {
+        self.selected_row()
+            .map(|row| {
+                self.graph
+                    .dependents(&row.name)
+                    .into_iter()
+                    .map(|(dist, _)| dist.name.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pylot_shared::distinfo::{DistInfo, Requirement};
+
+    fn dist(name: &str, requires: &[&str]) -> DistInfo {
+        DistInfo {
+            name: name.to_string(),
+            version: "1.0".to_string(),
+            requires: requires
+                .iter()
+                .filter_map(|r| Requirement::parse(r))
+                .collect(),
+            path: Default::default(),
+        }
+    }
+
+    fn make_tree() -> PkgTree {
+        let graph = DependencyGraph::new(vec![
+            dist("flask", &["werkzeug", "jinja2"]),
+            dist("jinja2", &["markupsafe"]),
+            dist("markupsafe", &[]),
+            dist("werkzeug", &["markupsafe"]),
+            dist("ruff", &[]),
+        ]);
+        PkgTree::new("env1".to_string(), graph)
+    }
+
+    #[test]
+    fn test_new_starts_with_roots_folded() {
+        let tree = make_tree();
+        let rows = tree.rows();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].name, "flask");
+        assert!(rows[0].collapsed);
+        assert_eq!(rows[1].name, "ruff");
+        assert!(!rows[1].collapsed);
+        assert_eq!(tree.package_count(), 5);
+    }
+
+    #[test]
+    fn test_toggle_unfolds_and_folds() {
+        let mut tree = make_tree();
+        tree.toggle();
+        assert_eq!(tree.rows().len(), 6);
+        tree.toggle();
+        assert_eq!(tree.rows().len(), 2);
+    }
+
+    #[test]
+    fn test_toggle_leaf_is_noop() {
+        let mut tree = make_tree();
+        tree.next();
+        tree.toggle();
+        assert_eq!(tree.rows().len(), 2);
+    }
+
+    #[test]
+    fn test_cursor_bounds() {
+        let mut tree = make_tree();
+        tree.prev();
+        assert_eq!(tree.cursor, 0);
+        tree.next();
+        tree.next();
+        assert_eq!(tree.cursor, 1);
+    }
+
+    #[test]
+    fn test_required_by() {
+        let mut tree = make_tree();
+        tree.toggle();
+        // flask, jinja2, markupsafe, werkzeug, markupsafe, ruff
+        tree.next();
+        tree.next();
+        assert_eq!(tree.selected_row().unwrap().name, "markupsafe");
+        assert_eq!(tree.required_by(), vec!["jinja2", "werkzeug"]);
+        tree.prev();
+        tree.prev();
+        assert!(tree.required_by().is_empty());
+    }
+}
@@ -5,15 +5,27 @@ use crate::dialogs::HelpMode;
 pub enum Tab {
     Environments,
     UvInfo,
+    Pythons,
+    Tools,
+    Tasks,
 }
 
 impl Tab {
-    pub const ALL: &'static [Tab] = &[Tab::Environments, Tab::UvInfo];
+    pub const ALL: &'static [Tab] = &[
+        Tab::Environments,
+        Tab::UvInfo,
+        Tab::Pythons,
+        Tab::Tools,
+        Tab::Tasks,
+    ];
 
     pub fn title(self) -> &'static str {
         match self {
             Tab::Environments => "Environments",
             Tab::UvInfo => "UV Info",
+            Tab::Pythons => "Pythons",
+            Tab::Tools => "Tools",
+            Tab::Tasks => "Tasks",
         }
     }
 
@@ -21,6 +33,9 @@ impl Tab {
         match self {
             Tab::Environments => HelpMode::EnvHelp,
             Tab::UvInfo => HelpMode::UvHelp,
+            Tab::Pythons => HelpMode::PythonHelp,
+            Tab::Tools => HelpMode::ToolHelp,
+            Tab::Tasks => HelpMode::TaskHelp,
         }
     }
 }
@@ -33,16 +48,22 @@ mod tests {
     fn test_tab_titles() {
         assert_eq!(Tab::Environments.title(), "Environments");
         assert_eq!(Tab::UvInfo.title(), "UV Info");
+        assert_eq!(Tab::Pythons.title(), "Pythons");
+        assert_eq!(Tab::Tools.title(), "Tools");
+        assert_eq!(Tab::Tasks.title(), "Tasks");
     }
 
     #[test]
     fn test_all_tabs() {
-        assert_eq!(Tab::ALL.len(), 2);
+        assert_eq!(Tab::ALL.len(), 5);
     }
 
     #[test]
     fn test_help_modes() {
         assert_eq!(Tab::Environments.help_mode(), HelpMode::EnvHelp);
         assert_eq!(Tab::UvInfo.help_mode(), HelpMode::UvHelp);
+        assert_eq!(Tab::Pythons.help_mode(), HelpMode::PythonHelp);
+        assert_eq!(Tab::Tools.help_mode(), HelpMode::ToolHelp);
+        assert_eq!(Tab::Tasks.help_mode(), HelpMode::TaskHelp);
     }
 }
@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+
+use pylot_shared::progress::ProgressEvent;
+
+/// Number of output lines kept for the log tail.
+pub const LOG_TAIL_LINES: usize = 200;
+
+/// What the running background task reported so far, shown in the progress
+/// panel below the tab content.
+#[derive(Debug, Default)]
+pub struct TaskProgress {
+    /// The step the task is on, e.g. `"Creating virtual environment 'api'"`.
+    pub step: Option<String>,
+    /// What uv is doing right now, e.g. `"Resolving"`.
+    pub phase: Option<String>,
+    /// Packages uv downloaded or installed, in the order it mentioned them.
+    pub packages: Vec<PackageProgress>,
+    /// The last [`LOG_TAIL_LINES`] lines of output.
+    pub log: VecDeque<String>,
+    /// Number of warnings uv printed.
+    pub warnings: usize,
+}
+
+/// A package the running task is working on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageProgress {
+    pub name: String,
+    pub state: PackageState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageState {
+    /// Being downloaded, with its size when uv printed it.
+    Downloading(Option<u64>),
+    Installed,
+}
+
+impl TaskProgress {
+    /// Record `event`.
+    pub fn apply(&mut self, event: ProgressEvent) {
+        match event {
+            ProgressEvent::Step(step) => {
+                self.step = Some(step);
+                self.phase = None;
+            }
+            ProgressEvent::Output { line, .. } => {
+                if self.log.len() == LOG_TAIL_LINES {
+                    self.log.pop_front();
+                }
+                self.log.push_back(line);
+            }
+            ProgressEvent::Resolving => self.phase = Some("Resolving".to_string()),
+            ProgressEvent::Downloading { package, bytes } => {
+                self.phase = Some(format!("Downloading {}", package));
+                self.set_state(package, PackageState::Downloading(bytes));
+            }
+            ProgressEvent::Installing { package } => {
+                self.phase = Some("Installing".to_string());
+                self.set_state(package, PackageState::Installed);
+            }
+            ProgressEvent::Done => self.phase = None,
+            ProgressEvent::Warning(_) => self.warnings += 1,
+            _ => {}
+        }
+    }
+
+    fn set_state(&mut self, name: String, state: PackageState) {
+        match self.packages.iter_mut().find(|p| p.name == name) {
+            Some(package) => package.state = state,
+            None => self.packages.push(PackageProgress { name, state }),
+        }
+    }
+
+    /// Number of packages installed so far.
+    pub fn installed(&self) -> usize {
+        self.packages
+            .iter()
+            .filter(|p| p.state == PackageState::Installed)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pylot_shared::processes::Stream;
+
+    use super::*;
+
+    fn output(line: &str) -> ProgressEvent {
+        ProgressEvent::Output {
+            stream: Stream::Stderr,
+            line: line.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_tracks_packages() {
+        let mut progress = TaskProgress::default();
+        progress.apply(ProgressEvent::Step("Adding packages to 'api'".to_string()));
+        progress.apply(ProgressEvent::Resolving);
+        assert_eq!(progress.phase.as_deref(), Some("Resolving"));
+        progress.apply(ProgressEvent::Downloading {
+            package: "numpy".to_string(),
+            bytes: Some(1024),
+        });
+        progress.apply(ProgressEvent::Installing {
+            package: "six".to_string(),
+        });
+        progress.apply(ProgressEvent::Installing {
+            package: "numpy".to_string(),
+        });
+        progress.apply(ProgressEvent::Warning("cache is stale".to_string()));
+        progress.apply(ProgressEvent::Done);
+
+        assert_eq!(progress.step.as_deref(), Some("Adding packages to 'api'"));
+        assert_eq!(progress.phase, None);
+        assert_eq!(progress.packages.len(), 2);
+        assert_eq!(progress.packages[0].name, "numpy");
+        assert_eq!(progress.installed(), 2);
+        assert_eq!(progress.warnings, 1);
+    }
+
+    #[test]
+    fn test_log_tail_is_bounded() {
+        let mut progress = TaskProgress::default();
+        for i in 0..LOG_TAIL_LINES + 5 {
+            progress.apply(output(&format!("line {}", i)));
+        }
+        assert_eq!(progress.log.len(), LOG_TAIL_LINES);
+        assert_eq!(progress.log.front().map(String::as_str), Some("line 5"));
+    }
+}
@@ -0,0 +1,554 @@
+use std::io;
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+
+use futures::future::BoxFuture;
+use pylot_shared::progress::ProgressEvent;
+use pylot_shared::venvtraits::Delete;
+use pylot_shared::Pylot;
+use tokio::sync::oneshot;
+
+use crate::task_progress::TaskProgress;
+
+/// Identifies a task in the [`TaskQueue`] for as long as the TUI runs.
+pub type TaskId = usize;
+
+/// What a background job reports when it ends.
+pub type TaskResult = Result<(), String>;
+
+/// Builds the future of a background job.  Called again when a failed task is
+/// retried, so it must not consume what it captures.
+pub type Job = Arc<dyn Fn(Arc<Pylot>) -> BoxFuture<'static, TaskResult> + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskState {
+    /// Waiting for a free slot.
+    Queued,
+    Running,
+    Succeeded,
+    Failed(String),
+    Cancelled,
+}
+
+/// A job started from the TUI and what it reported so far.
+pub struct Task {
+    pub id: TaskId,
+    /// Human-readable label, e.g. `"Creating 'api'"`.
+    pub label: String,
+    pub state: TaskState,
+    /// Progress events and captured output of the current attempt.
+    pub progress: TaskProgress,
+    pub started_at: Option<Instant>,
+    pub finished_at: Option<Instant>,
+    /// Venv the task works on.  Tasks on the same venv run one at a time.
+    pub venv: Option<String>,
+    /// The task creates [`Task::venv`].
+    pub creates_venv: bool,
+    /// Venv the running attempt is creating, removed again if it is
+    /// cancelled.  Decided when the attempt starts: only a venv that did not
+    /// exist then belongs to it.
+    pub partial_venv: Option<String>,
+    /// Set once the running task was cancelled; it is cleaning up.
+    pub cancelling: bool,
+    job: Job,
+    handle: Option<tokio::task::JoinHandle<()>>,
+    rx: Option<oneshot::Receiver<TaskResult>>,
+    progress_rx: Option<mpsc::Receiver<ProgressEvent>>,
+}
+
+impl Task {
+    /// Queued or running.
+    pub fn is_active(&self) -> bool {
+        matches!(self.state, TaskState::Queued | TaskState::Running)
+    }
+
+    /// Failed and cancelled tasks can be run again.
+    pub fn can_retry(&self) -> bool {
+        matches!(self.state, TaskState::Failed(_) | TaskState::Cancelled)
+    }
+
+    /// How long the task ran, or has been running; `None` while queued.
+    pub fn duration(&self) -> Option<Duration> {
+        let started = self.started_at?;
+        Some(
+            self.finished_at
+                .map_or_else(|| started.elapsed(), |end| end - started),
+        )
+    }
+}
+
+/// Background jobs started from the TUI, run in submission order with at most
+/// [`Settings::max_parallel_tasks`](pylot_shared::settings::Settings::max_parallel_tasks)
+/// of them at once.  Finished tasks stay listed, with their output, until the
+/// TUI exits.
+#[derive(Default)]
+pub struct TaskQueue {
+    pub tasks: Vec<Task>,
+    next_id: TaskId,
+}
+
+impl TaskQueue {
+    /// Queue `job` under `label`.  It starts on the next [`TaskQueue::start`].
+    pub fn push(&mut self, label: String, job: Job) -> TaskId {
+        self.push_on_venv(label, None, false, job)
+    }
+
+    /// Queue `job` under `label` as working on `venv`, which it creates if
+    /// `creates` is set.  It starts once no other task on `venv` is running.
+    pub fn push_on_venv(
+        &mut self,
+        label: String,
+        venv: Option<String>,
+        creates: bool,
+        job: Job,
+    ) -> TaskId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.tasks.push(Task {
+            id,
+            label,
+            state: TaskState::Queued,
+            progress: TaskProgress::default(),
+            started_at: None,
+            finished_at: None,
+            venv,
+            creates_venv: creates,
+            partial_venv: None,
+            cancelling: false,
+            job,
+            handle: None,
+            rx: None,
+            progress_rx: None,
+        });
+        id
+    }
+
+    pub fn get(&self, id: TaskId) -> Option<&Task> {
+        self.tasks.iter().find(|t| t.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: TaskId) -> Option<&mut Task> {
+        self.tasks.iter_mut().find(|t| t.id == id)
+    }
+
+    /// Returns `true` while any task is queued or running.
+    pub fn is_active(&self) -> bool {
+        self.tasks.iter().any(Task::is_active)
+    }
+
+    pub fn running(&self) -> usize {
+        self.count(|state| *state == TaskState::Running)
+    }
+
+    pub fn queued(&self) -> usize {
+        self.count(|state| *state == TaskState::Queued)
+    }
+
+    fn count(&self, f: impl Fn(&TaskState) -> bool) -> usize {
+        self.tasks.iter().filter(|t| f(&t.state)).count()
+    }
+
+    /// Returns `true` while a queued or running task creates `venv`.
+    pub fn is_creating(&self, venv: &str) -> bool {
+        self.tasks
+            .iter()
+            .any(|t| t.is_active() && t.creates_venv && t.venv.as_deref() == Some(venv))
+    }
+
+    /// Returns `true` while a task on `venv` is running.
+    fn is_running_on(&self, venv: &str) -> bool {
+        self.tasks
+            .iter()
+            .any(|t| t.state == TaskState::Running && t.venv.as_deref() == Some(venv))
+    }
+
+    /// The most recently started task that is still running, shown in the
+    /// progress panel and the status bar.
+    pub fn current(&self) -> Option<&Task> {
+        self.tasks
+            .iter()
+            .filter(|t| t.state == TaskState::Running)
+            .max_by_key(|t| t.started_at)
+    }
+
+    /// Start queued tasks, oldest first, until the limit set in `ctx` is
+    /// reached.  A task whose venv another running task works on waits for
+    /// it.  Each task reports its progress through its own copy of `ctx`.
+    pub fn start(&mut self, ctx: &Arc<Pylot>) {
+        let limit = ctx.settings.max_parallel_tasks.max(1);
+        let mut running = self.running();
+        for i in 0..self.tasks.len() {
+            if running >= limit {
+                break;
+            }
+            if self.tasks[i].state != TaskState::Queued {
+                continue;
+            }
+            if let Some(venv) = &self.tasks[i].venv {
+                if self.is_running_on(venv) {
+                    continue;
+                }
+            }
+            let task = &mut self.tasks[i];
+            // Nothing else runs on the venv now, so whether it exists is
+            // down to this attempt from here on.
+            task.partial_venv = task
+                .venv
+                .clone()
+                .filter(|name| task.creates_venv && !ctx.venvs_path().join(name).exists());
+            let (task_ctx, progress_rx) = ctx.as_ref().clone().with_progress_channel();
+            let fut = (task.job)(Arc::new(task_ctx));
+            let (tx, rx) = oneshot::channel();
+            task.handle = Some(tokio::spawn(async move {
+                let _ = tx.send(fut.await);
+            }));
+            task.rx = Some(rx);
+            task.progress_rx = Some(progress_rx);
+            task.state = TaskState::Running;
+            task.started_at = Some(Instant::now());
+            running += 1;
+        }
+    }
+
+    /// Apply the progress events running tasks sent since the last call and
+    /// return the ids of the tasks that ended.
+    pub fn poll(&mut self) -> Vec<TaskId> {
+        let mut finished = Vec::new();
+        for task in &mut self.tasks {
+            if let Some(rx) = &task.progress_rx {
+                for event in rx.try_iter() {
+                    task.progress.apply(event);
+                }
+            }
+            let Some(rx) = task.rx.as_mut() else {
+                continue;
+            };
+            task.state = match rx.try_recv() {
+                Ok(Ok(())) if task.cancelling => TaskState::Cancelled,
+                Ok(Ok(())) => TaskState::Succeeded,
+                Ok(Err(e)) => TaskState::Failed(e),
+                Err(oneshot::error::TryRecvError::Empty) => continue, // still running
+                Err(oneshot::error::TryRecvError::Closed) => {
+                    TaskState::Failed("The task stopped unexpectedly".to_string())
+                }
+            };
+            task.rx = None;
+            task.handle = None;
+            task.progress_rx = None;
+            task.cancelling = false;
+            task.progress.phase = None;
+            task.finished_at = Some(Instant::now());
+            finished.push(task.id);
+        }
+        finished
+    }
+
+    /// Cancel task `id`.  A queued task is marked cancelled and never starts.
+    /// A running one is aborted, which kills the commands it runs, then
+    /// the venv it was creating is removed; it ends as cancelled once that
+    /// cleanup is done.  Returns `false` when there is nothing to cancel.
+    pub fn cancel(&mut self, id: TaskId, ctx: &Arc<Pylot>) -> bool {
+        let Some(task) = self.get_mut(id) else {
+            return false;
+        };
+        match task.state {
+            TaskState::Queued => {
+                task.state = TaskState::Cancelled;
+                true
+            }
+            TaskState::Running if !task.cancelling => {
+                let Some(handle) = task.handle.take() else {
+                    return false;
+                };
+                handle.abort();
+                let partial_venv = task.partial_venv.clone();
+                let ctx = ctx.clone();
+                let (tx, rx) = oneshot::channel();
+                task.handle = Some(tokio::spawn(async move {
+                    // Wait until the aborted task has dropped, and so killed, its commands.
+                    let _ = handle.await;
+                    let result = match partial_venv {
+                        Some(name) if ctx.manager().check_if_exists(&name).await => ctx
+                            .venv(name)
+                            .delete(io::empty(), false)
+                            .await
+                            .map_err(|e| e.to_string()),
+                        _ => Ok(()),
+                    };
+                    let _ = tx.send(result);
+                }));
+                task.rx = Some(rx);
+                task.cancelling = true;
+                task.progress.phase = Some("Cancelling".to_string());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Queue a failed or cancelled task again, dropping what its last attempt
+    /// reported.  Returns `false` when the task cannot be retried, including
+    /// a create of a venv another task is creating.
+    pub fn retry(&mut self, id: TaskId) -> bool {
+        let Some(task) = self.get(id).filter(|t| t.can_retry()) else {
+            return false;
+        };
+        if task.creates_venv && task.venv.as_deref().is_some_and(|v| self.is_creating(v)) {
+            return false;
+        }
+        let Some(task) = self.get_mut(id) else {
+            return false;
+        };
+        task.state = TaskState::Queued;
+        task.progress = TaskProgress::default();
+        task.started_at = None;
+        task.finished_at = None;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use super::*;
+
+    fn job(result: TaskResult) -> Job {
+        Arc::new(move |_| futures::future::ready(result.clone()).boxed())
+    }
+
+    fn pending_job() -> Job {
+        Arc::new(|_| futures::future::pending().boxed())
+    }
+
+    fn limited(limit: usize) -> Arc<Pylot> {
+        let mut ctx = Pylot::default();
+        ctx.settings.max_parallel_tasks = limit;
+        Arc::new(ctx)
+    }
+
+    async fn wait_for(queue: &mut TaskQueue, id: TaskId) {
+        while queue.get(id).unwrap().is_active() {
+            queue.poll();
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_respects_limit() {
+        let ctx = limited(2);
+        let mut queue = TaskQueue::default();
+        let ids: Vec<TaskId> = (0..3)
+            .map(|i| queue.push(format!("Task {}", i), pending_job()))
+            .collect();
+        queue.start(&ctx);
+        assert_eq!(queue.running(), 2);
+        assert_eq!(queue.queued(), 1);
+        assert_eq!(queue.get(ids[2]).unwrap().state, TaskState::Queued);
+        assert!(queue.get(ids[2]).unwrap().duration().is_none());
+        assert_eq!(queue.current().map(|t| t.id), Some(ids[1]));
+
+        // A queued task takes the slot a cancelled one frees.
+        assert!(queue.cancel(ids[0], &ctx));
+        wait_for(&mut queue, ids[0]).await;
+        queue.start(&ctx);
+        assert_eq!(queue.get(ids[2]).unwrap().state, TaskState::Running);
+    }
+
+    #[tokio::test]
+    async fn test_poll_records_outcome_and_progress() {
+        let ctx = limited(4);
+        let mut queue = TaskQueue::default();
+        let ok = queue.push("Ok".to_string(), job(Ok(())));
+        let failed = queue.push("Broken".to_string(), job(Err("boom".to_string())));
+        let reporting = queue.push(
+            "Reporting".to_string(),
+            Arc::new(|ctx: Arc<Pylot>| {
+                async move {
+                    ctx.report(ProgressEvent::Step("Creating 'api'".to_string()));
+                    Ok(())
+                }
+                .boxed()
+            }),
+        );
+        queue.start(&ctx);
+        for id in [ok, failed, reporting] {
+            wait_for(&mut queue, id).await;
+        }
+
+        assert_eq!(queue.get(ok).unwrap().state, TaskState::Succeeded);
+        assert_eq!(
+            queue.get(failed).unwrap().state,
+            TaskState::Failed("boom".to_string())
+        );
+        assert!(queue.get(ok).unwrap().duration().is_some());
+        assert_eq!(
+            queue.get(reporting).unwrap().progress.step.as_deref(),
+            Some("Creating 'api'")
+        );
+        assert!(!queue.is_active());
+    }
+
+    #[tokio::test]
+    async fn test_retry_runs_failed_task_again() {
+        let ctx = limited(1);
+        let attempts = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = attempts.clone();
+        let mut queue = TaskQueue::default();
+        let id = queue.push(
+            "Flaky".to_string(),
+            Arc::new(move |_| {
+                let attempt = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                futures::future::ready(if attempt == 0 {
+                    Err("network down".to_string())
+                } else {
+                    Ok(())
+                })
+                .boxed()
+            }),
+        );
+        assert!(!queue.retry(id));
+        queue.start(&ctx);
+        wait_for(&mut queue, id).await;
+        assert!(queue.get(id).unwrap().can_retry());
+
+        assert!(queue.retry(id));
+        assert_eq!(queue.get(id).unwrap().state, TaskState::Queued);
+        queue.start(&ctx);
+        wait_for(&mut queue, id).await;
+        assert_eq!(queue.get(id).unwrap().state, TaskState::Succeeded);
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_queued_task() {
+        let ctx = limited(1);
+        let mut queue = TaskQueue::default();
+        queue.push("Running".to_string(), pending_job());
+        let queued = queue.push("Waiting".to_string(), pending_job());
+        queue.start(&ctx);
+        assert!(queue.cancel(queued, &ctx));
+        assert_eq!(queue.get(queued).unwrap().state, TaskState::Cancelled);
+        assert!(!queue.cancel(queued, &ctx));
+    }
+
+    fn venvs_in(root: &tempfile::TempDir) -> Arc<Pylot> {
+        Arc::new(Pylot::default().with_venvs_path(root.path().to_str().unwrap()))
+    }
+
+    fn create_api(queue: &mut TaskQueue) -> TaskId {
+        queue.push_on_venv(
+            "Creating 'api'".to_string(),
+            Some("api".to_string()),
+            true,
+            pending_job(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_cancel_removes_partial_venv() {
+        let root = tempfile::tempdir().unwrap();
+        let ctx = venvs_in(&root);
+        let partial = root.path().join("api");
+        let mut queue = TaskQueue::default();
+        let id = create_api(&mut queue);
+        queue.start(&ctx);
+        assert_eq!(queue.get(id).unwrap().partial_venv.as_deref(), Some("api"));
+        // What the job created before it was cancelled.
+        std::fs::create_dir_all(&partial).unwrap();
+
+        assert!(queue.cancel(id, &ctx));
+        // The cleanup itself cannot be cancelled.
+        assert!(!queue.cancel(id, &ctx));
+        assert_eq!(
+            queue.get(id).unwrap().progress.phase.as_deref(),
+            Some("Cancelling")
+        );
+        wait_for(&mut queue, id).await;
+        assert_eq!(queue.get(id).unwrap().state, TaskState::Cancelled);
+        assert!(!partial.exists());
+    }
+
+    #[tokio::test]
+    async fn test_partial_venv_decided_when_task_starts() {
+        let root = tempfile::tempdir().unwrap();
+        let ctx = venvs_in(&root);
+        let venv = root.path().join("api");
+        let mut queue = TaskQueue::default();
+        let first = create_api(&mut queue);
+        let retried = create_api(&mut queue);
+        queue.start(&ctx);
+        // The second create of 'api' waits for the first one.
+        assert_eq!(queue.get(first).unwrap().state, TaskState::Running);
+        assert_eq!(queue.get(retried).unwrap().state, TaskState::Queued);
+        assert!(queue.is_creating("api"));
+
+        // The first create owns the 'api' it made, so cancelling it removes it.
+        std::fs::create_dir_all(&venv).unwrap();
+        assert!(queue.cancel(first, &ctx));
+        wait_for(&mut queue, first).await;
+        assert!(!venv.exists());
+
+        // 'api' exists by the time the second one starts, so cancelling it
+        // leaves 'api' alone.
+        std::fs::create_dir_all(&venv).unwrap();
+        queue.start(&ctx);
+        assert_eq!(queue.get(retried).unwrap().state, TaskState::Running);
+        assert_eq!(queue.get(retried).unwrap().partial_venv, None);
+        assert!(queue.cancel(retried, &ctx));
+        wait_for(&mut queue, retried).await;
+        assert!(venv.exists());
+    }
+
+    #[tokio::test]
+    async fn test_retry_rejects_create_already_queued() {
+        let root = tempfile::tempdir().unwrap();
+        let ctx = venvs_in(&root);
+        let mut queue = TaskQueue::default();
+        let cancelled = create_api(&mut queue);
+        assert!(queue.cancel(cancelled, &ctx));
+        assert!(!queue.is_creating("api"));
+        create_api(&mut queue);
+        assert!(!queue.retry(cancelled));
+        assert_eq!(queue.get(cancelled).unwrap().state, TaskState::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_tasks_on_other_venvs_run_in_parallel() {
+        let root = tempfile::tempdir().unwrap();
+        let ctx = venvs_in(&root);
+        let mut queue = TaskQueue::default();
+        create_api(&mut queue);
+        let add = queue.push_on_venv(
+            "Adding packages to 'api'".to_string(),
+            Some("api".to_string()),
+            false,
+            pending_job(),
+        );
+        let web = queue.push_on_venv(
+            "Creating 'web'".to_string(),
+            Some("web".to_string()),
+            true,
+            pending_job(),
+        );
+        queue.start(&ctx);
+        assert_eq!(queue.get(add).unwrap().state, TaskState::Queued);
+        assert_eq!(queue.get(web).unwrap().state, TaskState::Running);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_keeps_other_venvs() {
+        let root = tempfile::tempdir().unwrap();
+        let ctx = Arc::new(Pylot::default().with_venvs_path(root.path().to_str().unwrap()));
+        let existing = root.path().join("api");
+        std::fs::create_dir_all(&existing).unwrap();
+        let mut queue = TaskQueue::default();
+        let id = queue.push("Adding packages to 'api'".to_string(), pending_job());
+        queue.start(&ctx);
+
+        assert!(queue.cancel(id, &ctx));
+        wait_for(&mut queue, id).await;
+        assert_eq!(queue.get(id).unwrap().state, TaskState::Cancelled);
+        assert!(existing.exists());
+    }
+}
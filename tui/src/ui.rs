@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -6,9 +8,14 @@ use ratatui::{
     Frame,
 };
 
+use pylot_shared::{distinfo::PackageDetails, utils};
+
 use crate::create_field::CreateField;
 use crate::dialogs::{ConfirmDialog, PkgDialog};
+use crate::pkg_tree::PkgTree;
 use crate::tabs::Tab;
+use crate::task_progress::PackageState;
+use crate::tasks::{Task, TaskState};
 use crate::{
     app::{App, STATUS_MESSAGE_TIMEOUT_SECS},
     dialogs::HelpDialog,
@@ -27,9 +34,29 @@ pub fn draw(frame: &mut Frame, app: &App) {
 
     draw_tabs(frame, app, chunks[0]);
 
+    // While a task runs, the progress panel takes the bottom of the content.
+    // The Tasks tab shows the output itself.
+    let current = app.tasks.current().filter(|_| app.tab != Tab::Tasks);
+    let content = if let Some(task) = current {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(PROGRESS_PANEL_HEIGHT),
+            ])
+            .split(chunks[1]);
+        draw_progress(frame, task, rows[1]);
+        rows[0]
+    } else {
+        chunks[1]
+    };
+
     match app.tab {
-        Tab::Environments => draw_environments(frame, app, chunks[1]),
-        Tab::UvInfo => draw_uv_info(frame, app, chunks[1]),
+        Tab::Environments => draw_environments(frame, app, content),
+        Tab::UvInfo => draw_uv_info(frame, app, content),
+        Tab::Pythons => draw_pythons(frame, app, content),
+        Tab::Tools => draw_tools(frame, app, content),
+        Tab::Tasks => draw_tasks(frame, app, content),
     }
 
     draw_status_bar(frame, app, chunks[2]);
@@ -84,7 +111,21 @@ fn draw_environments(frame: &mut Frame, app: &App, area: ratatui::layout::Rect)
         .split(area);
 
     // ── Left: venv list with column header ───────────────────────────────────
-    let title = format!(" Virtual Environments ({}) ", app.venvs.len());
+    let visible = app.visible_venvs();
+    let mut title = match app.venv_search {
+        Some(_) => format!(
+            " Virtual Environments ({}/{}) ",
+            visible.len(),
+            app.venvs.len()
+        ),
+        None => format!(" Virtual Environments ({}) ", app.venvs.len()),
+    };
+    if let Some(sort) = app.venv_sort {
+        title.push_str(&format!("· by {} ", sort.as_str().replace('-', " ")));
+    }
+    if let Some(query) = app.venv_search.as_deref().filter(|q| !q.is_empty()) {
+        title.push_str(&format!("· /{} ", query));
+    }
     let outer_block = Block::default().borders(Borders::ALL).title(title);
     let inner_area = outer_block.inner(columns[0]);
     frame.render_widget(outer_block, columns[0]);
@@ -98,32 +139,50 @@ fn draw_environments(frame: &mut Frame, app: &App, area: ratatui::layout::Rect)
     let header_style = Style::default()
         .fg(Color::DarkGray)
         .add_modifier(Modifier::BOLD);
+    // Size the name column to the longest name so the Size column fits in
+    // narrow terminals.
+    let name_width = app
+        .venvs
+        .iter()
+        .map(|venv| venv.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .clamp(4, 22);
     let header = Paragraph::new(Line::from(vec![
         // 7 chars offset matches: 2 (highlight prefix) + 3 (index field) + 2 (". ")
         Span::styled("       ", header_style),
-        Span::styled(format!("{:<22}", "Name"), header_style),
-        Span::styled("  Version", header_style),
+        Span::styled(format!("{:<name_width$}", "Name"), header_style),
+        Span::styled(format!("  {:<8}", "Version"), header_style),
+        Span::styled(format!("{:>9}", "Size"), header_style),
     ]));
     frame.render_widget(header, left_chunks[0]);
 
-    let items: Vec<ListItem> = app
-        .venvs
+    let items: Vec<ListItem> = visible
         .iter()
-        .enumerate()
-        .map(|(i, venv)| {
+        .map(|&i| {
+            let venv = &app.venvs[i];
             let line = Line::from(vec![
                 Span::styled(
                     format!("{:>3}. ", i + 1),
                     Style::default().fg(Color::DarkGray),
                 ),
                 Span::styled(
-                    format!("{:<22}", venv.name.as_ref()),
+                    format!("{:<name_width$}", venv.name.as_ref()),
                     Style::default().fg(Color::Cyan),
                 ),
                 Span::styled(
-                    format!("  {}", venv.python_version),
+                    format!("  {:<8}", venv.python_version),
                     Style::default().fg(Color::Green),
                 ),
+                Span::styled(
+                    format!(
+                        "{:>9}",
+                        venv.disk_usage
+                            .map(|usage| utils::format_size(usage.total()))
+                            .unwrap_or_default()
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                ),
             ]);
             ListItem::new(line)
         })
@@ -138,9 +197,7 @@ fn draw_environments(frame: &mut Frame, app: &App, area: ratatui::layout::Rect)
         .highlight_symbol("> ");
 
     let mut state = ListState::default();
-    if !app.venvs.is_empty() {
-        state.select(Some(app.selected));
-    }
+    state.select(visible.iter().position(|&i| i == app.selected));
 
     frame.render_stateful_widget(list, left_chunks[1], &mut state);
 
@@ -168,7 +225,20 @@ fn draw_venv_detail(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
         return;
     }
 
-    let venv = &app.venvs[app.selected];
+    let Some(venv) = app.selected_venv() else {
+        let lines = vec![
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                "  No environment matches the search.",
+                label_style,
+            )]),
+        ];
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Details ")),
+            area,
+        );
+        return;
+    };
 
     // Render the outer block, using the venv name as the title.
     let block_title = format!(" {} ", venv.name);
@@ -184,7 +254,7 @@ fn draw_venv_detail(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     frame.render_widget(outer_block, area);
 
     // Split inner area: fixed metadata section + packages list
-    let meta_height = 6u16; // python + blank + location + blank + packages header + divider
+    let meta_height = 6u16; // python + size + location + blank + packages header + divider
     let inner_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(meta_height), Constraint::Min(0)])
@@ -211,8 +281,17 @@ fn draw_venv_detail(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
         venv.installed_packages.iter().collect()
     };
 
+    let tree = app
+        .pkg_tree
+        .as_ref()
+        .filter(|t| t.venv_name == venv.name.as_ref());
+
     let total_pkg_count = venv.installed_packages.len();
-    let display_count = if search_active && !search_query.is_empty() {
+    let display_count = if let Some(tree) = tree {
+        format!("  Dependency tree ({})  ", tree.package_count())
+    } else if venv.package_count.is_none() {
+        "  Packages (reading…)  ".to_string()
+    } else if search_active && !search_query.is_empty() {
         format!(
             "  Packages ({}/{})  ",
             filtered_packages.len(),
@@ -224,10 +303,30 @@ fn draw_venv_detail(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
 
     let pkg_scroll_hint = if search_active {
         format!("/{}█", search_query)
+    } else if tree.is_some() {
+        "[space] fold  [t] list".to_string()
     } else {
         "[j] down  [k] up".to_string()
     };
 
+    // Outdated marker: shown once the venv has been checked with [o].
+    let outdated_span = if app.is_outdated_loading() {
+        Span::styled("checking for updates…  ", label_style)
+    } else {
+        match app.outdated.get(venv.name.as_ref()) {
+            Some(pkgs) if pkgs.is_empty() => {
+                Span::styled("up to date  ", Style::default().fg(Color::Green))
+            }
+            Some(pkgs) => Span::styled(
+                format!("{} outdated  ", pkgs.len()),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            None => Span::raw(""),
+        }
+    };
+
     let divider = "─".repeat(inner_chunks[0].width.saturating_sub(2) as usize);
 
     let meta_lines = vec![
@@ -235,12 +334,44 @@ fn draw_venv_detail(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
             Span::styled("  Python   : ", label_style),
             Span::styled(version_text, Style::default().fg(Color::Green)),
         ]),
-        Line::from(""),
+        match venv.disk_usage {
+            Some(usage) => Line::from(vec![
+                Span::styled("  Size     : ", label_style),
+                Span::styled(
+                    utils::format_size(usage.total()),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    format!(
+                        "  (site-packages {}, bin {}, cache {})",
+                        utils::format_size(usage.site_packages),
+                        utils::format_size(usage.bin),
+                        utils::format_size(usage.cache)
+                    ),
+                    label_style,
+                ),
+            ]),
+            None => Line::from(""),
+        },
         Line::from(vec![
             Span::styled("  Location : ", label_style),
             Span::styled(display_path, Style::default().fg(Color::White)),
         ]),
-        Line::from(""),
+        match tree {
+            // In tree mode, explain why the highlighted package is installed.
+            Some(tree) => {
+                let required_by = tree.required_by();
+                Line::from(vec![
+                    Span::styled("  Required : ", label_style),
+                    if required_by.is_empty() {
+                        Span::styled("top-level", Style::default().fg(Color::Green))
+                    } else {
+                        Span::styled(required_by.join(", "), Style::default().fg(Color::Cyan))
+                    },
+                ])
+            }
+            None => Line::from(""),
+        },
         Line::from(vec![
             Span::styled(
                 display_count,
@@ -248,6 +379,7 @@ fn draw_venv_detail(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                     .fg(Color::Magenta)
                     .add_modifier(Modifier::BOLD),
             ),
+            outdated_span,
             if search_active {
                 Span::styled(
                     pkg_scroll_hint,
@@ -263,6 +395,24 @@ fn draw_venv_detail(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     ];
     frame.render_widget(Paragraph::new(meta_lines), inner_chunks[0]);
 
+    // Metadata of the highlighted package below the list, when there is room.
+    let pkg_area = match app.highlighted_details() {
+        Some(details) if inner_chunks[1].height >= PKG_DETAILS_HEIGHT + 3 => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(PKG_DETAILS_HEIGHT)])
+                .split(inner_chunks[1]);
+            draw_pkg_details(frame, details, chunks[1]);
+            chunks[0]
+        }
+        _ => inner_chunks[1],
+    };
+
+    if let Some(tree) = tree {
+        draw_pkg_tree(frame, app, tree, pkg_area);
+        return;
+    }
+
     // Packages list (scrollable via pkg_scroll, filtered/highlighted when search active)
     let pkg_items: Vec<ListItem> = filtered_packages
         .iter()
@@ -270,7 +420,13 @@ fn draw_venv_detail(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
             // "name version" → render with optional search highlight
             let line = match p.splitn(2, ' ').collect::<Vec<_>>().as_slice() {
                 [name, version] => {
-                    if search_active && !search_query.is_empty() {
+                    let outdated_marker = app.outdated_package(&venv.name, name).map(|pkg| {
+                        Span::styled(
+                            format!(" → {}", pkg.latest),
+                            Style::default().fg(Color::Yellow),
+                        )
+                    });
+                    let mut line = if search_active && !search_query.is_empty() {
                         // Highlight matches in name and version
                         let name_spans = highlight_match(
                             &format!("  {}", name),
@@ -298,7 +454,11 @@ fn draw_venv_detail(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                                 Style::default().fg(Color::DarkGray),
                             ),
                         ])
+                    };
+                    if let Some(marker) = outdated_marker {
+                        line.push_span(marker);
                     }
+                    line
                 }
                 _ => Line::from(Span::styled(
                     format!("  {}", p),
@@ -309,10 +469,135 @@ fn draw_venv_detail(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
         })
         .collect();
 
-    let pkg_list = List::new(pkg_items);
-    let pkg_scroll = if search_active { 0 } else { app.pkg_scroll };
-    let mut pkg_state = ListState::default().with_offset(pkg_scroll);
-    frame.render_stateful_widget(pkg_list, inner_chunks[1], &mut pkg_state);
+    let pkg_list = List::new(pkg_items).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    let selected = if search_active {
+        None
+    } else {
+        Some(app.pkg_scroll)
+    };
+    let mut pkg_state = ListState::default().with_selected(selected);
+    frame.render_stateful_widget(pkg_list, pkg_area, &mut pkg_state);
+}
+
+/// Height of the package metadata section, including its divider.
+const PKG_DETAILS_HEIGHT: u16 = 6;
+
+/// Render the metadata of the highlighted package read from its `.dist-info`.
+fn draw_pkg_details(frame: &mut Frame, details: &PackageDetails, area: ratatui::layout::Rect) {
+    let label_style = Style::default().fg(Color::DarkGray);
+    let value_style = Style::default().fg(Color::White);
+    let or_unknown = |value: &Option<String>| value.clone().unwrap_or_else(|| "–".to_string());
+
+    let title = format!("─ {} {} ", details.name, details.version);
+    let divider = format!(
+        "{}{}",
+        title,
+        "─".repeat((area.width as usize).saturating_sub(title.chars().count() + 2))
+    );
+    let scripts = details.scripts();
+    let lines = vec![
+        Line::from(Span::styled(divider, label_style)),
+        Line::from(vec![
+            Span::styled("  Summary  : ", label_style),
+            Span::styled(or_unknown(&details.summary), value_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  License  : ", label_style),
+            Span::styled(or_unknown(&details.license), value_style),
+            Span::styled("   Installer : ", label_style),
+            Span::styled(or_unknown(&details.installer), value_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  Homepage : ", label_style),
+            Span::styled(
+                or_unknown(&details.homepage),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("  Size     : ", label_style),
+            Span::styled(
+                details
+                    .size
+                    .map(utils::format_size)
+                    .unwrap_or_else(|| "–".to_string()),
+                value_style,
+            ),
+            Span::styled("   Installed : ", label_style),
+            if details.requested {
+                Span::styled("explicitly", Style::default().fg(Color::Green))
+            } else {
+                Span::styled("as a dependency", Style::default().fg(Color::Yellow))
+            },
+        ]),
+        Line::from(vec![
+            Span::styled("  Scripts  : ", label_style),
+            if scripts.is_empty() {
+                Span::styled("none", label_style)
+            } else {
+                Span::styled(scripts.join(", "), Style::default().fg(Color::Magenta))
+            },
+        ]),
+    ];
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// Render the collapsible dependency tree in the packages area of the detail pane.
+fn draw_pkg_tree(frame: &mut Frame, app: &App, tree: &PkgTree, area: ratatui::layout::Rect) {
+    let label_style = Style::default().fg(Color::DarkGray);
+    let items: Vec<ListItem> = tree
+        .rows()
+        .into_iter()
+        .map(|row| {
+            let fold = match (row.has_children, row.collapsed) {
+                (true, true) => "▸ ",
+                (true, false) => "▾ ",
+                (false, _) => "  ",
+            };
+            let mut spans = vec![
+                Span::styled(format!("  {}", row.prefix), label_style),
+                Span::styled(fold, Style::default().fg(Color::Yellow)),
+            ];
+            match &row.version {
+                Some(version) => {
+                    spans.push(Span::styled(
+                        row.name.clone(),
+                        Style::default().fg(Color::Magenta),
+                    ));
+                    spans.push(Span::styled(format!(" {}", version), label_style));
+                }
+                None => spans.push(Span::styled(
+                    format!("{} (missing)", row.name),
+                    Style::default().fg(Color::Red),
+                )),
+            }
+            if !row.spec.is_empty() {
+                spans.push(Span::styled(format!(" [{}]", row.spec), label_style));
+            }
+            if row.cycle {
+                spans.push(Span::styled(" (cycle)", Style::default().fg(Color::Yellow)));
+            }
+            if let Some(pkg) = app.outdated_package(&tree.venv_name, &row.name) {
+                spans.push(Span::styled(
+                    format!(" → {}", pkg.latest),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut state = ListState::default().with_selected(Some(tree.cursor));
+    frame.render_stateful_widget(list, area, &mut state);
 }
 
 /// Split `text` into styled spans, highlighting occurrences of `query` (already lowercase).
@@ -370,16 +655,10 @@ fn draw_uv_info(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
         "Not installed"
     };
 
-    // Extract bare semver from "uv X.Y.Z (hash date)" for comparison.
-    let current_semver: Option<&str> = app.uv_version.as_deref().and_then(|v| {
-        // "uv 0.5.0 (abc 2024-01-01)" → "0.5.0"
-        v.split_whitespace().nth(1)
-    });
-
-    let update_available = matches!(
-        (current_semver, app.uv_latest_version.as_deref()),
-        (Some(cur), Some(latest)) if cur != latest
-    );
+    let update_available = app
+        .uv_info
+        .as_ref()
+        .is_some_and(|info| info.update_available());
 
     let loading = app.is_uv_info_loading();
 
@@ -390,30 +669,57 @@ fn draw_uv_info(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
             Span::styled(status_text, status_style.add_modifier(Modifier::BOLD)),
         ]),
         Line::from(""),
-        Line::from(vec![
-            Span::raw("  Version:  "),
-            Span::styled(
-                if loading {
-                    "..."
-                } else {
-                    app.uv_version.as_deref().unwrap_or("N/A")
-                },
-                Style::default().fg(Color::Cyan),
-            ),
-        ]),
-        Line::from(vec![
-            Span::raw("  Latest:   "),
-            Span::styled(
-                if loading {
-                    "..."
-                } else {
-                    app.uv_latest_version.as_deref().unwrap_or("N/A")
-                },
-                Style::default().fg(Color::Cyan),
-            ),
-        ]),
-        Line::from(""),
     ];
+    let value = |value: Option<String>| {
+        if loading {
+            "...".to_string()
+        } else {
+            value.unwrap_or_else(|| "N/A".to_string())
+        }
+    };
+    let info = app.uv_info.as_ref();
+    let fields = [
+        ("  Version:  ", value(info.map(|i| i.version.to_string()))),
+        (
+            "  Latest:   ",
+            value(info.and_then(|i| i.latest.as_ref()).map(|v| v.to_string())),
+        ),
+        (
+            "  Path:     ",
+            value(info.map(|i| utils::shorten_home_path(&i.path.to_string_lossy()))),
+        ),
+        (
+            "  Method:   ",
+            value(info.map(|i| i.install_method.to_string())),
+        ),
+    ];
+    for (label, text) in fields {
+        lines.push(Line::from(vec![
+            Span::raw(label),
+            Span::styled(text, Style::default().fg(Color::Cyan)),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    if app.uv_installed {
+        let (cache_path, cache_size) = match (&app.uv_cache, loading) {
+            (_, true) => ("...".to_string(), "...".to_string()),
+            (Some(cache), false) => (
+                cache.path.display().to_string(),
+                utils::format_size(cache.size),
+            ),
+            (None, false) => ("N/A".to_string(), "N/A".to_string()),
+        };
+        lines.push(Line::from(vec![
+            Span::raw("  Cache:    "),
+            Span::styled(cache_path, Style::default().fg(Color::Cyan)),
+        ]));
+        lines.push(Line::from(vec![
+            Span::raw("  Size:     "),
+            Span::styled(cache_size, Style::default().fg(Color::Cyan)),
+        ]));
+        lines.push(Line::from(""));
+    }
 
     if update_available {
         lines.push(Line::from(vec![
@@ -425,7 +731,14 @@ fn draw_uv_info(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                format!(" ({})", app.uv_latest_version.as_deref().unwrap_or("")),
+                format!(
+                    " ({})",
+                    app.uv_info
+                        .as_ref()
+                        .and_then(|info| info.latest.as_ref())
+                        .map(|v| v.to_string())
+                        .unwrap_or_default()
+                ),
                 Style::default().fg(Color::Yellow),
             ),
         ]));
@@ -438,6 +751,424 @@ fn draw_uv_info(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     frame.render_widget(paragraph, area);
 }
 
+fn draw_pythons(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(area);
+
+    // ── Left: interpreter list ───────────────────────────────────────────────
+    let installed = app.pythons.iter().filter(|p| p.is_installed()).count();
+    let title = format!(
+        " Python Interpreters ({} installed, {} available) ",
+        installed,
+        app.pythons.len() - installed
+    );
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    let placeholder = if !app.uv_installed {
+        Some("Astral UV is not installed".to_string())
+    } else if app.is_pythons_loading() && app.pythons.is_empty() {
+        Some("Loading...".to_string())
+    } else if let Some(ref e) = app.pythons_error {
+        Some(format!("Error: {}", e))
+    } else if app.pythons.is_empty() {
+        Some("No Python interpreters found".to_string())
+    } else {
+        None
+    };
+    if let Some(text) = placeholder {
+        let paragraph = Paragraph::new(vec![Line::from(""), Line::from(format!("  {}", text))])
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(paragraph, columns[0]);
+        frame.render_widget(
+            Block::default().borders(Borders::ALL).title(" Details "),
+            columns[1],
+        );
+        return;
+    }
+
+    let version_width = app
+        .pythons
+        .iter()
+        .map(|p| p.version.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(7);
+    let items: Vec<ListItem> = app
+        .pythons
+        .iter()
+        .map(|install| {
+            let (marker, marker_style) = if install.is_installed() {
+                ("●", Style::default().fg(Color::Green))
+            } else {
+                ("○", Style::default().fg(Color::DarkGray))
+            };
+            let users = app.python_users(install).len();
+            let mut spans = vec![
+                Span::styled(marker, marker_style),
+                Span::raw(" "),
+                Span::styled(
+                    format!("{:<version_width$}", install.version),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw("  "),
+                Span::styled(
+                    format!("{:<8}", install.implementation),
+                    Style::default().fg(Color::White),
+                ),
+            ];
+            if users > 0 {
+                spans.push(Span::styled(
+                    format!("  {} venv(s)", users),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+    let mut state = ListState::default().with_selected(Some(app.python_selected));
+    frame.render_stateful_widget(list, columns[0], &mut state);
+
+    // ── Right: details of the highlighted interpreter ────────────────────────
+    let mut lines = vec![Line::from("")];
+    if let Some(install) = app.selected_python() {
+        let label = Style::default().fg(Color::DarkGray);
+        let (status, status_style) = if install.is_installed() {
+            ("Installed", Style::default().fg(Color::Green))
+        } else {
+            ("Available for download", Style::default().fg(Color::Yellow))
+        };
+        lines.push(Line::from(vec![
+            Span::styled("  Version : ", label),
+            Span::styled(install.version.clone(), Style::default().fg(Color::Cyan)),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("  Key     : ", label),
+            Span::raw(install.key.clone()),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("  Status  : ", label),
+            Span::styled(status, status_style),
+        ]));
+        if let Some(ref path) = install.path {
+            lines.push(Line::from(vec![
+                Span::styled("  Path    : ", label),
+                Span::raw(utils::shorten_home_path(path)),
+            ]));
+        }
+        lines.push(Line::from(""));
+        let users = app.python_users(install);
+        if users.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  Not used by any managed environment",
+                label,
+            )));
+        } else {
+            lines.push(Line::from(Span::styled("  Used by:", label)));
+            for name in users {
+                lines.push(Line::from(vec![
+                    Span::raw("    - "),
+                    Span::styled(name.clone(), Style::default().fg(Color::Cyan)),
+                ]));
+            }
+        }
+    }
+    let details =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Details "));
+    frame.render_widget(details, columns[1]);
+}
+
+fn draw_tools(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(area);
+
+    // ── Left: tool list ──────────────────────────────────────────────────────
+    let title = format!(" Tools ({} installed) ", app.tools.len());
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    let placeholder = if !app.uv_installed {
+        Some("Astral UV is not installed".to_string())
+    } else if app.is_tools_loading() && app.tools.is_empty() {
+        Some("Loading...".to_string())
+    } else if let Some(ref e) = app.tools_error {
+        Some(format!("Error: {}", e))
+    } else if app.tools.is_empty() {
+        Some("No tools installed".to_string())
+    } else {
+        None
+    };
+    if let Some(text) = placeholder {
+        let paragraph = Paragraph::new(vec![Line::from(""), Line::from(format!("  {}", text))])
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(paragraph, columns[0]);
+        frame.render_widget(
+            Block::default().borders(Borders::ALL).title(" Details "),
+            columns[1],
+        );
+        return;
+    }
+
+    let name_width = app
+        .tools
+        .iter()
+        .map(|t| t.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let items: Vec<ListItem> = app
+        .tools
+        .iter()
+        .map(|tool| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<name_width$}", tool.name),
+                    Style::default().fg(Color::White),
+                ),
+                Span::raw("  "),
+                Span::styled(tool.version.clone(), Style::default().fg(Color::Cyan)),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+    let mut state = ListState::default().with_selected(Some(app.tool_selected));
+    frame.render_stateful_widget(list, columns[0], &mut state);
+
+    // ── Right: details of the highlighted tool ───────────────────────────────
+    let mut lines = vec![Line::from("")];
+    if let Some(tool) = app.selected_tool() {
+        let label = Style::default().fg(Color::DarkGray);
+        lines.push(Line::from(vec![
+            Span::styled("  Tool    : ", label),
+            Span::raw(tool.name.clone()),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("  Version : ", label),
+            Span::styled(tool.version.clone(), Style::default().fg(Color::Cyan)),
+        ]));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("  Executables:", label)));
+        for exe in &tool.executables {
+            lines.push(Line::from(vec![
+                Span::raw("    - "),
+                Span::styled(exe.clone(), Style::default().fg(Color::Cyan)),
+            ]));
+        }
+    }
+    let details =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Details "));
+    frame.render_widget(details, columns[1]);
+}
+
+fn draw_tasks(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(area);
+
+    // ── Left: task list ──────────────────────────────────────────────────────
+    let title = format!(
+        " Tasks ({} running, {} queued) ",
+        app.tasks.running(),
+        app.tasks.queued()
+    );
+    let block = Block::default().borders(Borders::ALL).title(title);
+    if app.tasks.tasks.is_empty() {
+        let paragraph = Paragraph::new(vec![Line::from(""), Line::from("  No tasks yet")])
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(paragraph, columns[0]);
+        frame.render_widget(
+            Block::default().borders(Borders::ALL).title(" Output "),
+            columns[1],
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .tasks
+        .tasks
+        .iter()
+        .map(|task| {
+            let (mark, color) = task_mark(&task.state);
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", mark), Style::default().fg(color)),
+                Span::styled(task.label.clone(), Style::default().fg(Color::White)),
+                Span::styled(
+                    task.duration()
+                        .map(|d| format!("  {}", format_duration(d)))
+                        .unwrap_or_default(),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+    let mut state = ListState::default().with_selected(Some(app.task_selected));
+    frame.render_stateful_widget(list, columns[0], &mut state);
+
+    // ── Right: state, error and output of the highlighted task ───────────────
+    let Some(task) = app.selected_task() else {
+        return;
+    };
+    let label = Style::default().fg(Color::DarkGray);
+    let (state, color) = match &task.state {
+        TaskState::Queued => ("Queued", Color::DarkGray),
+        TaskState::Running if task.cancelling => ("Cancelling", Color::Yellow),
+        TaskState::Running => ("Running", Color::Yellow),
+        TaskState::Succeeded => ("Succeeded", Color::Green),
+        TaskState::Failed(_) => ("Failed", Color::Red),
+        TaskState::Cancelled => ("Cancelled", Color::DarkGray),
+    };
+    let mut lines = vec![Line::from(vec![
+        Span::styled("  State    : ", label),
+        Span::styled(state, Style::default().fg(color)),
+    ])];
+    if let Some(duration) = task.duration() {
+        lines.push(Line::from(vec![
+            Span::styled("  Duration : ", label),
+            Span::raw(format_duration(duration)),
+        ]));
+    }
+    if let TaskState::Failed(e) = &task.state {
+        lines.push(Line::from(vec![
+            Span::styled("  Error    : ", label),
+            Span::styled(e.clone(), Style::default().fg(Color::Red)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    // Keep the end of the output in view.
+    let visible = (columns[1].height.saturating_sub(2) as usize).saturating_sub(lines.len());
+    let log = &task.progress.log;
+    lines.extend(
+        log.iter()
+            .skip(log.len().saturating_sub(visible))
+            .map(|line| {
+                Line::from(Span::styled(
+                    format!("  {}", line),
+                    Style::default().fg(Color::Gray),
+                ))
+            }),
+    );
+    let output = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", task.label)),
+    );
+    frame.render_widget(output, columns[1]);
+}
+
+/// Status mark and colour of a task in the task list.
+fn task_mark(state: &TaskState) -> (&'static str, Color) {
+    match state {
+        TaskState::Queued => ("…", Color::DarkGray),
+        TaskState::Running => ("▶", Color::Yellow),
+        TaskState::Succeeded => ("✓", Color::Green),
+        TaskState::Failed(_) => ("✗", Color::Red),
+        TaskState::Cancelled => ("⊘", Color::DarkGray),
+    }
+}
+
+/// `"4.2s"` under a minute, `"2m 05s"` above.
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Height of the progress panel shown while a background task runs.
+const PROGRESS_PANEL_HEIGHT: u16 = 10;
+
+/// Packages the running task downloaded or installed (left) and the tail of
+/// its output (right).
+fn draw_progress(frame: &mut Frame, task: &Task, area: ratatui::layout::Rect) {
+    let progress = &task.progress;
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(area);
+
+    let title = match &progress.phase {
+        Some(phase) => format!(" Packages ({}) · {} ", progress.installed(), phase),
+        None => format!(" Packages ({}) ", progress.installed()),
+    };
+    let items: Vec<ListItem> = progress
+        .packages
+        .iter()
+        .map(|package| {
+            let (mark, color, size) = match package.state {
+                PackageState::Downloading(bytes) => (
+                    "↓",
+                    Color::Yellow,
+                    bytes.map(utils::format_size).unwrap_or_default(),
+                ),
+                PackageState::Installed => ("✓", Color::Green, String::new()),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!(" {} ", mark), Style::default().fg(color)),
+                Span::raw(package.name.as_str()),
+                Span::styled(format!(" {}", size), Style::default().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+    // Keep the latest packages in view.
+    let visible = columns[0].height.saturating_sub(2) as usize;
+    let skip = items.len().saturating_sub(visible);
+    let list = List::new(items.into_iter().skip(skip).collect::<Vec<_>>())
+        .block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(list, columns[0]);
+
+    let mut title = format!(
+        " {} ",
+        progress.step.as_deref().unwrap_or(task.label.as_str())
+    );
+    if progress.warnings > 0 {
+        title.push_str(&format!("· {} warning(s) ", progress.warnings));
+    }
+    let visible = columns[1].height.saturating_sub(2) as usize;
+    let lines: Vec<Line> = progress
+        .log
+        .iter()
+        .skip(progress.log.len().saturating_sub(visible))
+        .map(|line| {
+            Line::from(Span::styled(
+                line.as_str(),
+                Style::default().fg(Color::Gray),
+            ))
+        })
+        .collect();
+    let log = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(log, columns[1]);
+}
+
 fn draw_status_bar(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     // Priority 1: show a one-shot status message (success or error from a background task).
     if let Some((ref msg, is_error, set_at)) = app.status_message {
@@ -460,7 +1191,16 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     }
 
     // Priority 2: show the background task name while a task is running.
-    if let Some(ref task_name) = app.bg_task_name {
+    if let Some(task) = app.tasks.current() {
+        let others = app.tasks.running() - 1;
+        let queued = app.tasks.queued();
+        let mut more = Vec::new();
+        if others > 0 {
+            more.push(format!("+{} running", others));
+        }
+        if queued > 0 {
+            more.push(format!("{} queued", queued));
+        }
         let spans = vec![
             Span::styled(
                 " ⏳ Running: ",
@@ -468,9 +1208,25 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(task_name.as_str(), Style::default().fg(Color::Cyan)),
+            Span::styled(task.label.as_str(), Style::default().fg(Color::Cyan)),
             Span::styled("…", Style::default().fg(Color::Yellow)),
-            Span::styled("  (q: quit)", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                task.progress
+                    .phase
+                    .as_deref()
+                    .map(|phase| format!(" {}", phase))
+                    .unwrap_or_default(),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(
+                if more.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({})", more.join(", "))
+                },
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled("  (Esc / q: cancel)", Style::default().fg(Color::DarkGray)),
         ];
         let bar = Paragraph::new(Line::from(spans)).alignment(Alignment::Center);
         frame.render_widget(bar, area);
@@ -500,9 +1256,7 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
 
     // When the create dialog is open, show dialog-specific hints instead of the normal bar.
     if let Some(ref d) = app.create_dialog {
-        let completions_active =
-            d.field == crate::create_field::CreateField::ReqFile && !d.completions.is_empty();
-        let spans = if completions_active {
+        let spans = if d.completions_active() {
             vec![
                 Span::styled("↑↓", Style::default().fg(Color::Yellow)),
                 Span::raw(": navigate completions  "),
@@ -549,6 +1303,29 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
         return;
     }
 
+    // While the environment search is typed, show the query and its syntax.
+    if let Some(query) = app.venv_search.as_deref().filter(|_| app.venv_search_input) {
+        let key_style = Style::default().fg(Color::Yellow);
+        let spans = vec![
+            Span::styled(
+                format!("/{}█", query),
+                key_style.add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("  "),
+            Span::styled("python:", key_style),
+            Span::raw("<version>  "),
+            Span::styled("has:", key_style),
+            Span::raw("<package>  "),
+            Span::styled("Enter", key_style),
+            Span::raw(": keep  "),
+            Span::styled("Esc", key_style),
+            Span::raw(": clear"),
+        ];
+        let help = Paragraph::new(Line::from(spans)).alignment(Alignment::Center);
+        frame.render_widget(help, area);
+        return;
+    }
+
     // When package search is active, show search hints (including other venvs).
     if let Some(ref query) = app.pkg_search {
         let query_lower = query.to_lowercase();
@@ -571,12 +1348,12 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
 
         let mut spans = vec![
             Span::styled(
-                "/",
+                "f",
                 Style::default()
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw(": search  "),
+            Span::raw(": search packages  "),
             Span::styled("Enter/Esc", Style::default().fg(Color::Yellow)),
             Span::raw(": close"),
         ];
@@ -609,6 +1386,10 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
             spans.push(Span::styled("n", Style::default().fg(Color::Yellow)));
             spans.push(Span::raw(": new  "));
             if !app.venvs.is_empty() {
+                spans.push(Span::styled("/", Style::default().fg(Color::Yellow)));
+                spans.push(Span::raw(": search  "));
+            }
+            if app.selected_venv().is_some() {
                 spans.push(Span::styled("d", Style::default().fg(Color::Yellow)));
                 spans.push(Span::raw(": delete  "));
                 spans.push(Span::styled("Enter", Style::default().fg(Color::Yellow)));
@@ -617,17 +1398,50 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                 spans.push(Span::raw(": add / remove pkgs  "));
             }
         }
+        Tab::Pythons => {
+            if let Some(install) = app.selected_python() {
+                if install.is_installed() {
+                    spans.push(Span::styled("d", Style::default().fg(Color::Yellow)));
+                    spans.push(Span::raw(": uninstall  "));
+                } else {
+                    spans.push(Span::styled("i", Style::default().fg(Color::Yellow)));
+                    spans.push(Span::raw(": install  "));
+                }
+            }
+        }
+        Tab::Tools => {
+            if app.selected_tool().is_some() {
+                spans.push(Span::styled("u / U", Style::default().fg(Color::Yellow)));
+                spans.push(Span::raw(": upgrade / all  "));
+                spans.push(Span::styled("d", Style::default().fg(Color::Yellow)));
+                spans.push(Span::raw(": uninstall  "));
+            }
+        }
         Tab::UvInfo => {
             if app.uv_installed {
                 spans.push(Span::styled("u", Style::default().fg(Color::Yellow)));
                 spans.push(Span::raw(": update  "));
                 spans.push(Span::styled("d", Style::default().fg(Color::Yellow)));
                 spans.push(Span::raw(": uninstall  "));
+                spans.push(Span::styled("p", Style::default().fg(Color::Yellow)));
+                spans.push(Span::raw(": prune cache  "));
             } else {
                 spans.push(Span::styled("i", Style::default().fg(Color::Yellow)));
                 spans.push(Span::raw(": install  "));
             }
         }
+        Tab::Tasks => {
+            if let Some(task) = app.selected_task() {
+                if task.can_retry() {
+                    spans.push(Span::styled("r", Style::default().fg(Color::Yellow)));
+                    spans.push(Span::raw(": retry  "));
+                }
+                if task.is_active() {
+                    spans.push(Span::styled("c", Style::default().fg(Color::Yellow)));
+                    spans.push(Span::raw(": cancel  "));
+                }
+            }
+        }
     }
 
     let help = Paragraph::new(Line::from(spans)).alignment(Alignment::Center);
@@ -639,7 +1453,7 @@ const COMPLETION_MAX_SHOWN: usize = 6;
 
 /// Render the create-venv dialog as a centered overlay popup.
 fn draw_create_dialog(frame: &mut Frame, dialog: &crate::create_dialog::CreateDialog) {
-    let completions_active = dialog.field == CreateField::ReqFile && !dialog.completions.is_empty();
+    let completions_active = dialog.completions_active();
 
     // Compute the visible window into the completions list.
     let total = dialog.completions.len();
@@ -662,8 +1476,8 @@ fn draw_create_dialog(frame: &mut Frame, dialog: &crate::create_dialog::CreateDi
         rows as u16
     } else {
         0
-    };
-    let area = centered_rect(60, 17 + extra_height, frame.area());
+    } + u16::from(dialog.error.is_some());
+    let area = centered_rect(60, 18 + extra_height, frame.area());
 
     // Clear the background so the dialog appears cleanly over other widgets.
     frame.render_widget(Clear, area);
@@ -727,6 +1541,10 @@ fn draw_create_dialog(frame: &mut Frame, dialog: &crate::create_dialog::CreateDi
                 Span::raw("")
             },
         ]),
+        Line::from(vec![
+            Span::raw("                "),
+            Span::styled("e.g. 3.12, >=3.10, pypy@3.10 or a path", hint_style),
+        ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("  Packages    : ", label_style(CreateField::Packages)),
@@ -752,13 +1570,15 @@ fn draw_create_dialog(frame: &mut Frame, dialog: &crate::create_dialog::CreateDi
         ]),
     ];
 
-    // Show directory completions with scroll indicators.
+    // Show the completion dropdown with scroll indicators, below the field it
+    // completes: Python versions or directory entries.
+    let mut dropdown = Vec::new();
     if completions_active {
-        lines.push(Line::from(""));
+        dropdown.push(Line::from(""));
 
         // ▲ indicator when entries are scrolled past above.
         if more_above {
-            lines.push(Line::from(vec![
+            dropdown.push(Line::from(vec![
                 Span::raw("    "),
                 Span::styled(format!("▲ {} more above", scroll), hint_style),
             ]));
@@ -779,7 +1599,7 @@ fn draw_create_dialog(frame: &mut Frame, dialog: &crate::create_dialog::CreateDi
             } else {
                 ("    ", Style::default().fg(Color::Blue))
             };
-            lines.push(Line::from(vec![
+            dropdown.push(Line::from(vec![
                 Span::styled(prefix, entry_style),
                 Span::styled(entry.as_str(), entry_style),
             ]));
@@ -787,13 +1607,31 @@ fn draw_create_dialog(frame: &mut Frame, dialog: &crate::create_dialog::CreateDi
 
         // ▼ indicator when more entries exist below the visible window.
         if more_below {
-            lines.push(Line::from(vec![
+            dropdown.push(Line::from(vec![
                 Span::raw("    "),
                 Span::styled(format!("▼ {} more below", total - visible_end), hint_style),
             ]));
         }
     }
 
+    // The version field and its hint are lines 3 and 4; its error and
+    // dropdown go right below them.
+    if let Some(ref error) = dialog.error {
+        lines.insert(
+            5,
+            Line::from(vec![
+                Span::raw("                "),
+                Span::styled(error.as_str(), Style::default().fg(Color::Red)),
+            ]),
+        );
+    }
+    if dialog.field == CreateField::Version {
+        let after_version = if dialog.error.is_some() { 6 } else { 5 };
+        lines.splice(after_version..after_version, dropdown);
+    } else {
+        lines.extend(dropdown);
+    }
+
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
         Span::styled("  Default pkgs: ", label_style(CreateField::DefaultPkgs)),
@@ -989,18 +1827,23 @@ mod tests {
     use crate::app::App;
     use crate::create_dialog::CreateDialog;
     use crate::dialogs::ConfirmDialog;
+    use pylot_shared::processes::Stream;
+    use pylot_shared::progress::ProgressEvent;
+    use pylot_shared::uvctrl::{InstallMethod, UvInfo};
     use pylot_shared::uvvenv::UvVenv;
+    use pylot_shared::Pylot;
     use ratatui::{backend::TestBackend, Terminal};
     use std::borrow::Cow;
     use std::time::Instant;
 
     fn make_app<'a>() -> App<'a> {
-        App::new(vec![], true, Some("uv 0.5.0".to_string()))
+        App::new(vec![], true, None)
     }
 
     fn make_app_with_venvs<'a>() -> App<'a> {
         let venvs = vec![
             UvVenv::new(
+                &Pylot::default(),
                 Cow::Owned("env1".to_string()),
                 "".to_string(),
                 "3.11".to_string(),
@@ -1008,6 +1851,7 @@ mod tests {
                 false,
             ),
             UvVenv::new(
+                &Pylot::default(),
                 Cow::Owned("env2".to_string()),
                 "".to_string(),
                 "3.12".to_string(),
@@ -1015,7 +1859,7 @@ mod tests {
                 false,
             ),
         ];
-        App::new(venvs, true, Some("uv 0.5.0".to_string()))
+        App::new(venvs, true, None)
     }
 
     // ── centered_rect ────────────────────────────────────────────────────────
@@ -1103,6 +1947,50 @@ mod tests {
         terminal.draw(|frame| draw(frame, &app)).unwrap();
     }
 
+    #[test]
+    fn test_draw_environments_size_column_and_breakdown() {
+        use pylot_shared::diskusage::DiskUsage;
+
+        let backend = TestBackend::new(200, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app_with_venvs();
+        app.venvs[0].disk_usage = Some(DiskUsage {
+            site_packages: 3 * 1024 * 1024,
+            bin: 1024,
+            cache: 512,
+            other: 0,
+        });
+        app.toggle_venv_sort();
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("by size"));
+        assert!(text.contains("Size"));
+        assert!(text.contains("3.0 MiB"));
+        assert!(text.contains("(site-packages 3.0 MiB, bin 1.0 KiB, cache 512 B)"));
+    }
+
+    #[test]
+    fn test_draw_environments_with_venv_search() {
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app_with_venvs();
+        app.venv_search = Some("python:3.12".to_string());
+        app.venv_search_input = true;
+        app.apply_venv_search();
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Virtual Environments (1/2)"));
+        assert!(text.contains("/python:3.12"));
+        assert!(text.contains("has:<package>"));
+
+        app.venv_search = Some("nomatch".to_string());
+        app.venv_search_input = false;
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("(0/2)"));
+        assert!(text.contains("No environment matches the search."));
+    }
+
     // ── draw – UV Info tab ───────────────────────────────────────────────────
 
     #[test]
@@ -1123,24 +2011,42 @@ mod tests {
         terminal.draw(|frame| draw(frame, &app)).unwrap();
     }
 
+    fn uv_info(version: &str, latest: Option<&str>) -> UvInfo {
+        UvInfo {
+            version: version.parse().unwrap(),
+            path: std::path::PathBuf::from("/opt/uv/bin/uv"),
+            install_method: InstallMethod::Standalone,
+            latest: latest.map(|v| v.parse().unwrap()),
+        }
+    }
+
     #[test]
     fn test_draw_uv_info_update_available() {
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
-        let mut app = App::new(vec![], true, Some("uv 0.5.0 (abc 2024-01-01)".to_string()));
-        app.uv_latest_version = Some("0.6.0".to_string());
+        let mut app = App::new(vec![], true, Some(uv_info("0.5.0", Some("0.6.0"))));
         app.next_tab();
         terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("0.5.0"));
+        assert!(text.contains("/opt/uv/bin/uv"));
+        assert!(text.contains("standalone installer"));
+        assert!(text.contains("Update available (0.6.0)"));
     }
 
     #[test]
     fn test_draw_uv_info_up_to_date() {
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
-        let mut app = App::new(vec![], true, Some("uv 0.6.0 (abc 2024-06-01)".to_string()));
-        app.uv_latest_version = Some("0.6.0".to_string());
+        let mut app = App::new(vec![], true, Some(uv_info("0.6.0", Some("0.6.0"))));
         app.next_tab();
         terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(!buffer_text(&terminal).contains("Update available"));
+
+        app.uv_info = Some(uv_info("0.6.0", None));
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Latest:   N/A"));
     }
 
     #[test]
@@ -1152,13 +2058,30 @@ mod tests {
         // Simulate a pending uv_info_rx by creating a channel whose sender we
         // immediately drop – the receiver will be Closed on try_recv, but
         // is_uv_info_loading() returns true while the Option is Some.
-        let (_tx, rx) = tokio::sync::oneshot::channel::<(Option<String>, Option<String>)>();
+        let (_tx, rx) = tokio::sync::oneshot::channel();
         app.uv_info_rx = Some(rx);
         assert!(app.is_uv_info_loading());
         app.next_tab();
         terminal.draw(|frame| draw(frame, &app)).unwrap();
     }
 
+    #[test]
+    fn test_draw_uv_info_cache() {
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app();
+        app.uv_cache = Some(pylot_shared::uvcache::CacheInfo {
+            path: std::path::PathBuf::from("/home/dev/.cache/uv"),
+            size: 5 * 1024 * 1024,
+        });
+        app.next_tab();
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Cache:    /home/dev/.cache/uv"));
+        assert!(text.contains("Size:     5.0 MiB"));
+        assert!(text.contains("prune cache"));
+    }
+
     // ── draw – status messages ───────────────────────────────────────────────
 
     #[test]
@@ -1179,16 +2102,103 @@ mod tests {
         terminal.draw(|frame| draw(frame, &app)).unwrap();
     }
 
-    #[test]
-    fn test_draw_with_bg_task_running() {
-        let backend = TestBackend::new(80, 24);
+    fn start_task(app: &mut App, label: &str) -> crate::tasks::TaskId {
+        use futures::FutureExt;
+        let id = app.tasks.push(
+            label.to_string(),
+            std::sync::Arc::new(|_| futures::future::pending().boxed()),
+        );
+        app.tasks.start(&app.ctx);
+        id
+    }
+
+    #[tokio::test]
+    async fn test_draw_with_bg_task_running() {
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app();
+        start_task(&mut app, "Updating UV");
+        start_task(&mut app, "Installing UV");
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Running: Installing UV… (+1 running)"));
+    }
+
+    #[tokio::test]
+    async fn test_draw_progress_panel() {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app();
+        let id = start_task(&mut app, "Adding packages to 'myenv'");
+        let progress = &mut app.tasks.get_mut(id).unwrap().progress;
+        progress.apply(ProgressEvent::Output {
+            stream: Stream::Stderr,
+            line: "Resolved 2 packages in 12ms".to_string(),
+        });
+        progress.apply(ProgressEvent::Downloading {
+            package: "numpy".to_string(),
+            bytes: Some(2 * 1024 * 1024),
+        });
+        progress.apply(ProgressEvent::Installing {
+            package: "six".to_string(),
+        });
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Packages (1) · Installing"));
+        assert!(text.contains("numpy 2.0 MiB"));
+        assert!(text.contains("Adding packages to 'myenv'"));
+        assert!(text.contains("Resolved 2 packages in 12ms"));
+
+        let ctx = app.ctx.clone();
+        app.tasks.cancel(id, &ctx);
+        while app.tasks.poll().is_empty() {
+            tokio::task::yield_now().await;
+        }
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(!buffer_text(&terminal).contains("Resolved 2 packages"));
+    }
+
+    // ── draw – tasks tab ─────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_draw_tasks_tab() {
+        let backend = TestBackend::new(120, 30);
         let mut terminal = Terminal::new(backend).unwrap();
         let mut app = make_app();
-        app.bg_task_name = Some("Installing UV".to_string());
-        // Simulate a busy state by setting a receiver.
-        let (_tx, rx) = tokio::sync::oneshot::channel::<Result<(), String>>();
-        app.bg_rx = Some(rx);
+        app.tab = Tab::Tasks;
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(buffer_text(&terminal).contains("No tasks yet"));
+
+        let id = start_task(&mut app, "Creating 'api'");
+        app.tasks
+            .get_mut(id)
+            .unwrap()
+            .progress
+            .apply(ProgressEvent::Output {
+                stream: Stream::Stderr,
+                line: "Using CPython 3.12.8".to_string(),
+            });
+        let ctx = app.ctx.clone();
+        app.tasks.cancel(id, &ctx);
+        while app.tasks.poll().is_empty() {
+            tokio::task::yield_now().await;
+        }
+        start_task(&mut app, "Adding packages to 'api'");
         terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Tasks (1 running, 0 queued)"));
+        assert!(text.contains("⊘ Creating 'api'"));
+        assert!(text.contains("▶ Adding packages to 'api'"));
+        assert!(text.contains("State    : Cancelled"));
+        assert!(text.contains("Using CPython 3.12.8"));
+        // The Tasks tab shows the output itself, so no progress panel.
+        assert!(!text.contains("Packages (0)"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_millis(4200)), "4.2s");
+        assert_eq!(format_duration(Duration::from_secs(125)), "2m 05s");
     }
 
     // ── draw – confirm dialog overlay ────────────────────────────────────────
@@ -1248,6 +2258,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_draw_create_dialog_version_dropdown_and_error() {
+        use crate::create_field::CreateField;
+        use pylot_shared::uvpython::parse_list;
+        let backend = TestBackend::new(80, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app();
+        let mut dlg = CreateDialog::new("3.1");
+        dlg.field = CreateField::Version;
+        dlg.set_pythons(&parse_list(
+            "cpython-3.13.1-linux-x86_64-gnu  <download available>\n\
+             cpython-3.12.3-linux-x86_64-gnu  /usr/bin/python3.12\n",
+        ));
+        dlg.update_version_completions();
+        dlg.error = Some("Invalid Python version: '3.x'".to_string());
+        app.create_dialog = Some(dlg);
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("▶ 3.13"));
+        assert!(text.contains("3.12"));
+        assert!(text.contains("Invalid Python version: '3.x'"));
+        assert!(text.contains("navigate completions"));
+    }
+
     // ── status-bar branches ──────────────────────────────────────────────────
 
     #[test]
@@ -1310,6 +2344,143 @@ mod tests {
         app.pkg_search = Some("req".to_string());
         terminal.draw(|frame| draw(frame, &app)).unwrap();
     }
+    // ── draw – outdated marker ───────────────────────────────────────────────
+
+    fn buffer_text(terminal: &Terminal<TestBackend>) -> String {
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_draw_outdated_marker_and_count() {
+        use pylot_shared::outdated::OutdatedPackage;
+
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app_with_venvs();
+        app.venvs[0].installed_packages =
+            vec!["flask 3.0.0".to_string(), "requests 2.30.0".to_string()];
+        app.outdated.insert(
+            "env1".to_string(),
+            vec![OutdatedPackage {
+                name: "requests".to_string(),
+                installed: "2.30.0".to_string(),
+                latest: "2.31.0".to_string(),
+            }],
+        );
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("1 outdated"));
+        assert!(text.contains("→ 2.31.0"));
+    }
+
+    #[test]
+    fn test_draw_outdated_up_to_date() {
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app_with_venvs();
+        app.outdated.insert("env1".to_string(), vec![]);
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(buffer_text(&terminal).contains("up to date"));
+    }
+
+    // ── draw – dependency tree mode ──────────────────────────────────────────
+
+    #[test]
+    fn test_draw_pkg_tree() {
+        use crate::pkg_tree::PkgTree;
+        use pylot_shared::deptree::DependencyGraph;
+        use pylot_shared::distinfo::{DistInfo, Requirement};
+
+        let dist = |name: &str, requires: &[&str]| DistInfo {
+            name: name.to_string(),
+            version: "1.0".to_string(),
+            requires: requires
+                .iter()
+                .filter_map(|r| Requirement::parse(r))
+                .collect(),
+            path: Default::default(),
+        };
+        let graph =
+            DependencyGraph::new(vec![dist("flask", &["werkzeug>=3"]), dist("werkzeug", &[])]);
+
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app_with_venvs();
+        let mut tree = PkgTree::new("env1".to_string(), graph);
+        tree.toggle();
+        tree.next();
+        app.pkg_tree = Some(tree);
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Dependency tree (2)"));
+        assert!(text.contains("▾ flask"));
+        assert!(text.contains("└── "));
+        assert!(text.contains("werkzeug 1.0 [>=3]"));
+        assert!(text.contains("Required : flask"));
+    }
+
+    // ── draw – package details ───────────────────────────────────────────────
+
+    #[test]
+    fn test_draw_pkg_details_for_highlighted_package() {
+        use pylot_shared::distinfo::EntryPoint;
+
+        let backend = TestBackend::new(180, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app_with_venvs();
+        app.venvs[0].installed_packages =
+            vec!["black 24.1.0".to_string(), "click 8.1.7".to_string()];
+        app.pkg_details_key = Some(("env1".to_string(), "black".to_string()));
+        app.pkg_details = Some(PackageDetails {
+            name: "black".to_string(),
+            version: "24.1.0".to_string(),
+            summary: Some("The uncompromising code formatter.".to_string()),
+            license: Some("MIT".to_string()),
+            homepage: Some("https://example.org/black".to_string()),
+            installer: Some("uv".to_string()),
+            requested: true,
+            size: Some(1536),
+            entry_points: vec![EntryPoint {
+                group: "console_scripts".to_string(),
+                name: "black".to_string(),
+                value: "black:main".to_string(),
+            }],
+        });
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("─ black 24.1.0 ─"));
+        assert!(text.contains("The uncompromising code formatter."));
+        assert!(text.contains("License  : MIT"));
+        assert!(text.contains("Installer : uv"));
+        assert!(text.contains("https://example.org/black"));
+        assert!(text.contains("Size     : 1.5 KiB"));
+        assert!(text.contains("Installed : explicitly"));
+        assert!(text.contains("Scripts  : black"));
+    }
+
+    #[test]
+    fn test_draw_pkg_details_hidden_for_other_package() {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app_with_venvs();
+        app.venvs[0].installed_packages =
+            vec!["black 24.1.0".to_string(), "click 8.1.7".to_string()];
+        app.pkg_scroll = 1;
+        app.pkg_details_key = Some(("env1".to_string(), "black".to_string()));
+        app.pkg_details = Some(PackageDetails {
+            name: "black".to_string(),
+            ..Default::default()
+        });
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(!buffer_text(&terminal).contains("Summary  :"));
+    }
+
     fn assert_help_dialog_renders(tab: Tab) {
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
@@ -1326,4 +2497,145 @@ mod tests {
     fn draw_renders_help_dialog_overlay_for_uv_info_tab() {
         assert_help_dialog_renders(Tab::UvInfo);
     }
+    #[test]
+    fn draw_renders_help_dialog_overlay_for_pythons_tab() {
+        assert_help_dialog_renders(Tab::Pythons);
+    }
+    #[test]
+    fn draw_renders_help_dialog_overlay_for_tools_tab() {
+        assert_help_dialog_renders(Tab::Tools);
+    }
+
+    // ── Pythons tab ──────────────────────────────────────────────────────────
+
+    fn make_app_with_pythons<'a>() -> App<'a> {
+        use pylot_shared::uvpython::parse_list;
+        let mut app = make_app_with_venvs();
+        let pythons = parse_list(
+            "cpython-3.13.0-linux-x86_64-gnu    <download available>\n\
+             cpython-3.12.3-linux-x86_64-gnu    /opt/python/bin/python3.12\n",
+        );
+        let mut usage = std::collections::HashMap::new();
+        usage.insert(
+            "cpython-3.12.3-linux-x86_64-gnu".to_string(),
+            vec!["env1".to_string(), "env2".to_string()],
+        );
+        app.set_pythons(pythons, usage);
+        app.tab = Tab::Pythons;
+        app
+    }
+
+    #[test]
+    fn test_draw_pythons_list_and_details() {
+        let backend = TestBackend::new(120, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app_with_pythons();
+        app.python_selected = 1;
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("1 installed, 1 available"));
+        assert!(text.contains("3.13.0"));
+        assert!(text.contains("2 venv(s)"));
+        assert!(text.contains("/opt/python/bin/python3.12"));
+        assert!(text.contains("Used by:"));
+        assert!(text.contains("uninstall"));
+    }
+
+    #[test]
+    fn test_draw_pythons_available_shows_install_hint() {
+        let backend = TestBackend::new(120, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let app = make_app_with_pythons();
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Available for download"));
+        assert!(text.contains("Not used by any managed environment"));
+        assert!(text.contains("i: install"));
+    }
+
+    #[test]
+    fn test_draw_pythons_placeholders() {
+        let backend = TestBackend::new(100, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = App::new(vec![], false, None);
+        app.tab = Tab::Pythons;
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(buffer_text(&terminal).contains("Astral UV is not installed"));
+
+        app.uv_installed = true;
+        app.pythons_error = Some("boom".to_string());
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(buffer_text(&terminal).contains("Error: boom"));
+
+        app.pythons_error = None;
+        let (_tx, rx) = tokio::sync::oneshot::channel();
+        app.pythons_rx = Some(rx);
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(buffer_text(&terminal).contains("Loading..."));
+    }
+
+    #[test]
+    fn test_draw_confirm_uninstall_python() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app_with_pythons();
+        app.confirm_dialog = Some(ConfirmDialog::new(ConfirmAction::UninstallPython {
+            key: "cpython-3.12.3-linux-x86_64-gnu".to_string(),
+            version: "3.12.3".to_string(),
+            used_by: vec!["env1".to_string()],
+        }));
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(buffer_text(&terminal).contains("Uninstall Python 3.12.3?"));
+    }
+
+    // ── Tools tab ────────────────────────────────────────────────────────────
+
+    fn make_app_with_tools<'a>() -> App<'a> {
+        let mut app = make_app_with_venvs();
+        app.set_tools(pylot_shared::uvtool::parse_list(
+            "black v24.2.0\n- black\n- blackd\nruff v0.3.0\n- ruff\n",
+        ));
+        app.tab = Tab::Tools;
+        app
+    }
+
+    #[test]
+    fn test_draw_tools_list_and_details() {
+        let backend = TestBackend::new(120, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let app = make_app_with_tools();
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Tools (2 installed)"));
+        assert!(text.contains("24.2.0"));
+        assert!(text.contains("0.3.0"));
+        assert!(text.contains("blackd"));
+        assert!(text.contains("upgrade / all"));
+    }
+
+    #[test]
+    fn test_draw_tools_placeholders() {
+        let backend = TestBackend::new(100, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = App::new(vec![], true, None);
+        app.tab = Tab::Tools;
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(buffer_text(&terminal).contains("No tools installed"));
+
+        app.tools_error = Some("boom".to_string());
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(buffer_text(&terminal).contains("Error: boom"));
+    }
+
+    #[test]
+    fn test_draw_confirm_uninstall_tool() {
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = make_app_with_tools();
+        app.confirm_dialog = Some(ConfirmDialog::new(ConfirmAction::UninstallTool(
+            "ruff".to_string(),
+        )));
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+        assert!(buffer_text(&terminal).contains("Uninstall tool 'ruff'?"));
+    }
 }
@@ -0,0 +1,269 @@
+use std::collections::{BTreeSet, HashSet};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use notify::{RecursiveMode, Watcher as _};
+
+/// How long the watched directories must stay quiet before a burst of changes
+/// is reported, so an install touching hundreds of files refreshes once.
+pub const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Reports the paths that change directly inside the directories it watches,
+/// through the channel it was created with.
+pub trait Watcher: Send {
+    fn watch(&mut self, dir: &Path) -> io::Result<()>;
+    fn unwatch(&mut self, dir: &Path);
+}
+
+/// [`Watcher`] backed by the platform's file notifications (inotify on Linux).
+pub struct NotifyWatcher(notify::RecommendedWatcher);
+
+impl NotifyWatcher {
+    pub fn new() -> io::Result<(Self, mpsc::Receiver<PathBuf>)> {
+        let (tx, rx) = mpsc::channel();
+        let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            // Reads do not change what the TUI shows.
+            let Ok(event) = event else {
+                return;
+            };
+            if !event.kind.is_access() {
+                for path in event.paths {
+                    let _ = tx.send(path);
+                }
+            }
+        })
+        .map_err(io::Error::other)?;
+        Ok((NotifyWatcher(watcher), rx))
+    }
+}
+
+impl Watcher for NotifyWatcher {
+    fn watch(&mut self, dir: &Path) -> io::Result<()> {
+        self.0
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(io::Error::other)
+    }
+
+    fn unwatch(&mut self, dir: &Path) {
+        // The watch is gone already when the directory was deleted.
+        let _ = self.0.unwatch(dir);
+    }
+}
+
+/// Watches the venvs directory, and each venv and its `site-packages`, and
+/// turns the changes into the names of the venvs to reload.
+pub struct VenvWatcher {
+    watcher: Box<dyn Watcher>,
+    events: mpsc::Receiver<PathBuf>,
+    root: PathBuf,
+    watched: HashSet<PathBuf>,
+    pending: BTreeSet<String>,
+    last_event: Option<Instant>,
+}
+
+impl VenvWatcher {
+    /// Watch `root` with `watcher`, whose changes arrive on `events`.
+    pub fn new(
+        mut watcher: Box<dyn Watcher>,
+        events: mpsc::Receiver<PathBuf>,
+        root: &Path,
+    ) -> io::Result<Self> {
+        // Notifications name the resolved path, e.g. `/private/var` on macOS.
+        let root = root.canonicalize()?;
+        watcher.watch(&root)?;
+        Ok(VenvWatcher {
+            watcher,
+            events,
+            root,
+            watched: HashSet::new(),
+            pending: BTreeSet::new(),
+            last_event: None,
+        })
+    }
+
+    /// Watch `root` with the platform watcher.  Returns `None`, after logging
+    /// why, when that is not possible; the TUI then only refreshes after its
+    /// own tasks.
+    pub fn start(root: &Path) -> Option<Self> {
+        match NotifyWatcher::new().and_then(|(w, rx)| Self::new(Box::new(w), rx, root)) {
+            Ok(watcher) => Some(watcher),
+            Err(e) => {
+                log::warn!("Cannot watch '{}': {}", root.display(), e);
+                None
+            }
+        }
+    }
+
+    /// Also watch the venv directory `dir` and its `site-packages`, so
+    /// installs and removals from other terminals are noticed.
+    pub fn watch_venv(&mut self, dir: &Path, site_packages: Option<&Path>) {
+        for path in std::iter::once(dir).chain(site_packages) {
+            let Ok(path) = path.canonicalize() else {
+                continue;
+            };
+            if self.watched.contains(&path) {
+                continue;
+            }
+            match self.watcher.watch(&path) {
+                Ok(()) => {
+                    self.watched.insert(path);
+                }
+                Err(e) => log::warn!("Cannot watch '{}': {}", path.display(), e),
+            }
+        }
+    }
+
+    /// Stop watching the directories of venv `name`.
+    pub fn unwatch_venv(&mut self, name: &str) {
+        let dir = self.root.join(name);
+        let (gone, kept): (HashSet<PathBuf>, HashSet<PathBuf>) = self
+            .watched
+            .drain()
+            .partition(|path| path.starts_with(&dir));
+        self.watched = kept;
+        for path in gone {
+            self.watcher.unwatch(&path);
+        }
+    }
+
+    /// Collect the changes reported since the last call.  Returns the names
+    /// of the changed venvs, sorted, once nothing changed for [`DEBOUNCE`].
+    pub fn changed(&mut self, now: Instant) -> Vec<String> {
+        for path in self.events.try_iter() {
+            if let Some(name) = venv_name(&self.root, &path) {
+                self.pending.insert(name);
+                self.last_event = Some(now);
+            }
+        }
+        match self.last_event {
+            Some(at) if now.duration_since(at) >= DEBOUNCE => {
+                self.last_event = None;
+                std::mem::take(&mut self.pending).into_iter().collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// The venv a changed `path` belongs to: its first component below `root`.
+/// Hidden entries, such as the scan cache, are not venvs.
+fn venv_name(root: &Path, path: &Path) -> Option<String> {
+    match path.strip_prefix(root).ok()?.components().next()? {
+        Component::Normal(name) => {
+            Some(name.to_string_lossy().to_string()).filter(|name| !name.starts_with('.'))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// Records what is watched; tests send the events themselves.
+    #[derive(Clone, Default)]
+    struct FakeWatcher(Arc<Mutex<HashSet<PathBuf>>>);
+
+    impl Watcher for FakeWatcher {
+        fn watch(&mut self, dir: &Path) -> io::Result<()> {
+            self.0.lock().unwrap().insert(dir.to_path_buf());
+            Ok(())
+        }
+
+        fn unwatch(&mut self, dir: &Path) {
+            self.0.lock().unwrap().remove(dir);
+        }
+    }
+
+    fn fake(root: &Path) -> (VenvWatcher, FakeWatcher, mpsc::Sender<PathBuf>) {
+        let fake = FakeWatcher::default();
+        let (tx, rx) = mpsc::channel();
+        let watcher = VenvWatcher::new(Box::new(fake.clone()), rx, root).unwrap();
+        (watcher, fake, tx)
+    }
+
+    #[test]
+    fn test_venv_name() {
+        let root = Path::new("/venvs");
+        assert_eq!(
+            venv_name(root, Path::new("/venvs/api/lib/site-packages/six.py")),
+            Some("api".to_string())
+        );
+        assert_eq!(
+            venv_name(root, Path::new("/venvs/web")),
+            Some("web".to_string())
+        );
+        assert_eq!(venv_name(root, Path::new("/venvs")), None);
+        assert_eq!(
+            venv_name(root, Path::new("/venvs/.pylot-scan-cache.json")),
+            None
+        );
+        assert_eq!(venv_name(root, Path::new("/elsewhere/api")), None);
+    }
+
+    #[test]
+    fn test_changed_is_debounced() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().canonicalize().unwrap();
+        let (mut watcher, _, tx) = fake(&root);
+        let start = Instant::now();
+
+        tx.send(root.join("api/lib/site-packages/numpy")).unwrap();
+        tx.send(root.join("web")).unwrap();
+        assert!(watcher.changed(start).is_empty());
+        tx.send(root.join("api/lib/site-packages/six.py")).unwrap();
+        assert!(watcher.changed(start + DEBOUNCE / 2).is_empty());
+        // Still busy: the last change was less than DEBOUNCE ago.
+        assert!(watcher.changed(start + DEBOUNCE).is_empty());
+
+        assert_eq!(
+            watcher.changed(start + DEBOUNCE * 2),
+            ["api".to_string(), "web".to_string()]
+        );
+        assert!(watcher.changed(start + DEBOUNCE * 4).is_empty());
+    }
+
+    #[test]
+    fn test_watch_and_unwatch_venv() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().canonicalize().unwrap();
+        let site = root.join("api/lib/python3.12/site-packages");
+        std::fs::create_dir_all(&site).unwrap();
+        std::fs::create_dir_all(root.join("web")).unwrap();
+        let (mut watcher, fake, _tx) = fake(&root);
+
+        watcher.watch_venv(&root.join("api"), Some(&site));
+        watcher.watch_venv(&root.join("web"), None);
+        // Missing directories are skipped.
+        watcher.watch_venv(&root.join("gone"), None);
+        let watched = fake.0.lock().unwrap().clone();
+        assert_eq!(
+            watched,
+            HashSet::from([root.clone(), root.join("api"), site, root.join("web")])
+        );
+
+        watcher.unwatch_venv("api");
+        let watched = fake.0.lock().unwrap().clone();
+        assert_eq!(watched, HashSet::from([root.clone(), root.join("web")]));
+    }
+
+    #[test]
+    fn test_notify_watcher_reports_new_venv() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (watcher, rx) = NotifyWatcher::new().unwrap();
+        let mut watcher = VenvWatcher::new(Box::new(watcher), rx, tmp.path()).unwrap();
+
+        std::fs::create_dir(tmp.path().join("api")).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut changed = Vec::new();
+        while changed.is_empty() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(50));
+            changed = watcher.changed(Instant::now());
+        }
+        assert_eq!(changed, ["api".to_string()]);
+    }
+}